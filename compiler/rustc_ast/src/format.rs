@@ -46,6 +46,8 @@ pub struct FormatArgs {
     pub span: Span,
     pub template: Vec<FormatArgsPiece>,
     pub arguments: FormatArguments,
+    /// The custom format specs used in the template, see [`FormatTrait::Custom`].
+    pub custom_specs: Vec<FormatCustomSpec>,
     /// The raw, un-split format string literal, with no escaping or processing.
     ///
     /// Generally only useful for lints that care about the raw bytes the user wrote.
@@ -162,6 +164,17 @@ pub fn ident(&self) -> Option<Ident> {
     }
 }
 
+/// A custom format spec, like `iso8601` in `format_args!("{:iso8601}", date)`.
+///
+/// The argument's type parses the spec itself, at compile time. For that, `expr` evaluates to a
+/// value of a unique unit struct that implements `core::fmt::CustomSpec` with `spec` as its
+/// `SPEC`.
+#[derive(Clone, Encodable, Decodable, Debug)]
+pub struct FormatCustomSpec {
+    pub spec: Symbol,
+    pub expr: P<Expr>,
+}
+
 #[derive(Clone, Encodable, Decodable, Debug, PartialEq, Eq)]
 pub struct FormatPlaceholder {
     /// Index into [`FormatArgs::arguments`].
@@ -215,6 +228,10 @@ pub enum FormatTrait {
     LowerHex,
     /// `{:X}`
     UpperHex,
+    /// `{:iso8601}`, with `#![feature(custom_format_spec)]`.
+    ///
+    /// Index into [`FormatArgs::custom_specs`]. Every custom placeholder has its own spec.
+    Custom(usize),
 }
 
 #[derive(Clone, Encodable, Decodable, Default, Debug, PartialEq, Eq)]
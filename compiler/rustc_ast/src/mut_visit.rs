@@ -1661,7 +1661,7 @@ fn walk_inline_asm_sym<T: MutVisitor>(
 
 fn walk_format_args<T: MutVisitor>(vis: &mut T, fmt: &mut FormatArgs) {
     // FIXME: visit the template exhaustively.
-    let FormatArgs { span, template: _, arguments, uncooked_fmt_str: _ } = fmt;
+    let FormatArgs { span, template: _, arguments, custom_specs, uncooked_fmt_str: _ } = fmt;
     for FormatArgument { kind, expr } in arguments.all_args_mut() {
         match kind {
             FormatArgumentKind::Named(ident) | FormatArgumentKind::Captured(ident) => {
@@ -1671,6 +1671,9 @@ fn walk_format_args<T: MutVisitor>(vis: &mut T, fmt: &mut FormatArgs) {
         }
         vis.visit_expr(expr);
     }
+    for FormatCustomSpec { spec: _, expr } in custom_specs {
+        vis.visit_expr(expr);
+    }
     vis.visit_span(span);
 }
 
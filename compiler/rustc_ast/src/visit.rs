@@ -1139,7 +1139,7 @@ pub fn walk_inline_asm_sym<'a, V: Visitor<'a>>(
 }
 
 pub fn walk_format_args<'a, V: Visitor<'a>>(visitor: &mut V, fmt: &'a FormatArgs) -> V::Result {
-    let FormatArgs { span: _, template: _, arguments, uncooked_fmt_str: _ } = fmt;
+    let FormatArgs { span: _, template: _, arguments, custom_specs, uncooked_fmt_str: _ } = fmt;
     for FormatArgument { kind, expr } in arguments.all_args() {
         match kind {
             FormatArgumentKind::Named(ident) | FormatArgumentKind::Captured(ident) => {
@@ -1149,6 +1149,9 @@ pub fn walk_format_args<'a, V: Visitor<'a>>(visitor: &mut V, fmt: &'a FormatArgs
         }
         try_visit!(visitor.visit_expr(expr));
     }
+    for FormatCustomSpec { spec: _, expr } in custom_specs {
+        try_visit!(visitor.visit_expr(expr));
+    }
     V::Result::output()
 }
 
@@ -191,6 +191,19 @@ fn flatten_format_args(mut fmt: Cow<'_, FormatArgs>) -> Cow<'_, FormatArgs> {
             let new_arg_offset = args.len();
             args.extend(remaining_args);
 
+            // Move the custom format specs of the inner FormatArgs over as well.
+            let custom_spec_offset = fmt.custom_specs.len();
+            fmt.custom_specs.append(&mut fmt2.custom_specs);
+            for piece in &mut fmt2.template {
+                if let FormatArgsPiece::Placeholder(FormatPlaceholder {
+                    format_trait: FormatTrait::Custom(i),
+                    ..
+                }) = piece
+                {
+                    *i += custom_spec_offset;
+                }
+            }
+
             // Correct the indexes that refer to the arguments after the newly inserted arguments.
             for_all_argument_indexes(&mut fmt.template, |index| {
                 if *index >= old_arg_offset {
@@ -230,14 +243,31 @@ enum ArgumentType {
 /// ```text
 ///     <core::fmt::Argument>::new_…(arg)
 /// ```
+///
+/// or, for a custom format spec:
+///
+/// ```text
+///     <core::fmt::Argument>::new_custom(arg, spec)
+/// ```
 fn make_argument<'hir>(
     ctx: &mut LoweringContext<'_, 'hir>,
     sp: Span,
     arg: &'hir hir::Expr<'hir>,
     ty: ArgumentType,
+    custom_specs: &[FormatCustomSpec],
 ) -> hir::Expr<'hir> {
     use ArgumentType::*;
     use FormatTrait::*;
+    if let Format(Custom(i)) = ty {
+        let new_fn = ctx.arena.alloc(ctx.expr_lang_item_type_relative(
+            sp,
+            hir::LangItem::FormatArgument,
+            sym::new_custom,
+        ));
+        let spec = ctx.lower_expr_mut(&custom_specs[i].expr);
+        let args = ctx.arena.alloc_from_iter([*arg, spec]);
+        return ctx.expr_call_mut(sp, new_fn, args);
+    }
     let new_fn = ctx.arena.alloc(ctx.expr_lang_item_type_relative(
         sp,
         hir::LangItem::FormatArgument,
@@ -254,6 +284,7 @@ fn make_argument<'hir>(
             Format(Binary) => sym::new_binary,
             Format(LowerHex) => sym::new_lower_hex,
             Format(UpperHex) => sym::new_upper_hex,
+            Format(Custom(_)) => unreachable!(),
             Usize => sym::from_usize,
         },
     ));
@@ -531,7 +562,7 @@ fn expand_format_args<'hir>(
                     arg_span,
                     hir::ExprKind::AddrOf(hir::BorrowKind::Ref, hir::Mutability::Not, arg),
                 ));
-                make_argument(ctx, placeholder_span, ref_arg, ty)
+                make_argument(ctx, placeholder_span, ref_arg, ty, &fmt.custom_specs)
             },
         ));
         ctx.expr_array_ref(macsp, elements)
@@ -564,7 +595,7 @@ fn expand_format_args<'hir>(
                         Ident::new(sym::integer(arg_index), macsp),
                     ),
                 ));
-                make_argument(ctx, placeholder_span, arg, ty)
+                make_argument(ctx, placeholder_span, arg, ty, &fmt.custom_specs)
             },
         ));
         let elements = ctx.arena.alloc_from_iter(arguments.iter().map(|arg| {
@@ -8,7 +8,7 @@
 use rustc_ast::util::parser::{self, ExprPrecedence, Fixity};
 use rustc_ast::{
     self as ast, BlockCheckMode, FormatAlignment, FormatArgPosition, FormatArgsPiece, FormatCount,
-    FormatDebugHex, FormatSign, FormatTrait, YieldKind, token,
+    FormatCustomSpec, FormatDebugHex, FormatSign, FormatTrait, YieldKind, token,
 };
 
 use crate::pp::Breaks::Inconsistent;
@@ -728,7 +728,10 @@ pub(super) fn print_expr_outer_attr_style(
                 self.word("format_args!");
                 self.popen();
                 self.ibox(0);
-                self.word(reconstruct_format_args_template_string(&fmt.template));
+                self.word(reconstruct_format_args_template_string(
+                    &fmt.template,
+                    &fmt.custom_specs,
+                ));
                 for arg in fmt.arguments.all_args() {
                     self.word_space(",");
                     self.print_expr(&arg.expr, FixupContext::default());
@@ -907,7 +910,10 @@ fn print_capture_clause(&mut self, capture_clause: ast::CaptureBy) {
     }
 }
 
-fn reconstruct_format_args_template_string(pieces: &[FormatArgsPiece]) -> String {
+fn reconstruct_format_args_template_string(
+    pieces: &[FormatArgsPiece],
+    custom_specs: &[FormatCustomSpec],
+) -> String {
     let mut template = "\"".to_string();
     for piece in pieces {
         match piece {
@@ -983,6 +989,7 @@ fn reconstruct_format_args_template_string(pieces: &[FormatArgsPiece]) -> String
                     FormatTrait::Binary => "b",
                     FormatTrait::LowerHex => "x",
                     FormatTrait::UpperHex => "X",
+                    FormatTrait::Custom(i) => custom_specs[i].spec.as_str(),
                 });
                 template.push('}');
             }
@@ -2,10 +2,12 @@
 use rustc_ast::ptr::P;
 use rustc_ast::tokenstream::TokenStream;
 use rustc_ast::{
-    Expr, ExprKind, FormatAlignment, FormatArgPosition, FormatArgPositionKind, FormatArgs,
+    AssocItem, AssocItemKind, AttrVec, Const, ConstItem, DUMMY_NODE_ID, Defaultness, Expr,
+    ExprKind, FormatAlignment, FormatArgPosition, FormatArgPositionKind, FormatArgs,
     FormatArgsPiece, FormatArgument, FormatArgumentKind, FormatArguments, FormatCount,
-    FormatDebugHex, FormatOptions, FormatPlaceholder, FormatSign, FormatTrait, Recovered, StmtKind,
-    token,
+    FormatCustomSpec, FormatDebugHex, FormatOptions, FormatPlaceholder, FormatSign, FormatTrait,
+    Generics, Impl, ImplPolarity, ItemKind, Mutability, Recovered, Safety, StmtKind, VariantData,
+    Visibility, VisibilityKind, token,
 };
 use rustc_data_structures::fx::FxHashSet;
 use rustc_errors::{
@@ -16,7 +18,8 @@
 use rustc_lint_defs::{BufferedEarlyLint, BuiltinLintDiag, LintId};
 use rustc_parse::exp;
 use rustc_parse_format as parse;
-use rustc_span::{BytePos, ErrorGuaranteed, Ident, InnerSpan, Span, Symbol};
+use rustc_span::{BytePos, ErrorGuaranteed, Ident, InnerSpan, Span, Symbol, sym};
+use thin_vec::thin_vec;
 
 use crate::errors;
 use crate::util::{ExprToSpannedString, expr_to_spanned_string};
@@ -403,6 +406,7 @@ enum ArgRef<'a> {
     };
 
     let mut template = Vec::new();
+    let mut custom_specs = Vec::new();
     let mut unfinished_literal = String::new();
     let mut placeholder_index = 0;
 
@@ -459,6 +463,13 @@ enum ArgRef<'a> {
                     "b" => FormatTrait::Binary,
                     "x" => FormatTrait::LowerHex,
                     "X" => FormatTrait::UpperHex,
+                    _ if ecx.ecfg.features.custom_format_spec() => {
+                        let spec = Symbol::intern(format.ty);
+                        let span = format.ty_span.and_then(to_span).unwrap_or(fmt_span);
+                        let expr = make_custom_spec(ecx, ecx.with_def_site_ctxt(span), spec);
+                        custom_specs.push(FormatCustomSpec { spec, expr });
+                        FormatTrait::Custom(custom_specs.len() - 1)
+                    }
                     _ => {
                         invalid_placeholder_type_error(ecx, format.ty, format.ty_span, fmt_span);
                         FormatTrait::Display
@@ -602,10 +613,74 @@ enum ArgRef<'a> {
         span: fmt_span,
         template,
         arguments: args,
+        custom_specs,
         uncooked_fmt_str,
     }))
 }
 
+/// Builds the expression for a custom format spec, a value of a fresh type that carries `spec`:
+///
+/// ```ignore (illustrative)
+/// {
+///     struct Spec;
+///     impl $crate::fmt::CustomSpec for Spec {
+///         const SPEC: &'static str = "spec";
+///     }
+///     Spec
+/// }
+/// ```
+fn make_custom_spec(ecx: &ExtCtxt<'_>, span: Span, spec: Symbol) -> P<Expr> {
+    let ident = Ident::new(sym::Spec, span);
+    let strukt = ecx.item(
+        span,
+        ident,
+        AttrVec::new(),
+        ItemKind::Struct(VariantData::Unit(DUMMY_NODE_ID), Generics::default()),
+    );
+    let str_ty = ecx.ty_ident(span, Ident::new(sym::str, span));
+    let spec_const = P(AssocItem {
+        attrs: AttrVec::new(),
+        id: DUMMY_NODE_ID,
+        span,
+        vis: Visibility {
+            span: span.shrink_to_lo(),
+            kind: VisibilityKind::Inherited,
+            tokens: None,
+        },
+        ident: Ident::new(sym::SPEC, span),
+        kind: AssocItemKind::Const(Box::new(ConstItem {
+            defaultness: Defaultness::Final,
+            generics: Generics::default(),
+            ty: ecx.ty_ref(span, str_ty, Some(ecx.lifetime_static(span)), Mutability::Not),
+            expr: Some(ecx.expr_str(span, spec)),
+            define_opaque: None,
+        })),
+        tokens: None,
+    });
+    let trait_path = ecx.path_global(span, ecx.std_path(&[sym::fmt, sym::CustomSpec]));
+    let imp = ecx.item(
+        span,
+        Ident::empty(),
+        AttrVec::new(),
+        ItemKind::Impl(Box::new(Impl {
+            safety: Safety::Default,
+            polarity: ImplPolarity::Positive,
+            defaultness: Defaultness::Final,
+            constness: Const::No,
+            generics: Generics::default(),
+            of_trait: Some(ecx.trait_ref(trait_path)),
+            self_ty: ecx.ty_ident(span, ident),
+            items: thin_vec![spec_const],
+        })),
+    );
+    let stmts = thin_vec![
+        ecx.stmt_item(span, strukt),
+        ecx.stmt_item(span, imp),
+        ecx.stmt_expr(ecx.expr_ident(span, ident)),
+    ];
+    ecx.expr_block(ecx.block(span, stmts))
+}
+
 fn invalid_placeholder_type_error(
     ecx: &ExtCtxt<'_>,
     ty: &str,
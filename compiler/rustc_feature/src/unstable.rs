@@ -447,6 +447,8 @@ pub fn internal(&self, feature: Symbol) -> bool {
     /// Allows function attribute `#[coverage(on/off)]`, to control coverage
     /// instrumentation of that function.
     (unstable, coverage_attribute, "1.74.0", Some(84605)),
+    /// Allows format specs that the formatted type parses itself, like `{:iso8601}`.
+    (unstable, custom_format_spec, "CURRENT_RUSTC_VERSION", None),
     /// Allows non-builtin attributes in inner attribute position.
     (unstable, custom_inner_attributes, "1.30.0", Some(54726)),
     /// Allows custom test frameworks with `#![test_runner]` and `#[test_case]`.
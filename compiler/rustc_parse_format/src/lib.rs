@@ -700,35 +700,28 @@ fn format(&mut self) -> FormatSpec<'a> {
         }
 
         let ty_span_start = self.current_pos();
-        // Optional radix followed by the actual format specifier
-        if self.consume('x') {
-            if self.consume('?') {
-                spec.debug_hex = Some(DebugHex::Lower);
-                spec.ty = "?";
-            } else {
-                spec.ty = "x";
-            }
-        } else if self.consume('X') {
-            if self.consume('?') {
-                spec.debug_hex = Some(DebugHex::Upper);
-                spec.ty = "?";
-            } else {
-                spec.ty = "X";
-            }
-        } else if self.consume('?') {
-            spec.ty = "?";
-            if let Some(&(_, maybe)) = self.cur.peek() {
-                match maybe {
-                    '#' | 'x' | 'X' => self.suggest_format_parameter(maybe),
-                    _ => (),
+        // The actual format specifier, optionally preceded by a radix for `?`. Any other word
+        // is a custom format specifier, like `iso8601`.
+        spec.ty = self.word();
+        let debug_hex = match spec.ty {
+            "x" => Some(DebugHex::Lower),
+            "X" => Some(DebugHex::Upper),
+            _ => None,
+        };
+        if (spec.ty.is_empty() || debug_hex.is_some()) && self.consume('?') {
+            if debug_hex.is_none() {
+                if let Some(&(_, maybe)) = self.cur.peek() {
+                    match maybe {
+                        '#' | 'x' | 'X' => self.suggest_format_parameter(maybe),
+                        _ => (),
+                    }
                 }
             }
-        } else {
-            spec.ty = self.word();
-            if !spec.ty.is_empty() {
-                let ty_span_end = self.current_pos();
-                spec.ty_span = Some(self.span(ty_span_start, ty_span_end));
-            }
+            spec.debug_hex = debug_hex;
+            spec.ty = "?";
+        } else if debug_hex.is_none() && !spec.ty.is_empty() {
+            let ty_span_end = self.current_pos();
+            spec.ty_span = Some(self.span(ty_span_start, ty_span_end));
         }
         spec
     }
@@ -148,6 +148,25 @@ fn format_type() {
     );
 }
 #[test]
+fn format_type_custom() {
+    same(
+        "{:xml}",
+        &[NextArgument(Box::new(Argument {
+            position: ArgumentImplicitlyIs(0),
+            position_span: InnerSpan { start: 2, end: 2 },
+            format: FormatSpec { ty: "xml", ty_span: Some(InnerSpan::new(3, 6)), ..fmtdflt() },
+        }))],
+    );
+    same(
+        "{:X?}",
+        &[NextArgument(Box::new(Argument {
+            position: ArgumentImplicitlyIs(0),
+            position_span: InnerSpan { start: 2, end: 2 },
+            format: FormatSpec { debug_hex: Some(DebugHex::Upper), ty: "?", ..fmtdflt() },
+        }))],
+    );
+}
+#[test]
 fn format_align_fill() {
     same(
         "{3:>}",
@@ -227,6 +227,7 @@
         ControlFlow,
         Copy,
         Cow,
+        CustomSpec,
         Debug,
         DebugStruct,
         Decodable,
@@ -352,6 +353,7 @@
         RwLock,
         RwLockReadGuard,
         RwLockWriteGuard,
+        SPEC,
         Saturating,
         SeekFrom,
         SelfTy,
@@ -362,6 +364,7 @@
         SliceIter,
         Some,
         SpanCtxt,
+        Spec,
         Stdin,
         String,
         StructuralPartialEq,
@@ -774,6 +777,7 @@
         custom_attribute,
         custom_code_classes_in_docs,
         custom_derive,
+        custom_format_spec,
         custom_inner_attributes,
         custom_mir,
         custom_test_frameworks,
@@ -1415,6 +1419,7 @@
         new,
         new_binary,
         new_const,
+        new_custom,
         new_debug,
         new_debug_noop,
         new_display,
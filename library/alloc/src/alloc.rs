@@ -8,6 +8,11 @@
 use core::hint;
 use core::ptr::{self, NonNull};
 
+#[unstable(feature = "bump_allocator", issue = "none")]
+pub use self::bump::{Bump, BumpScope};
+
+mod bump;
+
 unsafe extern "Rust" {
     // These are the magic symbols to call the global allocator. rustc generates
     // them to call the global allocator if there is a `#[global_allocator]` attribute
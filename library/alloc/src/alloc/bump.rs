@@ -0,0 +1,487 @@
+//! A bump allocator that frees everything at once.
+
+use core::alloc::{AllocError, Allocator, Layout};
+use core::cell::Cell;
+use core::marker::PhantomData;
+use core::ptr::{self, NonNull};
+use core::{cmp, fmt};
+
+use super::Global;
+#[cfg(not(no_global_oom_handling))]
+use super::handle_alloc_error;
+
+/// The size of the first chunk, unless a larger one is needed.
+const PAGE: usize = 4096;
+/// Chunk sizes double up to this size, after which they only grow as far as
+/// a single allocation requires.
+const HUGE_PAGE: usize = 2 * 1024 * 1024;
+
+/// The start of every chunk. Chunks form a list from the newest to the oldest.
+struct ChunkHeader {
+    prev: Option<NonNull<ChunkHeader>>,
+    /// The layout the whole chunk, header included, was allocated with.
+    layout: Layout,
+}
+
+/// A bump allocator, also known as an arena.
+///
+/// A `Bump` carves allocations out of large chunks of memory it requests from
+/// an underlying allocator, `Global` by default, by advancing a pointer. This
+/// makes allocating very cheap, at the cost of not reusing freed memory: the
+/// memory of individual allocations is only reclaimed when the whole arena
+/// is [reset] or dropped, or when a [scope] ends. The exception is the most
+/// recent allocation, which can be freed, grown or shrunk in place.
+///
+/// `Bump` implements [`Allocator`], and is usually used by reference, for
+/// example with [`Vec::new_in`] or [`Box::new_in`]. Values allocated in the
+/// arena are still dropped as usual, but their memory stays in use until the
+/// arena lets go of it.
+///
+/// When a chunk is full, a new one is allocated, twice as large as the
+/// previous one up to 2 MiB, or larger if the allocation requires it.
+///
+/// [reset]: Bump::reset
+/// [scope]: Bump::scope
+/// [`Vec::new_in`]: crate::vec::Vec::new_in
+/// [`Box::new_in`]: crate::boxed::Box::new_in
+///
+/// # Examples
+///
+/// ```
+/// #![feature(allocator_api, bump_allocator)]
+/// use std::alloc::Bump;
+///
+/// let mut bump = Bump::new();
+/// for request in 0..3 {
+///     {
+///         let mut v = Vec::new_in(&bump);
+///         v.extend(0..request);
+///         let b = Box::new_in(v.len(), &bump);
+///         assert_eq!(*b, request);
+///     }
+///     // Frees everything allocated for this request, but keeps the memory
+///     // around for the next one.
+///     bump.reset();
+/// }
+/// ```
+#[unstable(feature = "bump_allocator", issue = "none")]
+pub struct Bump<A: Allocator = Global> {
+    /// The newest chunk, which allocations are bumped from.
+    chunk: Cell<Option<NonNull<ChunkHeader>>>,
+    /// The first free byte of the newest chunk.
+    ptr: Cell<*mut u8>,
+    /// The end of the newest chunk.
+    end: Cell<*mut u8>,
+    alloc: A,
+}
+
+#[unstable(feature = "bump_allocator", issue = "none")]
+unsafe impl<A: Allocator + Send> Send for Bump<A> {}
+
+impl Bump {
+    /// Creates an empty arena, allocating from the global allocator.
+    ///
+    /// This does not allocate until the first allocation is made in the
+    /// arena.
+    #[inline]
+    #[must_use]
+    #[unstable(feature = "bump_allocator", issue = "none")]
+    pub const fn new() -> Self {
+        Self::new_in(Global)
+    }
+
+    /// Creates an arena whose first chunk can hold at least `capacity` bytes,
+    /// allocated from the global allocator.
+    #[cfg(not(no_global_oom_handling))]
+    #[inline]
+    #[must_use]
+    #[unstable(feature = "bump_allocator", issue = "none")]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self::with_capacity_in(capacity, Global)
+    }
+}
+
+impl<A: Allocator> Bump<A> {
+    /// Creates an empty arena, allocating from the given allocator.
+    #[inline]
+    #[unstable(feature = "bump_allocator", issue = "none")]
+    pub const fn new_in(alloc: A) -> Self {
+        Bump {
+            chunk: Cell::new(None),
+            ptr: Cell::new(ptr::null_mut()),
+            end: Cell::new(ptr::null_mut()),
+            alloc,
+        }
+    }
+
+    /// Creates an arena whose first chunk can hold at least `capacity` bytes,
+    /// allocated from the given allocator.
+    #[cfg(not(no_global_oom_handling))]
+    #[unstable(feature = "bump_allocator", issue = "none")]
+    pub fn with_capacity_in(capacity: usize, alloc: A) -> Self {
+        let bump = Self::new_in(alloc);
+        if capacity > 0 {
+            let Ok(layout) = Layout::array::<u8>(capacity) else { capacity_overflow() };
+            if bump.new_chunk(layout).is_err() {
+                handle_alloc_error(layout);
+            }
+        }
+        bump
+    }
+
+    /// Returns a reference to the underlying allocator.
+    #[inline]
+    #[unstable(feature = "bump_allocator", issue = "none")]
+    pub fn allocator(&self) -> &A {
+        &self.alloc
+    }
+
+    /// Returns the total size of the chunks the arena holds, including the
+    /// space that is not used yet.
+    #[unstable(feature = "bump_allocator", issue = "none")]
+    pub fn allocated_bytes(&self) -> usize {
+        let mut total = 0;
+        let mut chunk = self.chunk.get();
+        while let Some(header) = chunk {
+            let header = unsafe { header.as_ref() };
+            total += header.layout.size();
+            chunk = header.prev;
+        }
+        total
+    }
+
+    /// Frees all allocations made in the arena at once.
+    ///
+    /// The newest, and thus largest, chunk is kept for further allocations,
+    /// and all other chunks are returned to the underlying allocator.
+    ///
+    /// Taking `&mut self` ensures that nothing allocated in the arena is
+    /// still in use.
+    #[unstable(feature = "bump_allocator", issue = "none")]
+    pub fn reset(&mut self) {
+        unsafe { self.rewind_to(None, ptr::null_mut()) }
+    }
+
+    /// Opens a scope in the arena, which frees everything allocated through
+    /// it when it is dropped.
+    ///
+    /// The returned [`BumpScope`] is an allocator itself, and borrows the
+    /// arena mutably for its lifetime, so that allocations made before the
+    /// scope are kept, and allocations made in the scope cannot outlive it.
+    /// Scopes can be nested.
+    ///
+    /// Since a nested scope borrows the outer one mutably, the allocations
+    /// made through the outer scope must be gone before opening it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(allocator_api, bump_allocator)]
+    /// use std::alloc::Bump;
+    ///
+    /// let mut bump = Bump::new();
+    /// let mut outer = bump.scope();
+    /// let mut used = 0;
+    /// for i in 0..100 {
+    ///     let inner = outer.scope();
+    ///     let mut scratch = Vec::with_capacity_in(100, &inner);
+    ///     scratch.resize(100, i);
+    ///     // `scratch` and then `inner` are dropped at the end of each
+    ///     // iteration, so every iteration reuses the same memory.
+    ///     if i == 0 {
+    ///         used = inner.allocated_bytes();
+    ///     }
+    ///     assert_eq!(inner.allocated_bytes(), used);
+    /// }
+    /// ```
+    #[unstable(feature = "bump_allocator", issue = "none")]
+    pub fn scope(&mut self) -> BumpScope<'_, A> {
+        BumpScope::new(self)
+    }
+
+    /// Frees the allocations made after the newest chunk was `chunk` and the
+    /// bump pointer was `ptr`. The chunks allocated since then are freed,
+    /// except for the newest one, which is kept for reuse.
+    ///
+    /// # Safety
+    ///
+    /// `chunk` must be one of the arena's chunks, or `None` for rewinding
+    /// all the way, `ptr` must be a bump pointer within it, and nothing
+    /// allocated since then may still be in use.
+    unsafe fn rewind_to(&self, chunk: Option<NonNull<ChunkHeader>>, ptr: *mut u8) {
+        let Some(newest) = self.chunk.get() else { return };
+        if Some(newest) == chunk {
+            self.ptr.set(ptr);
+            return;
+        }
+
+        // Free the chunks between the newest one and `chunk`, and hang the
+        // newest chunk directly off `chunk`.
+        unsafe {
+            let mut cur = (*newest.as_ptr()).prev;
+            while cur != chunk {
+                let header = cur.unwrap();
+                cur = (*header.as_ptr()).prev;
+                self.free_chunk(header);
+            }
+            (*newest.as_ptr()).prev = chunk;
+            self.ptr.set(newest.as_ptr().cast::<u8>().add(size_of::<ChunkHeader>()));
+        }
+    }
+
+    /// # Safety
+    ///
+    /// `header` must be a chunk of this arena that is no longer in the list.
+    unsafe fn free_chunk(&self, header: NonNull<ChunkHeader>) {
+        unsafe {
+            let layout = (*header.as_ptr()).layout;
+            self.alloc.deallocate(header.cast(), layout);
+        }
+    }
+
+    /// Allocates from the newest chunk, if it has room.
+    #[inline]
+    fn try_bump(&self, layout: Layout) -> Option<NonNull<[u8]>> {
+        let ptr = self.ptr.get();
+        let remaining = self.end.get().addr() - ptr.addr();
+        let padding = ptr.addr().wrapping_neg() & (layout.align() - 1);
+        if padding > remaining || layout.size() > remaining - padding {
+            return None;
+        }
+        unsafe {
+            let start = ptr.add(padding);
+            self.ptr.set(start.add(layout.size()));
+            // The newest chunk is not null if it had room for the allocation,
+            // which is not zero-sized.
+            Some(NonNull::slice_from_raw_parts(NonNull::new_unchecked(start), layout.size()))
+        }
+    }
+
+    /// Allocates a new chunk that has room for `layout`, and makes it the
+    /// newest one.
+    fn new_chunk(&self, layout: Layout) -> Result<(), AllocError> {
+        let header = Layout::new::<ChunkHeader>();
+        let (required, _) = header.extend(layout).map_err(|_| AllocError)?;
+        let prev_size = self.chunk.get().map_or(0, |chunk| unsafe { chunk.as_ref().layout.size() });
+        let size = cmp::max(required.size(), prev_size.saturating_mul(2).clamp(PAGE, HUGE_PAGE));
+        let chunk_layout =
+            Layout::from_size_align(size, required.align()).map_err(|_| AllocError)?;
+
+        let memory = self.alloc.allocate(chunk_layout)?;
+        let base = memory.as_non_null_ptr();
+        unsafe {
+            base.cast::<ChunkHeader>()
+                .write(ChunkHeader { prev: self.chunk.get(), layout: chunk_layout });
+            self.chunk.set(Some(base.cast()));
+            self.ptr.set(base.as_ptr().add(header.size()));
+            self.end.set(base.as_ptr().add(memory.len()));
+        }
+        Ok(())
+    }
+
+    #[cold]
+    fn allocate_slow(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+        self.new_chunk(layout)?;
+        Ok(self.try_bump(layout).unwrap())
+    }
+
+    /// Returns `true` if the allocation at `ptr` with `layout` is the most
+    /// recent one, which ends at the bump pointer.
+    #[inline]
+    fn is_last(&self, ptr: NonNull<u8>, layout: Layout) -> bool {
+        ptr.as_ptr().wrapping_add(layout.size()) == self.ptr.get()
+    }
+}
+
+#[cfg(not(no_global_oom_handling))]
+#[cold]
+fn capacity_overflow() -> ! {
+    panic!("capacity overflow");
+}
+
+#[unstable(feature = "bump_allocator", issue = "none")]
+unsafe impl<A: Allocator> Allocator for Bump<A> {
+    #[inline]
+    fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+        if layout.size() == 0 {
+            return Ok(NonNull::slice_from_raw_parts(layout.dangling(), 0));
+        }
+        match self.try_bump(layout) {
+            Some(memory) => Ok(memory),
+            None => self.allocate_slow(layout),
+        }
+    }
+
+    #[inline]
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+        // Only the most recent allocation can be given back.
+        if layout.size() != 0 && self.is_last(ptr, layout) {
+            self.ptr.set(ptr.as_ptr());
+        }
+    }
+
+    unsafe fn grow(
+        &self,
+        ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Result<NonNull<[u8]>, AllocError> {
+        debug_assert!(new_layout.size() >= old_layout.size());
+
+        // Grow the most recent allocation in place, if the chunk has room.
+        if old_layout.size() != 0
+            && self.is_last(ptr, old_layout)
+            && ptr.as_ptr().is_aligned_to(new_layout.align())
+            && new_layout.size() <= self.end.get().addr() - ptr.addr().get()
+        {
+            self.ptr.set(unsafe { ptr.as_ptr().add(new_layout.size()) });
+            return Ok(NonNull::slice_from_raw_parts(ptr, new_layout.size()));
+        }
+
+        let new_ptr = self.allocate(new_layout)?;
+        unsafe {
+            ptr::copy_nonoverlapping(ptr.as_ptr(), new_ptr.as_mut_ptr(), old_layout.size());
+            self.deallocate(ptr, old_layout);
+        }
+        Ok(new_ptr)
+    }
+
+    unsafe fn shrink(
+        &self,
+        ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Result<NonNull<[u8]>, AllocError> {
+        debug_assert!(new_layout.size() <= old_layout.size());
+
+        if new_layout.size() == 0 {
+            unsafe { self.deallocate(ptr, old_layout) };
+            return Ok(NonNull::slice_from_raw_parts(new_layout.dangling(), 0));
+        }
+        if ptr.as_ptr().is_aligned_to(new_layout.align()) {
+            // Give back the freed tail of the most recent allocation.
+            if self.is_last(ptr, old_layout) {
+                self.ptr.set(unsafe { ptr.as_ptr().add(new_layout.size()) });
+            }
+            return Ok(NonNull::slice_from_raw_parts(ptr, new_layout.size()));
+        }
+
+        let new_ptr = self.allocate(new_layout)?;
+        unsafe {
+            ptr::copy_nonoverlapping(ptr.as_ptr(), new_ptr.as_mut_ptr(), new_layout.size());
+            self.deallocate(ptr, old_layout);
+        }
+        Ok(new_ptr)
+    }
+}
+
+#[unstable(feature = "bump_allocator", issue = "none")]
+impl<A: Allocator> Drop for Bump<A> {
+    fn drop(&mut self) {
+        let mut chunk = self.chunk.get();
+        while let Some(header) = chunk {
+            unsafe {
+                chunk = (*header.as_ptr()).prev;
+                self.free_chunk(header);
+            }
+        }
+    }
+}
+
+#[unstable(feature = "bump_allocator", issue = "none")]
+impl Default for Bump {
+    /// Creates an empty arena, allocating from the global allocator.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[unstable(feature = "bump_allocator", issue = "none")]
+impl<A: Allocator> fmt::Debug for Bump<A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Bump").field("allocated_bytes", &self.allocated_bytes()).finish()
+    }
+}
+
+/// A scope in a [`Bump`] arena, which frees everything allocated through it
+/// when it is dropped.
+///
+/// This `struct` is created by [`Bump::scope`] and [`BumpScope::scope`].
+/// See their documentation for more.
+#[unstable(feature = "bump_allocator", issue = "none")]
+pub struct BumpScope<'a, A: Allocator = Global> {
+    bump: &'a Bump<A>,
+    /// The newest chunk and the bump pointer when the scope was opened.
+    chunk: Option<NonNull<ChunkHeader>>,
+    ptr: *mut u8,
+    // Opening a nested scope must borrow this one mutably.
+    marker: PhantomData<&'a mut Bump<A>>,
+}
+
+impl<'a, A: Allocator> BumpScope<'a, A> {
+    fn new(bump: &'a Bump<A>) -> Self {
+        BumpScope { bump, chunk: bump.chunk.get(), ptr: bump.ptr.get(), marker: PhantomData }
+    }
+
+    /// Opens a scope nested in this one. See [`Bump::scope`].
+    #[unstable(feature = "bump_allocator", issue = "none")]
+    pub fn scope(&mut self) -> BumpScope<'_, A> {
+        BumpScope::new(self.bump)
+    }
+
+    /// Returns the total size of the chunks the arena holds. See
+    /// [`Bump::allocated_bytes`].
+    #[unstable(feature = "bump_allocator", issue = "none")]
+    pub fn allocated_bytes(&self) -> usize {
+        self.bump.allocated_bytes()
+    }
+}
+
+#[unstable(feature = "bump_allocator", issue = "none")]
+unsafe impl<A: Allocator> Allocator for BumpScope<'_, A> {
+    #[inline]
+    fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+        self.bump.allocate(layout)
+    }
+
+    #[inline]
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+        unsafe { self.bump.deallocate(ptr, layout) }
+    }
+
+    #[inline]
+    unsafe fn grow(
+        &self,
+        ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Result<NonNull<[u8]>, AllocError> {
+        unsafe { self.bump.grow(ptr, old_layout, new_layout) }
+    }
+
+    #[inline]
+    unsafe fn shrink(
+        &self,
+        ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Result<NonNull<[u8]>, AllocError> {
+        unsafe { self.bump.shrink(ptr, old_layout, new_layout) }
+    }
+}
+
+#[unstable(feature = "bump_allocator", issue = "none")]
+impl<A: Allocator> Drop for BumpScope<'_, A> {
+    fn drop(&mut self) {
+        // Everything allocated through the scope borrowed it, so none of it
+        // is in use anymore.
+        unsafe { self.bump.rewind_to(self.chunk, self.ptr) }
+    }
+}
+
+#[unstable(feature = "bump_allocator", issue = "none")]
+impl<A: Allocator> fmt::Debug for BumpScope<'_, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BumpScope").field("allocated_bytes", &self.allocated_bytes()).finish()
+    }
+}
@@ -0,0 +1,534 @@
+use core::cmp::Ordering;
+use core::iter::FusedIterator;
+use core::{fmt, mem, slice};
+
+use crate::vec::Vec;
+
+/// A priority queue implemented with a binary heap, whose elements can be
+/// found again after insertion.
+///
+/// Each call to [`push`] returns a [`Handle`] that refers to the pushed
+/// element for as long as it stays in the heap. The handle can be used to
+/// look the element up, to [`remove`] it, or to [`change_priority`] in
+/// *O*(log(*n*)) time. This makes `IndexedBinaryHeap` suitable for
+/// algorithms that need a decrease-key operation, where a [`BinaryHeap`]
+/// has to be filled with duplicates and stale entries skipped instead.
+///
+/// The heap is a max-heap when created with [`new`], and a min-heap when
+/// created with [`new_min`], so no [`Reverse`] wrapper is needed to pop the
+/// smallest element first.
+///
+/// Handles are never reused: once an element has left the heap, its handle
+/// is no longer valid, even if another element is stored in its place.
+///
+/// As with [`BinaryHeap`], it is a logic error for an element to be
+/// modified in such a way that its ordering relative to any other element
+/// changes while it is in the heap, other than through [`change_priority`].
+///
+/// [`BinaryHeap`]: super::BinaryHeap
+/// [`Reverse`]: core::cmp::Reverse
+/// [`push`]: IndexedBinaryHeap::push
+/// [`remove`]: IndexedBinaryHeap::remove
+/// [`change_priority`]: IndexedBinaryHeap::change_priority
+/// [`new`]: IndexedBinaryHeap::new
+/// [`new_min`]: IndexedBinaryHeap::new_min
+///
+/// # Examples
+///
+/// [Dijkstra's algorithm] with one entry per node, whose distance is
+/// decreased in place:
+///
+/// ```
+/// #![feature(indexed_binary_heap)]
+/// use std::collections::binary_heap::{Handle, IndexedBinaryHeap};
+///
+/// // `graph[node]` lists the outgoing edges of `node` as `(target, cost)`.
+/// fn shortest_paths(graph: &[Vec<(usize, u32)>], start: usize) -> Vec<Option<u32>> {
+///     let mut dist: Vec<Option<u32>> = vec![None; graph.len()];
+///     let mut handles: Vec<Option<Handle>> = vec![None; graph.len()];
+///     let mut queue = IndexedBinaryHeap::new_min();
+///
+///     dist[start] = Some(0);
+///     queue.push((0, start));
+///     while let Some((cost, node)) = queue.pop() {
+///         for &(next, edge) in &graph[node] {
+///             let new_cost = cost + edge;
+///             if dist[next].is_some_and(|d| d <= new_cost) {
+///                 continue;
+///             }
+///             dist[next] = Some(new_cost);
+///             match handles[next] {
+///                 Some(handle) if queue.contains(handle) => {
+///                     queue.change_priority(handle, (new_cost, next));
+///                 }
+///                 _ => handles[next] = Some(queue.push((new_cost, next))),
+///             }
+///         }
+///     }
+///     dist
+/// }
+///
+/// let graph = vec![
+///     vec![(2, 10), (1, 1)],
+///     vec![(3, 2)],
+///     vec![(1, 1), (3, 3), (4, 1)],
+///     vec![(0, 7), (4, 2)],
+///     vec![],
+/// ];
+/// assert_eq!(shortest_paths(&graph, 0), [Some(0), Some(1), Some(10), Some(3), Some(5)]);
+/// assert_eq!(shortest_paths(&graph, 4), [None, None, None, None, Some(0)]);
+/// ```
+///
+/// [Dijkstra's algorithm]: https://en.wikipedia.org/wiki/Dijkstra%27s_algorithm
+#[unstable(feature = "indexed_binary_heap", issue = "none")]
+pub struct IndexedBinaryHeap<T> {
+    /// The heap proper, with each element next to the index of its slot.
+    data: Vec<(T, usize)>,
+    /// Maps the slot of each handle to the position of its element in `data`.
+    slots: Vec<Slot>,
+    /// The slots not currently used by any element.
+    free: Vec<usize>,
+    /// The ordering an element has relative to its children: `Greater` for
+    /// a max-heap and `Less` for a min-heap.
+    top: Ordering,
+}
+
+#[derive(Clone)]
+struct Slot {
+    /// Counts how many elements have left this slot, so that handles to
+    /// those elements stop matching.
+    generation: usize,
+    /// The position in `data`, or `VACANT`.
+    pos: usize,
+}
+
+const VACANT: usize = usize::MAX;
+
+/// A reference to an element of an [`IndexedBinaryHeap`].
+///
+/// A handle is returned by [`IndexedBinaryHeap::push`], and stays valid
+/// until the element leaves the heap. Using a handle with another heap than
+/// the one that returned it gives unspecified results, but is memory-safe.
+#[unstable(feature = "indexed_binary_heap", issue = "none")]
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Handle {
+    slot: usize,
+    generation: usize,
+}
+
+impl<T: Ord> IndexedBinaryHeap<T> {
+    /// Creates an empty max-heap, which pops its greatest element first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(indexed_binary_heap)]
+    /// use std::collections::binary_heap::IndexedBinaryHeap;
+    ///
+    /// let mut heap = IndexedBinaryHeap::new();
+    /// heap.push(1);
+    /// heap.push(5);
+    /// assert_eq!(heap.pop(), Some(5));
+    /// ```
+    #[unstable(feature = "indexed_binary_heap", issue = "none")]
+    #[must_use]
+    pub const fn new() -> IndexedBinaryHeap<T> {
+        IndexedBinaryHeap::new_ordered(Ordering::Greater)
+    }
+
+    /// Creates an empty min-heap, which pops its smallest element first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(indexed_binary_heap)]
+    /// use std::collections::binary_heap::IndexedBinaryHeap;
+    ///
+    /// let mut heap = IndexedBinaryHeap::new_min();
+    /// heap.push(1);
+    /// heap.push(5);
+    /// assert_eq!(heap.pop(), Some(1));
+    /// ```
+    #[unstable(feature = "indexed_binary_heap", issue = "none")]
+    #[must_use]
+    pub const fn new_min() -> IndexedBinaryHeap<T> {
+        IndexedBinaryHeap::new_ordered(Ordering::Less)
+    }
+
+    /// Creates an empty max-heap with at least the specified capacity.
+    #[unstable(feature = "indexed_binary_heap", issue = "none")]
+    #[must_use]
+    pub fn with_capacity(capacity: usize) -> IndexedBinaryHeap<T> {
+        IndexedBinaryHeap::with_capacity_ordered(capacity, Ordering::Greater)
+    }
+
+    /// Creates an empty min-heap with at least the specified capacity.
+    #[unstable(feature = "indexed_binary_heap", issue = "none")]
+    #[must_use]
+    pub fn with_capacity_min(capacity: usize) -> IndexedBinaryHeap<T> {
+        IndexedBinaryHeap::with_capacity_ordered(capacity, Ordering::Less)
+    }
+
+    const fn new_ordered(top: Ordering) -> IndexedBinaryHeap<T> {
+        IndexedBinaryHeap { data: Vec::new(), slots: Vec::new(), free: Vec::new(), top }
+    }
+
+    fn with_capacity_ordered(capacity: usize, top: Ordering) -> IndexedBinaryHeap<T> {
+        IndexedBinaryHeap {
+            data: Vec::with_capacity(capacity),
+            slots: Vec::with_capacity(capacity),
+            free: Vec::new(),
+            top,
+        }
+    }
+
+    /// Pushes an item onto the heap, and returns a handle to it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(indexed_binary_heap)]
+    /// use std::collections::binary_heap::IndexedBinaryHeap;
+    ///
+    /// let mut heap = IndexedBinaryHeap::new();
+    /// let three = heap.push(3);
+    /// heap.push(5);
+    /// assert_eq!(heap.get(three), Some(&3));
+    /// assert_eq!(heap.peek(), Some(&5));
+    /// ```
+    ///
+    /// # Time complexity
+    ///
+    /// *O*(log(*n*)), plus an amortized *O*(1) for growing the buffers.
+    #[unstable(feature = "indexed_binary_heap", issue = "none")]
+    pub fn push(&mut self, item: T) -> Handle {
+        let pos = self.data.len();
+        let slot = match self.free.pop() {
+            Some(slot) => slot,
+            None => {
+                self.slots.push(Slot { generation: 0, pos: VACANT });
+                self.slots.len() - 1
+            }
+        };
+        self.slots[slot].pos = pos;
+        self.data.push((item, slot));
+        self.sift_up(pos);
+        Handle { slot, generation: self.slots[slot].generation }
+    }
+
+    /// Removes the top element from the heap and returns it, or `None` if
+    /// it is empty.
+    ///
+    /// The top element is the greatest one for a max-heap, and the smallest
+    /// one for a min-heap.
+    ///
+    /// # Time complexity
+    ///
+    /// *O*(log(*n*)).
+    #[unstable(feature = "indexed_binary_heap", issue = "none")]
+    pub fn pop(&mut self) -> Option<T> {
+        self.pop_with_handle().map(|(_, item)| item)
+    }
+
+    /// Removes the top element from the heap and returns it along with the
+    /// handle it had, or `None` if it is empty.
+    #[unstable(feature = "indexed_binary_heap", issue = "none")]
+    pub fn pop_with_handle(&mut self) -> Option<(Handle, T)> {
+        if self.data.is_empty() { None } else { Some(self.remove_at(0)) }
+    }
+
+    /// Removes the element the handle refers to and returns it, or `None`
+    /// if it is no longer in the heap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(indexed_binary_heap)]
+    /// use std::collections::binary_heap::IndexedBinaryHeap;
+    ///
+    /// let mut heap = IndexedBinaryHeap::new();
+    /// let five = heap.push(5);
+    /// heap.push(3);
+    /// assert_eq!(heap.remove(five), Some(5));
+    /// assert_eq!(heap.remove(five), None);
+    /// assert_eq!(heap.pop(), Some(3));
+    /// ```
+    ///
+    /// # Time complexity
+    ///
+    /// *O*(log(*n*)).
+    #[unstable(feature = "indexed_binary_heap", issue = "none")]
+    pub fn remove(&mut self, handle: Handle) -> Option<T> {
+        let pos = self.position(handle)?;
+        Some(self.remove_at(pos).1)
+    }
+
+    /// Replaces the element the handle refers to with `item`, moving it to
+    /// its new place in the heap, and returns the old element.
+    ///
+    /// The new element may be ordered before or after the old one, so this
+    /// covers both decrease-key and increase-key. The handle stays valid.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the handle's element is no longer in the heap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(indexed_binary_heap)]
+    /// use std::collections::binary_heap::IndexedBinaryHeap;
+    ///
+    /// let mut heap = IndexedBinaryHeap::new_min();
+    /// heap.push(("a", 5));
+    /// let b = heap.push(("b", 7));
+    /// assert_eq!(heap.peek(), Some(&("a", 5)));
+    ///
+    /// assert_eq!(heap.change_priority(b, ("0", 7)), ("b", 7));
+    /// assert_eq!(heap.peek(), Some(&("0", 7)));
+    /// ```
+    ///
+    /// # Time complexity
+    ///
+    /// *O*(log(*n*)).
+    #[unstable(feature = "indexed_binary_heap", issue = "none")]
+    pub fn change_priority(&mut self, handle: Handle, item: T) -> T {
+        let pos = self.position(handle).expect("handle is not in the heap");
+        let old = mem::replace(&mut self.data[pos].0, item);
+        if self.data[pos].0.cmp(&old) == self.top {
+            self.sift_up(pos);
+        } else {
+            self.sift_down(pos);
+        }
+        old
+    }
+
+    /// Returns the top element of the heap, or `None` if it is empty.
+    ///
+    /// # Time complexity
+    ///
+    /// *O*(1).
+    #[unstable(feature = "indexed_binary_heap", issue = "none")]
+    #[must_use]
+    pub fn peek(&self) -> Option<&T> {
+        self.data.first().map(|(item, _)| item)
+    }
+
+    /// Returns the top element of the heap along with its handle, or `None`
+    /// if it is empty.
+    #[unstable(feature = "indexed_binary_heap", issue = "none")]
+    #[must_use]
+    pub fn peek_with_handle(&self) -> Option<(Handle, &T)> {
+        self.data.first().map(|(item, slot)| (self.handle(*slot), item))
+    }
+
+    /// Returns the element the handle refers to, or `None` if it is no
+    /// longer in the heap.
+    #[unstable(feature = "indexed_binary_heap", issue = "none")]
+    #[must_use]
+    pub fn get(&self, handle: Handle) -> Option<&T> {
+        self.position(handle).map(|pos| &self.data[pos].0)
+    }
+
+    /// Returns `true` if the element the handle refers to is still in the
+    /// heap.
+    #[unstable(feature = "indexed_binary_heap", issue = "none")]
+    #[must_use]
+    pub fn contains(&self, handle: Handle) -> bool {
+        self.position(handle).is_some()
+    }
+
+    fn position(&self, handle: Handle) -> Option<usize> {
+        let slot = self.slots.get(handle.slot)?;
+        (slot.generation == handle.generation && slot.pos != VACANT).then_some(slot.pos)
+    }
+
+    fn handle(&self, slot: usize) -> Handle {
+        Handle { slot, generation: self.slots[slot].generation }
+    }
+
+    /// Returns `true` if the element at `a` belongs above the one at `b`.
+    fn above(&self, a: usize, b: usize) -> bool {
+        self.data[a].0.cmp(&self.data[b].0) == self.top
+    }
+
+    fn swap(&mut self, a: usize, b: usize) {
+        self.data.swap(a, b);
+        self.slots[self.data[a].1].pos = a;
+        self.slots[self.data[b].1].pos = b;
+    }
+
+    /// Moves the element at `pos` up until its parent belongs above it, and
+    /// returns its new position.
+    fn sift_up(&mut self, mut pos: usize) -> usize {
+        while pos > 0 {
+            let parent = (pos - 1) / 2;
+            if !self.above(pos, parent) {
+                break;
+            }
+            self.swap(pos, parent);
+            pos = parent;
+        }
+        pos
+    }
+
+    /// Moves the element at `pos` down until it belongs above its children.
+    fn sift_down(&mut self, mut pos: usize) {
+        let len = self.data.len();
+        loop {
+            let mut child = 2 * pos + 1;
+            if child >= len {
+                break;
+            }
+            if child + 1 < len && self.above(child + 1, child) {
+                child += 1;
+            }
+            if !self.above(child, pos) {
+                break;
+            }
+            self.swap(pos, child);
+            pos = child;
+        }
+    }
+
+    fn remove_at(&mut self, pos: usize) -> (Handle, T) {
+        let last = self.data.len() - 1;
+        if pos != last {
+            self.swap(pos, last);
+        }
+        let (item, slot) = self.data.pop().unwrap();
+        let handle = self.handle(slot);
+        self.release(slot);
+
+        // The element that took the hole may belong further up or down.
+        if pos < self.data.len() && self.sift_up(pos) == pos {
+            self.sift_down(pos);
+        }
+        (handle, item)
+    }
+}
+
+impl<T> IndexedBinaryHeap<T> {
+    /// Returns the number of elements in the heap.
+    #[unstable(feature = "indexed_binary_heap", issue = "none")]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Checks if the heap is empty.
+    #[unstable(feature = "indexed_binary_heap", issue = "none")]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns `true` if this is a min-heap, created by [`new_min`] or
+    /// [`with_capacity_min`].
+    ///
+    /// [`new_min`]: IndexedBinaryHeap::new_min
+    /// [`with_capacity_min`]: IndexedBinaryHeap::with_capacity_min
+    #[unstable(feature = "indexed_binary_heap", issue = "none")]
+    #[must_use]
+    pub fn is_min_heap(&self) -> bool {
+        self.top == Ordering::Less
+    }
+
+    /// Drops all elements from the heap. Their handles become invalid.
+    #[unstable(feature = "indexed_binary_heap", issue = "none")]
+    pub fn clear(&mut self) {
+        for (_, slot) in mem::take(&mut self.data) {
+            self.release(slot);
+        }
+    }
+
+    /// Returns an iterator visiting all elements in the heap, along with
+    /// their handles, in arbitrary order.
+    #[unstable(feature = "indexed_binary_heap", issue = "none")]
+    pub fn iter(&self) -> IndexedIter<'_, T> {
+        IndexedIter { iter: self.data.iter(), slots: &self.slots }
+    }
+
+    fn release(&mut self, slot: usize) {
+        let slot_ref = &mut self.slots[slot];
+        slot_ref.generation += 1;
+        slot_ref.pos = VACANT;
+        self.free.push(slot);
+    }
+}
+
+#[unstable(feature = "indexed_binary_heap", issue = "none")]
+impl<T: Clone> Clone for IndexedBinaryHeap<T> {
+    fn clone(&self) -> Self {
+        IndexedBinaryHeap {
+            data: self.data.clone(),
+            slots: self.slots.clone(),
+            free: self.free.clone(),
+            top: self.top,
+        }
+    }
+}
+
+#[unstable(feature = "indexed_binary_heap", issue = "none")]
+impl<T: Ord> Default for IndexedBinaryHeap<T> {
+    /// Creates an empty max-heap.
+    #[inline]
+    fn default() -> IndexedBinaryHeap<T> {
+        IndexedBinaryHeap::new()
+    }
+}
+
+#[unstable(feature = "indexed_binary_heap", issue = "none")]
+impl<T: fmt::Debug> fmt::Debug for IndexedBinaryHeap<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.data.iter().map(|(item, _)| item)).finish()
+    }
+}
+
+/// An iterator over the elements of an `IndexedBinaryHeap` and their
+/// handles, in arbitrary order.
+///
+/// This `struct` is created by [`IndexedBinaryHeap::iter()`]. See its
+/// documentation for more.
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+#[unstable(feature = "indexed_binary_heap", issue = "none")]
+pub struct IndexedIter<'a, T: 'a> {
+    iter: slice::Iter<'a, (T, usize)>,
+    slots: &'a [Slot],
+}
+
+#[unstable(feature = "indexed_binary_heap", issue = "none")]
+impl<T> Clone for IndexedIter<'_, T> {
+    fn clone(&self) -> Self {
+        IndexedIter { iter: self.iter.clone(), slots: self.slots }
+    }
+}
+
+#[unstable(feature = "indexed_binary_heap", issue = "none")]
+impl<T: fmt::Debug> fmt::Debug for IndexedIter<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+#[unstable(feature = "indexed_binary_heap", issue = "none")]
+impl<'a, T> Iterator for IndexedIter<'a, T> {
+    type Item = (Handle, &'a T);
+
+    #[inline]
+    fn next(&mut self) -> Option<(Handle, &'a T)> {
+        let (item, slot) = self.iter.next()?;
+        Some((Handle { slot: *slot, generation: self.slots[*slot].generation }, item))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+#[unstable(feature = "indexed_binary_heap", issue = "none")]
+impl<T> ExactSizeIterator for IndexedIter<'_, T> {}
+
+#[unstable(feature = "indexed_binary_heap", issue = "none")]
+impl<T> FusedIterator for IndexedIter<'_, T> {}
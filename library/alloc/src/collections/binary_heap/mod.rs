@@ -150,6 +150,8 @@
 use core::ops::{Deref, DerefMut};
 use core::{fmt, ptr};
 
+#[unstable(feature = "indexed_binary_heap", issue = "none")]
+pub use self::indexed::{Handle, IndexedBinaryHeap, IndexedIter};
 use crate::alloc::Global;
 use crate::collections::TryReserveError;
 use crate::slice;
@@ -157,6 +159,8 @@
 use crate::vec::AsVecIntoIter;
 use crate::vec::{self, Vec};
 
+mod indexed;
+
 /// A priority queue implemented with a binary heap.
 ///
 /// This will be a max-heap.
@@ -55,6 +55,8 @@ pub(super) fn bulk_push<I, A: Allocator + Clone>(
                 let mut open_node;
                 let mut test_node = cur_node.forget_type();
                 loop {
+                    // The node is full and we're leaving it behind for good.
+                    test_node.recalc_subtree_len();
                     match test_node.ascend() {
                         Ok(parent) => {
                             let parent = parent.into_node();
@@ -91,6 +93,7 @@ pub(super) fn bulk_push<I, A: Allocator + Clone>(
             // the appended elements even if advancing the iterator panicks.
             *length += 1;
         }
+        cur_node.forget_type().recalc_ancestors_subtree_lens();
         self.fix_right_border_of_plentiful();
     }
 }
@@ -1,3 +1,4 @@
+use core::cmp::Ordering;
 use core::iter::Peekable;
 
 /// An iterator for deduping the key of a sorted iterator.
@@ -11,6 +12,9 @@ pub(super) struct DedupSortedIter<K, V, I>
     I: Iterator<Item = (K, V)>,
 {
     iter: Peekable<I>,
+    /// Whether to panic upon encountering keys out of order, instead of
+    /// trusting the source of the iterator to have sorted them.
+    check_order: bool,
 }
 
 impl<K, V, I> DedupSortedIter<K, V, I>
@@ -18,13 +22,18 @@ impl<K, V, I> DedupSortedIter<K, V, I>
     I: Iterator<Item = (K, V)>,
 {
     pub(super) fn new(iter: I) -> Self {
-        Self { iter: iter.peekable() }
+        Self { iter: iter.peekable(), check_order: false }
+    }
+
+    /// Like `new`, but the iterator panics if a key is followed by a smaller key.
+    pub(super) fn new_checked(iter: I) -> Self {
+        Self { iter: iter.peekable(), check_order: true }
     }
 }
 
 impl<K, V, I> Iterator for DedupSortedIter<K, V, I>
 where
-    K: Eq,
+    K: Ord,
     I: Iterator<Item = (K, V)>,
 {
     type Item = (K, V);
@@ -41,7 +50,13 @@ fn next(&mut self) -> Option<(K, V)> {
                 None => return Some(next),
             };
 
-            if next.0 != peeked.0 {
+            if self.check_order {
+                match next.0.cmp(&peeked.0) {
+                    Ordering::Less => return Some(next),
+                    Ordering::Equal => {}
+                    Ordering::Greater => panic!("keys are not sorted in ascending order"),
+                }
+            } else if next.0 != peeked.0 {
                 return Some(next);
             }
         }
@@ -0,0 +1,119 @@
+use core::alloc::Allocator;
+use core::mem;
+
+use super::map::MIN_LEN;
+use super::node::ForceResult::*;
+use super::node::{CAPACITY, Root};
+
+impl<K, V> Root<K, V> {
+    /// Joins the tree `self`, a separating key-value pair and the tree `right`
+    /// into a single tree, in time proportional to the height of the trees.
+    ///
+    /// The result is meaningful only if all keys in `self` are smaller than
+    /// `key`, and `key` is smaller than all keys in `right`. Both trees must be
+    /// non-empty. If both trees respect all `BTreeMap` tree invariants, then
+    /// the joined tree will respect those invariants.
+    pub(super) fn join<A: Allocator + Clone>(&mut self, key: K, val: V, right: Self, alloc: A) {
+        debug_assert!(self.len() > 0 && right.len() > 0);
+        let (left_height, right_height) = (self.height(), right.height());
+        if left_height == right_height {
+            self.join_level(key, val, right, alloc);
+        } else if left_height > right_height {
+            self.join_taller_left(key, val, right, alloc);
+        } else {
+            let left = mem::replace(self, right);
+            left.join_shorter_left(self, key, val, alloc);
+        }
+    }
+
+    /// Joins two trees of the same height by putting them under a new root,
+    /// then balancing the two children of that root.
+    fn join_level<A: Allocator + Clone>(&mut self, key: K, val: V, right: Self, alloc: A) {
+        let mut root = self.push_internal_level(alloc.clone());
+        root.push(key, val, right);
+        let mut kv = root.last_kv().consider_for_balancing();
+        let (left_len, right_len) = (kv.left_child_len(), kv.right_child_len());
+        if kv.can_merge() {
+            kv.merge_tracking_parent(alloc.clone());
+        } else if left_len < MIN_LEN {
+            kv.bulk_steal_right(MIN_LEN - left_len);
+        } else if right_len < MIN_LEN {
+            kv.bulk_steal_left(MIN_LEN - right_len);
+        }
+        self.fix_top(alloc);
+    }
+
+    /// Hangs `right` below the right border of the taller tree `self`, at the
+    /// lowest node with room for another edge. Levels of empty nodes make up
+    /// for any difference in height, and are stocked up afterwards.
+    fn join_taller_left<A: Allocator + Clone>(&mut self, key: K, val: V, right: Self, alloc: A) {
+        let mut cur_node = self.borrow_mut();
+        while cur_node.height() > right.height() + 1 {
+            cur_node = match cur_node.force() {
+                Internal(internal) => internal.last_edge().descend(),
+                Leaf(_) => unreachable!(),
+            };
+        }
+        let Internal(mut test_node) = cur_node.force() else { unreachable!() };
+
+        let mut open_node;
+        loop {
+            if test_node.len() < CAPACITY {
+                open_node = test_node;
+                break;
+            }
+            match test_node.ascend() {
+                Ok(parent) => test_node = parent.into_node(),
+                Err(_) => {
+                    open_node = self.push_internal_level(alloc.clone());
+                    break;
+                }
+            }
+        }
+
+        let mut pillar = right;
+        while pillar.height() < open_node.height() - 1 {
+            pillar.push_internal_level(alloc.clone());
+        }
+        open_node.push(key, val, pillar);
+        // `push` only accounted for the pillar in the open node itself.
+        open_node.forget_type().recalc_ancestors_subtree_lens();
+        self.fix_right_border(alloc);
+    }
+
+    /// The symmetric clone of `join_taller_left`, hanging the shorter tree
+    /// `self` below the left border of `right`, which ends up in `right`.
+    fn join_shorter_left<A: Allocator + Clone>(self, right: &mut Self, key: K, val: V, alloc: A) {
+        let mut cur_node = right.borrow_mut();
+        while cur_node.height() > self.height() + 1 {
+            cur_node = match cur_node.force() {
+                Internal(internal) => internal.first_edge().descend(),
+                Leaf(_) => unreachable!(),
+            };
+        }
+        let Internal(mut test_node) = cur_node.force() else { unreachable!() };
+
+        let mut open_node;
+        loop {
+            if test_node.len() < CAPACITY {
+                open_node = test_node;
+                break;
+            }
+            match test_node.ascend() {
+                Ok(parent) => test_node = parent.into_node(),
+                Err(_) => {
+                    open_node = right.push_internal_level(alloc.clone());
+                    break;
+                }
+            }
+        }
+
+        let mut pillar = self;
+        while pillar.height() < open_node.height() - 1 {
+            pillar.push_internal_level(alloc.clone());
+        }
+        open_node.push_front(key, val, pillar);
+        open_node.forget_type().recalc_ancestors_subtree_lens();
+        right.fix_left_border(alloc);
+    }
+}
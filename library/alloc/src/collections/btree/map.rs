@@ -628,6 +628,36 @@ impl<K, V> BTreeMap<K, V> {
     pub const fn new() -> BTreeMap<K, V> {
         BTreeMap { root: None, length: 0, alloc: ManuallyDrop::new(Global), _marker: PhantomData }
     }
+
+    /// Makes a `BTreeMap` from an iterator of key-value pairs sorted by key.
+    ///
+    /// Instead of inserting the pairs one by one, the tree is built bottom-up
+    /// in linear time. If the iterator produces the same key more than once,
+    /// the last of the pairs with that key ends up in the map, just like with
+    /// `collect`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a key is followed by a smaller key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(btree_from_sorted_iter)]
+    /// use std::collections::BTreeMap;
+    ///
+    /// let map = BTreeMap::from_sorted_iter((0..1000).map(|i| (i, i * 2)));
+    /// assert_eq!(map.len(), 1000);
+    /// assert_eq!(map[&500], 1000);
+    /// ```
+    #[unstable(feature = "btree_from_sorted_iter", issue = "none")]
+    pub fn from_sorted_iter<I>(iter: I) -> BTreeMap<K, V>
+    where
+        K: Ord,
+        I: IntoIterator<Item = (K, V)>,
+    {
+        BTreeMap::from_sorted_iter_in(iter, Global)
+    }
 }
 
 impl<K, V, A: Allocator + Clone> BTreeMap<K, V, A> {
@@ -673,6 +703,42 @@ pub fn clear(&mut self) {
     pub const fn new_in(alloc: A) -> BTreeMap<K, V, A> {
         BTreeMap { root: None, length: 0, alloc: ManuallyDrop::new(alloc), _marker: PhantomData }
     }
+
+    /// Makes a `BTreeMap` from an iterator of key-value pairs sorted by key,
+    /// using the given allocator. See [`BTreeMap::from_sorted_iter`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if a key is followed by a smaller key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(allocator_api)]
+    /// #![feature(btree_from_sorted_iter)]
+    /// use std::alloc::Global;
+    /// use std::collections::BTreeMap;
+    ///
+    /// let map = BTreeMap::from_sorted_iter_in([(1, "a"), (2, "b"), (2, "c")], Global);
+    /// assert!(map.into_iter().eq([(1, "a"), (2, "c")]));
+    /// ```
+    #[unstable(feature = "btree_from_sorted_iter", issue = "none")]
+    pub fn from_sorted_iter_in<I>(iter: I, alloc: A) -> BTreeMap<K, V, A>
+    where
+        K: Ord,
+        I: IntoIterator<Item = (K, V)>,
+    {
+        let mut map = BTreeMap::new_in(alloc);
+        let root = map.root.insert(Root::new((*map.alloc).clone()));
+        // Counting the pairs in the map itself means that it drops whatever
+        // was pushed so far if the iterator panics or turns out to be unsorted.
+        root.bulk_push(
+            DedupSortedIter::new_checked(iter.into_iter()),
+            &mut map.length,
+            (*map.alloc).clone(),
+        );
+        map
+    }
 }
 
 impl<K, V, A: Allocator + Clone> BTreeMap<K, V, A> {
@@ -937,6 +1003,70 @@ pub fn pop_last(&mut self) -> Option<(K, V)>
         self.last_entry().map(|entry| entry.remove_entry())
     }
 
+    /// Returns the number of elements in the map with a key smaller than the
+    /// given key, i.e. the position the key has or would have in ascending
+    /// key order.
+    ///
+    /// By default, the map does not keep track of the size of its subtrees,
+    /// so the elements before the key are counted, though mostly a node at a
+    /// time rather than one by one. If the standard library is built with the
+    /// `btree_subtree_len` Cargo feature, nodes keep track of the size of their
+    /// subtree, and this takes logarithmic time.
+    ///
+    /// The key may be any borrowed form of the map's key type, but the ordering
+    /// on the borrowed form *must* match the ordering on the key type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(btree_order_statistics)]
+    /// use std::collections::BTreeMap;
+    ///
+    /// let map = BTreeMap::from([(10, "a"), (20, "b"), (30, "c")]);
+    /// assert_eq!(map.rank(&5), 0);
+    /// assert_eq!(map.rank(&20), 1);
+    /// assert_eq!(map.rank(&25), 2);
+    /// assert_eq!(map.rank(&40), 3);
+    /// ```
+    #[unstable(feature = "btree_order_statistics", issue = "none")]
+    pub fn rank<Q: ?Sized>(&self, key: &Q) -> usize
+    where
+        K: Borrow<Q> + Ord,
+        Q: Ord,
+    {
+        match self.root.as_ref() {
+            Some(root) => root.reborrow().rank(key),
+            None => 0,
+        }
+    }
+
+    /// Returns the key-value pair at the given position in ascending key
+    /// order, or `None` if `index` is out of bounds.
+    ///
+    /// Like [`rank`], this counts the elements before the position, though
+    /// mostly a node at a time rather than one by one.
+    ///
+    /// [`rank`]: BTreeMap::rank
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(btree_order_statistics)]
+    /// use std::collections::BTreeMap;
+    ///
+    /// let map = BTreeMap::from([(10, "a"), (20, "b"), (30, "c")]);
+    /// assert_eq!(map.select(0), Some((&10, &"a")));
+    /// assert_eq!(map.select(2), Some((&30, &"c")));
+    /// assert_eq!(map.select(3), None);
+    /// ```
+    #[unstable(feature = "btree_order_statistics", issue = "none")]
+    pub fn select(&self, index: usize) -> Option<(&K, &V)> {
+        if index >= self.length {
+            return None;
+        }
+        self.root.as_ref()?.reborrow().select(index)
+    }
+
     /// Returns `true` if the map contains a value for the specified key.
     ///
     /// The key may be any borrowed form of the map's key type, but the ordering
@@ -1151,7 +1281,7 @@ pub fn retain<F>(&mut self, mut f: F)
         K: Ord,
         F: FnMut(&K, &mut V) -> bool,
     {
-        self.extract_if(|k, v| !f(k, v)).for_each(drop);
+        self.extract_if(.., |k, v| !f(k, v)).for_each(drop);
     }
 
     /// Moves all elements from `other` into `self`, leaving `other` empty.
@@ -1397,11 +1527,131 @@ pub fn split_off<Q: ?Sized + Ord>(&mut self, key: &Q) -> Self
         }
     }
 
-    /// Creates an iterator that visits all elements (key-value pairs) in
-    /// ascending key order and uses a closure to determine if an element should
-    /// be removed. If the closure returns `true`, the element is removed from
-    /// the map and yielded. If the closure returns `false`, or panics, the
-    /// element remains in the map and will not be yielded.
+    /// Removes the elements with keys in the given range from the map and
+    /// returns them in a new map.
+    ///
+    /// Rather than removing the elements one by one, this cuts the tree at
+    /// both ends of the range and joins the outer parts back together, which
+    /// takes logarithmic time, plus the time needed to count the elements of
+    /// the smaller parts. See [`rank`] for when that counting is avoided.
+    ///
+    /// [`rank`]: BTreeMap::rank
+    ///
+    /// # Panics
+    ///
+    /// Panics if range `start > end`.
+    /// Panics if range `start == end` and both bounds are `Excluded`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(btree_remove_range)]
+    /// use std::collections::BTreeMap;
+    ///
+    /// let mut a: BTreeMap<i32, char> = (0..10).zip('a'..).collect();
+    /// let b = a.split_off_range(3..7);
+    ///
+    /// assert!(a.into_iter().eq([(0, 'a'), (1, 'b'), (2, 'c'), (7, 'h'), (8, 'i'), (9, 'j')]));
+    /// assert!(b.into_iter().eq([(3, 'd'), (4, 'e'), (5, 'f'), (6, 'g')]));
+    /// ```
+    #[unstable(feature = "btree_remove_range", issue = "none")]
+    pub fn split_off_range<T: ?Sized, R>(&mut self, range: R) -> Self
+    where
+        T: Ord,
+        K: Borrow<T> + Ord,
+        R: RangeBounds<T>,
+        A: Clone,
+    {
+        let alloc = (*self.alloc).clone();
+        let Some(left_root) = self.root.as_mut() else {
+            return Self::new_in(alloc);
+        };
+        // Besides checking the bounds, this tells whether there is anything to remove.
+        if left_root.reborrow().search_tree_for_bifurcation(&range).is_err() {
+            return Self::new_in(alloc);
+        }
+
+        let total_num = self.length;
+        let mut middle_root = left_root.split_off_bound(range.start_bound(), alloc.clone());
+        let (left_len, rest_len) = Root::calc_split_length(total_num, left_root, &middle_root);
+        let right_root = match range.end_bound() {
+            Bound::Included(end) => {
+                middle_root.split_off_bound(Bound::Excluded(end), alloc.clone())
+            }
+            Bound::Excluded(end) => {
+                middle_root.split_off_bound(Bound::Included(end), alloc.clone())
+            }
+            Bound::Unbounded => Root::new(alloc.clone()),
+        };
+        let (middle_len, right_len) = Root::calc_split_length(rest_len, &middle_root, &right_root);
+        self.length = left_len;
+
+        let mut right = BTreeMap {
+            root: Some(right_root),
+            length: right_len,
+            alloc: self.alloc.clone(),
+            _marker: PhantomData,
+        };
+        if left_len == 0 {
+            mem::swap(self, &mut right);
+        } else if let Some((key, val)) = right.pop_first() {
+            if right.is_empty() {
+                self.insert(key, val);
+            } else {
+                let right_root = right.root.take().unwrap();
+                self.root.as_mut().unwrap().join(key, val, right_root, alloc);
+                self.length += 1 + mem::take(&mut right.length);
+            }
+        }
+
+        BTreeMap {
+            root: Some(middle_root),
+            length: middle_len,
+            alloc: self.alloc.clone(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Removes the elements with keys in the given range from the map.
+    ///
+    /// The elements are dropped, but the tree is cut and joined as in
+    /// [`split_off_range`], instead of removing the elements one by one.
+    ///
+    /// [`split_off_range`]: BTreeMap::split_off_range
+    ///
+    /// # Panics
+    ///
+    /// Panics if range `start > end`.
+    /// Panics if range `start == end` and both bounds are `Excluded`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(btree_remove_range)]
+    /// use std::collections::BTreeMap;
+    ///
+    /// let mut map: BTreeMap<i32, i32> = (0..100).map(|i| (i, i)).collect();
+    /// map.remove_range(10..=89);
+    /// assert_eq!(map.len(), 20);
+    /// assert_eq!(map.range(5..95).count(), 10);
+    /// ```
+    #[unstable(feature = "btree_remove_range", issue = "none")]
+    pub fn remove_range<T: ?Sized, R>(&mut self, range: R)
+    where
+        T: Ord,
+        K: Borrow<T> + Ord,
+        R: RangeBounds<T>,
+        A: Clone,
+    {
+        drop(self.split_off_range(range));
+    }
+
+    /// Creates an iterator that visits the elements (key-value pairs) with keys
+    /// in the given range in ascending key order and uses a closure to
+    /// determine if an element should be removed. If the closure returns
+    /// `true`, the element is removed from the map and yielded. If the closure
+    /// returns `false`, or panics, the element remains in the map and will not
+    /// be yielded. Elements outside the range are not visited.
     ///
     /// The iterator also lets you mutate the value of each element in the
     /// closure, regardless of whether you choose to keep or remove it.
@@ -1421,33 +1671,48 @@ pub fn split_off<Q: ?Sized + Ord>(&mut self, key: &Q) -> Self
     /// use std::collections::BTreeMap;
     ///
     /// let mut map: BTreeMap<i32, i32> = (0..8).map(|x| (x, x)).collect();
-    /// let evens: BTreeMap<_, _> = map.extract_if(|k, _v| k % 2 == 0).collect();
+    /// let evens: BTreeMap<_, _> = map.extract_if(.., |k, _v| k % 2 == 0).collect();
     /// let odds = map;
     /// assert_eq!(evens.keys().copied().collect::<Vec<_>>(), [0, 2, 4, 6]);
     /// assert_eq!(odds.keys().copied().collect::<Vec<_>>(), [1, 3, 5, 7]);
     /// ```
+    ///
+    /// Splitting off the even keys from a part of the map:
+    ///
+    /// ```
+    /// #![feature(btree_extract_if)]
+    /// use std::collections::BTreeMap;
+    ///
+    /// let mut map: BTreeMap<i32, i32> = (0..8).map(|x| (x, x)).collect();
+    /// let evens: BTreeMap<_, _> = map.extract_if(2..6, |k, _v| k % 2 == 0).collect();
+    /// assert_eq!(evens.keys().copied().collect::<Vec<_>>(), [2, 4]);
+    /// assert_eq!(map.keys().copied().collect::<Vec<_>>(), [0, 1, 3, 5, 6, 7]);
+    /// ```
     #[unstable(feature = "btree_extract_if", issue = "70530")]
-    pub fn extract_if<F>(&mut self, pred: F) -> ExtractIf<'_, K, V, F, A>
+    pub fn extract_if<R, F>(&mut self, range: R, pred: F) -> ExtractIf<'_, K, V, R, F, A>
     where
         K: Ord,
+        R: RangeBounds<K>,
         F: FnMut(&K, &mut V) -> bool,
     {
-        let (inner, alloc) = self.extract_if_inner();
+        let (inner, alloc) = self.extract_if_inner(range);
         ExtractIf { pred, inner, alloc }
     }
 
-    pub(super) fn extract_if_inner(&mut self) -> (ExtractIfInner<'_, K, V>, A)
+    pub(super) fn extract_if_inner<R>(&mut self, range: R) -> (ExtractIfInner<'_, K, V, R>, A)
     where
         K: Ord,
+        R: RangeBounds<K>,
     {
         if let Some(root) = self.root.as_mut() {
             let (root, dormant_root) = DormantMutRef::new(root);
-            let front = root.borrow_mut().first_leaf_edge();
+            let front = root.borrow_mut().lower_bound(SearchBound::from_range(range.start_bound()));
             (
                 ExtractIfInner {
                     length: &mut self.length,
                     dormant_root: Some(dormant_root),
                     cur_leaf_edge: Some(front),
+                    range,
                 },
                 (*self.alloc).clone(),
             )
@@ -1457,6 +1722,7 @@ pub(super) fn extract_if_inner(&mut self) -> (ExtractIfInner<'_, K, V>, A)
                     length: &mut self.length,
                     dormant_root: None,
                     cur_leaf_edge: None,
+                    range,
                 },
                 (*self.alloc).clone(),
             )
@@ -1915,19 +2181,20 @@ pub struct ExtractIf<
     'a,
     K,
     V,
+    R,
     F,
     #[unstable(feature = "allocator_api", issue = "32838")] A: Allocator + Clone = Global,
 > where
     F: 'a + FnMut(&K, &mut V) -> bool,
 {
     pred: F,
-    inner: ExtractIfInner<'a, K, V>,
+    inner: ExtractIfInner<'a, K, V, R>,
     /// The BTreeMap will outlive this IntoIter so we don't care about drop order for `alloc`.
     alloc: A,
 }
 /// Most of the implementation of ExtractIf are generic over the type
 /// of the predicate, thus also serving for BTreeSet::ExtractIf.
-pub(super) struct ExtractIfInner<'a, K, V> {
+pub(super) struct ExtractIfInner<'a, K, V, R> {
     /// Reference to the length field in the borrowed map, updated live.
     length: &'a mut usize,
     /// Buried reference to the root field in the borrowed map.
@@ -1937,13 +2204,16 @@ pub(super) struct ExtractIfInner<'a, K, V> {
     /// Empty if the map has no root, if iteration went beyond the last leaf edge,
     /// or if a panic occurred in the predicate.
     cur_leaf_edge: Option<Handle<NodeRef<marker::Mut<'a>, K, V, marker::Leaf>, marker::Edge>>,
+    /// Range of keys to visit; iteration stops at the first key beyond it.
+    range: R,
 }
 
 #[unstable(feature = "btree_extract_if", issue = "70530")]
-impl<K, V, F> fmt::Debug for ExtractIf<'_, K, V, F>
+impl<K, V, R, F> fmt::Debug for ExtractIf<'_, K, V, R, F>
 where
-    K: fmt::Debug,
+    K: fmt::Debug + Ord,
     V: fmt::Debug,
+    R: RangeBounds<K>,
     F: FnMut(&K, &mut V) -> bool,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -1952,8 +2222,10 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
 }
 
 #[unstable(feature = "btree_extract_if", issue = "70530")]
-impl<K, V, F, A: Allocator + Clone> Iterator for ExtractIf<'_, K, V, F, A>
+impl<K, V, R, F, A: Allocator + Clone> Iterator for ExtractIf<'_, K, V, R, F, A>
 where
+    K: Ord,
+    R: RangeBounds<K>,
     F: FnMut(&K, &mut V) -> bool,
 {
     type Item = (K, V);
@@ -1967,11 +2239,11 @@ fn size_hint(&self) -> (usize, Option<usize>) {
     }
 }
 
-impl<'a, K, V> ExtractIfInner<'a, K, V> {
+impl<'a, K: Ord, V, R: RangeBounds<K>> ExtractIfInner<'a, K, V, R> {
     /// Allow Debug implementations to predict the next element.
     pub(super) fn peek(&self) -> Option<(&K, &V)> {
         let edge = self.cur_leaf_edge.as_ref()?;
-        edge.reborrow().next_kv().ok().map(Handle::into_kv)
+        edge.reborrow().next_kv().ok().map(Handle::into_kv).filter(|(k, _)| self.range.contains(k))
     }
 
     /// Implementation of a typical `ExtractIf::next` method, given the predicate.
@@ -1981,6 +2253,9 @@ pub(super) fn next<F, A: Allocator + Clone>(&mut self, pred: &mut F, alloc: A) -
     {
         while let Ok(mut kv) = self.cur_leaf_edge.take()?.next_kv() {
             let (k, v) = kv.kv_mut();
+            if !self.range.contains(k) {
+                return None;
+            }
             if pred(k, v) {
                 *self.length -= 1;
                 let (kv, pos) = kv.remove_kv_tracking(
@@ -2012,7 +2287,13 @@ pub(super) fn size_hint(&self) -> (usize, Option<usize>) {
 }
 
 #[unstable(feature = "btree_extract_if", issue = "70530")]
-impl<K, V, F> FusedIterator for ExtractIf<'_, K, V, F> where F: FnMut(&K, &mut V) -> bool {}
+impl<K, V, R, F> FusedIterator for ExtractIf<'_, K, V, R, F>
+where
+    K: Ord,
+    R: RangeBounds<K>,
+    F: FnMut(&K, &mut V) -> bool,
+{
+}
 
 #[stable(feature = "btree_range", since = "1.17.0")]
 impl<'a, K, V> Iterator for Range<'a, K, V> {
@@ -49,8 +49,11 @@ fn check_invariants(&self) {
             assert!(root_node.ascend().is_err());
             root_node.assert_back_pointers();
 
-            // Check consistency of `length` with what navigation code encounters.
+            // Check consistency of `length` with what navigation code encounters,
+            // and with the subtree lengths kept in the nodes.
             assert_eq!(self.length, root_node.calc_length());
+            #[cfg(feature = "btree_subtree_len")]
+            assert_eq!(self.length, root_node.assert_subtree_lens());
 
             // Lastly, check the invariant causing the least harm.
             root_node.assert_min_len(if root_node.height() > 0 { 1 } else { 0 });
@@ -944,7 +947,7 @@ mod test_extract_if {
     #[test]
     fn empty() {
         let mut map: BTreeMap<i32, i32> = BTreeMap::new();
-        map.extract_if(|_, _| unreachable!("there's nothing to decide on")).for_each(drop);
+        map.extract_if(.., |_, _| unreachable!("there's nothing to decide on")).for_each(drop);
         assert_eq!(map.height(), None);
         map.check();
     }
@@ -954,7 +957,7 @@ fn empty() {
     fn consumed_keeping_all() {
         let pairs = (0..3).map(|i| (i, i));
         let mut map = BTreeMap::from_iter(pairs);
-        assert!(map.extract_if(|_, _| false).eq(iter::empty()));
+        assert!(map.extract_if(.., |_, _| false).eq(iter::empty()));
         map.check();
     }
 
@@ -963,7 +966,7 @@ fn consumed_keeping_all() {
     fn consumed_removing_all() {
         let pairs = (0..3).map(|i| (i, i));
         let mut map = BTreeMap::from_iter(pairs.clone());
-        assert!(map.extract_if(|_, _| true).eq(pairs));
+        assert!(map.extract_if(.., |_, _| true).eq(pairs));
         assert!(map.is_empty());
         map.check();
     }
@@ -974,7 +977,7 @@ fn mutating_and_keeping() {
         let pairs = (0..3).map(|i| (i, i));
         let mut map = BTreeMap::from_iter(pairs);
         assert!(
-            map.extract_if(|_, v| {
+            map.extract_if(.., |_, v| {
                 *v += 6;
                 false
             })
@@ -991,7 +994,7 @@ fn mutating_and_removing() {
         let pairs = (0..3).map(|i| (i, i));
         let mut map = BTreeMap::from_iter(pairs);
         assert!(
-            map.extract_if(|_, v| {
+            map.extract_if(.., |_, v| {
                 *v += 6;
                 true
             })
@@ -1005,7 +1008,7 @@ fn mutating_and_removing() {
     fn underfull_keeping_all() {
         let pairs = (0..3).map(|i| (i, i));
         let mut map = BTreeMap::from_iter(pairs);
-        map.extract_if(|_, _| false).for_each(drop);
+        map.extract_if(.., |_, _| false).for_each(drop);
         assert!(map.keys().copied().eq(0..3));
         map.check();
     }
@@ -1015,7 +1018,7 @@ fn underfull_removing_one() {
         let pairs = (0..3).map(|i| (i, i));
         for doomed in 0..3 {
             let mut map = BTreeMap::from_iter(pairs.clone());
-            map.extract_if(|i, _| *i == doomed).for_each(drop);
+            map.extract_if(.., |i, _| *i == doomed).for_each(drop);
             assert_eq!(map.len(), 2);
             map.check();
         }
@@ -1026,17 +1029,38 @@ fn underfull_keeping_one() {
         let pairs = (0..3).map(|i| (i, i));
         for sacred in 0..3 {
             let mut map = BTreeMap::from_iter(pairs.clone());
-            map.extract_if(|i, _| *i != sacred).for_each(drop);
+            map.extract_if(.., |i, _| *i != sacred).for_each(drop);
             assert!(map.keys().copied().eq(sacred..=sacred));
             map.check();
         }
     }
 
+    #[test]
+    fn within_range() {
+        let pairs = (0..100).map(|i| (i, i));
+        let mut map = BTreeMap::from_iter(pairs);
+        let extracted = Vec::from_iter(map.extract_if(20..=80, |i, _| i % 2 == 0));
+        assert!(extracted.into_iter().map(|(k, _)| k).eq((20..=80).step_by(2)));
+        assert_eq!(map.len(), 100 - 31);
+        assert!(map.keys().all(|k| k % 2 == 1 || !(20..=80).contains(k)));
+        map.check();
+    }
+
+    #[test]
+    fn empty_range() {
+        let pairs = (0..100).map(|i| (i, i));
+        let mut map = BTreeMap::from_iter(pairs);
+        map.extract_if(50..50, |_, _| unreachable!("nothing in range")).for_each(drop);
+        map.extract_if(200.., |_, _| unreachable!("nothing in range")).for_each(drop);
+        assert_eq!(map.len(), 100);
+        map.check();
+    }
+
     #[test]
     fn underfull_removing_all() {
         let pairs = (0..3).map(|i| (i, i));
         let mut map = BTreeMap::from_iter(pairs);
-        map.extract_if(|_, _| true).for_each(drop);
+        map.extract_if(.., |_, _| true).for_each(drop);
         assert!(map.is_empty());
         map.check();
     }
@@ -1045,7 +1069,7 @@ fn underfull_removing_all() {
     fn height_0_keeping_all() {
         let pairs = (0..node::CAPACITY).map(|i| (i, i));
         let mut map = BTreeMap::from_iter(pairs);
-        map.extract_if(|_, _| false).for_each(drop);
+        map.extract_if(.., |_, _| false).for_each(drop);
         assert!(map.keys().copied().eq(0..node::CAPACITY));
         map.check();
     }
@@ -1055,7 +1079,7 @@ fn height_0_removing_one() {
         let pairs = (0..node::CAPACITY).map(|i| (i, i));
         for doomed in 0..node::CAPACITY {
             let mut map = BTreeMap::from_iter(pairs.clone());
-            map.extract_if(|i, _| *i == doomed).for_each(drop);
+            map.extract_if(.., |i, _| *i == doomed).for_each(drop);
             assert_eq!(map.len(), node::CAPACITY - 1);
             map.check();
         }
@@ -1066,7 +1090,7 @@ fn height_0_keeping_one() {
         let pairs = (0..node::CAPACITY).map(|i| (i, i));
         for sacred in 0..node::CAPACITY {
             let mut map = BTreeMap::from_iter(pairs.clone());
-            map.extract_if(|i, _| *i != sacred).for_each(drop);
+            map.extract_if(.., |i, _| *i != sacred).for_each(drop);
             assert!(map.keys().copied().eq(sacred..=sacred));
             map.check();
         }
@@ -1076,7 +1100,7 @@ fn height_0_keeping_one() {
     fn height_0_removing_all() {
         let pairs = (0..node::CAPACITY).map(|i| (i, i));
         let mut map = BTreeMap::from_iter(pairs);
-        map.extract_if(|_, _| true).for_each(drop);
+        map.extract_if(.., |_, _| true).for_each(drop);
         assert!(map.is_empty());
         map.check();
     }
@@ -1084,7 +1108,7 @@ fn height_0_removing_all() {
     #[test]
     fn height_0_keeping_half() {
         let mut map = BTreeMap::from_iter((0..16).map(|i| (i, i)));
-        assert_eq!(map.extract_if(|i, _| *i % 2 == 0).count(), 8);
+        assert_eq!(map.extract_if(.., |i, _| *i % 2 == 0).count(), 8);
         assert_eq!(map.len(), 8);
         map.check();
     }
@@ -1093,7 +1117,7 @@ fn height_0_keeping_half() {
     fn height_1_removing_all() {
         let pairs = (0..MIN_INSERTS_HEIGHT_1).map(|i| (i, i));
         let mut map = BTreeMap::from_iter(pairs);
-        map.extract_if(|_, _| true).for_each(drop);
+        map.extract_if(.., |_, _| true).for_each(drop);
         assert!(map.is_empty());
         map.check();
     }
@@ -1103,7 +1127,7 @@ fn height_1_removing_one() {
         let pairs = (0..MIN_INSERTS_HEIGHT_1).map(|i| (i, i));
         for doomed in 0..MIN_INSERTS_HEIGHT_1 {
             let mut map = BTreeMap::from_iter(pairs.clone());
-            map.extract_if(|i, _| *i == doomed).for_each(drop);
+            map.extract_if(.., |i, _| *i == doomed).for_each(drop);
             assert_eq!(map.len(), MIN_INSERTS_HEIGHT_1 - 1);
             map.check();
         }
@@ -1114,7 +1138,7 @@ fn height_1_keeping_one() {
         let pairs = (0..MIN_INSERTS_HEIGHT_1).map(|i| (i, i));
         for sacred in 0..MIN_INSERTS_HEIGHT_1 {
             let mut map = BTreeMap::from_iter(pairs.clone());
-            map.extract_if(|i, _| *i != sacred).for_each(drop);
+            map.extract_if(.., |i, _| *i != sacred).for_each(drop);
             assert!(map.keys().copied().eq(sacred..=sacred));
             map.check();
         }
@@ -1125,7 +1149,7 @@ fn height_2_removing_one() {
         let pairs = (0..MIN_INSERTS_HEIGHT_2).map(|i| (i, i));
         for doomed in (0..MIN_INSERTS_HEIGHT_2).step_by(12) {
             let mut map = BTreeMap::from_iter(pairs.clone());
-            map.extract_if(|i, _| *i == doomed).for_each(drop);
+            map.extract_if(.., |i, _| *i == doomed).for_each(drop);
             assert_eq!(map.len(), MIN_INSERTS_HEIGHT_2 - 1);
             map.check();
         }
@@ -1136,7 +1160,7 @@ fn height_2_keeping_one() {
         let pairs = (0..MIN_INSERTS_HEIGHT_2).map(|i| (i, i));
         for sacred in (0..MIN_INSERTS_HEIGHT_2).step_by(12) {
             let mut map = BTreeMap::from_iter(pairs.clone());
-            map.extract_if(|i, _| *i != sacred).for_each(drop);
+            map.extract_if(.., |i, _| *i != sacred).for_each(drop);
             assert!(map.keys().copied().eq(sacred..=sacred));
             map.check();
         }
@@ -1146,7 +1170,7 @@ fn height_2_keeping_one() {
     fn height_2_removing_all() {
         let pairs = (0..MIN_INSERTS_HEIGHT_2).map(|i| (i, i));
         let mut map = BTreeMap::from_iter(pairs);
-        map.extract_if(|_, _| true).for_each(drop);
+        map.extract_if(.., |_, _| true).for_each(drop);
         assert!(map.is_empty());
         map.check();
     }
@@ -1162,7 +1186,7 @@ fn drop_panic_leak() {
         map.insert(b.spawn(Panic::InDrop), ());
         map.insert(c.spawn(Panic::Never), ());
 
-        catch_unwind(move || map.extract_if(|dummy, _| dummy.query(true)).for_each(drop))
+        catch_unwind(move || map.extract_if(.., |dummy, _| dummy.query(true)).for_each(drop))
             .unwrap_err();
 
         assert_eq!(a.queried(), 1);
@@ -1185,7 +1209,7 @@ fn pred_panic_leak() {
         map.insert(c.spawn(Panic::InQuery), ());
 
         catch_unwind(AssertUnwindSafe(|| {
-            map.extract_if(|dummy, _| dummy.query(true)).for_each(drop)
+            map.extract_if(.., |dummy, _| dummy.query(true)).for_each(drop)
         }))
         .unwrap_err();
 
@@ -1214,7 +1238,7 @@ fn pred_panic_reuse() {
         map.insert(c.spawn(Panic::InQuery), ());
 
         {
-            let mut it = map.extract_if(|dummy, _| dummy.query(true));
+            let mut it = map.extract_if(.., |dummy, _| dummy.query(true));
             catch_unwind(AssertUnwindSafe(|| while it.next().is_some() {})).unwrap_err();
             // Iterator behavior after a panic is explicitly unspecified,
             // so this is just the current implementation:
@@ -1658,7 +1682,7 @@ fn into_values<T: Sync + Ord>(v: BTreeMap<T, T>) -> impl Sync {
     }
 
     fn extract_if<T: Sync + Ord>(v: &mut BTreeMap<T, T>) -> impl Sync + '_ {
-        v.extract_if(|_, _| false)
+        v.extract_if(.., |_, _| false)
     }
 
     fn iter<T: Sync>(v: &BTreeMap<T, T>) -> impl Sync + '_ {
@@ -1727,7 +1751,7 @@ fn into_values<T: Send + Ord>(v: BTreeMap<T, T>) -> impl Send {
     }
 
     fn extract_if<T: Send + Ord>(v: &mut BTreeMap<T, T>) -> impl Send + '_ {
-        v.extract_if(|_, _| false)
+        v.extract_if(.., |_, _| false)
     }
 
     fn iter<T: Send + Sync>(v: &BTreeMap<T, T>) -> impl Send + '_ {
@@ -2246,6 +2270,143 @@ fn test_split_off_large_random_sorted() {
     assert!(right.into_iter().eq(data.into_iter().filter(|x| x.0 >= key)));
 }
 
+#[test]
+fn test_split_off_range() {
+    let bounds = |i| [Included(i), Excluded(i), Unbounded];
+    for len in [0, 1, node::CAPACITY, MIN_INSERTS_HEIGHT_1, 50, MIN_INSERTS_HEIGHT_2, 300] {
+        let step = len / 16 + 1;
+        for compact in [false, true] {
+            let mut rng = DeterministicRng::new();
+            let mut keys = Vec::from_iter((0..len).map(|i| (i * 2, rng.next())));
+            // Insertion in non-ascending order creates some variation in node length.
+            keys.sort_by_key(|&(_, r)| r);
+            let mut original = BTreeMap::from_iter(keys.iter().map(|&(k, _)| (k, ())));
+            if compact {
+                original.compact();
+            }
+            for start in (0..len * 2 + 1).step_by(step) {
+                for end in (start..len * 2 + 1).step_by(step) {
+                    for range in iter::zip(bounds(start), bounds(end)) {
+                        if range == (Excluded(start), Excluded(end)) && start == end {
+                            continue;
+                        }
+                        let mut map = original.clone();
+                        let removed = map.split_off_range(range);
+                        map.check();
+                        removed.check();
+                        let contained = |k: &usize| range.contains(k);
+                        assert!(
+                            removed.keys().copied().eq(original.keys().copied().filter(contained))
+                        );
+                        assert!(
+                            map.keys()
+                                .copied()
+                                .eq(original.keys().copied().filter(|k| !contained(k)))
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_split_off_range_joins_trees_of_any_height() {
+    let data = Vec::from_iter((0..1000).map(|i| (i, i)));
+    for (start, end) in [(0, 1), (1, 999), (10, 12), (400, 990), (995, 999), (998, 1000)] {
+        let mut map = BTreeMap::from_iter(data.iter().copied());
+        let removed = map.split_off_range(start..end);
+        map.check();
+        removed.check();
+        assert_eq!(removed.len(), end - start);
+        assert!(map.keys().copied().eq((0..start).chain(end..1000)));
+    }
+}
+
+#[test]
+#[should_panic(expected = "range start is greater than range end in BTreeMap")]
+fn test_split_off_range_backwards() {
+    let mut map = BTreeMap::from_iter((0..10).map(|i| (i, i)));
+    map.split_off_range(5..3);
+}
+
+#[test]
+fn test_remove_range_drops() {
+    let dummies = Vec::from_iter((0..20).map(CrashTestDummy::new));
+    let mut map = BTreeMap::from_iter(dummies.iter().map(|d| (d.spawn(Panic::Never), ())));
+    map.remove_range(dummies[5].spawn(Panic::Never)..dummies[15].spawn(Panic::Never));
+    map.check();
+    assert_eq!(map.len(), 10);
+    for (i, d) in dummies.iter().enumerate() {
+        // The bounds of the range get dropped as well.
+        let expected = (5..15).contains(&i) as usize + (i == 5 || i == 15) as usize;
+        assert_eq!(d.dropped(), expected);
+    }
+}
+
+#[test]
+fn test_rank_select() {
+    for len in [0, 1, node::CAPACITY, MIN_INSERTS_HEIGHT_1, MIN_INSERTS_HEIGHT_2, 500] {
+        let map = BTreeMap::from_iter((0..len).map(|i| (i * 2, i)));
+        for i in 0..len {
+            assert_eq!(map.select(i), Some((&(i * 2), &i)));
+            assert_eq!(map.rank(&(i * 2)), i);
+            assert_eq!(map.rank(&(i * 2 + 1)), i + 1);
+        }
+        assert_eq!(map.select(len), None);
+        assert_eq!(map.rank(&(len * 2)), len);
+    }
+}
+
+#[test]
+fn test_rank_select_after_insert_and_remove() {
+    let mut rng = DeterministicRng::new();
+    let mut map = BTreeMap::new();
+    for _ in 0..MIN_INSERTS_HEIGHT_2 {
+        map.insert(rng.next() % 1000, ());
+    }
+    map.retain(|k, _| k % 3 != 0);
+    map.check();
+    for (i, k) in map.keys().enumerate() {
+        assert_eq!(map.select(i), Some((k, &())));
+        assert_eq!(map.rank(k), i);
+    }
+}
+
+#[test]
+fn test_from_sorted_iter() {
+    for len in [0, 1, node::CAPACITY, MIN_INSERTS_HEIGHT_1, MIN_INSERTS_HEIGHT_2, 500] {
+        let map = BTreeMap::from_sorted_iter((0..len).map(|i| (i, i)));
+        map.check();
+        assert!(map.into_iter().eq((0..len).map(|i| (i, i))));
+    }
+}
+
+#[test]
+fn test_from_sorted_iter_duplicates() {
+    let map = BTreeMap::from_sorted_iter([(1, 'a'), (1, 'b'), (2, 'c'), (3, 'd'), (3, 'e')]);
+    map.check();
+    assert!(map.into_iter().eq([(1, 'b'), (2, 'c'), (3, 'e')]));
+}
+
+#[test]
+#[should_panic(expected = "keys are not sorted in ascending order")]
+fn test_from_sorted_iter_unsorted() {
+    BTreeMap::from_sorted_iter([(1, ()), (3, ()), (2, ())]);
+}
+
+#[test]
+#[cfg_attr(not(panic = "unwind"), ignore = "test requires unwinding support")]
+fn test_from_sorted_iter_unsorted_drops() {
+    let dummies = Vec::from_iter((0..50).map(CrashTestDummy::new));
+    let keys = dummies
+        .iter()
+        .map(|d| (d.spawn(Panic::Never), ()))
+        .chain(iter::once((dummies[0].spawn(Panic::Never), ())));
+    catch_unwind(AssertUnwindSafe(|| BTreeMap::from_sorted_iter(keys))).unwrap_err();
+    assert!(dummies.iter().all(|d| d.dropped() == 1 + (d.id == 0) as usize));
+}
+
 #[test]
 #[cfg_attr(not(panic = "unwind"), ignore = "test requires unwinding support")]
 fn test_into_iter_drop_leak_height_0() {
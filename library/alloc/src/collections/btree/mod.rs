@@ -2,6 +2,7 @@
 mod borrow;
 mod dedup_sorted_iter;
 mod fix;
+mod join;
 pub(super) mod map;
 mod mem;
 mod merge_iter;
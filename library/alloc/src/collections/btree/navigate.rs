@@ -5,6 +5,7 @@
 use super::node::ForceResult::*;
 use super::node::{Handle, NodeRef, marker};
 use super::search::SearchBound;
+use super::search::SearchResult::*;
 use crate::alloc::Allocator;
 // `front` and `back` are always both `None` or both `Some`.
 pub(super) struct LeafRange<BorrowType, K, V> {
@@ -417,6 +418,7 @@ pub(super) fn next_back_kv(
     }
 }
 
+#[cfg(test)]
 impl<BorrowType: marker::BorrowType, K, V>
     Handle<NodeRef<BorrowType, K, V, marker::Internal>, marker::Edge>
 {
@@ -655,6 +657,7 @@ pub(super) fn last_leaf_edge(
     }
 }
 
+#[cfg(test)]
 pub(super) enum Position<BorrowType, K, V> {
     Leaf(NodeRef<BorrowType, K, V, marker::Leaf>),
     Internal(NodeRef<BorrowType, K, V, marker::Internal>),
@@ -665,6 +668,7 @@ impl<'a, K: 'a, V: 'a> NodeRef<marker::Immut<'a>, K, V, marker::LeafOrInternal>
     /// Visits leaf nodes and internal KVs in order of ascending keys, and also
     /// visits internal nodes as a whole in a depth first order, meaning that
     /// internal nodes precede their individual KVs and their child nodes.
+    #[cfg(test)]
     pub(super) fn visit_nodes_in_order<F>(self, mut visit: F)
     where
         F: FnMut(Position<marker::Immut<'a>, K, V>),
@@ -697,6 +701,7 @@ pub(super) fn visit_nodes_in_order<F>(self, mut visit: F)
     }
 
     /// Calculates the number of elements in a (sub)tree.
+    #[cfg(test)]
     pub(super) fn calc_length(self) -> usize {
         let mut result = 0;
         self.visit_nodes_in_order(|pos| match pos {
@@ -706,6 +711,98 @@ pub(super) fn calc_length(self) -> usize {
         });
         result
     }
+
+    /// Calculates the number of elements in a subtree, which unlike with
+    /// `calc_length` need not be headed by the root of the tree.
+    #[cfg(not(feature = "btree_subtree_len"))]
+    pub(super) fn calc_subtree_length(self) -> usize {
+        match self.force() {
+            Leaf(leaf) => leaf.len(),
+            Internal(internal) => {
+                let mut result = internal.len();
+                for idx in 0..=internal.len() {
+                    // SAFETY: `idx` is at most the length of the node.
+                    let child = unsafe { Handle::new_edge(internal, idx) }.descend();
+                    result += child.calc_subtree_length();
+                }
+                result
+            }
+        }
+    }
+
+    /// Returns the number of elements in a subtree, as kept track of by its
+    /// top node.
+    #[cfg(feature = "btree_subtree_len")]
+    pub(super) fn calc_subtree_length(self) -> usize {
+        self.subtree_len()
+    }
+
+    /// Counts the elements in a (sub)tree with a key smaller than the given
+    /// key. Subtrees to the left of the search path are counted as a whole,
+    /// so unless nodes keep track of their subtree length, this takes time
+    /// proportional to the number of nodes visited there.
+    ///
+    /// The result is meaningful only if the tree is ordered by key.
+    pub(super) fn rank<Q: ?Sized>(self, key: &Q) -> usize
+    where
+        Q: Ord,
+        K: Borrow<Q>,
+    {
+        let mut node = self;
+        let mut result = 0;
+        loop {
+            let edge = match node.search_node(key) {
+                Found(kv) => kv.left_edge(),
+                GoDown(edge) => edge,
+            };
+            result += edge.idx();
+            match edge.force() {
+                Leaf(_) => return result,
+                Internal(edge) => {
+                    let parent = edge.into_node();
+                    for idx in 0..edge.idx() {
+                        // SAFETY: `idx` is smaller than the index of an existing edge.
+                        result += unsafe { Handle::new_edge(parent, idx) }
+                            .descend()
+                            .calc_subtree_length();
+                    }
+                    node = edge.descend();
+                }
+            }
+        }
+    }
+
+    /// Finds the key-value pair at the given position in ascending key order,
+    /// counting the elements of subtrees on the way down instead of visiting
+    /// them one by one.
+    pub(super) fn select(self, mut index: usize) -> Option<(&'a K, &'a V)> {
+        let mut node = self;
+        'descend: loop {
+            let internal = match node.force() {
+                Leaf(leaf) => {
+                    // SAFETY: `index` was just checked against the length of the node.
+                    return (index < leaf.len())
+                        .then(|| unsafe { Handle::new_kv(leaf, index) }.into_kv());
+                }
+                Internal(internal) => internal,
+            };
+            for idx in 0..internal.len() {
+                // SAFETY: `idx` is a valid edge and KV index for the node.
+                let child = unsafe { Handle::new_edge(internal, idx) }.descend();
+                let child_len = child.calc_subtree_length();
+                if index < child_len {
+                    node = child;
+                    continue 'descend;
+                }
+                index -= child_len;
+                if index == 0 {
+                    return Some(unsafe { Handle::new_kv(internal, idx) }.into_kv());
+                }
+                index -= 1;
+            }
+            node = internal.last_edge().descend();
+        }
+    }
 }
 
 impl<BorrowType: marker::BorrowType, K, V>
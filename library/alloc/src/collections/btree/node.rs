@@ -97,6 +97,12 @@ fn new<A: Allocator + Clone>(alloc: A) -> Box<Self, A> {
 struct InternalNode<K, V> {
     data: LeafNode<K, V>,
 
+    /// The number of key-value pairs in the subtree headed by this node, which is the node's
+    /// own `len` plus the `subtree_len` of each child (or the `len` of each leaf child).
+    /// Only kept if the `btree_subtree_len` feature is enabled, to support order statistics.
+    #[cfg(feature = "btree_subtree_len")]
+    subtree_len: usize,
+
     /// The pointers to the children of this node. `len + 1` of these are considered
     /// initialized and valid, except that near the end, while the tree is held
     /// through borrow type `Dying`, some of these pointers are dangling.
@@ -115,6 +121,8 @@ unsafe fn new<A: Allocator + Clone>(alloc: A) -> Box<Self, A> {
             let mut node = Box::<Self, _>::new_uninit_in(alloc);
             // We only need to initialize the data; the edges are MaybeUninit.
             LeafNode::init(&raw mut (*node.as_mut_ptr()).data);
+            #[cfg(feature = "btree_subtree_len")]
+            (&raw mut (*node.as_mut_ptr()).subtree_len).write(0);
             node.assume_init()
         }
     }
@@ -225,6 +233,10 @@ fn from_new_leaf<A: Allocator + Clone>(leaf: Box<LeafNode<K, V>, A>) -> Self {
 impl<K, V> NodeRef<marker::Owned, K, V, marker::Internal> {
     fn new_internal<A: Allocator + Clone>(child: Root<K, V>, alloc: A) -> Self {
         let mut new_node = unsafe { InternalNode::new(alloc) };
+        #[cfg(feature = "btree_subtree_len")]
+        {
+            new_node.subtree_len = child.subtree_len();
+        }
         new_node.edges[0].write(child.node);
         unsafe { NodeRef::from_new_internal(new_node, child.height + 1) }
     }
@@ -280,6 +292,20 @@ pub(super) fn len(&self) -> usize {
         unsafe { usize::from((*Self::as_leaf_ptr(self)).len) }
     }
 
+    /// Returns the number of key-value pairs in the subtree headed by the node,
+    /// which for a leaf is the length of the node itself.
+    /// Like `len`, this only accesses the field holding the number.
+    #[cfg(feature = "btree_subtree_len")]
+    pub(super) fn subtree_len(&self) -> usize {
+        if self.height == 0 {
+            self.len()
+        } else {
+            // SAFETY: nodes above height zero are internal.
+            let internal = self.node.as_ptr() as *const InternalNode<K, V>;
+            unsafe { (*internal).subtree_len }
+        }
+    }
+
     /// Returns the number of levels that the node and leaves are apart. Zero
     /// height means the node is a leaf itself. If you picture trees with the
     /// root on top, the number says at which elevation the node appears.
@@ -555,6 +581,79 @@ fn correct_all_childrens_parent_links(&mut self) {
         let len = self.len();
         unsafe { self.correct_childrens_parent_links(0..=len) };
     }
+
+    /// Adds up the subtree lengths of the children in the given range of edges.
+    ///
+    /// # Safety
+    /// Every item returned by `range` is a valid edge index for the node.
+    #[cfg(feature = "btree_subtree_len")]
+    unsafe fn sum_childrens_subtree_lens<R: Iterator<Item = usize>>(&self, range: R) -> usize {
+        let mut sum = 0;
+        for i in range {
+            debug_assert!(i <= self.len());
+            sum += unsafe { Handle::new_edge(self.reborrow(), i) }.descend().subtree_len();
+        }
+        sum
+    }
+
+    /// Recalculates the subtree length of the node from scratch, assuming
+    /// those of its children are up to date.
+    #[cfg(feature = "btree_subtree_len")]
+    fn recalc_subtree_len(&mut self) {
+        let len = self.len();
+        let subtree_len = len + unsafe { self.sum_childrens_subtree_lens(0..=len) };
+        self.as_internal_mut().subtree_len = subtree_len;
+    }
+}
+
+#[cfg(feature = "btree_subtree_len")]
+impl<'a, K: 'a, V: 'a> NodeRef<marker::Mut<'a>, K, V, marker::LeafOrInternal> {
+    /// Recalculates the subtree length of the node, unless it is a leaf.
+    pub(super) fn recalc_subtree_len(&mut self) {
+        if let ForceResult::Internal(mut internal) = unsafe { self.reborrow_mut() }.force() {
+            internal.recalc_subtree_len();
+        }
+    }
+
+    /// Recalculates the subtree length of each ancestor of the node, from the
+    /// bottom up, assuming those of the node and its siblings are up to date.
+    pub(super) fn recalc_ancestors_subtree_lens(self) {
+        let mut node = self;
+        while let Ok(parent) = node.ascend() {
+            let mut parent = parent.into_node();
+            parent.recalc_subtree_len();
+            node = parent.forget_type();
+        }
+    }
+
+    /// Adds `delta` to the subtree length of the node, unless it is a leaf,
+    /// and to that of each of its ancestors.
+    pub(super) fn add_to_subtree_lens(mut self, delta: isize) {
+        loop {
+            if let ForceResult::Internal(mut internal) = unsafe { self.reborrow_mut() }.force() {
+                let subtree_len = &mut internal.as_internal_mut().subtree_len;
+                *subtree_len = subtree_len.wrapping_add_signed(delta);
+            }
+            match self.ascend() {
+                Ok(parent) => self = parent.into_node().forget_type(),
+                Err(_) => return,
+            }
+        }
+    }
+}
+
+/// Without the `btree_subtree_len` feature, nodes don't keep track of their
+/// subtree length, and there is nothing to maintain.
+#[cfg(not(feature = "btree_subtree_len"))]
+impl<'a, K: 'a, V: 'a> NodeRef<marker::Mut<'a>, K, V, marker::LeafOrInternal> {
+    #[inline(always)]
+    pub(super) fn recalc_subtree_len(&mut self) {}
+
+    #[inline(always)]
+    pub(super) fn recalc_ancestors_subtree_lens(self) {}
+
+    #[inline(always)]
+    pub(super) fn add_to_subtree_lens(self, _delta: isize) {}
 }
 
 impl<'a, K: 'a, V: 'a> NodeRef<marker::Mut<'a>, K, V, marker::LeafOrInternal> {
@@ -688,6 +787,10 @@ pub(super) fn push(&mut self, key: K, val: V, edge: Root<K, V>) {
         let idx = usize::from(*len);
         assert!(idx < CAPACITY);
         *len += 1;
+        #[cfg(feature = "btree_subtree_len")]
+        {
+            self.as_internal_mut().subtree_len += 1 + edge.subtree_len();
+        }
         unsafe {
             self.key_area_mut(idx).write(key);
             self.val_area_mut(idx).write(val);
@@ -695,6 +798,27 @@ pub(super) fn push(&mut self, key: K, val: V, edge: Root<K, V>) {
             Handle::new_edge(self.reborrow_mut(), idx + 1).correct_parent_link();
         }
     }
+
+    /// Adds a key-value pair, and an edge to go to the left of that pair,
+    /// to the beginning of the node.
+    pub(super) fn push_front(&mut self, key: K, val: V, edge: Root<K, V>) {
+        assert!(edge.height == self.height - 1);
+
+        let old_len = self.len();
+        assert!(old_len < CAPACITY);
+        let new_len = old_len + 1;
+        unsafe {
+            slice_insert(self.key_area_mut(..new_len), 0, key);
+            slice_insert(self.val_area_mut(..new_len), 0, val);
+            slice_insert(self.edge_area_mut(..new_len + 1), 0, edge.node);
+        }
+        *self.len_mut() = new_len as u16;
+        #[cfg(feature = "btree_subtree_len")]
+        {
+            self.as_internal_mut().subtree_len += 1 + edge.subtree_len();
+        }
+        self.correct_all_childrens_parent_links();
+    }
 }
 
 impl<BorrowType, K, V> NodeRef<BorrowType, K, V, marker::Leaf> {
@@ -1008,18 +1132,26 @@ fn insert_fit(&mut self, key: K, val: V, edge: Root<K, V>) {
     /// Inserts a new key-value pair and an edge that will go to the right of that new pair
     /// between this edge and the key-value pair to the right of this edge. This method splits
     /// the node if there isn't enough room.
+    ///
+    /// Returns the node if the pair fit, leaving its subtree length to the caller.
+    /// Otherwise, returns the split result with the subtree length of both halves
+    /// recalculated (if nodes keep track of it), assuming those of the children
+    /// are up to date.
     fn insert<A: Allocator + Clone>(
         mut self,
         key: K,
         val: V,
         edge: Root<K, V>,
         alloc: A,
-    ) -> Option<SplitResult<'a, K, V, marker::Internal>> {
+    ) -> Result<
+        NodeRef<marker::Mut<'a>, K, V, marker::Internal>,
+        SplitResult<'a, K, V, marker::Internal>,
+    > {
         assert!(edge.height == self.node.height - 1);
 
         if self.node.len() < CAPACITY {
             self.insert_fit(key, val, edge);
-            None
+            Ok(self.node)
         } else {
             let (middle_kv_idx, insertion) = splitpoint(self.idx);
             let middle = unsafe { Handle::new_kv(self.node, middle_kv_idx) };
@@ -1033,7 +1165,12 @@ fn insert<A: Allocator + Clone>(
                 },
             };
             insertion_edge.insert_fit(key, val, edge);
-            Some(result)
+            #[cfg(feature = "btree_subtree_len")]
+            {
+                result.left.recalc_subtree_len();
+                result.right.borrow_mut().recalc_subtree_len();
+            }
+            Err(result)
         }
     }
 }
@@ -1054,9 +1191,13 @@ pub(super) fn insert_recursing<A: Allocator + Clone>(
         split_root: impl FnOnce(SplitResult<'a, K, V, marker::LeafOrInternal>),
     ) -> Handle<NodeRef<marker::Mut<'a>, K, V, marker::Leaf>, marker::KV> {
         let (mut split, handle) = match self.insert(key, value, alloc.clone()) {
-            // SAFETY: we have finished splitting and can now re-awaken the
-            // handle to the inserted element.
-            (None, handle) => return unsafe { handle.awaken() },
+            (None, handle) => {
+                // SAFETY: we have finished splitting and can now re-awaken the
+                // handle to the inserted element.
+                let mut handle = unsafe { handle.awaken() };
+                unsafe { handle.reborrow_mut() }.into_node().forget_type().add_to_subtree_lens(1);
+                return handle;
+            }
             (Some(split), handle) => (split.forget_node_type(), handle),
         };
 
@@ -1064,10 +1205,15 @@ pub(super) fn insert_recursing<A: Allocator + Clone>(
             split = match split.left.ascend() {
                 Ok(parent) => {
                     match parent.insert(split.kv.0, split.kv.1, split.right, alloc.clone()) {
-                        // SAFETY: we have finished splitting and can now re-awaken the
-                        // handle to the inserted element.
-                        None => return unsafe { handle.awaken() },
-                        Some(split) => split.forget_node_type(),
+                        Ok(parent) => {
+                            // The elements of the split child, plus the inserted one, are
+                            // now spread over its two halves and the middle key-value pair.
+                            parent.forget_type().add_to_subtree_lens(1);
+                            // SAFETY: we have finished splitting and can now re-awaken the
+                            // handle to the inserted element.
+                            return unsafe { handle.awaken() };
+                        }
+                        Err(split) => split.forget_node_type(),
                     }
                 }
                 Err(root) => {
@@ -1435,6 +1581,10 @@ fn do_merge<
                 );
 
                 left_node.correct_childrens_parent_links(old_left_len + 1..new_left_len + 1);
+                #[cfg(feature = "btree_subtree_len")]
+                {
+                    left_node.as_internal_mut().subtree_len += 1 + right_node.subtree_len();
+                }
 
                 alloc.deallocate(right_node.node.cast(), Layout::new::<InternalNode<K, V>>());
             } else {
@@ -1570,6 +1720,13 @@ pub(super) fn bulk_steal_left(&mut self, count: usize) {
                     );
 
                     right.correct_childrens_parent_links(0..new_right_len + 1);
+
+                    #[cfg(feature = "btree_subtree_len")]
+                    {
+                        let moved = count + right.sum_childrens_subtree_lens(0..count);
+                        left.as_internal_mut().subtree_len -= moved;
+                        right.as_internal_mut().subtree_len += moved;
+                    }
                 }
                 (ForceResult::Leaf(_), ForceResult::Leaf(_)) => {}
                 _ => unreachable!(),
@@ -1634,6 +1791,14 @@ pub(super) fn bulk_steal_right(&mut self, count: usize) {
 
                     left.correct_childrens_parent_links(old_left_len + 1..new_left_len + 1);
                     right.correct_childrens_parent_links(0..new_right_len + 1);
+
+                    #[cfg(feature = "btree_subtree_len")]
+                    {
+                        let moved = count
+                            + left.sum_childrens_subtree_lens(old_left_len + 1..new_left_len + 1);
+                        left.as_internal_mut().subtree_len += moved;
+                        right.as_internal_mut().subtree_len -= moved;
+                    }
                 }
                 (ForceResult::Leaf(_), ForceResult::Leaf(_)) => {}
                 _ => unreachable!(),
@@ -1698,6 +1863,7 @@ pub(super) unsafe fn cast_to_leaf_unchecked(
 impl<'a, K, V> Handle<NodeRef<marker::Mut<'a>, K, V, marker::LeafOrInternal>, marker::Edge> {
     /// Move the suffix after `self` from one node to another one. `right` must be empty.
     /// The first edge of `right` remains unchanged.
+    /// The subtree lengths of both nodes are left to the caller.
     pub(super) fn move_suffix(
         &mut self,
         right: &mut NodeRef<marker::Mut<'a>, K, V, marker::LeafOrInternal>,
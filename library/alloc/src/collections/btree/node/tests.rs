@@ -17,6 +17,23 @@ pub(crate) fn assert_back_pointers(self) {
         }
     }
 
+    // Asserts that the subtree length in each reachable internal node matches
+    // the number of key-value pairs in its subtree, and returns that number.
+    #[cfg(feature = "btree_subtree_len")]
+    pub(crate) fn assert_subtree_lens(self) -> usize {
+        match self.force() {
+            ForceResult::Leaf(leaf) => leaf.len(),
+            ForceResult::Internal(node) => {
+                let mut len = node.len();
+                for idx in 0..=node.len() {
+                    len += unsafe { Handle::new_edge(node, idx) }.descend().assert_subtree_lens();
+                }
+                assert_eq!(node.subtree_len(), len);
+                len
+            }
+        }
+    }
+
     // Renders a multi-line display of the keys in order and in tree hierarchy,
     // picturing the tree growing sideways from its root on the left to its
     // leaves on the right.
@@ -92,8 +109,9 @@ fn test_partial_eq() {
 #[cfg(target_arch = "x86_64")]
 #[cfg_attr(any(miri, randomized_layouts), ignore)] // We'd like to run Miri with layout randomization
 fn test_sizes() {
+    let subtree_len_size = if cfg!(feature = "btree_subtree_len") { 8 } else { 0 };
     assert_eq!(size_of::<LeafNode<(), ()>>(), 16);
     assert_eq!(size_of::<LeafNode<i64, i64>>(), 16 + CAPACITY * 2 * 8);
-    assert_eq!(size_of::<InternalNode<(), ()>>(), 16 + (CAPACITY + 1) * 8);
-    assert_eq!(size_of::<InternalNode<i64, i64>>(), 16 + (CAPACITY * 3 + 1) * 8);
+    assert_eq!(size_of::<InternalNode<(), ()>>(), 16 + subtree_len_size + (CAPACITY + 1) * 8);
+    assert_eq!(size_of::<InternalNode<i64, i64>>(), 16 + subtree_len_size + (CAPACITY * 3 + 1) * 8);
 }
@@ -344,6 +344,34 @@ impl<T> BTreeSet<T> {
     pub const fn new() -> BTreeSet<T> {
         BTreeSet { map: BTreeMap::new() }
     }
+
+    /// Makes a `BTreeSet` from an iterator of values in ascending order.
+    ///
+    /// Instead of inserting the values one by one, the tree is built bottom-up
+    /// in linear time. If the iterator produces the same value more than once,
+    /// the last of them ends up in the set.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a value is followed by a smaller value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(btree_from_sorted_iter)]
+    /// use std::collections::BTreeSet;
+    ///
+    /// let set = BTreeSet::from_sorted_iter([1, 2, 2, 3]);
+    /// assert!(set.into_iter().eq([1, 2, 3]));
+    /// ```
+    #[unstable(feature = "btree_from_sorted_iter", issue = "none")]
+    pub fn from_sorted_iter<I>(iter: I) -> BTreeSet<T>
+    where
+        T: Ord,
+        I: IntoIterator<Item = T>,
+    {
+        BTreeSet::from_sorted_iter_in(iter, Global)
+    }
 }
 
 impl<T, A: Allocator + Clone> BTreeSet<T, A> {
@@ -365,6 +393,22 @@ pub const fn new_in(alloc: A) -> BTreeSet<T, A> {
         BTreeSet { map: BTreeMap::new_in(alloc) }
     }
 
+    /// Makes a `BTreeSet` from an iterator of values in ascending order,
+    /// using the given allocator. See [`BTreeSet::from_sorted_iter`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if a value is followed by a smaller value.
+    #[unstable(feature = "btree_from_sorted_iter", issue = "none")]
+    pub fn from_sorted_iter_in<I>(iter: I, alloc: A) -> BTreeSet<T, A>
+    where
+        T: Ord,
+        I: IntoIterator<Item = T>,
+    {
+        let iter = iter.into_iter().map(|k| (k, SetValZST::default()));
+        BTreeSet { map: BTreeMap::from_sorted_iter_in(iter, alloc) }
+    }
+
     /// Constructs a double-ended iterator over a sub-range of elements in the set.
     /// The simplest way is to use the range syntax `min..max`, thus `range(min..max)` will
     /// yield elements from min (inclusive) to max (exclusive).
@@ -876,6 +920,48 @@ pub fn pop_last(&mut self) -> Option<T>
         self.map.pop_last().map(|kv| kv.0)
     }
 
+    /// Returns the number of elements in the set smaller than the given value,
+    /// i.e. the position the value has or would have in ascending order.
+    ///
+    /// See [`BTreeMap::rank`] for how the elements are counted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(btree_order_statistics)]
+    /// use std::collections::BTreeSet;
+    ///
+    /// let set = BTreeSet::from([10, 20, 30]);
+    /// assert_eq!(set.rank(&20), 1);
+    /// assert_eq!(set.rank(&25), 2);
+    /// ```
+    #[unstable(feature = "btree_order_statistics", issue = "none")]
+    pub fn rank<Q: ?Sized>(&self, value: &Q) -> usize
+    where
+        T: Borrow<Q> + Ord,
+        Q: Ord,
+    {
+        self.map.rank(value)
+    }
+
+    /// Returns the element at the given position in ascending order, or `None`
+    /// if `index` is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(btree_order_statistics)]
+    /// use std::collections::BTreeSet;
+    ///
+    /// let set = BTreeSet::from([10, 20, 30]);
+    /// assert_eq!(set.select(1), Some(&20));
+    /// assert_eq!(set.select(3), None);
+    /// ```
+    #[unstable(feature = "btree_order_statistics", issue = "none")]
+    pub fn select(&self, index: usize) -> Option<&T> {
+        self.map.select(index).map(|(k, _)| k)
+    }
+
     /// Adds a value to the set.
     ///
     /// Returns whether the value was newly inserted. That is:
@@ -1109,7 +1195,7 @@ pub fn retain<F>(&mut self, mut f: F)
         T: Ord,
         F: FnMut(&T) -> bool,
     {
-        self.extract_if(|v| !f(v)).for_each(drop);
+        self.extract_if(.., |v| !f(v)).for_each(drop);
     }
 
     /// Moves all elements from `other` into `self`, leaving `other` empty.
@@ -1187,8 +1273,70 @@ pub fn split_off<Q: ?Sized + Ord>(&mut self, value: &Q) -> Self
         BTreeSet { map: self.map.split_off(value) }
     }
 
-    /// Creates an iterator that visits all elements in ascending order and
-    /// uses a closure to determine if an element should be removed.
+    /// Removes the elements in the given range from the set and returns them
+    /// in a new set.
+    ///
+    /// See [`BTreeMap::split_off_range`] for how this avoids removing the
+    /// elements one by one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if range `start > end`.
+    /// Panics if range `start == end` and both bounds are `Excluded`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(btree_remove_range)]
+    /// use std::collections::BTreeSet;
+    ///
+    /// let mut a: BTreeSet<i32> = (0..10).collect();
+    /// let b = a.split_off_range(3..7);
+    /// assert!(a.into_iter().eq([0, 1, 2, 7, 8, 9]));
+    /// assert!(b.into_iter().eq([3, 4, 5, 6]));
+    /// ```
+    #[unstable(feature = "btree_remove_range", issue = "none")]
+    pub fn split_off_range<K: ?Sized, R>(&mut self, range: R) -> Self
+    where
+        K: Ord,
+        T: Borrow<K> + Ord,
+        R: RangeBounds<K>,
+        A: Clone,
+    {
+        BTreeSet { map: self.map.split_off_range(range) }
+    }
+
+    /// Removes the elements in the given range from the set.
+    ///
+    /// # Panics
+    ///
+    /// Panics if range `start > end`.
+    /// Panics if range `start == end` and both bounds are `Excluded`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(btree_remove_range)]
+    /// use std::collections::BTreeSet;
+    ///
+    /// let mut set: BTreeSet<i32> = (0..10).collect();
+    /// set.remove_range(..5);
+    /// assert!(set.into_iter().eq(5..10));
+    /// ```
+    #[unstable(feature = "btree_remove_range", issue = "none")]
+    pub fn remove_range<K: ?Sized, R>(&mut self, range: R)
+    where
+        K: Ord,
+        T: Borrow<K> + Ord,
+        R: RangeBounds<K>,
+        A: Clone,
+    {
+        self.map.remove_range(range)
+    }
+
+    /// Creates an iterator that visits the elements in the given range in
+    /// ascending order and uses a closure to determine if an element should be
+    /// removed. Elements outside the range are not visited.
     ///
     /// If the closure returns `true`, the element is removed from the set and
     /// yielded. If the closure returns `false`, or panics, the element remains
@@ -1208,18 +1356,19 @@ pub fn split_off<Q: ?Sized + Ord>(&mut self, value: &Q) -> Self
     /// use std::collections::BTreeSet;
     ///
     /// let mut set: BTreeSet<i32> = (0..8).collect();
-    /// let evens: BTreeSet<_> = set.extract_if(|v| v % 2 == 0).collect();
+    /// let evens: BTreeSet<_> = set.extract_if(.., |v| v % 2 == 0).collect();
     /// let odds = set;
     /// assert_eq!(evens.into_iter().collect::<Vec<_>>(), vec![0, 2, 4, 6]);
     /// assert_eq!(odds.into_iter().collect::<Vec<_>>(), vec![1, 3, 5, 7]);
     /// ```
     #[unstable(feature = "btree_extract_if", issue = "70530")]
-    pub fn extract_if<'a, F>(&'a mut self, pred: F) -> ExtractIf<'a, T, F, A>
+    pub fn extract_if<'a, R, F>(&'a mut self, range: R, pred: F) -> ExtractIf<'a, T, R, F, A>
     where
         T: Ord,
+        R: RangeBounds<T>,
         F: 'a + FnMut(&T) -> bool,
     {
-        let (inner, alloc) = self.map.extract_if_inner();
+        let (inner, alloc) = self.map.extract_if_inner(range);
         ExtractIf { pred, inner, alloc }
     }
 
@@ -1475,12 +1624,12 @@ fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> BTreeSet<T> {
 
         // use stable sort to preserve the insertion order.
         inputs.sort();
-        BTreeSet::from_sorted_iter(inputs.into_iter(), Global)
+        BTreeSet::bulk_build_from_sorted_iter(inputs.into_iter(), Global)
     }
 }
 
 impl<T: Ord, A: Allocator + Clone> BTreeSet<T, A> {
-    fn from_sorted_iter<I: Iterator<Item = T>>(iter: I, alloc: A) -> BTreeSet<T, A> {
+    fn bulk_build_from_sorted_iter<I: Iterator<Item = T>>(iter: I, alloc: A) -> BTreeSet<T, A> {
         let iter = iter.map(|k| (k, SetValZST::default()));
         let map = BTreeMap::bulk_build_from_sorted_iter(iter, alloc);
         BTreeSet { map }
@@ -1554,6 +1703,7 @@ fn into_iter(self) -> Iter<'a, T> {
 pub struct ExtractIf<
     'a,
     T,
+    R,
     F,
     #[unstable(feature = "allocator_api", issue = "32838")] A: Allocator + Clone = Global,
 > where
@@ -1561,15 +1711,16 @@ pub struct ExtractIf<
     F: 'a + FnMut(&T) -> bool,
 {
     pred: F,
-    inner: super::map::ExtractIfInner<'a, T, SetValZST>,
+    inner: super::map::ExtractIfInner<'a, T, SetValZST, R>,
     /// The BTreeMap will outlive this IntoIter so we don't care about drop order for `alloc`.
     alloc: A,
 }
 
 #[unstable(feature = "btree_extract_if", issue = "70530")]
-impl<T, F, A: Allocator + Clone> fmt::Debug for ExtractIf<'_, T, F, A>
+impl<T, R, F, A: Allocator + Clone> fmt::Debug for ExtractIf<'_, T, R, F, A>
 where
-    T: fmt::Debug,
+    T: fmt::Debug + Ord,
+    R: RangeBounds<T>,
     F: FnMut(&T) -> bool,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -1578,8 +1729,10 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
 }
 
 #[unstable(feature = "btree_extract_if", issue = "70530")]
-impl<'a, T, F, A: Allocator + Clone> Iterator for ExtractIf<'_, T, F, A>
+impl<'a, T, R, F, A: Allocator + Clone> Iterator for ExtractIf<'_, T, R, F, A>
 where
+    T: Ord,
+    R: RangeBounds<T>,
     F: 'a + FnMut(&T) -> bool,
 {
     type Item = T;
@@ -1596,7 +1749,13 @@ fn size_hint(&self) -> (usize, Option<usize>) {
 }
 
 #[unstable(feature = "btree_extract_if", issue = "70530")]
-impl<T, F, A: Allocator + Clone> FusedIterator for ExtractIf<'_, T, F, A> where F: FnMut(&T) -> bool {}
+impl<T, R, F, A: Allocator + Clone> FusedIterator for ExtractIf<'_, T, R, F, A>
+where
+    T: Ord,
+    R: RangeBounds<T>,
+    F: FnMut(&T) -> bool,
+{
+}
 
 #[stable(feature = "rust1", since = "1.0.0")]
 impl<T: Ord, A: Allocator + Clone> Extend<T> for BTreeSet<T, A> {
@@ -1651,7 +1810,7 @@ impl<T: Ord + Clone, A: Allocator + Clone> Sub<&BTreeSet<T, A>> for &BTreeSet<T,
     /// assert_eq!(result, BTreeSet::from([1, 2]));
     /// ```
     fn sub(self, rhs: &BTreeSet<T, A>) -> BTreeSet<T, A> {
-        BTreeSet::from_sorted_iter(
+        BTreeSet::bulk_build_from_sorted_iter(
             self.difference(rhs).cloned(),
             ManuallyDrop::into_inner(self.map.alloc.clone()),
         )
@@ -1676,7 +1835,7 @@ impl<T: Ord + Clone, A: Allocator + Clone> BitXor<&BTreeSet<T, A>> for &BTreeSet
     /// assert_eq!(result, BTreeSet::from([1, 4]));
     /// ```
     fn bitxor(self, rhs: &BTreeSet<T, A>) -> BTreeSet<T, A> {
-        BTreeSet::from_sorted_iter(
+        BTreeSet::bulk_build_from_sorted_iter(
             self.symmetric_difference(rhs).cloned(),
             ManuallyDrop::into_inner(self.map.alloc.clone()),
         )
@@ -1701,7 +1860,7 @@ impl<T: Ord + Clone, A: Allocator + Clone> BitAnd<&BTreeSet<T, A>> for &BTreeSet
     /// assert_eq!(result, BTreeSet::from([2, 3]));
     /// ```
     fn bitand(self, rhs: &BTreeSet<T, A>) -> BTreeSet<T, A> {
-        BTreeSet::from_sorted_iter(
+        BTreeSet::bulk_build_from_sorted_iter(
             self.intersection(rhs).cloned(),
             ManuallyDrop::into_inner(self.map.alloc.clone()),
         )
@@ -1726,7 +1885,7 @@ impl<T: Ord + Clone, A: Allocator + Clone> BitOr<&BTreeSet<T, A>> for &BTreeSet<
     /// assert_eq!(result, BTreeSet::from([1, 2, 3, 4, 5]));
     /// ```
     fn bitor(self, rhs: &BTreeSet<T, A>) -> BTreeSet<T, A> {
-        BTreeSet::from_sorted_iter(
+        BTreeSet::bulk_build_from_sorted_iter(
             self.union(rhs).cloned(),
             ManuallyDrop::into_inner(self.map.alloc.clone()),
         )
@@ -1,5 +1,6 @@
 use core::alloc::Allocator;
 use core::borrow::Borrow;
+use core::ops::Bound;
 
 use super::node::ForceResult::*;
 use super::node::Root;
@@ -15,13 +16,13 @@ pub(super) fn calc_split_length(
     ) -> (usize, usize) {
         let (length_a, length_b);
         if root_a.height() < root_b.height() {
-            length_a = root_a.reborrow().calc_length();
+            length_a = root_a.reborrow().calc_subtree_length();
             length_b = total_num - length_a;
-            debug_assert_eq!(length_b, root_b.reborrow().calc_length());
+            debug_assert_eq!(length_b, root_b.reborrow().calc_subtree_length());
         } else {
-            length_b = root_b.reborrow().calc_length();
+            length_b = root_b.reborrow().calc_subtree_length();
             length_a = total_num - length_b;
-            debug_assert_eq!(length_a, root_a.reborrow().calc_length());
+            debug_assert_eq!(length_a, root_a.reborrow().calc_subtree_length());
         }
         (length_a, length_b)
     }
@@ -36,6 +37,21 @@ pub(super) fn split_off<Q: ?Sized + Ord, A: Allocator + Clone>(
         key: &Q,
         alloc: A,
     ) -> Self
+    where
+        K: Borrow<Q>,
+    {
+        self.split_off_bound(Bound::Included(key), alloc)
+    }
+
+    /// Split off a tree with the key-value pairs that satisfy the given lower
+    /// bound: those at and after the key for `Included`, those after the key
+    /// for `Excluded`, and all of them for `Unbounded`.
+    /// The same conditions as for `split_off` apply.
+    pub(super) fn split_off_bound<Q: ?Sized + Ord, A: Allocator + Clone>(
+        &mut self,
+        bound: Bound<&Q>,
+        alloc: A,
+    ) -> Self
     where
         K: Borrow<Q>,
     {
@@ -45,10 +61,18 @@ pub(super) fn split_off<Q: ?Sized + Ord, A: Allocator + Clone>(
         let mut right_node = right_root.borrow_mut();
 
         loop {
-            let mut split_edge = match left_node.search_node(key) {
-                // key is going to the right tree
-                Found(kv) => kv.left_edge(),
-                GoDown(edge) => edge,
+            let mut split_edge = match bound {
+                Bound::Included(key) => match left_node.search_node(key) {
+                    // key is going to the right tree
+                    Found(kv) => kv.left_edge(),
+                    GoDown(edge) => edge,
+                },
+                Bound::Excluded(key) => match left_node.search_node(key) {
+                    // key is staying in the left tree
+                    Found(kv) => kv.right_edge(),
+                    GoDown(edge) => edge,
+                },
+                Bound::Unbounded => left_node.first_edge(),
             };
 
             split_edge.move_suffix(&mut right_node);
@@ -58,7 +82,13 @@ pub(super) fn split_off<Q: ?Sized + Ord, A: Allocator + Clone>(
                     left_node = edge.descend();
                     right_node = node.first_edge().descend();
                 }
-                (Leaf(_), Leaf(_)) => break,
+                (Leaf(edge), Leaf(node)) => {
+                    // Only the nodes along the split edges and along the left
+                    // border of the right tree have changed their contents.
+                    edge.into_node().forget_type().recalc_ancestors_subtree_lens();
+                    node.forget_type().recalc_ancestors_subtree_lens();
+                    break;
+                }
                 _ => unreachable!(),
             }
         }
@@ -1309,6 +1309,58 @@ pub fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
         unsafe { (&mut *self.buffer_range(a_range), &mut *self.buffer_range(b_range)) }
     }
 
+    /// Returns the contents of the deque as a single slice, if they are
+    /// stored contiguously, or `None` if they wrap around the end of the
+    /// buffer.
+    ///
+    /// Unlike [`make_contiguous`], this never moves any elements, so it can
+    /// be used on a shared reference. Elements pushed only at the back of a
+    /// deque that has not wrapped around yet, or after calling
+    /// [`make_contiguous`], are always contiguous.
+    ///
+    /// [`make_contiguous`]: VecDeque::make_contiguous
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(vec_deque_bounded)]
+    /// use std::collections::VecDeque;
+    ///
+    /// let mut deque = VecDeque::new();
+    /// deque.extend([1, 2, 3]);
+    /// assert_eq!(deque.as_contiguous(), Some(&[1, 2, 3][..]));
+    ///
+    /// deque.push_front(0);
+    /// assert_eq!(deque.as_contiguous(), None);
+    ///
+    /// deque.make_contiguous();
+    /// assert_eq!(deque.as_contiguous(), Some(&[0, 1, 2, 3][..]));
+    /// ```
+    #[inline]
+    #[unstable(feature = "vec_deque_bounded", issue = "none")]
+    pub fn as_contiguous(&self) -> Option<&[T]> {
+        match self.as_slices() {
+            (front, []) => Some(front),
+            _ => None,
+        }
+    }
+
+    /// Returns the contents of the deque as a single mutable slice, if they
+    /// are stored contiguously, or `None` if they wrap around the end of the
+    /// buffer.
+    ///
+    /// See [`as_contiguous`] for details.
+    ///
+    /// [`as_contiguous`]: VecDeque::as_contiguous
+    #[inline]
+    #[unstable(feature = "vec_deque_bounded", issue = "none")]
+    pub fn as_contiguous_mut(&mut self) -> Option<&mut [T]> {
+        match self.as_mut_slices() {
+            (front, []) => Some(front),
+            _ => None,
+        }
+    }
+
     /// Returns the number of elements in the deque.
     ///
     /// # Examples
@@ -1834,6 +1886,258 @@ pub fn push_back(&mut self, value: T) {
         self.len += 1;
     }
 
+    /// Prepends an element to the deque, or returns an error if the deque
+    /// needed to grow and the allocation failed.
+    ///
+    /// This is a fallible version of [`push_front`]. On error, the deque is
+    /// left unchanged and `value` is dropped.
+    ///
+    /// [`push_front`]: VecDeque::push_front
+    ///
+    /// # Errors
+    ///
+    /// If the capacity overflows, or the allocator reports a failure, then an error
+    /// is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(fallible_collections)]
+    /// use std::collections::VecDeque;
+    ///
+    /// let mut d = VecDeque::new();
+    /// d.try_push_front(1).expect("out of memory");
+    /// d.try_push_front(2).expect("out of memory");
+    /// assert_eq!(d, [2, 1]);
+    /// ```
+    #[unstable(feature = "fallible_collections", issue = "none")]
+    pub fn try_push_front(&mut self, value: T) -> Result<(), TryReserveError> {
+        if self.is_full() {
+            self.try_grow()?;
+        }
+
+        self.head = self.wrap_sub(self.head, 1);
+        self.len += 1;
+
+        unsafe {
+            self.buffer_write(self.head, value);
+        }
+        Ok(())
+    }
+
+    /// Appends an element to the back of the deque, or returns an error if
+    /// the deque needed to grow and the allocation failed.
+    ///
+    /// This is a fallible version of [`push_back`]. On error, the deque is
+    /// left unchanged and `value` is dropped.
+    ///
+    /// [`push_back`]: VecDeque::push_back
+    ///
+    /// # Errors
+    ///
+    /// If the capacity overflows, or the allocator reports a failure, then an error
+    /// is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(fallible_collections)]
+    /// use std::collections::VecDeque;
+    ///
+    /// let mut d = VecDeque::new();
+    /// d.try_push_back(1).expect("out of memory");
+    /// d.try_push_back(2).expect("out of memory");
+    /// assert_eq!(d, [1, 2]);
+    /// ```
+    #[unstable(feature = "fallible_collections", issue = "none")]
+    pub fn try_push_back(&mut self, value: T) -> Result<(), TryReserveError> {
+        if self.is_full() {
+            self.try_grow()?;
+        }
+
+        unsafe { self.buffer_write(self.to_physical_idx(self.len), value) }
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Prepends an element if there is spare capacity, otherwise an error is
+    /// returned with the element.
+    ///
+    /// Unlike [`push_front`] this method will never reallocate, which makes
+    /// the capacity an upper bound on the length of the deque.
+    ///
+    /// [`push_front`]: VecDeque::push_front
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(vec_deque_bounded)]
+    /// use std::collections::VecDeque;
+    ///
+    /// let mut deque = VecDeque::with_capacity(2);
+    /// let capacity = deque.capacity();
+    /// for i in 0..capacity {
+    ///     assert_eq!(deque.push_front_within_capacity(i), Ok(()));
+    /// }
+    /// assert_eq!(deque.push_front_within_capacity(capacity), Err(capacity));
+    /// assert_eq!(deque.len(), capacity);
+    /// assert_eq!(deque.front(), Some(&(capacity - 1)));
+    /// ```
+    ///
+    /// # Time complexity
+    ///
+    /// Takes *O*(1) time.
+    #[inline]
+    #[unstable(feature = "vec_deque_bounded", issue = "none")]
+    pub fn push_front_within_capacity(&mut self, value: T) -> Result<(), T> {
+        if self.is_full() {
+            return Err(value);
+        }
+
+        self.head = self.wrap_sub(self.head, 1);
+        self.len += 1;
+
+        unsafe {
+            self.buffer_write(self.head, value);
+        }
+        Ok(())
+    }
+
+    /// Appends an element if there is spare capacity, otherwise an error is
+    /// returned with the element.
+    ///
+    /// Unlike [`push_back`] this method will never reallocate, which makes
+    /// the capacity an upper bound on the length of the deque.
+    ///
+    /// [`push_back`]: VecDeque::push_back
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(vec_deque_bounded)]
+    /// use std::collections::VecDeque;
+    ///
+    /// let mut deque = VecDeque::with_capacity(2);
+    /// let capacity = deque.capacity();
+    /// for i in 0..capacity {
+    ///     assert_eq!(deque.push_back_within_capacity(i), Ok(()));
+    /// }
+    /// assert_eq!(deque.push_back_within_capacity(capacity), Err(capacity));
+    /// assert_eq!(deque.len(), capacity);
+    /// assert_eq!(deque.back(), Some(&(capacity - 1)));
+    /// ```
+    ///
+    /// # Time complexity
+    ///
+    /// Takes *O*(1) time.
+    #[inline]
+    #[unstable(feature = "vec_deque_bounded", issue = "none")]
+    pub fn push_back_within_capacity(&mut self, value: T) -> Result<(), T> {
+        if self.is_full() {
+            return Err(value);
+        }
+
+        unsafe { self.buffer_write(self.to_physical_idx(self.len), value) }
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Prepends an element, overwriting the back element if the deque is at
+    /// full capacity, and returns the overwritten element.
+    ///
+    /// This method never reallocates, which turns the deque into a ring
+    /// buffer of its current capacity that keeps the most recently pushed
+    /// elements. If the capacity is zero, the element is returned right away.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(vec_deque_bounded)]
+    /// use std::collections::VecDeque;
+    ///
+    /// let mut deque = VecDeque::with_capacity(2);
+    /// let capacity = deque.capacity();
+    /// for i in 0..capacity {
+    ///     assert_eq!(deque.push_front_overwrite(i), None);
+    /// }
+    /// // The deque is full, so the first element pushed, now at the back, is overwritten.
+    /// assert_eq!(deque.push_front_overwrite(capacity), Some(0));
+    /// assert_eq!(deque.len(), capacity);
+    /// assert_eq!(deque.front(), Some(&capacity));
+    /// ```
+    ///
+    /// # Time complexity
+    ///
+    /// Takes *O*(1) time.
+    #[unstable(feature = "vec_deque_bounded", issue = "none")]
+    pub fn push_front_overwrite(&mut self, value: T) -> Option<T> {
+        let value = match self.push_front_within_capacity(value) {
+            Ok(()) => return None,
+            Err(value) => value,
+        };
+        if self.capacity() == 0 {
+            return Some(value);
+        }
+
+        // When full, the slot in front of the head holds the back element.
+        self.head = self.wrap_sub(self.head, 1);
+        unsafe {
+            let old = self.buffer_read(self.head);
+            self.buffer_write(self.head, value);
+            Some(old)
+        }
+    }
+
+    /// Appends an element, overwriting the front element if the deque is at
+    /// full capacity, and returns the overwritten element.
+    ///
+    /// This method never reallocates, which turns the deque into a ring
+    /// buffer of its current capacity that keeps the most recently pushed
+    /// elements. If the capacity is zero, the element is returned right away.
+    ///
+    /// # Examples
+    ///
+    /// A buffer that keeps the most recent samples, as many as fit in its
+    /// capacity:
+    ///
+    /// ```
+    /// #![feature(vec_deque_bounded)]
+    /// use std::collections::VecDeque;
+    ///
+    /// let mut samples = VecDeque::with_capacity(3);
+    /// let capacity = samples.capacity();
+    /// for sample in 0..capacity + 2 {
+    ///     samples.push_back_overwrite(sample);
+    /// }
+    /// // The two oldest samples were overwritten.
+    /// assert_eq!(samples.len(), capacity);
+    /// assert_eq!(samples.front(), Some(&2));
+    /// assert_eq!(samples.push_back_overwrite(capacity + 2), Some(2));
+    /// ```
+    ///
+    /// # Time complexity
+    ///
+    /// Takes *O*(1) time.
+    #[unstable(feature = "vec_deque_bounded", issue = "none")]
+    pub fn push_back_overwrite(&mut self, value: T) -> Option<T> {
+        let value = match self.push_back_within_capacity(value) {
+            Ok(()) => return None,
+            Err(value) => value,
+        };
+        if self.capacity() == 0 {
+            return Some(value);
+        }
+
+        // When full, the slot past the back element is the head.
+        let old_head = self.head;
+        self.head = self.to_physical_idx(1);
+        unsafe {
+            let old = self.buffer_read(old_head);
+            self.buffer_write(old_head, value);
+            Some(old)
+        }
+    }
+
     #[inline]
     fn is_contiguous(&self) -> bool {
         // Do the calculation like this to avoid overflowing if len + head > usize::MAX
@@ -2244,6 +2548,19 @@ fn grow(&mut self) {
         debug_assert!(!self.is_full());
     }
 
+    // The same as `grow`, but returns on errors instead of panicking or aborting.
+    #[inline(never)]
+    fn try_grow(&mut self) -> Result<(), TryReserveError> {
+        debug_assert!(self.is_full());
+        let old_cap = self.capacity();
+        self.buf.try_grow_one()?;
+        unsafe {
+            self.handle_capacity_increase(old_cap);
+        }
+        debug_assert!(!self.is_full());
+        Ok(())
+    }
+
     /// Modifies the deque in-place so that `len()` is equal to `new_len`,
     /// either by removing excess elements from the back or by appending
     /// elements generated by calling `generator` to the back.
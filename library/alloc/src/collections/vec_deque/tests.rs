@@ -1175,3 +1175,84 @@ fn hash_code(value: impl Hash) -> u64 {
     assert_eq!(vda, vdb);
     assert_eq!(hash_code(vda), hash_code(vdb));
 }
+
+#[test]
+fn test_push_within_capacity() {
+    let mut deq = VecDeque::with_capacity(4);
+    assert_eq!(deq.capacity(), 4);
+    for i in 0..4 {
+        if i % 2 == 0 {
+            assert_eq!(deq.push_back_within_capacity(i), Ok(()));
+        } else {
+            assert_eq!(deq.push_front_within_capacity(i), Ok(()));
+        }
+    }
+    assert_eq!(deq.push_back_within_capacity(4), Err(4));
+    assert_eq!(deq.push_front_within_capacity(5), Err(5));
+    assert_eq!(deq, [3, 1, 0, 2]);
+    assert_eq!(deq.capacity(), 4);
+
+    let mut empty: VecDeque<i32> = VecDeque::new();
+    assert_eq!(empty.push_back_within_capacity(0), Err(0));
+    assert_eq!(empty.push_back_overwrite(1), Some(1));
+    assert_eq!(empty.push_front_overwrite(2), Some(2));
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn test_push_overwrite() {
+    let mut deq = VecDeque::with_capacity(3);
+    let mut model = Vec::new();
+    for i in 0..20 {
+        // Mix both ends with a deque whose head sits everywhere in the buffer.
+        if i % 5 == 4 {
+            let old = deq.push_front_overwrite(i);
+            let expected = if model.len() == 3 { model.pop() } else { None };
+            model.insert(0, i);
+            assert_eq!(old, expected);
+        } else {
+            let old = deq.push_back_overwrite(i);
+            let expected = if model.len() == 3 { Some(model.remove(0)) } else { None };
+            model.push(i);
+            assert_eq!(old, expected);
+        }
+        assert_eq!(deq, model);
+        assert_eq!(deq.capacity(), 3);
+    }
+}
+
+#[test]
+fn test_push_overwrite_drops() {
+    static mut DROPS: usize = 0;
+    struct Elem(#[allow(dead_code)] u8);
+    impl Drop for Elem {
+        fn drop(&mut self) {
+            unsafe {
+                DROPS += 1;
+            }
+        }
+    }
+
+    let mut deq = VecDeque::with_capacity(2);
+    for _ in 0..5 {
+        drop(deq.push_back_overwrite(Elem(0)));
+    }
+    assert_eq!(unsafe { DROPS }, 3);
+    drop(deq);
+    assert_eq!(unsafe { DROPS }, 5);
+}
+
+#[test]
+fn test_as_contiguous() {
+    let mut deq = VecDeque::with_capacity(4);
+    assert_eq!(deq.as_contiguous(), Some(&[][..]));
+    deq.extend([1, 2, 3]);
+    assert_eq!(deq.as_contiguous(), Some(&[1, 2, 3][..]));
+    deq.push_back_overwrite(4);
+    deq.push_back_overwrite(5);
+    assert_eq!(deq.as_contiguous(), None);
+    assert_eq!(deq.as_contiguous_mut(), None);
+    deq.make_contiguous();
+    deq.as_contiguous_mut().unwrap()[0] = 0;
+    assert_eq!(deq.as_contiguous(), Some(&[0, 3, 4, 5][..]));
+}
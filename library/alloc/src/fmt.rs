@@ -594,6 +594,8 @@
 pub use core::fmt::{Arguments, write};
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use core::fmt::{Binary, Octal};
+#[unstable(feature = "custom_format_spec", issue = "none")]
+pub use core::fmt::{CustomFormat, CustomSpec, ParseCustomSpec};
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use core::fmt::{Debug, Display};
 #[unstable(feature = "formatting_options", issue = "118117")]
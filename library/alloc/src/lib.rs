@@ -102,6 +102,7 @@
 #![feature(assert_matches)]
 #![feature(async_fn_traits)]
 #![feature(async_iterator)]
+#![feature(bigint_helper_methods)]
 #![feature(bstr)]
 #![feature(bstr_internals)]
 #![feature(char_max_len)]
@@ -142,6 +143,7 @@
 #![feature(slice_from_ptr_range)]
 #![feature(slice_index_methods)]
 #![feature(slice_iter_mut_as_mut_slice)]
+#![feature(slice_partition_dedup)]
 #![feature(slice_ptr_get)]
 #![feature(slice_range)]
 #![feature(std_internals)]
@@ -219,6 +221,9 @@
 #[cfg(all(not(no_rc), not(no_sync), not(no_global_oom_handling)))]
 pub mod ffi;
 pub mod fmt;
+#[cfg(not(no_global_oom_handling))]
+#[unstable(feature = "bigint", issue = "none")]
+pub mod num;
 #[cfg(not(no_rc))]
 pub mod rc;
 pub mod slice;
@@ -0,0 +1,619 @@
+use core::cmp::Ordering;
+use core::fmt;
+use core::iter::{Product, Sum};
+use core::ops::{
+    Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Div, DivAssign,
+    Mul, MulAssign, Neg, Not, Rem, RemAssign, Shl, ShlAssign, Shr, ShrAssign, Sub, SubAssign,
+};
+use core::str::FromStr;
+
+use super::biguint::decode_f64;
+use super::limbs::{self, Limb};
+use super::{BigUint, ParseBigIntError, TryFromBigIntError, radix};
+use crate::string::String;
+use crate::vec::Vec;
+
+/// An arbitrary-precision signed integer.
+///
+/// A `BigInt` is a sign and a [`BigUint`] magnitude. Like the primitive signed integers,
+/// division rounds towards zero, and the remainder has the sign of the dividend; see
+/// [`div_euclid`] and [`rem_euclid`] for the Euclidean variants. The bitwise operators and the
+/// shifts behave as if the number were stored in two's complement with infinitely many sign
+/// bits, so `-1 >> 1` is `-1`.
+///
+/// The formatting traits print the sign and the magnitude, so negative numbers are formatted
+/// with a `-` in every radix, unlike the primitive integers in hexadecimal, octal and binary.
+///
+/// [`div_euclid`]: BigInt::div_euclid
+/// [`rem_euclid`]: BigInt::rem_euclid
+///
+/// # Examples
+///
+/// ```
+/// #![feature(bigint)]
+///
+/// use std::num::BigInt;
+///
+/// let a = BigInt::from(i128::MIN);
+/// let b = &a * &a;
+/// assert_eq!(b.to_string(), "28948022309329048855892746252171976963317496166410141009864396001978282409984");
+/// assert_eq!(&b / &a, a);
+/// assert_eq!(format!("{:x}", BigInt::from(-255)), "-ff");
+/// ```
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
+#[unstable(feature = "bigint", issue = "none")]
+pub struct BigInt {
+    /// Whether the number is negative, which zero is not.
+    negative: bool,
+    magnitude: BigUint,
+}
+
+impl BigInt {
+    /// The value zero.
+    #[unstable(feature = "bigint", issue = "none")]
+    pub const ZERO: Self = BigInt { negative: false, magnitude: BigUint::ZERO };
+
+    /// Creates a number from its sign and magnitude. The sign of zero is ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(bigint)]
+    ///
+    /// use std::num::{BigInt, BigUint};
+    ///
+    /// assert_eq!(BigInt::from_biguint(true, BigUint::from(5u8)), BigInt::from(-5));
+    /// assert_eq!(BigInt::from_biguint(true, BigUint::ZERO), BigInt::ZERO);
+    /// ```
+    #[unstable(feature = "bigint", issue = "none")]
+    pub fn from_biguint(negative: bool, magnitude: BigUint) -> Self {
+        BigInt { negative: negative && !magnitude.is_zero(), magnitude }
+    }
+
+    fn sign_and_limbs(&self) -> (bool, &[Limb]) {
+        (!self.negative, self.magnitude.limbs())
+    }
+
+    /// Returns the absolute value of `self`, as a reference to a [`BigUint`].
+    #[unstable(feature = "bigint", issue = "none")]
+    pub fn magnitude(&self) -> &BigUint {
+        &self.magnitude
+    }
+
+    /// Returns the absolute value of `self`, as a [`BigUint`].
+    #[unstable(feature = "bigint", issue = "none")]
+    pub fn unsigned_abs(self) -> BigUint {
+        self.magnitude
+    }
+
+    /// Returns the absolute value of `self`.
+    #[unstable(feature = "bigint", issue = "none")]
+    pub fn abs(&self) -> Self {
+        BigInt { negative: false, magnitude: self.magnitude.clone() }
+    }
+
+    /// Returns `true` if the number is zero.
+    #[unstable(feature = "bigint", issue = "none")]
+    pub fn is_zero(&self) -> bool {
+        self.magnitude.is_zero()
+    }
+
+    /// Returns `true` if the number is negative.
+    #[unstable(feature = "bigint", issue = "none")]
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    /// Returns `true` if the number is positive.
+    #[unstable(feature = "bigint", issue = "none")]
+    pub fn is_positive(&self) -> bool {
+        !self.negative && !self.is_zero()
+    }
+
+    /// Returns the number of bits needed to represent the absolute value of the number.
+    #[unstable(feature = "bigint", issue = "none")]
+    pub fn bits(&self) -> u64 {
+        self.magnitude.bits()
+    }
+
+    /// Raises `self` to the power of `exp`.
+    #[unstable(feature = "bigint", issue = "none")]
+    pub fn pow(&self, exp: u32) -> Self {
+        BigInt::from_biguint(self.negative && exp & 1 == 1, self.magnitude.pow(exp))
+    }
+
+    /// Returns `self` to the power of `exponent`, modulo `modulus`, as a number from zero to
+    /// `modulus - 1`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `modulus` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(bigint)]
+    ///
+    /// use std::num::{BigInt, BigUint};
+    ///
+    /// let m = BigUint::from(11u8);
+    /// assert_eq!(BigInt::from(-2).pow_mod(&BigUint::from(3u8), &m), BigUint::from(3u8));
+    /// ```
+    #[unstable(feature = "bigint", issue = "none")]
+    pub fn pow_mod(&self, exponent: &BigUint, modulus: &BigUint) -> BigUint {
+        let r = self.magnitude.pow_mod(exponent, modulus);
+        if self.negative && exponent.bit(0) && !r.is_zero() { modulus - r } else { r }
+    }
+
+    /// Returns the quotient, rounded towards zero, and the remainder of the division of `self`
+    /// by `rhs`, computed together.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero.
+    #[unstable(feature = "bigint", issue = "none")]
+    pub fn div_rem(&self, rhs: &Self) -> (Self, Self) {
+        let (q, r) = self.magnitude.div_rem(&rhs.magnitude);
+        (
+            BigInt::from_biguint(self.negative != rhs.negative, q),
+            BigInt::from_biguint(self.negative, r),
+        )
+    }
+
+    /// Calculates the quotient of Euclidean division of `self` by `rhs`, like
+    /// [`i64::div_euclid`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero.
+    #[unstable(feature = "bigint", issue = "none")]
+    pub fn div_euclid(&self, rhs: &Self) -> Self {
+        let (q, r) = self.div_rem(rhs);
+        if r.negative {
+            if rhs.negative { q + BigInt::from(1) } else { q - BigInt::from(1) }
+        } else {
+            q
+        }
+    }
+
+    /// Calculates the least nonnegative remainder of `self` modulo `rhs`, like
+    /// [`i64::rem_euclid`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(bigint)]
+    ///
+    /// use std::num::BigInt;
+    ///
+    /// let (a, b) = (BigInt::from(-7), BigInt::from(4));
+    /// assert_eq!(&a % &b, BigInt::from(-3));
+    /// assert_eq!(a.rem_euclid(&b), BigInt::from(1));
+    /// assert_eq!(a.div_euclid(&b), BigInt::from(-2));
+    /// ```
+    #[unstable(feature = "bigint", issue = "none")]
+    pub fn rem_euclid(&self, rhs: &Self) -> Self {
+        let r = self % rhs;
+        if r.negative { r + rhs.abs() } else { r }
+    }
+
+    /// Parses a number from a string in the given radix.
+    ///
+    /// The string is an optional `+` or `-` sign followed by digits, like for
+    /// [`i64::from_str_radix`]. Letters `a` to `z`, in either case, are the digits 10 to 35.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not in the range from 2 to 36.
+    #[unstable(feature = "bigint", issue = "none")]
+    pub fn from_str_radix(src: &str, radix: u32) -> Result<Self, ParseBigIntError> {
+        let (negative, digits) = match src.as_bytes() {
+            [b'-', rest @ ..] if !rest.is_empty() => (true, rest),
+            [b'+', rest @ ..] if !rest.is_empty() => (false, rest),
+            digits => (false, digits),
+        };
+        match radix::parse(digits, radix) {
+            Ok(limbs) => Ok(BigInt::from_biguint(negative, BigUint::from_limbs(limbs))),
+            Err(kind) => Err(ParseBigIntError { kind }),
+        }
+    }
+
+    /// Returns the number as a string of lowercase digits in the given radix, preceded by a `-`
+    /// sign if it is negative.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not in the range from 2 to 36.
+    #[unstable(feature = "bigint", issue = "none")]
+    pub fn to_str_radix(&self, radix: u32) -> String {
+        let mut s = String::new();
+        if self.negative {
+            s.push('-');
+        }
+        s.push_str(&self.magnitude.to_str_radix(radix));
+        s
+    }
+
+    /// Converts the number to the nearest `f64`, rounding half to even. Numbers too large for
+    /// `f64` become infinite.
+    #[unstable(feature = "bigint", issue = "none")]
+    pub fn to_f64(&self) -> f64 {
+        let x = self.magnitude.to_f64();
+        if self.negative { -x } else { x }
+    }
+
+    /// Converts the number to the nearest `f32`, rounding half to even. Numbers too large for
+    /// `f32` become infinite.
+    #[unstable(feature = "bigint", issue = "none")]
+    pub fn to_f32(&self) -> f32 {
+        let x = self.magnitude.to_f32();
+        if self.negative { -x } else { x }
+    }
+
+    /// Adds `(-1)^negative * magnitude` to `self`.
+    fn add_signed(&mut self, negative: bool, magnitude: &BigUint) {
+        if self.negative == negative {
+            self.magnitude += magnitude;
+        } else {
+            match self.magnitude.cmp(magnitude) {
+                Ordering::Greater => self.magnitude -= magnitude,
+                Ordering::Less => {
+                    self.magnitude = magnitude - &self.magnitude;
+                    self.negative = negative;
+                }
+                Ordering::Equal => *self = BigInt::ZERO,
+            }
+        }
+    }
+
+    /// Returns the number in two's complement, sign-extended to `len` limbs.
+    fn to_twos_complement(&self, len: usize) -> Vec<Limb> {
+        let mut v = self.magnitude.limbs().to_vec();
+        v.resize(len, 0);
+        if self.negative {
+            negate(&mut v);
+        }
+        v
+    }
+
+    /// Creates a number from its two's complement representation.
+    fn from_twos_complement(mut v: Vec<Limb>) -> Self {
+        let negative = v.last().is_some_and(|&top| top >> (limbs::LIMB_BITS - 1) == 1);
+        if negative {
+            negate(&mut v);
+        }
+        BigInt::from_biguint(negative, BigUint::from_limbs(v))
+    }
+
+    /// Applies a bitwise operation to the two's complement representations of `self` and `rhs`.
+    fn bitwise(&self, rhs: &Self, op: impl Fn(Limb, Limb) -> Limb) -> Self {
+        // One more limb than either magnitude makes room for the sign bit.
+        let len = self.magnitude.limbs().len().max(rhs.magnitude.limbs().len()) + 1;
+        let a = self.to_twos_complement(len);
+        let b = rhs.to_twos_complement(len);
+        BigInt::from_twos_complement(a.iter().zip(&b).map(|(&x, &y)| op(x, y)).collect())
+    }
+}
+
+/// Negates a number in two's complement.
+fn negate(v: &mut [Limb]) {
+    let mut carry = true;
+    for x in v {
+        (*x, carry) = (!*x).overflowing_add(carry as Limb);
+    }
+}
+
+#[unstable(feature = "bigint", issue = "none")]
+impl Ord for BigInt {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, false) => self.magnitude.cmp(&other.magnitude),
+            (true, true) => other.magnitude.cmp(&self.magnitude),
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+        }
+    }
+}
+
+#[unstable(feature = "bigint", issue = "none")]
+impl PartialOrd for BigInt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[unstable(feature = "bigint", issue = "none")]
+impl AddAssign<&BigInt> for BigInt {
+    fn add_assign(&mut self, rhs: &BigInt) {
+        self.add_signed(rhs.negative, &rhs.magnitude);
+    }
+}
+
+#[unstable(feature = "bigint", issue = "none")]
+impl SubAssign<&BigInt> for BigInt {
+    fn sub_assign(&mut self, rhs: &BigInt) {
+        self.add_signed(!rhs.negative, &rhs.magnitude);
+    }
+}
+
+#[unstable(feature = "bigint", issue = "none")]
+impl Mul<&BigInt> for &BigInt {
+    type Output = BigInt;
+
+    fn mul(self, rhs: &BigInt) -> BigInt {
+        BigInt::from_biguint(self.negative != rhs.negative, &self.magnitude * &rhs.magnitude)
+    }
+}
+
+#[unstable(feature = "bigint", issue = "none")]
+impl Div<&BigInt> for &BigInt {
+    type Output = BigInt;
+
+    #[track_caller]
+    fn div(self, rhs: &BigInt) -> BigInt {
+        BigInt::from_biguint(self.negative != rhs.negative, &self.magnitude / &rhs.magnitude)
+    }
+}
+
+#[unstable(feature = "bigint", issue = "none")]
+impl Rem<&BigInt> for &BigInt {
+    type Output = BigInt;
+
+    #[track_caller]
+    fn rem(self, rhs: &BigInt) -> BigInt {
+        BigInt::from_biguint(self.negative, &self.magnitude % &rhs.magnitude)
+    }
+}
+
+#[unstable(feature = "bigint", issue = "none")]
+impl BitAnd<&BigInt> for &BigInt {
+    type Output = BigInt;
+
+    fn bitand(self, rhs: &BigInt) -> BigInt {
+        self.bitwise(rhs, |a, b| a & b)
+    }
+}
+
+#[unstable(feature = "bigint", issue = "none")]
+impl BitOr<&BigInt> for &BigInt {
+    type Output = BigInt;
+
+    fn bitor(self, rhs: &BigInt) -> BigInt {
+        self.bitwise(rhs, |a, b| a | b)
+    }
+}
+
+#[unstable(feature = "bigint", issue = "none")]
+impl BitXor<&BigInt> for &BigInt {
+    type Output = BigInt;
+
+    fn bitxor(self, rhs: &BigInt) -> BigInt {
+        self.bitwise(rhs, |a, b| a ^ b)
+    }
+}
+
+forward_assign_binop!(BigInt, Add, add, AddAssign, add_assign);
+forward_assign_binop!(BigInt, Sub, sub, SubAssign, sub_assign);
+forward_ref_binop!(BigInt, Mul, mul, MulAssign, mul_assign);
+forward_ref_binop!(BigInt, Div, div, DivAssign, div_assign);
+forward_ref_binop!(BigInt, Rem, rem, RemAssign, rem_assign);
+forward_ref_binop!(BigInt, BitAnd, bitand, BitAndAssign, bitand_assign);
+forward_ref_binop!(BigInt, BitOr, bitor, BitOrAssign, bitor_assign);
+forward_ref_binop!(BigInt, BitXor, bitxor, BitXorAssign, bitxor_assign);
+
+#[unstable(feature = "bigint", issue = "none")]
+impl Shl<usize> for &BigInt {
+    type Output = BigInt;
+
+    fn shl(self, rhs: usize) -> BigInt {
+        BigInt::from_biguint(self.negative, &self.magnitude << rhs)
+    }
+}
+
+#[unstable(feature = "bigint", issue = "none")]
+impl Shr<usize> for &BigInt {
+    type Output = BigInt;
+
+    /// Shifts right with sign extension, which rounds towards negative infinity.
+    fn shr(self, rhs: usize) -> BigInt {
+        if self.negative {
+            // -x >> n == -((x - 1) >> n) - 1
+            let one = BigUint::from(1u8);
+            let magnitude = ((&self.magnitude - &one) >> rhs) + one;
+            BigInt::from_biguint(true, magnitude)
+        } else {
+            BigInt::from_biguint(false, &self.magnitude >> rhs)
+        }
+    }
+}
+
+forward_shift!(BigInt, Shl, shl, ShlAssign, shl_assign);
+forward_shift!(BigInt, Shr, shr, ShrAssign, shr_assign);
+
+#[unstable(feature = "bigint", issue = "none")]
+impl Neg for BigInt {
+    type Output = BigInt;
+
+    #[inline]
+    fn neg(self) -> BigInt {
+        BigInt::from_biguint(!self.negative, self.magnitude)
+    }
+}
+
+#[unstable(feature = "bigint", issue = "none")]
+impl Neg for &BigInt {
+    type Output = BigInt;
+
+    #[inline]
+    fn neg(self) -> BigInt {
+        -self.clone()
+    }
+}
+
+#[unstable(feature = "bigint", issue = "none")]
+impl Not for BigInt {
+    type Output = BigInt;
+
+    /// Returns `-self - 1`, the bitwise complement in two's complement.
+    fn not(mut self) -> BigInt {
+        self.add_signed(false, &BigUint::from(1u8));
+        -self
+    }
+}
+
+#[unstable(feature = "bigint", issue = "none")]
+impl Not for &BigInt {
+    type Output = BigInt;
+
+    #[inline]
+    fn not(self) -> BigInt {
+        !self.clone()
+    }
+}
+
+#[unstable(feature = "bigint", issue = "none")]
+impl Sum for BigInt {
+    fn sum<I: Iterator<Item = BigInt>>(iter: I) -> Self {
+        iter.fold(BigInt::ZERO, |acc, x| acc + x)
+    }
+}
+
+#[unstable(feature = "bigint", issue = "none")]
+impl<'a> Sum<&'a BigInt> for BigInt {
+    fn sum<I: Iterator<Item = &'a BigInt>>(iter: I) -> Self {
+        iter.fold(BigInt::ZERO, |acc, x| acc + x)
+    }
+}
+
+#[unstable(feature = "bigint", issue = "none")]
+impl Product for BigInt {
+    fn product<I: Iterator<Item = BigInt>>(iter: I) -> Self {
+        iter.fold(BigInt::from(1), |acc, x| acc * x)
+    }
+}
+
+#[unstable(feature = "bigint", issue = "none")]
+impl<'a> Product<&'a BigInt> for BigInt {
+    fn product<I: Iterator<Item = &'a BigInt>>(iter: I) -> Self {
+        iter.fold(BigInt::from(1), |acc, x| acc * x)
+    }
+}
+
+#[unstable(feature = "bigint", issue = "none")]
+impl From<BigUint> for BigInt {
+    #[inline]
+    fn from(magnitude: BigUint) -> Self {
+        BigInt { negative: false, magnitude }
+    }
+}
+
+#[unstable(feature = "bigint", issue = "none")]
+impl TryFrom<BigInt> for BigUint {
+    type Error = TryFromBigIntError;
+
+    #[inline]
+    fn try_from(n: BigInt) -> Result<Self, TryFromBigIntError> {
+        if n.negative { Err(TryFromBigIntError(())) } else { Ok(n.magnitude) }
+    }
+}
+
+#[unstable(feature = "bigint", issue = "none")]
+impl TryFrom<&BigInt> for BigUint {
+    type Error = TryFromBigIntError;
+
+    #[inline]
+    fn try_from(n: &BigInt) -> Result<Self, TryFromBigIntError> {
+        if n.negative { Err(TryFromBigIntError(())) } else { Ok(n.magnitude.clone()) }
+    }
+}
+
+macro_rules! impl_from_int {
+    ($($t:ty => |$n:ident| $magnitude:expr),* $(,)?) => {$(
+        #[unstable(feature = "bigint", issue = "none")]
+        impl From<$t> for BigInt {
+            #[inline]
+            #[allow(unused_comparisons)]
+            fn from($n: $t) -> Self {
+                BigInt::from_biguint($n < 0, BigUint::from($magnitude))
+            }
+        }
+
+        #[unstable(feature = "bigint", issue = "none")]
+        impl TryFrom<&BigInt> for $t {
+            type Error = TryFromBigIntError;
+
+            fn try_from(n: &BigInt) -> Result<$t, TryFromBigIntError> {
+                let magnitude = u128::try_from(&n.magnitude)?;
+                if n.negative {
+                    let n = 0i128.checked_sub_unsigned(magnitude).ok_or(TryFromBigIntError(()))?;
+                    n.try_into().map_err(|_| TryFromBigIntError(()))
+                } else {
+                    magnitude.try_into().map_err(|_| TryFromBigIntError(()))
+                }
+            }
+        }
+
+        #[unstable(feature = "bigint", issue = "none")]
+        impl TryFrom<BigInt> for $t {
+            type Error = TryFromBigIntError;
+
+            #[inline]
+            fn try_from(n: BigInt) -> Result<$t, TryFromBigIntError> {
+                <$t>::try_from(&n)
+            }
+        }
+    )*};
+}
+
+impl_from_int! {
+    u8 => |n| n, u16 => |n| n, u32 => |n| n, u64 => |n| n, u128 => |n| n, usize => |n| n,
+    i8 => |n| n.unsigned_abs(), i16 => |n| n.unsigned_abs(), i32 => |n| n.unsigned_abs(),
+    i64 => |n| n.unsigned_abs(), i128 => |n| n.unsigned_abs(), isize => |n| n.unsigned_abs(),
+}
+
+macro_rules! impl_try_from_float {
+    ($($t:ty)*) => {$(
+        /// Converts the integer part of a float, which must be finite.
+        #[unstable(feature = "bigint", issue = "none")]
+        impl TryFrom<$t> for BigInt {
+            type Error = TryFromBigIntError;
+
+            fn try_from(x: $t) -> Result<Self, TryFromBigIntError> {
+                let (negative, mantissa, exp) = decode_f64(x as f64).ok_or(TryFromBigIntError(()))?;
+                Ok(BigInt::from_biguint(negative, BigUint::from_float_parts(mantissa, exp)))
+            }
+        }
+    )*};
+}
+
+impl_try_from_float!(f32 f64);
+
+#[unstable(feature = "bigint", issue = "none")]
+impl FromStr for BigInt {
+    type Err = ParseBigIntError;
+
+    #[inline]
+    fn from_str(src: &str) -> Result<Self, ParseBigIntError> {
+        BigInt::from_str_radix(src, 10)
+    }
+}
+
+impl_fmt_radix! {
+    BigInt;
+    Display, 10, "", false;
+    Binary, 2, "0b", false;
+    Octal, 8, "0o", false;
+    LowerHex, 16, "0x", false;
+    UpperHex, 16, "0x", true;
+}
+
+#[unstable(feature = "bigint", issue = "none")]
+impl fmt::Debug for BigInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
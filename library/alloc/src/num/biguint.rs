@@ -0,0 +1,820 @@
+use core::cmp::Ordering;
+use core::fmt;
+use core::iter::{Product, Sum};
+use core::ops::{
+    Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Div, DivAssign,
+    Mul, MulAssign, Rem, RemAssign, Shl, ShlAssign, Shr, ShrAssign, Sub, SubAssign,
+};
+use core::str::FromStr;
+
+use super::limbs::{self, LIMB_BITS, Limb};
+use super::{ParseBigIntError, TryFromBigIntError, radix};
+use crate::string::String;
+use crate::vec::Vec;
+
+/// An arbitrary-precision unsigned integer.
+///
+/// `BigUint` stores its value on the heap and grows as needed, so its arithmetic never
+/// overflows. Subtraction that would go below zero panics, like it does for the primitive
+/// unsigned integers in debug builds, and so does division by zero. See [`BigInt`] for the signed
+/// counterpart.
+///
+/// All the arithmetic and bitwise operators are implemented, for owned values as well as for
+/// references, together with [`pow`], [`pow_mod`] and [`div_rem`]. Multiplication of large
+/// numbers uses the Karatsuba algorithm.
+///
+/// `BigUint` converts from and to every primitive integer type, and from the floating-point
+/// types. It parses from and formats to strings in any radix from 2 to 36.
+///
+/// [`BigInt`]: super::BigInt
+/// [`pow`]: BigUint::pow
+/// [`pow_mod`]: BigUint::pow_mod
+/// [`div_rem`]: BigUint::div_rem
+///
+/// # Examples
+///
+/// ```
+/// #![feature(bigint)]
+///
+/// use std::num::BigUint;
+///
+/// let a: BigUint = "123456789012345678901234567890".parse().unwrap();
+/// let b = BigUint::from(u64::MAX);
+///
+/// assert_eq!((&a * &b).to_string(), "2277375791072698140124934049010216029110176642350");
+/// assert_eq!(&a % &b, BigUint::from(14083847780529871560u64));
+/// assert_eq!(format!("{b:#x}"), "0xffffffffffffffff");
+/// ```
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
+#[unstable(feature = "bigint", issue = "none")]
+pub struct BigUint {
+    /// The value in base 2<sup>64</sup>, least significant limb first, without high zero limbs.
+    limbs: Vec<Limb>,
+}
+
+impl BigUint {
+    /// The value zero.
+    #[unstable(feature = "bigint", issue = "none")]
+    pub const ZERO: Self = BigUint { limbs: Vec::new() };
+
+    /// Creates a number from limbs that may have high zero limbs.
+    pub(super) fn from_limbs(mut limbs: Vec<Limb>) -> Self {
+        limbs::trim(&mut limbs);
+        BigUint { limbs }
+    }
+
+    pub(super) fn limbs(&self) -> &[Limb] {
+        &self.limbs
+    }
+
+    /// Returns whether the number is nonnegative and its magnitude, for formatting.
+    fn sign_and_limbs(&self) -> (bool, &[Limb]) {
+        (true, &self.limbs)
+    }
+
+    /// Returns `true` if the number is zero.
+    #[unstable(feature = "bigint", issue = "none")]
+    pub fn is_zero(&self) -> bool {
+        self.limbs.is_empty()
+    }
+
+    /// Returns the number of bits needed to represent the number, which is zero for zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(bigint)]
+    ///
+    /// use std::num::BigUint;
+    ///
+    /// assert_eq!(BigUint::ZERO.bits(), 0);
+    /// assert_eq!(BigUint::from(255u8).bits(), 8);
+    /// assert_eq!(BigUint::from(u128::MAX).bits(), 128);
+    /// ```
+    #[unstable(feature = "bigint", issue = "none")]
+    pub fn bits(&self) -> u64 {
+        match self.limbs.last() {
+            Some(top) => self.limbs.len() as u64 * LIMB_BITS as u64 - top.leading_zeros() as u64,
+            None => 0,
+        }
+    }
+
+    /// Returns the number of trailing zero bits, or `None` for zero.
+    #[unstable(feature = "bigint", issue = "none")]
+    pub fn trailing_zeros(&self) -> Option<u64> {
+        let i = self.limbs.iter().position(|&l| l != 0)?;
+        Some(i as u64 * LIMB_BITS as u64 + self.limbs[i].trailing_zeros() as u64)
+    }
+
+    /// Returns the number of ones in the binary representation.
+    #[unstable(feature = "bigint", issue = "none")]
+    pub fn count_ones(&self) -> u64 {
+        self.limbs.iter().map(|l| l.count_ones() as u64).sum()
+    }
+
+    /// Returns the bit of weight 2<sup>`n`</sup>.
+    #[unstable(feature = "bigint", issue = "none")]
+    pub fn bit(&self, n: u64) -> bool {
+        let (i, shift) = ((n / LIMB_BITS as u64) as usize, n % LIMB_BITS as u64);
+        self.limbs.get(i).is_some_and(|l| l >> shift & 1 == 1)
+    }
+
+    /// Sets the bit of weight 2<sup>`n`</sup> to `value`.
+    #[unstable(feature = "bigint", issue = "none")]
+    pub fn set_bit(&mut self, n: u64, value: bool) {
+        let (i, shift) = ((n / LIMB_BITS as u64) as usize, n % LIMB_BITS as u64);
+        if value {
+            if i >= self.limbs.len() {
+                self.limbs.resize(i + 1, 0);
+            }
+            self.limbs[i] |= 1 << shift;
+        } else if i < self.limbs.len() {
+            self.limbs[i] &= !(1 << shift);
+            limbs::trim(&mut self.limbs);
+        }
+    }
+
+    /// Raises `self` to the power of `exp`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(bigint)]
+    ///
+    /// use std::num::BigUint;
+    ///
+    /// assert_eq!(BigUint::from(2u8).pow(100).to_string(), "1267650600228229401496703205376");
+    /// assert_eq!(BigUint::ZERO.pow(0), BigUint::from(1u8));
+    /// ```
+    #[unstable(feature = "bigint", issue = "none")]
+    pub fn pow(&self, mut exp: u32) -> Self {
+        let mut base = self.clone();
+        let mut acc = BigUint::from(1u8);
+        while exp > 0 {
+            if exp & 1 == 1 {
+                acc *= &base;
+            }
+            exp >>= 1;
+            if exp > 0 {
+                base = &base * &base;
+            }
+        }
+        acc
+    }
+
+    /// Returns `self` to the power of `exponent`, modulo `modulus`.
+    ///
+    /// The computation works on numbers smaller than `modulus` throughout, so it is practical
+    /// for exponents of any size. Odd moduli, as used in cryptography, take the faster path of
+    /// Montgomery multiplication.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `modulus` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(bigint)]
+    ///
+    /// use std::num::BigUint;
+    ///
+    /// // Fermat's little theorem: a^(p-1) = 1 (mod p) for a prime p.
+    /// let p = BigUint::from(2u8).pow(127) - BigUint::from(1u8);
+    /// let a = BigUint::from(123456789u32);
+    /// let p_minus_1 = &p - BigUint::from(1u8);
+    /// assert_eq!(a.pow_mod(&p_minus_1, &p), BigUint::from(1u8));
+    /// ```
+    #[unstable(feature = "bigint", issue = "none")]
+    pub fn pow_mod(&self, exponent: &Self, modulus: &Self) -> Self {
+        assert!(!modulus.is_zero(), "attempt to calculate the remainder with a divisor of zero");
+        if modulus.limbs == [1] {
+            return BigUint::ZERO;
+        }
+        let base = self % modulus;
+        if modulus.limbs[0] & 1 == 1 {
+            Montgomery::new(&modulus.limbs).pow(&base.limbs, &exponent.limbs)
+        } else {
+            let mut acc = BigUint::from(1u8);
+            for i in (0..exponent.bits()).rev() {
+                acc = &(&acc * &acc) % modulus;
+                if exponent.bit(i) {
+                    acc = &(&acc * &base) % modulus;
+                }
+            }
+            acc
+        }
+    }
+
+    /// Returns the quotient and the remainder of the division of `self` by `rhs`, computed
+    /// together.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(bigint)]
+    ///
+    /// use std::num::BigUint;
+    ///
+    /// let (q, r) = BigUint::from(100u8).div_rem(&BigUint::from(7u8));
+    /// assert_eq!((q, r), (BigUint::from(14u8), BigUint::from(2u8)));
+    /// ```
+    #[unstable(feature = "bigint", issue = "none")]
+    pub fn div_rem(&self, rhs: &Self) -> (Self, Self) {
+        assert!(!rhs.is_zero(), "attempt to divide by zero");
+        let (q, r) = limbs::div_rem(&self.limbs, &rhs.limbs);
+        (BigUint::from_limbs(q), BigUint::from_limbs(r))
+    }
+
+    /// Checked subtraction. Returns `None` if `rhs` is greater than `self`.
+    #[unstable(feature = "bigint", issue = "none")]
+    pub fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+        if *self < *rhs {
+            return None;
+        }
+        let mut diff = self.limbs.clone();
+        limbs::sub_assign(&mut diff, &rhs.limbs);
+        Some(BigUint::from_limbs(diff))
+    }
+
+    /// Parses a number from a string in the given radix.
+    ///
+    /// The string is an optional `+` sign followed by digits, like for [`u64::from_str_radix`].
+    /// Letters `a` to `z`, in either case, are the digits 10 to 35.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not in the range from 2 to 36.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(bigint)]
+    ///
+    /// use std::num::BigUint;
+    ///
+    /// let n = BigUint::from_str_radix("ffffffffffffffffffffffffffffffff", 16).unwrap();
+    /// assert_eq!(n, BigUint::from(u128::MAX));
+    /// assert!(BigUint::from_str_radix("-1", 10).is_err());
+    /// ```
+    #[unstable(feature = "bigint", issue = "none")]
+    pub fn from_str_radix(src: &str, radix: u32) -> Result<Self, ParseBigIntError> {
+        let digits = src.as_bytes();
+        let digits = digits.strip_prefix(b"+").filter(|d| !d.is_empty()).unwrap_or(digits);
+        match radix::parse(digits, radix) {
+            Ok(limbs) => Ok(BigUint { limbs }),
+            Err(kind) => Err(ParseBigIntError { kind }),
+        }
+    }
+
+    /// Returns the number as a string of lowercase digits in the given radix.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not in the range from 2 to 36.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(bigint)]
+    ///
+    /// use std::num::BigUint;
+    ///
+    /// assert_eq!(BigUint::from(u64::MAX).to_str_radix(36), "3w5e11264sgsf");
+    /// ```
+    #[unstable(feature = "bigint", issue = "none")]
+    pub fn to_str_radix(&self, radix: u32) -> String {
+        // SAFETY: the digits are ASCII.
+        unsafe { String::from_utf8_unchecked(radix::to_digits(&self.limbs, radix)) }
+    }
+
+    /// Creates a number from its little-endian bytes.
+    #[unstable(feature = "bigint", issue = "none")]
+    pub fn from_le_bytes(bytes: &[u8]) -> Self {
+        let limbs = bytes
+            .chunks(size_of::<Limb>())
+            .map(|chunk| {
+                let mut buf = [0; size_of::<Limb>()];
+                buf[..chunk.len()].copy_from_slice(chunk);
+                Limb::from_le_bytes(buf)
+            })
+            .collect();
+        BigUint::from_limbs(limbs)
+    }
+
+    /// Creates a number from its big-endian bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(bigint)]
+    ///
+    /// use std::num::BigUint;
+    ///
+    /// let n = BigUint::from_be_bytes(&[0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]);
+    /// assert_eq!(n, BigUint::from(1u128 << 64));
+    /// assert_eq!(n.to_be_bytes(), [0x01, 0, 0, 0, 0, 0, 0, 0, 0]);
+    /// ```
+    #[unstable(feature = "bigint", issue = "none")]
+    pub fn from_be_bytes(bytes: &[u8]) -> Self {
+        let limbs = bytes
+            .rchunks(size_of::<Limb>())
+            .map(|chunk| {
+                let mut buf = [0; size_of::<Limb>()];
+                buf[size_of::<Limb>() - chunk.len()..].copy_from_slice(chunk);
+                Limb::from_be_bytes(buf)
+            })
+            .collect();
+        BigUint::from_limbs(limbs)
+    }
+
+    /// Returns the little-endian bytes of the number, without trailing zero bytes except for
+    /// zero itself, which is `[0]`.
+    #[unstable(feature = "bigint", issue = "none")]
+    pub fn to_le_bytes(&self) -> Vec<u8> {
+        let mut bytes: Vec<u8> = self.limbs.iter().flat_map(|l| l.to_le_bytes()).collect();
+        while bytes.len() > 1 && bytes.last() == Some(&0) {
+            bytes.pop();
+        }
+        if bytes.is_empty() {
+            bytes.push(0);
+        }
+        bytes
+    }
+
+    /// Returns the big-endian bytes of the number, without leading zero bytes except for zero
+    /// itself, which is `[0]`.
+    #[unstable(feature = "bigint", issue = "none")]
+    pub fn to_be_bytes(&self) -> Vec<u8> {
+        let mut bytes = self.to_le_bytes();
+        bytes.reverse();
+        bytes
+    }
+
+    /// Converts the number to the nearest `f64`, rounding half to even. Numbers too large for
+    /// `f64` become infinity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(bigint)]
+    ///
+    /// use std::num::BigUint;
+    ///
+    /// assert_eq!(BigUint::from(u128::MAX).to_f64(), 2f64.powi(128));
+    /// assert_eq!(BigUint::from(2u8).pow(1024).to_f64(), f64::INFINITY);
+    /// ```
+    #[unstable(feature = "bigint", issue = "none")]
+    pub fn to_f64(&self) -> f64 {
+        let (top, exp) = self.top_bits();
+        // `top` has far more than 53 bits, so rounding it once is exact; the scaling is exact as
+        // long as the result is finite.
+        if exp > f64::MAX_EXP as u64 {
+            return f64::INFINITY;
+        }
+        top as f64 * f64::from_bits((f64::MAX_EXP as u64 - 1 + exp) << (f64::MANTISSA_DIGITS - 1))
+    }
+
+    /// Converts the number to the nearest `f32`, rounding half to even. Numbers too large for
+    /// `f32` become infinity.
+    #[unstable(feature = "bigint", issue = "none")]
+    pub fn to_f32(&self) -> f32 {
+        let (top, exp) = self.top_bits();
+        if exp > f32::MAX_EXP as u64 {
+            return f32::INFINITY;
+        }
+        let scale =
+            f32::from_bits(((f32::MAX_EXP as u64 - 1 + exp) as u32) << (f32::MANTISSA_DIGITS - 1));
+        top as f32 * scale
+    }
+
+    /// Returns the top 64 bits of the number, with the lowest one set if any of the following
+    /// bits is (so that rounding the result to fewer bits rounds the number correctly), and the
+    /// power of two that they are to be scaled by.
+    fn top_bits(&self) -> (u64, u64) {
+        let bits = self.bits();
+        if bits <= 64 {
+            return (self.limbs.first().copied().unwrap_or(0), 0);
+        }
+        let exp = bits - 64;
+        let shifted = self >> exp as usize;
+        let sticky = self.trailing_zeros().is_some_and(|tz| tz < exp);
+        (shifted.limbs[0] | sticky as u64, exp)
+    }
+
+    /// Creates a number from the integer part of a finite float, given as its mantissa and
+    /// exponent.
+    pub(super) fn from_float_parts(mantissa: u64, exp: i32) -> Self {
+        let m = BigUint::from(mantissa);
+        if exp >= 0 { m << exp as usize } else { m >> exp.unsigned_abs() as usize }
+    }
+}
+
+/// Decomposes a finite, nonzero float into a sign, mantissa and exponent.
+pub(super) fn decode_f64(x: f64) -> Option<(bool, u64, i32)> {
+    if !x.is_finite() {
+        return None;
+    }
+    let bits = x.to_bits();
+    let negative = bits >> 63 == 1;
+    let biased = ((bits >> 52) & 0x7ff) as i32;
+    let fraction = bits & ((1 << 52) - 1);
+    Some(if biased == 0 {
+        (negative, fraction, -1074)
+    } else {
+        (negative, fraction | 1 << 52, biased - 1075)
+    })
+}
+
+/// Montgomery multiplication modulo an odd number, for [`BigUint::pow_mod`].
+struct Montgomery<'a> {
+    modulus: &'a [Limb],
+    /// `-modulus⁻¹ mod 2⁶⁴`.
+    m_inv: Limb,
+}
+
+impl<'a> Montgomery<'a> {
+    fn new(modulus: &'a [Limb]) -> Self {
+        // Newton's iteration doubles the number of correct low bits of the inverse every step,
+        // and `m` is its own inverse modulo 2^3.
+        let m = modulus[0];
+        let mut inv = m;
+        for _ in 0..5 {
+            inv = inv.wrapping_mul(2u64.wrapping_sub(m.wrapping_mul(inv)));
+        }
+        Montgomery { modulus, m_inv: inv.wrapping_neg() }
+    }
+
+    /// Returns `a * b / R mod m`, where `R = 2^(64 * m.len())`, for `a, b < m` that are as long
+    /// as `m`.
+    fn mul(&self, a: &[Limb], b: &[Limb]) -> Vec<Limb> {
+        let m = self.modulus;
+        let n = m.len();
+        let mut t = vec![0; n + 2];
+        for &ai in a {
+            // t += ai * b
+            let mut carry = 0;
+            for (tj, &bj) in t.iter_mut().zip(b) {
+                (*tj, carry) = ai.carrying_mul_add(bj, carry, *tj);
+            }
+            let (s, c) = t[n].overflowing_add(carry);
+            t[n] = s;
+            t[n + 1] = c as Limb;
+
+            // t = (t + u * m) / 2^64, where u makes the low limb vanish.
+            let u = t[0].wrapping_mul(self.m_inv);
+            let (_, mut carry) = u.carrying_mul_add(m[0], 0, t[0]);
+            for j in 1..n {
+                (t[j - 1], carry) = u.carrying_mul_add(m[j], carry, t[j]);
+            }
+            let (s, c) = t[n].overflowing_add(carry);
+            t[n - 1] = s;
+            t[n] = t[n + 1] + c as Limb;
+        }
+        // t < 2m now.
+        if t[n] != 0 || limbs::cmp(&t[..n], m) != Ordering::Less {
+            limbs::sub_assign(&mut t[..=n], m);
+        }
+        t.truncate(n);
+        t
+    }
+
+    /// Converts `a < m` to the Montgomery form `a * R mod m`.
+    fn to_montgomery(&self, a: &[Limb]) -> Vec<Limb> {
+        let mut shifted = vec![0; self.modulus.len()];
+        shifted.extend_from_slice(a);
+        limbs::trim(&mut shifted);
+        let (_, mut r) = limbs::div_rem(&shifted, self.modulus);
+        r.resize(self.modulus.len(), 0);
+        r
+    }
+
+    fn pow(&self, base: &[Limb], exponent: &[Limb]) -> BigUint {
+        const WINDOW: u32 = 4;
+        let n = self.modulus.len();
+
+        // table[i] = base^i, in Montgomery form.
+        let mut one = vec![0; n];
+        one[0] = 1;
+        let mut table = Vec::with_capacity(1 << WINDOW);
+        table.push(self.to_montgomery(&one));
+        table.push(self.to_montgomery(base));
+        for i in 2..1 << WINDOW {
+            let next = self.mul(&table[i - 1], &table[1]);
+            table.push(next);
+        }
+
+        let mut acc = table[0].clone();
+        for &limb in exponent.iter().rev() {
+            for shift in (0..LIMB_BITS / WINDOW).rev() {
+                for _ in 0..WINDOW {
+                    acc = self.mul(&acc, &acc);
+                }
+                let digit = (limb >> (shift * WINDOW)) as usize & ((1 << WINDOW) - 1);
+                if digit != 0 {
+                    acc = self.mul(&acc, &table[digit]);
+                }
+            }
+        }
+        // Multiplying by 1 divides by R, which leaves the Montgomery form.
+        BigUint::from_limbs(self.mul(&acc, &one))
+    }
+}
+
+#[unstable(feature = "bigint", issue = "none")]
+impl Ord for BigUint {
+    fn cmp(&self, other: &Self) -> Ordering {
+        limbs::cmp(&self.limbs, &other.limbs)
+    }
+}
+
+#[unstable(feature = "bigint", issue = "none")]
+impl PartialOrd for BigUint {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[unstable(feature = "bigint", issue = "none")]
+impl AddAssign<&BigUint> for BigUint {
+    fn add_assign(&mut self, rhs: &BigUint) {
+        if self.limbs.len() < rhs.limbs.len() {
+            self.limbs.resize(rhs.limbs.len(), 0);
+        }
+        if limbs::add_assign(&mut self.limbs, &rhs.limbs) {
+            self.limbs.push(1);
+        }
+    }
+}
+
+#[unstable(feature = "bigint", issue = "none")]
+impl SubAssign<&BigUint> for BigUint {
+    #[track_caller]
+    fn sub_assign(&mut self, rhs: &BigUint) {
+        assert!(*self >= *rhs, "attempt to subtract with overflow");
+        limbs::sub_assign(&mut self.limbs, &rhs.limbs);
+        limbs::trim(&mut self.limbs);
+    }
+}
+
+#[unstable(feature = "bigint", issue = "none")]
+impl Mul<&BigUint> for &BigUint {
+    type Output = BigUint;
+
+    fn mul(self, rhs: &BigUint) -> BigUint {
+        BigUint::from_limbs(limbs::mul(&self.limbs, &rhs.limbs))
+    }
+}
+
+#[unstable(feature = "bigint", issue = "none")]
+impl Div<&BigUint> for &BigUint {
+    type Output = BigUint;
+
+    #[track_caller]
+    fn div(self, rhs: &BigUint) -> BigUint {
+        self.div_rem(rhs).0
+    }
+}
+
+#[unstable(feature = "bigint", issue = "none")]
+impl Rem<&BigUint> for &BigUint {
+    type Output = BigUint;
+
+    #[track_caller]
+    fn rem(self, rhs: &BigUint) -> BigUint {
+        assert!(!rhs.is_zero(), "attempt to calculate the remainder with a divisor of zero");
+        self.div_rem(rhs).1
+    }
+}
+
+#[unstable(feature = "bigint", issue = "none")]
+impl BitAndAssign<&BigUint> for BigUint {
+    fn bitand_assign(&mut self, rhs: &BigUint) {
+        self.limbs.truncate(rhs.limbs.len());
+        for (a, b) in self.limbs.iter_mut().zip(&rhs.limbs) {
+            *a &= b;
+        }
+        limbs::trim(&mut self.limbs);
+    }
+}
+
+#[unstable(feature = "bigint", issue = "none")]
+impl BitOrAssign<&BigUint> for BigUint {
+    fn bitor_assign(&mut self, rhs: &BigUint) {
+        if self.limbs.len() < rhs.limbs.len() {
+            self.limbs.resize(rhs.limbs.len(), 0);
+        }
+        for (a, b) in self.limbs.iter_mut().zip(&rhs.limbs) {
+            *a |= b;
+        }
+    }
+}
+
+#[unstable(feature = "bigint", issue = "none")]
+impl BitXorAssign<&BigUint> for BigUint {
+    fn bitxor_assign(&mut self, rhs: &BigUint) {
+        if self.limbs.len() < rhs.limbs.len() {
+            self.limbs.resize(rhs.limbs.len(), 0);
+        }
+        for (a, b) in self.limbs.iter_mut().zip(&rhs.limbs) {
+            *a ^= b;
+        }
+        limbs::trim(&mut self.limbs);
+    }
+}
+
+forward_assign_binop!(BigUint, Add, add, AddAssign, add_assign);
+forward_assign_binop!(BigUint, Sub, sub, SubAssign, sub_assign);
+forward_ref_binop!(BigUint, Mul, mul, MulAssign, mul_assign);
+forward_ref_binop!(BigUint, Div, div, DivAssign, div_assign);
+forward_ref_binop!(BigUint, Rem, rem, RemAssign, rem_assign);
+forward_assign_binop!(BigUint, BitAnd, bitand, BitAndAssign, bitand_assign);
+forward_assign_binop!(BigUint, BitOr, bitor, BitOrAssign, bitor_assign);
+forward_assign_binop!(BigUint, BitXor, bitxor, BitXorAssign, bitxor_assign);
+
+#[unstable(feature = "bigint", issue = "none")]
+impl Shl<usize> for &BigUint {
+    type Output = BigUint;
+
+    fn shl(self, rhs: usize) -> BigUint {
+        if self.is_zero() {
+            return BigUint::ZERO;
+        }
+        let (whole, bits) = (rhs / LIMB_BITS as usize, (rhs % LIMB_BITS as usize) as u32);
+        let mut limbs = vec![0; whole];
+        limbs.extend(limbs::shl_bits(&self.limbs, bits));
+        BigUint { limbs }
+    }
+}
+
+#[unstable(feature = "bigint", issue = "none")]
+impl Shr<usize> for &BigUint {
+    type Output = BigUint;
+
+    fn shr(self, rhs: usize) -> BigUint {
+        let (whole, bits) = (rhs / LIMB_BITS as usize, (rhs % LIMB_BITS as usize) as u32);
+        match self.limbs.get(whole..) {
+            Some(rest) => BigUint::from_limbs(limbs::shr_bits(rest, bits)),
+            None => BigUint::ZERO,
+        }
+    }
+}
+
+forward_shift!(BigUint, Shl, shl, ShlAssign, shl_assign);
+forward_shift!(BigUint, Shr, shr, ShrAssign, shr_assign);
+
+#[unstable(feature = "bigint", issue = "none")]
+impl Sum for BigUint {
+    fn sum<I: Iterator<Item = BigUint>>(iter: I) -> Self {
+        iter.fold(BigUint::ZERO, |acc, x| acc + x)
+    }
+}
+
+#[unstable(feature = "bigint", issue = "none")]
+impl<'a> Sum<&'a BigUint> for BigUint {
+    fn sum<I: Iterator<Item = &'a BigUint>>(iter: I) -> Self {
+        iter.fold(BigUint::ZERO, |acc, x| acc + x)
+    }
+}
+
+#[unstable(feature = "bigint", issue = "none")]
+impl Product for BigUint {
+    fn product<I: Iterator<Item = BigUint>>(iter: I) -> Self {
+        iter.fold(BigUint::from(1u8), |acc, x| acc * x)
+    }
+}
+
+#[unstable(feature = "bigint", issue = "none")]
+impl<'a> Product<&'a BigUint> for BigUint {
+    fn product<I: Iterator<Item = &'a BigUint>>(iter: I) -> Self {
+        iter.fold(BigUint::from(1u8), |acc, x| acc * x)
+    }
+}
+
+macro_rules! impl_from_unsigned {
+    ($($t:ty)*) => {$(
+        #[unstable(feature = "bigint", issue = "none")]
+        impl From<$t> for BigUint {
+            #[inline]
+            fn from(n: $t) -> Self {
+                let n = n as u128;
+                BigUint::from_limbs(vec![n as Limb, (n >> LIMB_BITS) as Limb])
+            }
+        }
+
+        #[unstable(feature = "bigint", issue = "none")]
+        impl TryFrom<&BigUint> for $t {
+            type Error = TryFromBigIntError;
+
+            fn try_from(n: &BigUint) -> Result<$t, TryFromBigIntError> {
+                let n = match *n.limbs {
+                    [] => 0,
+                    [lo] => lo as u128,
+                    [lo, hi] => (hi as u128) << LIMB_BITS | lo as u128,
+                    _ => return Err(TryFromBigIntError(())),
+                };
+                n.try_into().map_err(|_| TryFromBigIntError(()))
+            }
+        }
+
+        #[unstable(feature = "bigint", issue = "none")]
+        impl TryFrom<BigUint> for $t {
+            type Error = TryFromBigIntError;
+
+            #[inline]
+            fn try_from(n: BigUint) -> Result<$t, TryFromBigIntError> {
+                <$t>::try_from(&n)
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_from_signed {
+    ($($t:ty)*) => {$(
+        #[unstable(feature = "bigint", issue = "none")]
+        impl TryFrom<$t> for BigUint {
+            type Error = TryFromBigIntError;
+
+            #[inline]
+            fn try_from(n: $t) -> Result<Self, TryFromBigIntError> {
+                match u128::try_from(n) {
+                    Ok(n) => Ok(BigUint::from(n)),
+                    Err(_) => Err(TryFromBigIntError(())),
+                }
+            }
+        }
+
+        #[unstable(feature = "bigint", issue = "none")]
+        impl TryFrom<&BigUint> for $t {
+            type Error = TryFromBigIntError;
+
+            fn try_from(n: &BigUint) -> Result<$t, TryFromBigIntError> {
+                u128::try_from(n)?.try_into().map_err(|_| TryFromBigIntError(()))
+            }
+        }
+
+        #[unstable(feature = "bigint", issue = "none")]
+        impl TryFrom<BigUint> for $t {
+            type Error = TryFromBigIntError;
+
+            #[inline]
+            fn try_from(n: BigUint) -> Result<$t, TryFromBigIntError> {
+                <$t>::try_from(&n)
+            }
+        }
+    )*};
+}
+
+impl_from_unsigned!(u8 u16 u32 u64 u128 usize);
+impl_from_signed!(i8 i16 i32 i64 i128 isize);
+
+macro_rules! impl_try_from_float {
+    ($($t:ty)*) => {$(
+        /// Converts the integer part of a float, which must be finite and greater than -1.
+        #[unstable(feature = "bigint", issue = "none")]
+        impl TryFrom<$t> for BigUint {
+            type Error = TryFromBigIntError;
+
+            fn try_from(x: $t) -> Result<Self, TryFromBigIntError> {
+                match decode_f64(x as f64) {
+                    Some((negative, mantissa, exp)) if !negative || x > -1.0 => {
+                        Ok(BigUint::from_float_parts(mantissa, exp))
+                    }
+                    _ => Err(TryFromBigIntError(())),
+                }
+            }
+        }
+    )*};
+}
+
+impl_try_from_float!(f32 f64);
+
+#[unstable(feature = "bigint", issue = "none")]
+impl FromStr for BigUint {
+    type Err = ParseBigIntError;
+
+    #[inline]
+    fn from_str(src: &str) -> Result<Self, ParseBigIntError> {
+        BigUint::from_str_radix(src, 10)
+    }
+}
+
+impl_fmt_radix! {
+    BigUint;
+    Display, 10, "", false;
+    Binary, 2, "0b", false;
+    Octal, 8, "0o", false;
+    LowerHex, 16, "0x", false;
+    UpperHex, 16, "0x", true;
+}
+
+#[unstable(feature = "bigint", issue = "none")]
+impl fmt::Debug for BigUint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
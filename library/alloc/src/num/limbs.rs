@@ -0,0 +1,294 @@
+//! Arithmetic on little-endian slices of 64-bit limbs.
+//!
+//! Unless stated otherwise, the functions here accept slices with high zero limbs, and return
+//! vectors that may have them too. Callers normalize with [`trim`].
+
+use core::cmp::Ordering;
+
+use crate::vec::Vec;
+
+pub(super) type Limb = u64;
+type DoubleLimb = u128;
+
+pub(super) const LIMB_BITS: u32 = Limb::BITS;
+
+/// Below this many limbs in the shorter operand, schoolbook multiplication is used.
+const KARATSUBA_THRESHOLD: usize = 32;
+
+/// Removes the high zero limbs.
+#[inline]
+pub(super) fn trim(v: &mut Vec<Limb>) {
+    while let Some(&0) = v.last() {
+        v.pop();
+    }
+}
+
+/// Returns `v` without its high zero limbs.
+#[inline]
+pub(super) fn trimmed(v: &[Limb]) -> &[Limb] {
+    let len = v.iter().rposition(|&l| l != 0).map_or(0, |i| i + 1);
+    &v[..len]
+}
+
+/// Compares two normalized numbers.
+pub(super) fn cmp(a: &[Limb], b: &[Limb]) -> Ordering {
+    a.len().cmp(&b.len()).then_with(|| a.iter().rev().cmp(b.iter().rev()))
+}
+
+/// `a += b`, where `a` is at least as long as `b`. Returns the carry out of `a`.
+pub(super) fn add_assign(a: &mut [Limb], b: &[Limb]) -> bool {
+    debug_assert!(a.len() >= b.len());
+    let mut carry = false;
+    for (x, &y) in a.iter_mut().zip(b) {
+        let (s, c) = x.carrying_add(y, carry);
+        *x = s;
+        carry = c;
+    }
+    if carry {
+        for x in &mut a[b.len()..] {
+            let (s, c) = x.overflowing_add(1);
+            *x = s;
+            if !c {
+                return false;
+            }
+        }
+    }
+    carry
+}
+
+/// `a -= b`, where `a` is at least as long as `b`. Returns the borrow out of `a`.
+pub(super) fn sub_assign(a: &mut [Limb], b: &[Limb]) -> bool {
+    debug_assert!(a.len() >= b.len());
+    let mut borrow = false;
+    for (x, &y) in a.iter_mut().zip(b) {
+        let (d, c) = x.borrowing_sub(y, borrow);
+        *x = d;
+        borrow = c;
+    }
+    if borrow {
+        for x in &mut a[b.len()..] {
+            let (d, c) = x.overflowing_sub(1);
+            *x = d;
+            if !c {
+                return false;
+            }
+        }
+    }
+    borrow
+}
+
+/// Returns `a + b`.
+pub(super) fn add(a: &[Limb], b: &[Limb]) -> Vec<Limb> {
+    let (a, b) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    let mut sum = Vec::with_capacity(a.len() + 1);
+    sum.extend_from_slice(a);
+    let carry = add_assign(&mut sum, b);
+    sum.push(carry as Limb);
+    sum
+}
+
+/// `a = a * m + carry`. Returns the limb carried out of `a`.
+pub(super) fn mul_limb_assign(a: &mut [Limb], m: Limb, mut carry: Limb) -> Limb {
+    for x in a {
+        let (lo, hi) = x.carrying_mul(m, carry);
+        *x = lo;
+        carry = hi;
+    }
+    carry
+}
+
+/// `acc += a * m`, where `acc` is at least as long as `a`. Returns the carry out of `acc`.
+fn mul_limb_add_assign(acc: &mut [Limb], a: &[Limb], m: Limb) -> Limb {
+    let mut carry = 0;
+    for (x, &y) in acc.iter_mut().zip(a) {
+        let (lo, hi) = y.carrying_mul_add(m, carry, *x);
+        *x = lo;
+        carry = hi;
+    }
+    for x in &mut acc[a.len()..] {
+        if carry == 0 {
+            break;
+        }
+        let (s, c) = x.overflowing_add(carry);
+        *x = s;
+        carry = c as Limb;
+    }
+    carry
+}
+
+/// Returns `a * b`.
+pub(super) fn mul(a: &[Limb], b: &[Limb]) -> Vec<Limb> {
+    let (a, b) = (trimmed(a), trimmed(b));
+    let (a, b) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    if b.is_empty() {
+        Vec::new()
+    } else if b.len() < KARATSUBA_THRESHOLD {
+        schoolbook_mul(a, b)
+    } else if a.len() >= 2 * b.len() {
+        unbalanced_mul(a, b)
+    } else {
+        karatsuba_mul(a, b)
+    }
+}
+
+fn schoolbook_mul(a: &[Limb], b: &[Limb]) -> Vec<Limb> {
+    let mut prod = vec![0; a.len() + b.len()];
+    for (i, &y) in b.iter().enumerate() {
+        let carry = mul_limb_add_assign(&mut prod[i..i + a.len()], a, y);
+        prod[i + a.len()] = carry;
+    }
+    prod
+}
+
+/// Multiplies by `b` in chunks of `a` that are as long as `b`, so that each partial product is
+/// balanced enough for Karatsuba.
+fn unbalanced_mul(a: &[Limb], b: &[Limb]) -> Vec<Limb> {
+    let mut prod = vec![0; a.len() + b.len()];
+    for (i, chunk) in a.chunks(b.len()).enumerate() {
+        let partial = mul(chunk, b);
+        add_assign(&mut prod[i * b.len()..], trimmed(&partial));
+    }
+    prod
+}
+
+/// Karatsuba multiplication, for `a.len() / 2 < b.len() <= a.len()`.
+///
+/// With `a = a1 * B + a0` and `b = b1 * B + b0`, `a * b = z2 * B^2 + z1 * B + z0` where
+/// `z0 = a0 * b0`, `z2 = a1 * b1` and `z1 = (a0 + a1) * (b0 + b1) - z0 - z2`, which takes three
+/// half-sized multiplications instead of four.
+fn karatsuba_mul(a: &[Limb], b: &[Limb]) -> Vec<Limb> {
+    let half = a.len() / 2;
+    let (a0, a1) = a.split_at(half);
+    let (b0, b1) = b.split_at(half);
+
+    let z0 = mul(a0, b0);
+    let z2 = mul(a1, b1);
+    let mut z1 = mul(&add(a0, a1), &add(b0, b1));
+    sub_assign(&mut z1, &z0);
+    sub_assign(&mut z1, &z2);
+
+    let mut prod = vec![0; a.len() + b.len()];
+    add_assign(&mut prod, trimmed(&z0));
+    add_assign(&mut prod[half..], trimmed(&z1));
+    add_assign(&mut prod[2 * half..], trimmed(&z2));
+    prod
+}
+
+/// Divides `a` by `d` in place. Returns the remainder.
+pub(super) fn div_rem_limb_assign(a: &mut [Limb], d: Limb) -> Limb {
+    let mut rem = 0;
+    for x in a.iter_mut().rev() {
+        let (q, r) = div_wide(rem, *x, d);
+        *x = q;
+        rem = r;
+    }
+    rem
+}
+
+/// Divides `hi * B + lo` by `d`, where `hi < d`.
+#[inline]
+fn div_wide(hi: Limb, lo: Limb, d: Limb) -> (Limb, Limb) {
+    debug_assert!(hi < d);
+    let n = (hi as DoubleLimb) << LIMB_BITS | lo as DoubleLimb;
+    ((n / d as DoubleLimb) as Limb, (n % d as DoubleLimb) as Limb)
+}
+
+/// Returns the quotient and remainder of normalized `u` divided by normalized, nonzero `v`.
+pub(super) fn div_rem(u: &[Limb], v: &[Limb]) -> (Vec<Limb>, Vec<Limb>) {
+    debug_assert!(!v.is_empty());
+    if cmp(u, v) == Ordering::Less {
+        return (Vec::new(), u.to_vec());
+    }
+    if let [d] = *v {
+        let mut q = u.to_vec();
+        let r = div_rem_limb_assign(&mut q, d);
+        return (q, vec![r]);
+    }
+
+    // Knuth, TAOCP vol. 2, 4.3.1, algorithm D. Shift both operands so that the top limb of the
+    // divisor has its high bit set, which makes the estimated quotient limbs off by at most two.
+    let shift = v[v.len() - 1].leading_zeros();
+    let v = shl_bits(v, shift);
+    let n = v.len();
+    let m = u.len() - n + 1;
+    let mut u = shl_bits(u, shift);
+    u.resize(m + n, 0);
+    let (v_top, v_next) = (v[n - 1], v[n - 2]);
+    let mut q = vec![0; m];
+
+    for j in (0..m).rev() {
+        let (u_top, u_next, u_next2) = (u[j + n], u[j + n - 1], u[j + n - 2]);
+        let (mut qhat, mut rhat) = if u_top >= v_top {
+            // The estimate would overflow a limb; start from the largest limb instead.
+            // As `u_top == v_top`, `rhat = u_top * B + u_next - (B - 1) * v_top`.
+            (Limb::MAX, u_next as DoubleLimb + v_top as DoubleLimb)
+        } else {
+            let (q, r) = div_wide(u_top, u_next, v_top);
+            (q, r as DoubleLimb)
+        };
+        while rhat >> LIMB_BITS == 0
+            && qhat as DoubleLimb * v_next as DoubleLimb > rhat << LIMB_BITS | u_next2 as DoubleLimb
+        {
+            qhat -= 1;
+            rhat += v_top as DoubleLimb;
+        }
+
+        // u[j..=j + n] -= qhat * v
+        let window = &mut u[j..=j + n];
+        let mut borrow = false;
+        let mut carry = 0;
+        for (x, &y) in window.iter_mut().zip(&v) {
+            let (lo, hi) = qhat.carrying_mul(y, carry);
+            carry = hi;
+            let (d, b) = x.borrowing_sub(lo, borrow);
+            *x = d;
+            borrow = b;
+        }
+        let (d, b) = window[n].borrowing_sub(carry, borrow);
+        window[n] = d;
+        if b {
+            // The estimate was one too large: add the divisor back.
+            qhat -= 1;
+            let carry = add_assign(&mut window[..n], &v);
+            window[n] = window[n].wrapping_add(carry as Limb);
+        }
+        q[j] = qhat;
+    }
+
+    u.truncate(n);
+    let mut r = shr_bits(&u, shift);
+    trim(&mut q);
+    trim(&mut r);
+    (q, r)
+}
+
+/// Returns `a << shift`, for `shift < LIMB_BITS`, with an extra limb for the bits shifted out.
+pub(super) fn shl_bits(a: &[Limb], shift: u32) -> Vec<Limb> {
+    let mut out = Vec::with_capacity(a.len() + 1);
+    if shift == 0 {
+        out.extend_from_slice(a);
+        return out;
+    }
+    let mut carry = 0;
+    for &x in a {
+        out.push(x << shift | carry);
+        carry = x >> (LIMB_BITS - shift);
+    }
+    if carry != 0 {
+        out.push(carry);
+    }
+    out
+}
+
+/// Returns `a >> shift`, for `shift < LIMB_BITS`.
+pub(super) fn shr_bits(a: &[Limb], shift: u32) -> Vec<Limb> {
+    if shift == 0 {
+        return a.to_vec();
+    }
+    let mut out = Vec::with_capacity(a.len());
+    for (i, &x) in a.iter().enumerate() {
+        let next = a.get(i + 1).map_or(0, |&n| n << (LIMB_BITS - shift));
+        out.push(x >> shift | next);
+    }
+    out
+}
@@ -0,0 +1,224 @@
+//! Arbitrary-precision integers.
+
+use core::error::Error;
+use core::fmt;
+use core::num::IntErrorKind;
+
+/// Implements `$Op` for all combinations of owned and borrowed operands, and `$OpAssign`, in terms
+/// of `$OpAssign<&T> for T`.
+macro_rules! forward_assign_binop {
+    ($T:ty, $Op:ident, $op:ident, $OpAssign:ident, $op_assign:ident) => {
+        #[unstable(feature = "bigint", issue = "none")]
+        impl $OpAssign for $T {
+            #[inline]
+            fn $op_assign(&mut self, rhs: $T) {
+                self.$op_assign(&rhs);
+            }
+        }
+
+        #[unstable(feature = "bigint", issue = "none")]
+        impl $Op<&$T> for $T {
+            type Output = $T;
+
+            #[inline]
+            fn $op(mut self, rhs: &$T) -> $T {
+                self.$op_assign(rhs);
+                self
+            }
+        }
+
+        #[unstable(feature = "bigint", issue = "none")]
+        impl $Op for $T {
+            type Output = $T;
+
+            #[inline]
+            fn $op(mut self, rhs: $T) -> $T {
+                self.$op_assign(&rhs);
+                self
+            }
+        }
+
+        #[unstable(feature = "bigint", issue = "none")]
+        impl $Op<&$T> for &$T {
+            type Output = $T;
+
+            #[inline]
+            fn $op(self, rhs: &$T) -> $T {
+                self.clone().$op(rhs)
+            }
+        }
+
+        #[unstable(feature = "bigint", issue = "none")]
+        impl $Op<$T> for &$T {
+            type Output = $T;
+
+            #[inline]
+            fn $op(self, rhs: $T) -> $T {
+                self.clone().$op(&rhs)
+            }
+        }
+    };
+}
+
+/// Implements `$Op` for all combinations of owned and borrowed operands, and `$OpAssign`, in terms
+/// of `$Op<&T> for &T`.
+macro_rules! forward_ref_binop {
+    ($T:ty, $Op:ident, $op:ident, $OpAssign:ident, $op_assign:ident) => {
+        #[unstable(feature = "bigint", issue = "none")]
+        impl $Op<&$T> for $T {
+            type Output = $T;
+
+            #[inline]
+            fn $op(self, rhs: &$T) -> $T {
+                (&self).$op(rhs)
+            }
+        }
+
+        #[unstable(feature = "bigint", issue = "none")]
+        impl $Op for $T {
+            type Output = $T;
+
+            #[inline]
+            fn $op(self, rhs: $T) -> $T {
+                (&self).$op(&rhs)
+            }
+        }
+
+        #[unstable(feature = "bigint", issue = "none")]
+        impl $Op<$T> for &$T {
+            type Output = $T;
+
+            #[inline]
+            fn $op(self, rhs: $T) -> $T {
+                self.$op(&rhs)
+            }
+        }
+
+        #[unstable(feature = "bigint", issue = "none")]
+        impl $OpAssign<&$T> for $T {
+            #[inline]
+            fn $op_assign(&mut self, rhs: &$T) {
+                *self = (&*self).$op(rhs);
+            }
+        }
+
+        #[unstable(feature = "bigint", issue = "none")]
+        impl $OpAssign for $T {
+            #[inline]
+            fn $op_assign(&mut self, rhs: $T) {
+                *self = (&*self).$op(&rhs);
+            }
+        }
+    };
+}
+
+/// Implements `$Op<usize>` for owned and borrowed values, and `$OpAssign<usize>`, in terms of
+/// `$Op<usize> for &T`.
+macro_rules! forward_shift {
+    ($T:ty, $Op:ident, $op:ident, $OpAssign:ident, $op_assign:ident) => {
+        #[unstable(feature = "bigint", issue = "none")]
+        impl $Op<usize> for $T {
+            type Output = $T;
+
+            #[inline]
+            fn $op(self, rhs: usize) -> $T {
+                (&self).$op(rhs)
+            }
+        }
+
+        #[unstable(feature = "bigint", issue = "none")]
+        impl $OpAssign<usize> for $T {
+            #[inline]
+            fn $op_assign(&mut self, rhs: usize) {
+                *self = (&*self).$op(rhs);
+            }
+        }
+    };
+}
+
+/// Implements the formatting traits that take a radix, given a `sign_and_limbs` method.
+macro_rules! impl_fmt_radix {
+    ($T:ty; $($Trait:ident, $radix:literal, $prefix:literal, $upper:literal;)*) => {$(
+        #[unstable(feature = "bigint", issue = "none")]
+        impl fmt::$Trait for $T {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let (is_nonnegative, limbs) = self.sign_and_limbs();
+                radix::fmt(f, is_nonnegative, limbs, $radix, $prefix, $upper)
+            }
+        }
+    )*};
+}
+
+mod bigint;
+mod biguint;
+mod limbs;
+mod radix;
+
+#[unstable(feature = "bigint", issue = "none")]
+pub use bigint::BigInt;
+#[unstable(feature = "bigint", issue = "none")]
+pub use biguint::BigUint;
+
+/// An error which can be returned when parsing a [`BigUint`] or a [`BigInt`].
+///
+/// This error is used as the error type for the [`FromStr`] implementations and for the
+/// `from_str_radix` functions of both types.
+///
+/// [`FromStr`]: core::str::FromStr
+///
+/// # Example
+///
+/// ```
+/// #![feature(bigint)]
+///
+/// use std::num::{BigInt, IntErrorKind};
+///
+/// let err = "12a".parse::<BigInt>().unwrap_err();
+/// assert_eq!(err.kind(), &IntErrorKind::InvalidDigit);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[unstable(feature = "bigint", issue = "none")]
+pub struct ParseBigIntError {
+    kind: IntErrorKind,
+}
+
+impl ParseBigIntError {
+    /// Outputs the detailed cause of parsing a big integer failing, which is either
+    /// [`IntErrorKind::Empty`] or [`IntErrorKind::InvalidDigit`].
+    #[unstable(feature = "bigint", issue = "none")]
+    pub fn kind(&self) -> &IntErrorKind {
+        &self.kind
+    }
+}
+
+#[unstable(feature = "bigint", issue = "none")]
+impl fmt::Display for ParseBigIntError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            IntErrorKind::Empty => "cannot parse integer from empty string",
+            _ => "invalid digit found in string",
+        }
+        .fmt(f)
+    }
+}
+
+#[unstable(feature = "bigint", issue = "none")]
+impl Error for ParseBigIntError {}
+
+/// The error type returned when a checked conversion to or from a big integer fails.
+///
+/// This happens when converting a big integer to a primitive integer type that cannot hold its
+/// value, a negative number to [`BigUint`], or an infinite or NaN float to either type.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[unstable(feature = "bigint", issue = "none")]
+pub struct TryFromBigIntError(());
+
+#[unstable(feature = "bigint", issue = "none")]
+impl fmt::Display for TryFromBigIntError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        "out of range integral type conversion attempted".fmt(f)
+    }
+}
+
+#[unstable(feature = "bigint", issue = "none")]
+impl Error for TryFromBigIntError {}
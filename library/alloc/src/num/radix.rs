@@ -0,0 +1,137 @@
+//! Conversion between limbs and digit strings.
+
+use core::fmt;
+use core::num::IntErrorKind;
+
+use super::limbs::{self, LIMB_BITS, Limb};
+use crate::vec::Vec;
+
+/// Returns the largest power of `radix` that fits in a limb, and its exponent.
+fn big_base(radix: u32) -> (Limb, usize) {
+    let radix = radix as Limb;
+    let mut base = radix;
+    let mut digits = 1;
+    while let Some(b) = base.checked_mul(radix) {
+        base = b;
+        digits += 1;
+    }
+    (base, digits)
+}
+
+#[track_caller]
+pub(super) fn check_radix(radix: u32) {
+    assert!((2..=36).contains(&radix), "radix must lie in the range `[2, 36]`, found {radix}");
+}
+
+/// Parses unsigned ASCII digits in the given radix into little-endian limbs.
+pub(super) fn parse(digits: &[u8], radix: u32) -> Result<Vec<Limb>, IntErrorKind> {
+    check_radix(radix);
+    if digits.is_empty() {
+        return Err(IntErrorKind::Empty);
+    }
+    let digit = |&c: &u8| (c as char).to_digit(radix).ok_or(IntErrorKind::InvalidDigit);
+
+    let mut out = Vec::new();
+    if radix.is_power_of_two() {
+        // Every digit holds a whole number of bits: pack them from the least significant end.
+        let bits = radix.trailing_zeros();
+        let (mut acc, mut acc_bits) = (0, 0);
+        for c in digits.iter().rev() {
+            let d = digit(c)? as Limb;
+            acc |= d << acc_bits;
+            acc_bits += bits;
+            if acc_bits >= LIMB_BITS {
+                out.push(acc);
+                acc_bits -= LIMB_BITS;
+                acc = if acc_bits == 0 { 0 } else { d >> (bits - acc_bits) };
+            }
+        }
+        out.push(acc);
+    } else {
+        // Accumulate as many digits as fit in a limb at once, then multiply them in.
+        let (base, chunk_len) = big_base(radix);
+        let first = match digits.len() % chunk_len {
+            0 => chunk_len,
+            n => n,
+        };
+        let (head, tail) = digits.split_at(first);
+        for chunk in [head].into_iter().chain(tail.chunks(chunk_len)) {
+            let mut n: Limb = 0;
+            for c in chunk {
+                n = n * radix as Limb + digit(c)? as Limb;
+            }
+            let factor = if chunk.len() == chunk_len {
+                base
+            } else {
+                (radix as Limb).pow(chunk.len() as u32)
+            };
+            let carry = limbs::mul_limb_assign(&mut out, factor, n);
+            if carry != 0 {
+                out.push(carry);
+            }
+        }
+    }
+    limbs::trim(&mut out);
+    Ok(out)
+}
+
+/// Returns the lowercase ASCII digits of `n` in the given radix, most significant first.
+pub(super) fn to_digits(n: &[Limb], radix: u32) -> Vec<u8> {
+    check_radix(radix);
+    let digit = |d: Limb| char::from_digit(d as u32, radix).unwrap() as u8;
+    if n.is_empty() {
+        return vec![b'0'];
+    }
+
+    let mut out = Vec::new();
+    if radix.is_power_of_two() {
+        let bits = radix.trailing_zeros();
+        let mask = radix as Limb - 1;
+        let total = (n.len() as u64 - 1) * LIMB_BITS as u64
+            + (LIMB_BITS - n[n.len() - 1].leading_zeros()) as u64;
+        let mut pos = 0;
+        while pos < total {
+            let (i, shift) = ((pos / LIMB_BITS as u64) as usize, (pos % LIMB_BITS as u64) as u32);
+            let mut d = n[i] >> shift;
+            if shift + bits > LIMB_BITS && i + 1 < n.len() {
+                d |= n[i + 1] << (LIMB_BITS - shift);
+            }
+            out.push(digit(d & mask));
+            pos += bits as u64;
+        }
+    } else {
+        let (base, chunk_len) = big_base(radix);
+        let mut n = n.to_vec();
+        while !n.is_empty() {
+            let mut rem = limbs::div_rem_limb_assign(&mut n, base);
+            limbs::trim(&mut n);
+            for _ in 0..chunk_len {
+                if n.is_empty() && rem == 0 {
+                    break;
+                }
+                out.push(digit(rem % radix as Limb));
+                rem /= radix as Limb;
+            }
+        }
+    }
+    out.reverse();
+    out
+}
+
+/// Formats a number given by its sign and magnitude, for the formatting traits.
+pub(super) fn fmt(
+    f: &mut fmt::Formatter<'_>,
+    is_nonnegative: bool,
+    n: &[Limb],
+    radix: u32,
+    prefix: &str,
+    upper: bool,
+) -> fmt::Result {
+    let mut digits = to_digits(n, radix);
+    if upper {
+        digits.make_ascii_uppercase();
+    }
+    // SAFETY: the digits are ASCII.
+    let digits = unsafe { core::str::from_utf8_unchecked(&digits) };
+    f.pad_integral(is_nonnegative, prefix, digits)
+}
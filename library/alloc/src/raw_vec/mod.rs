@@ -340,6 +340,12 @@ pub(crate) fn grow_one(&mut self) {
         self.inner.grow_one(T::LAYOUT)
     }
 
+    /// The same as `grow_one`, but returns on errors instead of panicking or aborting.
+    #[inline(never)]
+    pub(crate) fn try_grow_one(&mut self) -> Result<(), TryReserveError> {
+        self.inner.try_grow_one(T::LAYOUT)
+    }
+
     /// The same as `reserve`, but returns on errors instead of panicking or aborting.
     pub(crate) fn try_reserve(
         &mut self,
@@ -568,11 +574,16 @@ fn do_reserve_and_handle<A: Allocator>(
     #[inline]
     #[track_caller]
     fn grow_one(&mut self, elem_layout: Layout) {
-        if let Err(err) = self.grow_amortized(self.cap.as_inner(), 1, elem_layout) {
+        if let Err(err) = self.try_grow_one(elem_layout) {
             handle_error(err);
         }
     }
 
+    #[inline]
+    fn try_grow_one(&mut self, elem_layout: Layout) -> Result<(), TryReserveError> {
+        self.grow_amortized(self.cap.as_inner(), 1, elem_layout)
+    }
+
     fn try_reserve(
         &mut self,
         len: usize,
@@ -153,6 +153,37 @@ fn zst_reserve_exact_panic() {
     v.reserve_exact(101, usize::MAX - 100);
 }
 
+#[test]
+fn try_grow_one() {
+    use crate::alloc::AllocError;
+    use crate::collections::TryReserveErrorKind;
+
+    // An allocator that always fails.
+    struct NoAlloc;
+    unsafe impl Allocator for NoAlloc {
+        fn allocate(&self, _layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+            Err(AllocError)
+        }
+        unsafe fn deallocate(&self, _ptr: NonNull<u8>, _layout: Layout) {
+            unreachable!()
+        }
+    }
+
+    let mut v: RawVec<u32, _> = RawVec::new_in(NoAlloc);
+    let err = v.try_grow_one().unwrap_err();
+    assert!(matches!(err.kind(), TryReserveErrorKind::AllocError { .. }));
+    assert_eq!(v.capacity(), 0);
+
+    let mut v: RawVec<u32> = RawVec::new();
+    v.try_grow_one().unwrap();
+    assert!(v.capacity() >= 1);
+
+    let mut v: RawVec<ZST> = RawVec::new();
+    let err = v.try_grow_one().unwrap_err();
+    assert_eq!(err.kind(), TryReserveErrorKind::CapacityOverflow);
+    zst_sanity(&v);
+}
+
 #[test]
 fn niches() {
     let baseline = size_of::<RawVec<u8>>();
@@ -26,6 +26,8 @@
 pub use core::slice::EscapeAscii;
 #[stable(feature = "get_many_mut", since = "1.86.0")]
 pub use core::slice::GetDisjointMutError;
+#[unstable(feature = "slice_radix_sort", issue = "none")]
+pub use core::slice::RadixKey;
 #[stable(feature = "slice_get_slice", since = "1.28.0")]
 pub use core::slice::SliceIndex;
 #[cfg(not(no_global_oom_handling))]
@@ -354,6 +356,99 @@ macro_rules! sort_by_key {
         sort_by_key!(usize, self, f)
     }
 
+    /// Sorts the slice with a key extraction function using radix sort, preserving initial order
+    /// of equal elements.
+    ///
+    /// This sort is stable (i.e., does not reorder equal elements) and *O*(*m* \* *n* + *w* \*
+    /// *n*) worst-case, where the key function is *O*(*m*) and *w* is the size of the key in bytes.
+    /// Unlike the comparison sorts, its running time does not grow with log(*n*), which makes it
+    /// a good fit for very large slices keyed by integers or fixed-size byte strings.
+    ///
+    /// During sorting, the key function is called exactly once per element, by using temporary
+    /// storage to remember the results of key evaluation. The order of calls to the key function is
+    /// unspecified and may change in future versions of the standard library.
+    ///
+    /// The keys must implement [`RadixKey`], which is the case for the primitive integer types,
+    /// `bool`, `char`, arrays of radix keys such as `[u8; N]`, and pairs and triples of radix keys.
+    /// Keys are ordered the same way as by their [`Ord`] implementation.
+    ///
+    /// # Current implementation
+    ///
+    /// The current implementation is a least-significant-digit radix sort that sorts the cached
+    /// keys one byte at a time, skipping bytes that are equal across all keys. Slices that are
+    /// short compared to the size of the key are sorted with [`sort_by_key`](slice::sort_by_key)
+    /// on the cached keys instead.
+    ///
+    /// The algorithm allocates temporary storage for two `Vec<(K, usize)>` the length of the slice.
+    ///
+    /// # Panics
+    ///
+    /// May panic if the key-function `f` panics.
+    ///
+    /// All safe functions on slices preserve the invariant that even if the function panics, all
+    /// original elements will remain in the slice and any possible modifications via interior
+    /// mutability are observed in the input. This ensures that recovery code (for instance inside
+    /// of a `Drop` or following a `catch_unwind`) will still have access to all the original
+    /// elements. For instance, if the slice belongs to a `Vec`, the `Vec::drop` method will be able
+    /// to dispose of all contained elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_radix_sort)]
+    ///
+    /// let mut v = [(3, 'a'), (-1, 'b'), (3, 'c'), (0, 'd'), (-7, 'e')];
+    ///
+    /// v.sort_by_radix_key(|&(k, _)| k);
+    /// assert_eq!(v, [(-7, 'e'), (-1, 'b'), (0, 'd'), (3, 'a'), (3, 'c')]);
+    ///
+    /// // Byte strings are sorted by lexicographical order.
+    /// let mut names = [*b"carol", *b"alice", *b"bobby"];
+    /// names.sort_by_radix_key(|&name| name);
+    /// assert_eq!(names, [*b"alice", *b"bobby", *b"carol"]);
+    /// ```
+    #[cfg(not(no_global_oom_handling))]
+    #[rustc_allow_incoherent_impl]
+    #[unstable(feature = "slice_radix_sort", issue = "none")]
+    #[inline]
+    pub fn sort_by_radix_key<K, F>(&mut self, f: F)
+    where
+        F: FnMut(&T) -> K,
+        K: RadixKey,
+    {
+        // Helper macro for indexing our vector by the smallest possible type, to reduce allocation.
+        macro_rules! sort_by_key {
+            ($t:ty, $slice:ident, $f:ident) => {{
+                let mut indices: Vec<_> =
+                    $slice.iter().map($f).enumerate().map(|(i, k)| (k, i as $t)).collect();
+                // The radix sort is stable, so equal keys keep the order of their indices.
+                sort::stable::radix::sort::<K, $t, Vec<(K, $t)>>(&mut indices);
+                for i in 0..$slice.len() {
+                    let mut index = indices[i].1;
+                    while (index as usize) < i {
+                        index = indices[index as usize].1;
+                    }
+                    indices[i].1 = index;
+                    $slice.swap(i, index as usize);
+                }
+            }};
+        }
+
+        let len = self.len();
+        if len < 2 {
+            return;
+        }
+
+        let is_using_u32_as_idx_type_helpful =
+            const { size_of::<(K, u32)>() < size_of::<(K, usize)>() };
+
+        if is_using_u32_as_idx_type_helpful && len <= (u32::MAX as usize) {
+            return sort_by_key!(u32, self, f);
+        }
+
+        sort_by_key!(usize, self, f)
+    }
+
     /// Copies `self` into a new `Vec`.
     ///
     /// # Examples
@@ -49,12 +49,18 @@
 use core::unicode::conversions;
 use core::{mem, ptr};
 
+#[cfg(not(no_global_oom_handling))]
+#[unstable(feature = "str_multi_pattern", issue = "none")]
+pub use self::multi_pattern::{MultiPattern, MultiPatternSearcher};
 use crate::borrow::ToOwned;
 use crate::boxed::Box;
 use crate::slice::{Concat, Join, SliceIndex};
 use crate::string::String;
 use crate::vec::Vec;
 
+#[cfg(not(no_global_oom_handling))]
+mod multi_pattern;
+
 /// Note: `str` in `Concat<str>` is not meaningful here.
 /// This type parameter of the trait only exists to enable another impl.
 #[cfg(not(no_global_oom_handling))]
@@ -0,0 +1,270 @@
+use core::fmt;
+use core::str::pattern::{Pattern, SearchStep, Searcher};
+
+use crate::vec::Vec;
+
+/// A pattern that matches any of a set of strings, searched in a single pass over the haystack.
+///
+/// A `MultiPattern` compiles its needles into an [Aho-Corasick] automaton once, and can then be
+/// used by reference with every method that accepts a [`Pattern`], such as [`str::find`],
+/// [`str::split`], [`str::replace`] and [`str::matches`]. Searching takes *O*(*n*) time in the
+/// length of the haystack, regardless of the number of needles.
+///
+/// When several needles match, the match that starts first wins, and among the matches that
+/// start at the same position the longest one wins. The order in which needles are given does
+/// not matter. An empty needle matches at every char boundary where no other needle matches,
+/// like the `""` pattern does.
+///
+/// `MultiPattern` only supports searching from the front, so it cannot be used with methods such
+/// as [`str::rfind`] or [`str::ends_with`].
+///
+/// [Aho-Corasick]: https://en.wikipedia.org/wiki/Aho%E2%80%93Corasick_algorithm
+///
+/// # Examples
+///
+/// ```
+/// #![feature(str_multi_pattern)]
+///
+/// use std::str::MultiPattern;
+///
+/// let secrets = MultiPattern::new(["password", "token", "pass"]);
+/// let line = "user=bob pass=hunter2 token=abc password=xyz";
+///
+/// assert!(line.contains(&secrets));
+/// assert_eq!(line.find(&secrets), Some(9));
+/// assert_eq!(line.matches(&secrets).collect::<Vec<_>>(), ["pass", "token", "password"]);
+/// assert_eq!(line.replace(&secrets, "***"), "user=bob ***=hunter2 ***=abc ***=xyz");
+/// ```
+#[derive(Clone)]
+#[unstable(feature = "str_multi_pattern", issue = "none")]
+pub struct MultiPattern {
+    /// Maps every byte to its equivalence class. Class 0 holds all bytes that occur in no needle.
+    classes: [u8; 256],
+    /// The number of byte classes, which is the stride of `transitions`.
+    stride: usize,
+    /// The complete transition table of the automaton, `stride` entries per state. State 0 is
+    /// the start state.
+    transitions: Vec<u32>,
+    /// Per state, the length of the longest prefix of a needle the state stands for.
+    depths: Vec<u32>,
+    /// Per state, the length of the longest needle that ends in that state, or `NO_MATCH`.
+    match_lens: Vec<u32>,
+    needles: usize,
+}
+
+const NO_MATCH: u32 = u32::MAX;
+
+impl MultiPattern {
+    /// Builds a pattern matching any of `needles`.
+    ///
+    /// This takes time and space proportional to the total length of the needles, times the
+    /// number of distinct bytes they contain.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the total length of the needles exceeds `u32::MAX - 1` bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(str_multi_pattern)]
+    ///
+    /// use std::str::MultiPattern;
+    ///
+    /// let keywords = ["fn", "let", "match"];
+    /// let pattern = MultiPattern::new(keywords);
+    /// let words: Vec<&str> = "let x = match y { _ => 1 };".split(&pattern).collect();
+    /// assert_eq!(words, ["", " x = ", " y { _ => 1 };"]);
+    /// ```
+    #[unstable(feature = "str_multi_pattern", issue = "none")]
+    pub fn new<I>(needles: I) -> MultiPattern
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let needles: Vec<I::Item> = needles.into_iter().collect();
+
+        let mut classes = [0; 256];
+        let mut stride = 1;
+        for needle in &needles {
+            for &byte in needle.as_ref().as_bytes() {
+                if classes[byte as usize] == 0 {
+                    // UTF-8 never contains 0xC0, 0xC1 or 0xF5..=0xFF, so this cannot overflow.
+                    classes[byte as usize] = stride as u8;
+                    stride += 1;
+                }
+            }
+        }
+
+        // Build the trie of all needles. A transition to state 0 means there is no child yet,
+        // which is unambiguous because the start state is never a child.
+        let mut transitions = vec![0; stride];
+        let mut depths = vec![0];
+        let mut match_lens = vec![NO_MATCH];
+        for needle in &needles {
+            let mut state = 0;
+            for &byte in needle.as_ref().as_bytes() {
+                let slot = state * stride + classes[byte as usize] as usize;
+                if transitions[slot] == 0 {
+                    let next = depths.len();
+                    assert!(next < NO_MATCH as usize, "too many needles for MultiPattern");
+                    transitions[slot] = next as u32;
+                    transitions.resize(transitions.len() + stride, 0);
+                    depths.push(depths[state] + 1);
+                    match_lens.push(NO_MATCH);
+                }
+                state = transitions[slot] as usize;
+            }
+            match_lens[state] = depths[state];
+        }
+
+        // Turn the trie into a complete automaton, visiting states in breadth-first order so
+        // that the failure state of every state is complete before it is needed. Missing
+        // transitions are copied from the failure state, and states inherit the longest match of
+        // their failure state if they do not end a needle themselves.
+        let mut failures = vec![0u32; depths.len()];
+        let mut queue = Vec::with_capacity(depths.len());
+        for &child in &transitions[..stride] {
+            if child != 0 {
+                queue.push(child);
+                if match_lens[child as usize] == NO_MATCH {
+                    match_lens[child as usize] = match_lens[0];
+                }
+            }
+        }
+        let mut next = 0;
+        while let Some(&state) = queue.get(next) {
+            next += 1;
+            let state = state as usize;
+            let failure = failures[state] as usize;
+            for class in 0..stride {
+                let slot = state * stride + class;
+                let fallback = transitions[failure * stride + class];
+                let child = transitions[slot];
+                if child == 0 {
+                    transitions[slot] = fallback;
+                } else {
+                    let child = child as usize;
+                    failures[child] = fallback;
+                    if match_lens[child] == NO_MATCH {
+                        match_lens[child] = match_lens[fallback as usize];
+                    }
+                    queue.push(child as u32);
+                }
+            }
+        }
+
+        MultiPattern { classes, stride, transitions, depths, match_lens, needles: needles.len() }
+    }
+
+    /// Returns the leftmost-longest match in `haystack` that starts at or after `from`.
+    fn find_at(&self, haystack: &[u8], from: usize) -> Option<(usize, usize)> {
+        let mut best = (self.match_lens[0] != NO_MATCH).then_some((from, from));
+        let mut state = 0;
+        for (i, &byte) in haystack.iter().enumerate().skip(from) {
+            state = self.transitions[state * self.stride + self.classes[byte as usize] as usize]
+                as usize;
+            let end = i + 1;
+            // Every match found from here on starts at or after `end - depth`.
+            if best.is_some_and(|(start, _)| end - self.depths[state] as usize > start) {
+                break;
+            }
+            let len = self.match_lens[state];
+            if len != NO_MATCH {
+                let start = end - len as usize;
+                // Matches that end later and start at the same position are longer.
+                if best.is_none_or(|(best_start, _)| start <= best_start) {
+                    best = Some((start, end));
+                }
+            }
+        }
+        best
+    }
+}
+
+#[unstable(feature = "str_multi_pattern", issue = "none")]
+impl fmt::Debug for MultiPattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MultiPattern")
+            .field("needles", &self.needles)
+            .field("states", &self.depths.len())
+            .finish_non_exhaustive()
+    }
+}
+
+/// Associated type for `<&MultiPattern as Pattern>::Searcher<'a>`.
+#[derive(Clone, Debug)]
+#[unstable(feature = "str_multi_pattern", issue = "none")]
+pub struct MultiPatternSearcher<'a, 'b> {
+    pattern: &'b MultiPattern,
+    haystack: &'a str,
+    /// Everything before `position` has been reported.
+    position: usize,
+    /// Where the search for the next match starts, or `None` once the haystack is exhausted.
+    search_from: Option<usize>,
+    /// A match that was found after a gap, which is reported as a reject first.
+    pending: Option<(usize, usize)>,
+}
+
+impl MultiPatternSearcher<'_, '_> {
+    fn find(&mut self) -> Option<(usize, usize)> {
+        let from = self.search_from?;
+        let (start, end) = self.pattern.find_at(self.haystack.as_bytes(), from)?;
+        self.search_from = if start < end {
+            Some(end)
+        } else {
+            // After an empty match, the next match has to start after the next char.
+            self.haystack[start..].chars().next().map(|c| start + c.len_utf8())
+        };
+        Some((start, end))
+    }
+}
+
+#[unstable(feature = "str_multi_pattern", issue = "none")]
+impl<'b> Pattern for &'b MultiPattern {
+    type Searcher<'a> = MultiPatternSearcher<'a, 'b>;
+
+    fn into_searcher(self, haystack: &str) -> MultiPatternSearcher<'_, 'b> {
+        MultiPatternSearcher {
+            pattern: self,
+            haystack,
+            position: 0,
+            search_from: Some(0),
+            pending: None,
+        }
+    }
+}
+
+#[unstable(feature = "str_multi_pattern", issue = "none")]
+unsafe impl<'a, 'b> Searcher<'a> for MultiPatternSearcher<'a, 'b> {
+    #[inline]
+    fn haystack(&self) -> &'a str {
+        self.haystack
+    }
+
+    fn next(&mut self) -> SearchStep {
+        let Some((start, end)) = self.pending.take().or_else(|| self.find()) else {
+            let position = self.position;
+            if position == self.haystack.len() {
+                return SearchStep::Done;
+            }
+            self.position = self.haystack.len();
+            return SearchStep::Reject(position, self.haystack.len());
+        };
+        if start > self.position {
+            self.pending = Some((start, end));
+            let position = self.position;
+            self.position = start;
+            SearchStep::Reject(position, start)
+        } else {
+            self.position = end;
+            SearchStep::Match(start, end)
+        }
+    }
+
+    fn next_match(&mut self) -> Option<(usize, usize)> {
+        let (start, end) = self.pending.take().or_else(|| self.find())?;
+        self.position = end;
+        Some((start, end))
+    }
+}
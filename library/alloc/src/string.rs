@@ -1112,6 +1112,34 @@ pub fn push_str(&mut self, string: &str) {
         self.vec.extend_from_slice(string.as_bytes())
     }
 
+    /// Appends a given string slice onto the end of this `String`, or returns
+    /// an error if the string needed to grow and the allocation failed.
+    ///
+    /// This is a fallible version of [`push_str`]. On error, the string is
+    /// left unchanged.
+    ///
+    /// [`push_str`]: String::push_str
+    ///
+    /// # Errors
+    ///
+    /// If the capacity overflows, or the allocator reports a failure, then an error
+    /// is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(fallible_collections)]
+    ///
+    /// let mut s = String::from("foo");
+    /// s.try_push_str("bar").expect("out of memory");
+    /// assert_eq!("foobar", s);
+    /// ```
+    #[inline]
+    #[unstable(feature = "fallible_collections", issue = "none")]
+    pub fn try_push_str(&mut self, string: &str) -> Result<(), TryReserveError> {
+        self.vec.try_extend_from_slice(string.as_bytes())
+    }
+
     /// Copies elements from `src` range to the end of the string.
     ///
     /// # Panics
@@ -1409,6 +1437,40 @@ pub fn push(&mut self, ch: char) {
         }
     }
 
+    /// Appends the given [`char`] to the end of this `String`, or returns an
+    /// error if the string needed to grow and the allocation failed.
+    ///
+    /// This is a fallible version of [`push`]. On error, the string is left
+    /// unchanged.
+    ///
+    /// [`push`]: String::push
+    ///
+    /// # Errors
+    ///
+    /// If the capacity overflows, or the allocator reports a failure, then an error
+    /// is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(fallible_collections)]
+    ///
+    /// let mut s = String::from("abc");
+    /// s.try_push('1').expect("out of memory");
+    /// s.try_push('€').expect("out of memory");
+    /// assert_eq!("abc1€", s);
+    /// ```
+    #[inline]
+    #[unstable(feature = "fallible_collections", issue = "none")]
+    pub fn try_push(&mut self, ch: char) -> Result<(), TryReserveError> {
+        match ch.len_utf8() {
+            1 => self.vec.try_push(ch as u8),
+            _ => self
+                .vec
+                .try_extend_from_slice(ch.encode_utf8(&mut [0; char::MAX_LEN_UTF8]).as_bytes()),
+        }
+    }
+
     /// Returns a byte slice of this `String`'s contents.
     ///
     /// The inverse of this method is [`from_utf8`].
@@ -145,6 +145,14 @@
 #[cfg(not(no_global_oom_handling))]
 mod spec_extend;
 
+#[cfg(not(no_global_oom_handling))]
+#[unstable(feature = "small_vec", issue = "none")]
+pub use self::small_vec::SmallVec;
+
+#[cfg(not(no_global_oom_handling))]
+#[unstable(feature = "small_vec", issue = "none")]
+pub mod small_vec;
+
 /// A contiguous growable array type, written as `Vec<T>`, short for 'vector'.
 ///
 /// # Examples
@@ -2036,6 +2044,68 @@ fn assert_failed(index: usize, len: usize) -> ! {
         }
     }
 
+    /// Inserts an element at position `index` within the vector, shifting all
+    /// elements after it to the right, or returns an error if the vector
+    /// needed to grow and the allocation failed.
+    ///
+    /// This is a fallible version of [`insert`]. On error, the vector is
+    /// left unchanged and `element` is dropped.
+    ///
+    /// [`insert`]: Vec::insert
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    ///
+    /// # Errors
+    ///
+    /// If the capacity overflows, or the allocator reports a failure, then an error
+    /// is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(fallible_collections)]
+    ///
+    /// let mut vec = vec!['a', 'b', 'c'];
+    /// vec.try_insert(1, 'd').expect("out of memory");
+    /// assert_eq!(vec, ['a', 'd', 'b', 'c']);
+    /// ```
+    ///
+    /// # Time complexity
+    ///
+    /// Takes *O*([`Vec::len`]) time.
+    #[unstable(feature = "fallible_collections", issue = "none")]
+    #[track_caller]
+    pub fn try_insert(&mut self, index: usize, element: T) -> Result<(), TryReserveError> {
+        #[cold]
+        #[cfg_attr(not(feature = "panic_immediate_abort"), inline(never))]
+        #[track_caller]
+        #[optimize(size)]
+        fn assert_failed(index: usize, len: usize) -> ! {
+            panic!("insertion index (is {index}) should be <= len (is {len})");
+        }
+
+        let len = self.len();
+        if index > len {
+            assert_failed(index, len);
+        }
+
+        if len == self.buf.capacity() {
+            self.buf.try_grow_one()?;
+        }
+
+        unsafe {
+            let p = self.as_mut_ptr().add(index);
+            if index < len {
+                ptr::copy(p, p.add(1), len - index);
+            }
+            ptr::write(p, element);
+            self.set_len(len + 1);
+        }
+        Ok(())
+    }
+
     /// Removes and returns the element at position `index` within the vector,
     /// shifting all elements after it to the left.
     ///
@@ -2503,6 +2573,55 @@ pub fn push_within_capacity(&mut self, value: T) -> Result<(), T> {
         Ok(())
     }
 
+    /// Appends an element to the back of a collection, or returns an error if
+    /// the vector needed to grow and the allocation failed.
+    ///
+    /// This is a fallible version of [`push`]. On error, the vector is left
+    /// unchanged and `value` is dropped.
+    ///
+    /// [`push`]: Vec::push
+    ///
+    /// # Errors
+    ///
+    /// If the capacity overflows, or the allocator reports a failure, then an error
+    /// is returned.
+    ///
+    /// # Examples
+    ///
+    /// A panic-free alternative to [`FromIterator`]:
+    ///
+    /// ```
+    /// #![feature(fallible_collections)]
+    ///
+    /// use std::collections::TryReserveError;
+    /// fn from_iter_fallible<T>(iter: impl Iterator<Item=T>) -> Result<Vec<T>, TryReserveError> {
+    ///     let mut vec = Vec::new();
+    ///     for value in iter {
+    ///         vec.try_push(value)?;
+    ///     }
+    ///     Ok(vec)
+    /// }
+    /// assert_eq!(from_iter_fallible(0..100), Ok(Vec::from_iter(0..100)));
+    /// ```
+    ///
+    /// # Time complexity
+    ///
+    /// Takes amortized *O*(1) time, like [`push`].
+    #[inline]
+    #[unstable(feature = "fallible_collections", issue = "none")]
+    pub fn try_push(&mut self, value: T) -> Result<(), TryReserveError> {
+        let len = self.len;
+        if len == self.buf.capacity() {
+            self.buf.try_grow_one()?;
+        }
+        unsafe {
+            let end = self.as_mut_ptr().add(len);
+            ptr::write(end, value);
+            self.len = len + 1;
+        }
+        Ok(())
+    }
+
     /// Removes the last element from a vector and returns it, or [`None`] if it
     /// is empty.
     ///
@@ -3059,6 +3178,40 @@ pub fn extend_from_slice(&mut self, other: &[T]) {
         self.spec_extend(other.iter())
     }
 
+    /// Clones and appends all elements in a slice to the `Vec`, or returns an
+    /// error if the vector needed to grow and the allocation failed.
+    ///
+    /// This is a fallible version of [`extend_from_slice`]. The space for all
+    /// of `other` is reserved up front, so on error the vector is left
+    /// unchanged.
+    ///
+    /// [`extend_from_slice`]: Vec::extend_from_slice
+    ///
+    /// # Errors
+    ///
+    /// If the capacity overflows, or the allocator reports a failure, then an error
+    /// is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(fallible_collections)]
+    ///
+    /// let mut vec = vec![1];
+    /// vec.try_extend_from_slice(&[2, 3, 4]).expect("out of memory");
+    /// assert_eq!(vec, [1, 2, 3, 4]);
+    /// ```
+    #[unstable(feature = "fallible_collections", issue = "none")]
+    pub fn try_extend_from_slice(&mut self, other: &[T]) -> Result<(), TryReserveError> {
+        self.try_reserve(other.len())?;
+        for value in other {
+            // The capacity was reserved above, so there is room for every element.
+            let pushed = self.push_within_capacity(value.clone());
+            debug_assert!(pushed.is_ok());
+        }
+        Ok(())
+    }
+
     /// Given a range `src`, clones a slice of elements in that range and appends it to the end.
     ///
     /// `src` must be a range that can form a valid subslice of the `Vec`.
@@ -0,0 +1,147 @@
+use core::fmt;
+use core::iter::{FusedIterator, TrustedLen};
+use core::mem::{self, SizedTypeProperties};
+use core::ptr::{self, NonNull};
+use core::slice::{self};
+
+use super::SmallVec;
+
+/// A draining iterator for `SmallVec<T, N>`.
+///
+/// This `struct` is created by [`SmallVec::drain`].
+/// See its documentation for more.
+#[unstable(feature = "small_vec", issue = "none")]
+pub struct Drain<'a, T: 'a, const N: usize> {
+    /// Index of tail to preserve
+    pub(super) tail_start: usize,
+    /// Length of tail
+    pub(super) tail_len: usize,
+    /// Current remaining range to remove
+    pub(super) iter: slice::Iter<'a, T>,
+    pub(super) vec: NonNull<SmallVec<T, N>>,
+}
+
+#[unstable(feature = "small_vec", issue = "none")]
+impl<T: fmt::Debug, const N: usize> fmt::Debug for Drain<'_, T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Drain").field(&self.iter.as_slice()).finish()
+    }
+}
+
+impl<'a, T, const N: usize> Drain<'a, T, N> {
+    /// Returns the remaining items of this iterator as a slice.
+    #[must_use]
+    #[unstable(feature = "small_vec", issue = "none")]
+    pub fn as_slice(&self) -> &[T] {
+        self.iter.as_slice()
+    }
+}
+
+#[unstable(feature = "small_vec", issue = "none")]
+impl<'a, T, const N: usize> AsRef<[T]> for Drain<'a, T, N> {
+    fn as_ref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+#[unstable(feature = "small_vec", issue = "none")]
+unsafe impl<T: Sync, const N: usize> Sync for Drain<'_, T, N> {}
+#[unstable(feature = "small_vec", issue = "none")]
+unsafe impl<T: Send, const N: usize> Send for Drain<'_, T, N> {}
+
+#[unstable(feature = "small_vec", issue = "none")]
+impl<T, const N: usize> Iterator for Drain<'_, T, N> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        self.iter.next().map(|elt| unsafe { ptr::read(elt as *const _) })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+#[unstable(feature = "small_vec", issue = "none")]
+impl<T, const N: usize> DoubleEndedIterator for Drain<'_, T, N> {
+    #[inline]
+    fn next_back(&mut self) -> Option<T> {
+        self.iter.next_back().map(|elt| unsafe { ptr::read(elt as *const _) })
+    }
+}
+
+#[unstable(feature = "small_vec", issue = "none")]
+impl<T, const N: usize> Drop for Drain<'_, T, N> {
+    fn drop(&mut self) {
+        /// Moves back the un-`Drain`ed elements to restore the original `SmallVec`.
+        struct DropGuard<'r, 'a, T, const N: usize>(&'r mut Drain<'a, T, N>);
+
+        impl<'r, 'a, T, const N: usize> Drop for DropGuard<'r, 'a, T, N> {
+            fn drop(&mut self) {
+                if self.0.tail_len > 0 {
+                    unsafe {
+                        let source_vec = self.0.vec.as_mut();
+                        // memmove back untouched tail, update to new length
+                        let start = source_vec.len();
+                        let tail = self.0.tail_start;
+                        if tail != start {
+                            let src = source_vec.as_ptr().add(tail);
+                            let dst = source_vec.as_mut_ptr().add(start);
+                            ptr::copy(src, dst, self.0.tail_len);
+                        }
+                        source_vec.set_len(start + self.0.tail_len);
+                    }
+                }
+            }
+        }
+
+        let iter = mem::take(&mut self.iter);
+        let drop_len = iter.len();
+
+        let mut vec = self.vec;
+
+        if T::IS_ZST {
+            // ZSTs have no identity, so we only need to drop the correct amount.
+            unsafe {
+                let vec = vec.as_mut();
+                let old_len = vec.len();
+                vec.set_len(old_len + drop_len + self.tail_len);
+                vec.truncate(old_len + self.tail_len);
+            }
+
+            return;
+        }
+
+        // ensure elements are moved back into their appropriate places, even when drop_in_place panics
+        let _guard = DropGuard(self);
+
+        if drop_len == 0 {
+            return;
+        }
+
+        // as_slice() must only be called when iter.len() is > 0, see `vec::Drain::drop`.
+        let drop_ptr = iter.as_slice().as_ptr();
+
+        unsafe {
+            // Reconstruct a pointer with mutable provenance from the vector.
+            let vec_ptr = vec.as_mut().as_mut_ptr();
+            let drop_offset = drop_ptr.offset_from_unsigned(vec_ptr);
+            let to_drop = ptr::slice_from_raw_parts_mut(vec_ptr.add(drop_offset), drop_len);
+            ptr::drop_in_place(to_drop);
+        }
+    }
+}
+
+#[unstable(feature = "small_vec", issue = "none")]
+impl<T, const N: usize> ExactSizeIterator for Drain<'_, T, N> {
+    fn is_empty(&self) -> bool {
+        self.iter.is_empty()
+    }
+}
+
+#[unstable(feature = "trusted_len", issue = "37572")]
+unsafe impl<T, const N: usize> TrustedLen for Drain<'_, T, N> {}
+
+#[unstable(feature = "small_vec", issue = "none")]
+impl<T, const N: usize> FusedIterator for Drain<'_, T, N> {}
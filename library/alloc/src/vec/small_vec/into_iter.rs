@@ -0,0 +1,153 @@
+use core::iter::{FusedIterator, TrustedLen};
+use core::mem::MaybeUninit;
+use core::ops::Range;
+use core::ptr::{self};
+use core::{fmt, slice};
+
+use super::SmallVec;
+use crate::vec::{self, Vec};
+
+/// An iterator that moves out of a `SmallVec`.
+///
+/// This `struct` is created by the `into_iter` method on
+/// [`SmallVec`](super::SmallVec) (provided by the [`IntoIterator`] trait).
+#[unstable(feature = "small_vec", issue = "none")]
+pub struct IntoIter<T, const N: usize> {
+    inner: Inner<T, N>,
+}
+
+enum Inner<T, const N: usize> {
+    /// The elements in `buf[alive]` have not been yielded yet.
+    Inline {
+        buf: [MaybeUninit<T>; N],
+        alive: Range<usize>,
+    },
+    Heap(vec::IntoIter<T>),
+}
+
+impl<T, const N: usize> IntoIter<T, N> {
+    pub(super) fn from_vec(vec: Vec<T>) -> Self {
+        IntoIter { inner: Inner::Heap(vec.into_iter()) }
+    }
+
+    /// # Safety
+    ///
+    /// The first `len` elements of `buf` must be initialized.
+    pub(super) unsafe fn from_inline(buf: [MaybeUninit<T>; N], len: usize) -> Self {
+        IntoIter { inner: Inner::Inline { buf, alive: 0..len } }
+    }
+
+    /// Returns the remaining items of this iterator as a slice.
+    #[unstable(feature = "small_vec", issue = "none")]
+    pub fn as_slice(&self) -> &[T] {
+        match &self.inner {
+            Inner::Inline { buf, alive } => unsafe {
+                slice::from_raw_parts(buf.as_ptr().cast::<T>().add(alive.start), alive.len())
+            },
+            Inner::Heap(iter) => iter.as_slice(),
+        }
+    }
+
+    /// Returns the remaining items of this iterator as a mutable slice.
+    #[unstable(feature = "small_vec", issue = "none")]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        match &mut self.inner {
+            Inner::Inline { buf, alive } => unsafe {
+                slice::from_raw_parts_mut(
+                    buf.as_mut_ptr().cast::<T>().add(alive.start),
+                    alive.len(),
+                )
+            },
+            Inner::Heap(iter) => iter.as_mut_slice(),
+        }
+    }
+}
+
+#[unstable(feature = "small_vec", issue = "none")]
+impl<T: fmt::Debug, const N: usize> fmt::Debug for IntoIter<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("IntoIter").field(&self.as_slice()).finish()
+    }
+}
+
+#[unstable(feature = "small_vec", issue = "none")]
+impl<T, const N: usize> Iterator for IntoIter<T, N> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        match &mut self.inner {
+            Inner::Inline { buf, alive } => {
+                let index = alive.next()?;
+                // The element at `index` is now outside of `alive`, so it is
+                // read exactly once.
+                Some(unsafe { ptr::read(buf.as_ptr().cast::<T>().add(index)) })
+            }
+            Inner::Heap(iter) => iter.next(),
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.len()
+    }
+}
+
+#[unstable(feature = "small_vec", issue = "none")]
+impl<T, const N: usize> DoubleEndedIterator for IntoIter<T, N> {
+    #[inline]
+    fn next_back(&mut self) -> Option<T> {
+        match &mut self.inner {
+            Inner::Inline { buf, alive } => {
+                let index = alive.next_back()?;
+                Some(unsafe { ptr::read(buf.as_ptr().cast::<T>().add(index)) })
+            }
+            Inner::Heap(iter) => iter.next_back(),
+        }
+    }
+}
+
+#[unstable(feature = "small_vec", issue = "none")]
+impl<T, const N: usize> ExactSizeIterator for IntoIter<T, N> {
+    fn len(&self) -> usize {
+        match &self.inner {
+            Inner::Inline { alive, .. } => alive.len(),
+            Inner::Heap(iter) => iter.len(),
+        }
+    }
+}
+
+#[unstable(feature = "small_vec", issue = "none")]
+impl<T, const N: usize> FusedIterator for IntoIter<T, N> {}
+
+#[unstable(feature = "trusted_len", issue = "37572")]
+unsafe impl<T, const N: usize> TrustedLen for IntoIter<T, N> {}
+
+#[unstable(feature = "small_vec", issue = "none")]
+impl<T: Clone, const N: usize> Clone for IntoIter<T, N> {
+    fn clone(&self) -> Self {
+        match &self.inner {
+            // The remaining elements fit inline again.
+            Inner::Inline { .. } => {
+                self.as_slice().iter().cloned().collect::<SmallVec<T, N>>().into_iter()
+            }
+            Inner::Heap(iter) => IntoIter { inner: Inner::Heap(iter.clone()) },
+        }
+    }
+}
+
+#[unstable(feature = "small_vec", issue = "none")]
+impl<T, const N: usize> Drop for IntoIter<T, N> {
+    fn drop(&mut self) {
+        // The heap iterator drops its own remaining elements.
+        if let Inner::Inline { .. } = self.inner {
+            unsafe { ptr::drop_in_place(self.as_mut_slice()) }
+        }
+    }
+}
@@ -0,0 +1,1090 @@
+//! A vector that stores a few elements inline before spilling to the heap.
+
+use core::borrow::{Borrow, BorrowMut};
+use core::cmp::{self, Ordering};
+use core::hash::{Hash, Hasher};
+use core::iter::TrustedLen;
+use core::mem::{self, ManuallyDrop, MaybeUninit, SizedTypeProperties};
+use core::ops::{Deref, DerefMut, Range, RangeBounds};
+use core::ptr::{self, NonNull};
+use core::{fmt, slice};
+
+#[unstable(feature = "small_vec", issue = "none")]
+pub use self::drain::Drain;
+use super::{SetLenOnDrop, Vec};
+use crate::collections::TryReserveError;
+
+mod drain;
+
+#[unstable(feature = "small_vec", issue = "none")]
+pub use self::into_iter::IntoIter;
+
+mod into_iter;
+
+#[unstable(feature = "small_vec", issue = "none")]
+pub use self::splice::Splice;
+
+mod splice;
+
+/// A contiguous growable array type that stores up to `N` elements inline,
+/// and moves them to a heap-allocated [`Vec`] once it outgrows that space.
+///
+/// A `SmallVec` behaves like a `Vec<T>`, and dereferences to a slice in the
+/// same way. As long as it holds no more than `N` elements it does not
+/// allocate at all, which makes it a good fit for collections that are
+/// usually small but occasionally large. Once the elements have *spilled*
+/// to the heap, the `SmallVec` grows exactly like a `Vec`, and stays on the
+/// heap until [`shrink_to_fit`] is called.
+///
+/// The inline storage makes a `SmallVec` larger than a `Vec`, by roughly
+/// `N` elements, and moving it copies that storage.
+///
+/// [`shrink_to_fit`]: SmallVec::shrink_to_fit
+///
+/// # Examples
+///
+/// ```
+/// #![feature(small_vec)]
+/// use std::vec::SmallVec;
+///
+/// let mut v: SmallVec<u32, 4> = SmallVec::new();
+/// v.extend([1, 2, 3]);
+/// assert!(!v.spilled());
+/// assert_eq!(v, [1, 2, 3]);
+///
+/// v.extend([4, 5]);
+/// assert!(v.spilled());
+/// assert_eq!(v.len(), 5);
+///
+/// v.retain(|&x| x % 2 == 1);
+/// v.shrink_to_fit();
+/// assert!(!v.spilled());
+/// assert_eq!(v.into_vec(), [1, 3, 5]);
+/// ```
+#[unstable(feature = "small_vec", issue = "none")]
+pub struct SmallVec<T, const N: usize> {
+    repr: Repr<T, N>,
+}
+
+enum Repr<T, const N: usize> {
+    /// The first `len` elements of `buf` are initialized.
+    Inline {
+        len: usize,
+        buf: [MaybeUninit<T>; N],
+    },
+    Heap(Vec<T>),
+}
+
+impl<T, const N: usize> SmallVec<T, N> {
+    /// The capacity of the inline storage. Zero-sized types never need to
+    /// spill, so for them it is unlimited, like the capacity of a `Vec`.
+    const INLINE_CAPACITY: usize = if T::IS_ZST { usize::MAX } else { N };
+
+    /// Constructs a new, empty `SmallVec<T, N>`, using the inline storage.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(small_vec)]
+    /// use std::vec::SmallVec;
+    ///
+    /// let v: SmallVec<i32, 8> = SmallVec::new();
+    /// assert_eq!(v.capacity(), 8);
+    /// ```
+    #[inline]
+    #[must_use]
+    #[unstable(feature = "small_vec", issue = "none")]
+    pub const fn new() -> Self {
+        SmallVec { repr: Repr::Inline { len: 0, buf: [const { MaybeUninit::uninit() }; N] } }
+    }
+
+    /// Constructs a new, empty `SmallVec<T, N>` with at least the specified
+    /// capacity.
+    ///
+    /// If `capacity` fits in the inline storage, this does not allocate.
+    #[inline]
+    #[must_use]
+    #[unstable(feature = "small_vec", issue = "none")]
+    pub fn with_capacity(capacity: usize) -> Self {
+        if capacity <= Self::INLINE_CAPACITY {
+            Self::new()
+        } else {
+            SmallVec { repr: Repr::Heap(Vec::with_capacity(capacity)) }
+        }
+    }
+
+    /// Returns `true` if the elements have spilled from the inline storage
+    /// to the heap.
+    #[inline]
+    #[unstable(feature = "small_vec", issue = "none")]
+    pub fn spilled(&self) -> bool {
+        matches!(self.repr, Repr::Heap(_))
+    }
+
+    /// Returns the number of elements in the vector.
+    #[inline]
+    #[unstable(feature = "small_vec", issue = "none")]
+    pub fn len(&self) -> usize {
+        match &self.repr {
+            Repr::Inline { len, .. } => *len,
+            Repr::Heap(vec) => vec.len(),
+        }
+    }
+
+    /// Returns `true` if the vector contains no elements.
+    #[inline]
+    #[unstable(feature = "small_vec", issue = "none")]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the total number of elements the vector can hold without
+    /// reallocating. This is `N` while the elements are stored inline.
+    #[inline]
+    #[unstable(feature = "small_vec", issue = "none")]
+    pub fn capacity(&self) -> usize {
+        match &self.repr {
+            Repr::Inline { .. } => Self::INLINE_CAPACITY,
+            Repr::Heap(vec) => vec.capacity(),
+        }
+    }
+
+    /// Returns a raw pointer to the vector's buffer.
+    ///
+    /// The pointer is invalidated by any operation that may reallocate, and
+    /// while the elements are stored inline, by moving the `SmallVec`.
+    #[inline]
+    #[unstable(feature = "small_vec", issue = "none")]
+    pub fn as_ptr(&self) -> *const T {
+        match &self.repr {
+            Repr::Inline { buf, .. } => buf.as_ptr().cast(),
+            Repr::Heap(vec) => vec.as_ptr(),
+        }
+    }
+
+    /// Returns a raw mutable pointer to the vector's buffer.
+    ///
+    /// See [`as_ptr`](SmallVec::as_ptr) for when the pointer is invalidated.
+    #[inline]
+    #[unstable(feature = "small_vec", issue = "none")]
+    pub fn as_mut_ptr(&mut self) -> *mut T {
+        match &mut self.repr {
+            Repr::Inline { buf, .. } => buf.as_mut_ptr().cast(),
+            Repr::Heap(vec) => vec.as_mut_ptr(),
+        }
+    }
+
+    /// Returns the buffer, the length field and the capacity, whichever
+    /// storage is in use.
+    #[inline]
+    fn triple_mut(&mut self) -> (*mut T, &mut usize, usize) {
+        match &mut self.repr {
+            Repr::Inline { len, buf } => (buf.as_mut_ptr().cast(), len, Self::INLINE_CAPACITY),
+            Repr::Heap(vec) => {
+                let capacity = vec.capacity();
+                (vec.as_mut_ptr(), &mut vec.len, capacity)
+            }
+        }
+    }
+
+    /// Extracts a slice containing the entire vector.
+    #[inline]
+    #[unstable(feature = "small_vec", issue = "none")]
+    pub fn as_slice(&self) -> &[T] {
+        unsafe { slice::from_raw_parts(self.as_ptr(), self.len()) }
+    }
+
+    /// Extracts a mutable slice of the entire vector.
+    #[inline]
+    #[unstable(feature = "small_vec", issue = "none")]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        let (ptr, len, _) = self.triple_mut();
+        unsafe { slice::from_raw_parts_mut(ptr, *len) }
+    }
+
+    /// Forces the length of the vector to `new_len`.
+    ///
+    /// # Safety
+    ///
+    /// - `new_len` must be less than or equal to [`capacity()`].
+    /// - The elements at `old_len..new_len` must be initialized.
+    ///
+    /// [`capacity()`]: SmallVec::capacity
+    #[inline]
+    #[unstable(feature = "small_vec", issue = "none")]
+    pub unsafe fn set_len(&mut self, new_len: usize) {
+        debug_assert!(new_len <= self.capacity());
+        *self.triple_mut().1 = new_len;
+    }
+
+    /// Reserves capacity for at least `additional` more elements to be
+    /// inserted, spilling to the heap if the inline storage is too small.
+    ///
+    /// Once spilled, this grows like [`Vec::reserve`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity exceeds `isize::MAX` _bytes_.
+    #[unstable(feature = "small_vec", issue = "none")]
+    #[track_caller]
+    pub fn reserve(&mut self, additional: usize) {
+        let len = self.len();
+        unsafe { self.reserve_past(len, additional, false) }
+    }
+
+    /// Reserves the minimum capacity for at least `additional` more elements
+    /// to be inserted, spilling to the heap if the inline storage is too
+    /// small.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity exceeds `isize::MAX` _bytes_.
+    #[unstable(feature = "small_vec", issue = "none")]
+    #[track_caller]
+    pub fn reserve_exact(&mut self, additional: usize) {
+        let len = self.len();
+        unsafe { self.reserve_past(len, additional, true) }
+    }
+
+    /// Tries to reserve capacity for at least `additional` more elements to
+    /// be inserted.
+    ///
+    /// # Errors
+    ///
+    /// If the capacity overflows, or the allocator reports a failure, then
+    /// an error is returned.
+    #[unstable(feature = "small_vec", issue = "none")]
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        let len = self.len();
+        match &mut self.repr {
+            Repr::Heap(vec) => vec.try_reserve(additional),
+            Repr::Inline { .. } if additional <= Self::INLINE_CAPACITY - len => Ok(()),
+            Repr::Inline { .. } => {
+                let mut vec = Vec::new();
+                vec.try_reserve(Self::spill_capacity(len, additional))?;
+                unsafe { self.spill_into(vec, len) };
+                Ok(())
+            }
+        }
+    }
+
+    /// Tries to reserve the minimum capacity for at least `additional` more
+    /// elements to be inserted.
+    ///
+    /// # Errors
+    ///
+    /// If the capacity overflows, or the allocator reports a failure, then
+    /// an error is returned.
+    #[unstable(feature = "small_vec", issue = "none")]
+    pub fn try_reserve_exact(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        let len = self.len();
+        match &mut self.repr {
+            Repr::Heap(vec) => vec.try_reserve_exact(additional),
+            Repr::Inline { .. } if additional <= Self::INLINE_CAPACITY - len => Ok(()),
+            Repr::Inline { .. } => {
+                let mut vec = Vec::new();
+                vec.try_reserve_exact(len.saturating_add(additional))?;
+                unsafe { self.spill_into(vec, len) };
+                Ok(())
+            }
+        }
+    }
+
+    /// The capacity to spill into for `additional` more elements past `used`:
+    /// at least double the inline capacity, as `RawVec` grows a full buffer.
+    fn spill_capacity(used: usize, additional: usize) -> usize {
+        cmp::max(used.saturating_add(additional), N.saturating_mul(2))
+    }
+
+    /// Makes room for `additional` elements past the first `used` slots of
+    /// the buffer, which are preserved even if they are beyond `len`.
+    ///
+    /// # Safety
+    ///
+    /// `used` must be at least `len` and at most the capacity.
+    #[track_caller]
+    unsafe fn reserve_past(&mut self, used: usize, additional: usize, exact: bool) {
+        let len = self.len();
+        match &mut self.repr {
+            Repr::Heap(vec) if exact => vec.reserve_exact(used - len + additional),
+            Repr::Heap(vec) => vec.reserve(used - len + additional),
+            Repr::Inline { .. } if additional <= Self::INLINE_CAPACITY - used => {}
+            Repr::Inline { .. } => {
+                let Some(required) = used.checked_add(additional) else { capacity_overflow() };
+                let capacity = if exact { required } else { Self::spill_capacity(used, 0) };
+                let vec = Vec::with_capacity(cmp::max(capacity, required));
+                unsafe { self.spill_into(vec, used) };
+            }
+        }
+    }
+
+    /// Moves the first `used` slots of the inline storage into `vec`, and
+    /// switches to it.
+    ///
+    /// # Safety
+    ///
+    /// The elements must be inline, and `vec` must be empty with a capacity
+    /// of at least `used`.
+    unsafe fn spill_into(&mut self, mut vec: Vec<T>, used: usize) {
+        let Repr::Inline { len, buf } = &self.repr else { unreachable!() };
+        unsafe {
+            ptr::copy_nonoverlapping(buf.as_ptr().cast(), vec.as_mut_ptr(), used);
+            vec.set_len(*len);
+        }
+        // The inline storage does not drop its elements, which now belong
+        // to `vec`.
+        self.repr = Repr::Heap(vec);
+    }
+
+    /// Shrinks the capacity of the vector as much as possible.
+    ///
+    /// If the elements have spilled to the heap but fit in the inline
+    /// storage again, they are moved back and the heap buffer is freed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(small_vec)]
+    /// use std::vec::SmallVec;
+    ///
+    /// let mut v: SmallVec<i32, 2> = SmallVec::from_iter(0..10);
+    /// assert!(v.spilled());
+    /// v.truncate(2);
+    /// v.shrink_to_fit();
+    /// assert!(!v.spilled());
+    /// ```
+    #[unstable(feature = "small_vec", issue = "none")]
+    #[track_caller]
+    pub fn shrink_to_fit(&mut self) {
+        let Repr::Heap(vec) = &mut self.repr else { return };
+        if vec.len() > N {
+            vec.shrink_to_fit();
+            return;
+        }
+        let mut buf = [const { MaybeUninit::uninit() }; N];
+        let len = vec.len();
+        unsafe {
+            ptr::copy_nonoverlapping(vec.as_ptr(), buf.as_mut_ptr().cast(), len);
+            vec.set_len(0);
+        }
+        self.repr = Repr::Inline { len, buf };
+    }
+
+    /// Converts the vector into a [`Vec<T>`].
+    ///
+    /// This does not allocate if the elements have already spilled to the
+    /// heap.
+    #[unstable(feature = "small_vec", issue = "none")]
+    pub fn into_vec(self) -> Vec<T> {
+        let mut this = ManuallyDrop::new(self);
+        match &mut this.repr {
+            Repr::Heap(vec) => mem::take(vec),
+            Repr::Inline { len, buf } => {
+                let mut vec = Vec::with_capacity(*len);
+                unsafe {
+                    ptr::copy_nonoverlapping(buf.as_ptr().cast(), vec.as_mut_ptr(), *len);
+                    vec.set_len(*len);
+                }
+                vec
+            }
+        }
+    }
+
+    /// Converts the vector into an array, if it holds exactly `N` elements.
+    /// Otherwise returns the vector unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(small_vec)]
+    /// use std::vec::SmallVec;
+    ///
+    /// let v: SmallVec<_, 3> = SmallVec::from([1, 2, 3]);
+    /// assert_eq!(v.into_inner(), Ok([1, 2, 3]));
+    /// ```
+    #[unstable(feature = "small_vec", issue = "none")]
+    pub fn into_inner(self) -> Result<[T; N], Self> {
+        if self.len() != N {
+            return Err(self);
+        }
+        let mut this = ManuallyDrop::new(self);
+        unsafe {
+            let array = ptr::read(this.as_ptr().cast::<[T; N]>());
+            if let Repr::Heap(vec) = &mut this.repr {
+                vec.set_len(0);
+                ptr::drop_in_place(vec);
+            }
+            Ok(array)
+        }
+    }
+
+    /// Appends an element to the back of the vector.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity exceeds `isize::MAX` _bytes_.
+    #[inline]
+    #[unstable(feature = "small_vec", issue = "none")]
+    #[track_caller]
+    pub fn push(&mut self, value: T) {
+        let (_, &mut len, capacity) = self.triple_mut();
+        if len == capacity {
+            self.reserve(1);
+        }
+        let (ptr, len, _) = self.triple_mut();
+        unsafe { ptr::write(ptr.add(*len), value) };
+        *len += 1;
+    }
+
+    /// Removes the last element from the vector and returns it, or [`None`]
+    /// if it is empty.
+    #[inline]
+    #[unstable(feature = "small_vec", issue = "none")]
+    pub fn pop(&mut self) -> Option<T> {
+        let (ptr, len, _) = self.triple_mut();
+        if *len == 0 {
+            None
+        } else {
+            *len -= 1;
+            unsafe { Some(ptr::read(ptr.add(*len))) }
+        }
+    }
+
+    /// Inserts an element at position `index` within the vector, shifting
+    /// all elements after it to the right.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    #[unstable(feature = "small_vec", issue = "none")]
+    #[track_caller]
+    pub fn insert(&mut self, index: usize, element: T) {
+        let len = self.len();
+        if index > len {
+            panic!("insertion index (is {index}) should be <= len (is {len})");
+        }
+        if len == self.capacity() {
+            self.reserve(1);
+        }
+        let (ptr, len_ref, _) = self.triple_mut();
+        unsafe {
+            let p = ptr.add(index);
+            if index < len {
+                ptr::copy(p, p.add(1), len - index);
+            }
+            ptr::write(p, element);
+        }
+        *len_ref = len + 1;
+    }
+
+    /// Removes and returns the element at position `index` within the
+    /// vector, shifting all elements after it to the left.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    #[unstable(feature = "small_vec", issue = "none")]
+    #[track_caller]
+    pub fn remove(&mut self, index: usize) -> T {
+        let (ptr, len_ref, _) = self.triple_mut();
+        let len = *len_ref;
+        if index >= len {
+            panic!("removal index (is {index}) should be < len (is {len})");
+        }
+        unsafe {
+            let p = ptr.add(index);
+            let ret = ptr::read(p);
+            ptr::copy(p.add(1), p, len - index - 1);
+            *len_ref = len - 1;
+            ret
+        }
+    }
+
+    /// Removes an element from the vector and returns it, replacing it with
+    /// the last element.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    #[unstable(feature = "small_vec", issue = "none")]
+    #[track_caller]
+    pub fn swap_remove(&mut self, index: usize) -> T {
+        let (ptr, len_ref, _) = self.triple_mut();
+        let len = *len_ref;
+        if index >= len {
+            panic!("swap_remove index (is {index}) should be < len (is {len})");
+        }
+        unsafe {
+            let value = ptr::read(ptr.add(index));
+            ptr::copy(ptr.add(len - 1), ptr.add(index), 1);
+            *len_ref = len - 1;
+            value
+        }
+    }
+
+    /// Shortens the vector, keeping the first `len` elements and dropping
+    /// the rest. Stays inline or on the heap, whichever it was.
+    #[unstable(feature = "small_vec", issue = "none")]
+    pub fn truncate(&mut self, len: usize) {
+        let (ptr, len_ref, _) = self.triple_mut();
+        if len >= *len_ref {
+            return;
+        }
+        let remaining = *len_ref - len;
+        // Set the length first, in case dropping an element panics.
+        *len_ref = len;
+        unsafe { ptr::drop_in_place(ptr::slice_from_raw_parts_mut(ptr.add(len), remaining)) };
+    }
+
+    /// Clears the vector, removing all values.
+    #[inline]
+    #[unstable(feature = "small_vec", issue = "none")]
+    pub fn clear(&mut self) {
+        self.truncate(0)
+    }
+
+    /// Retains only the elements specified by the predicate, preserving the
+    /// order of the retained elements. See [`Vec::retain`].
+    #[unstable(feature = "small_vec", issue = "none")]
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.retain_mut(|elem| f(elem));
+    }
+
+    /// Retains only the elements specified by the predicate, passing a
+    /// mutable reference to it. See [`Vec::retain_mut`].
+    #[unstable(feature = "small_vec", issue = "none")]
+    pub fn retain_mut<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut T) -> bool,
+    {
+        // Same algorithm as `Vec::retain_mut`: the length is zero while the
+        // predicate runs, and the guard closes the hole left by removed
+        // elements even if the predicate or a destructor panics.
+        struct BackshiftOnDrop<'a, T> {
+            ptr: *mut T,
+            len: &'a mut usize,
+            processed_len: usize,
+            deleted_cnt: usize,
+            original_len: usize,
+        }
+
+        impl<T> Drop for BackshiftOnDrop<'_, T> {
+            fn drop(&mut self) {
+                if self.deleted_cnt > 0 {
+                    unsafe {
+                        ptr::copy(
+                            self.ptr.add(self.processed_len),
+                            self.ptr.add(self.processed_len - self.deleted_cnt),
+                            self.original_len - self.processed_len,
+                        );
+                    }
+                }
+                *self.len = self.original_len - self.deleted_cnt;
+            }
+        }
+
+        let (ptr, len, _) = self.triple_mut();
+        let original_len = mem::replace(len, 0);
+        let mut g = BackshiftOnDrop { ptr, len, processed_len: 0, deleted_cnt: 0, original_len };
+
+        while g.processed_len != original_len {
+            let cur = unsafe { &mut *g.ptr.add(g.processed_len) };
+            if !f(cur) {
+                g.processed_len += 1;
+                g.deleted_cnt += 1;
+                unsafe { ptr::drop_in_place(cur) };
+                continue;
+            }
+            if g.deleted_cnt > 0 {
+                unsafe {
+                    let hole_slot = g.ptr.add(g.processed_len - g.deleted_cnt);
+                    ptr::copy_nonoverlapping(cur, hole_slot, 1);
+                }
+            }
+            g.processed_len += 1;
+        }
+    }
+
+    /// Removes all but the first of consecutive elements in the vector
+    /// satisfying a given equality relation. See [`Vec::dedup_by`].
+    #[unstable(feature = "small_vec", issue = "none")]
+    pub fn dedup_by<F>(&mut self, same_bucket: F)
+    where
+        F: FnMut(&mut T, &mut T) -> bool,
+    {
+        let len = self.as_mut_slice().partition_dedup_by(same_bucket).0.len();
+        self.truncate(len);
+    }
+
+    /// Removes all but the first of consecutive elements in the vector that
+    /// resolve to the same key.
+    #[inline]
+    #[unstable(feature = "small_vec", issue = "none")]
+    pub fn dedup_by_key<F, K>(&mut self, mut key: F)
+    where
+        F: FnMut(&mut T) -> K,
+        K: PartialEq,
+    {
+        self.dedup_by(|a, b| key(a) == key(b))
+    }
+
+    /// Removes consecutive repeated elements in the vector according to the
+    /// [`PartialEq`] trait implementation.
+    #[inline]
+    #[unstable(feature = "small_vec", issue = "none")]
+    pub fn dedup(&mut self)
+    where
+        T: PartialEq,
+    {
+        self.dedup_by(|a, b| a == b)
+    }
+
+    /// Resizes the vector in-place so that `len` is equal to `new_len`,
+    /// filling any new slots with the results of calling `f`.
+    #[unstable(feature = "small_vec", issue = "none")]
+    #[track_caller]
+    pub fn resize_with<F>(&mut self, new_len: usize, f: F)
+    where
+        F: FnMut() -> T,
+    {
+        let len = self.len();
+        if new_len > len {
+            self.extend(core::iter::repeat_with(f).take(new_len - len));
+        } else {
+            self.truncate(new_len);
+        }
+    }
+
+    /// Moves all the elements of `other` into `self`, leaving `other` empty.
+    #[unstable(feature = "small_vec", issue = "none")]
+    #[track_caller]
+    pub fn append<const M: usize>(&mut self, other: &mut SmallVec<T, M>) {
+        self.extend(other.drain(..));
+    }
+
+    /// Removes the specified range from the vector in bulk, returning all
+    /// removed elements as an iterator. See [`Vec::drain`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the starting point is greater than the end point or if
+    /// the end point is greater than the length of the vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(small_vec)]
+    /// use std::vec::SmallVec;
+    ///
+    /// let mut v: SmallVec<_, 4> = SmallVec::from([1, 2, 3, 4]);
+    /// let u: Vec<_> = v.drain(1..3).collect();
+    /// assert_eq!(v, [1, 4]);
+    /// assert_eq!(u, [2, 3]);
+    /// ```
+    #[unstable(feature = "small_vec", issue = "none")]
+    #[track_caller]
+    pub fn drain<R>(&mut self, range: R) -> Drain<'_, T, N>
+    where
+        R: RangeBounds<usize>,
+    {
+        let len = self.len();
+        let Range { start, end } = slice::range(range, ..len);
+
+        unsafe {
+            // Set the length to the start, to be safe in case Drain is leaked.
+            self.set_len(start);
+            let range_slice = slice::from_raw_parts(self.as_ptr().add(start), end - start);
+            Drain {
+                tail_start: end,
+                tail_len: len - end,
+                iter: range_slice.iter(),
+                vec: NonNull::from(self),
+            }
+        }
+    }
+
+    /// Creates a splicing iterator that replaces the specified range in the
+    /// vector with the given `replace_with` iterator and yields the removed
+    /// items. See [`Vec::splice`].
+    ///
+    /// The vector spills to the heap if the replacement makes it outgrow
+    /// the inline storage.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(small_vec)]
+    /// use std::vec::SmallVec;
+    ///
+    /// let mut v: SmallVec<_, 4> = SmallVec::from([1, 2, 3, 4]);
+    /// let new = [7, 8, 9];
+    /// let u: Vec<_> = v.splice(1..3, new).collect();
+    /// assert_eq!(v, [1, 7, 8, 9, 4]);
+    /// assert_eq!(u, [2, 3]);
+    /// ```
+    #[inline]
+    #[unstable(feature = "small_vec", issue = "none")]
+    #[track_caller]
+    pub fn splice<R, I>(&mut self, range: R, replace_with: I) -> Splice<'_, I::IntoIter, N>
+    where
+        R: RangeBounds<usize>,
+        I: IntoIterator<Item = T>,
+    {
+        Splice { drain: self.drain(range), replace_with: replace_with.into_iter() }
+    }
+}
+
+impl<T: Clone, const N: usize> SmallVec<T, N> {
+    /// Clones and appends all elements in a slice to the vector.
+    #[unstable(feature = "small_vec", issue = "none")]
+    #[track_caller]
+    pub fn extend_from_slice(&mut self, other: &[T]) {
+        self.spec_extend(other.iter().cloned())
+    }
+
+    /// Resizes the vector in-place so that `len` is equal to `new_len`,
+    /// filling any new slots with clones of `value`.
+    #[unstable(feature = "small_vec", issue = "none")]
+    #[track_caller]
+    pub fn resize(&mut self, new_len: usize, value: T) {
+        let len = self.len();
+        if new_len > len {
+            self.extend(core::iter::repeat_n(value, new_len - len));
+        } else {
+            self.truncate(new_len);
+        }
+    }
+}
+
+#[cold]
+#[track_caller]
+fn capacity_overflow() -> ! {
+    panic!("capacity overflow");
+}
+
+// Specialization trait used for SmallVec::extend, like `Vec`'s.
+trait SpecExtend<I> {
+    #[track_caller]
+    fn spec_extend(&mut self, iter: I);
+}
+
+impl<T, I, const N: usize> SpecExtend<I> for SmallVec<T, N>
+where
+    I: Iterator<Item = T>,
+{
+    #[track_caller]
+    default fn spec_extend(&mut self, mut iter: I) {
+        let (lower, _) = iter.size_hint();
+        self.reserve(lower);
+
+        // Fill the spare capacity without checking it for every element,
+        // then push the rest one by one.
+        let (ptr, len, capacity) = self.triple_mut();
+        let mut local_len = SetLenOnDrop::new(len);
+        while local_len.current_len() < capacity {
+            let Some(element) = iter.next() else { return };
+            unsafe { ptr::write(ptr.add(local_len.current_len()), element) };
+            local_len.increment_len(1);
+        }
+        drop(local_len);
+
+        iter.for_each(|element| self.push(element));
+    }
+}
+
+impl<T, I, const N: usize> SpecExtend<I> for SmallVec<T, N>
+where
+    I: TrustedLen<Item = T>,
+{
+    #[track_caller]
+    fn spec_extend(&mut self, iter: I) {
+        let (_, Some(additional)) = iter.size_hint() else { capacity_overflow() };
+        self.reserve(additional);
+
+        let (ptr, len, _) = self.triple_mut();
+        let mut local_len = SetLenOnDrop::new(len);
+        iter.for_each(move |element| {
+            unsafe { ptr::write(ptr.add(local_len.current_len()), element) };
+            // Since the loop executes user code which can panic we have to
+            // update the length every step to correctly drop what we've
+            // written.
+            local_len.increment_len(1);
+        });
+    }
+}
+
+#[unstable(feature = "small_vec", issue = "none")]
+unsafe impl<#[may_dangle] T, const N: usize> Drop for SmallVec<T, N> {
+    fn drop(&mut self) {
+        // The heap storage is a `Vec`, which drops its own elements.
+        if let Repr::Inline { len, buf } = &mut self.repr {
+            unsafe {
+                ptr::drop_in_place(ptr::slice_from_raw_parts_mut(
+                    buf.as_mut_ptr().cast::<T>(),
+                    *len,
+                ))
+            }
+        }
+    }
+}
+
+#[unstable(feature = "small_vec", issue = "none")]
+impl<T, const N: usize> Deref for SmallVec<T, N> {
+    type Target = [T];
+
+    #[inline]
+    fn deref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+#[unstable(feature = "small_vec", issue = "none")]
+impl<T, const N: usize> DerefMut for SmallVec<T, N> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+
+#[unstable(feature = "small_vec", issue = "none")]
+impl<T: Clone, const N: usize> Clone for SmallVec<T, N> {
+    #[track_caller]
+    fn clone(&self) -> Self {
+        SmallVec::from(self.as_slice())
+    }
+
+    #[track_caller]
+    fn clone_from(&mut self, source: &Self) {
+        self.clear();
+        self.extend_from_slice(source);
+    }
+}
+
+#[unstable(feature = "small_vec", issue = "none")]
+impl<T: fmt::Debug, const N: usize> fmt::Debug for SmallVec<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+#[unstable(feature = "small_vec", issue = "none")]
+impl<T, const N: usize> Default for SmallVec<T, N> {
+    /// Creates an empty `SmallVec<T, N>`, using the inline storage.
+    fn default() -> Self {
+        SmallVec::new()
+    }
+}
+
+#[unstable(feature = "small_vec", issue = "none")]
+impl<T: Hash, const N: usize> Hash for SmallVec<T, N> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Hash::hash(&**self, state)
+    }
+}
+
+#[unstable(feature = "small_vec", issue = "none")]
+impl<T, U, const N: usize, const M: usize> PartialEq<SmallVec<U, M>> for SmallVec<T, N>
+where
+    T: PartialEq<U>,
+{
+    #[inline]
+    fn eq(&self, other: &SmallVec<U, M>) -> bool {
+        self[..] == other[..]
+    }
+}
+
+#[unstable(feature = "small_vec", issue = "none")]
+impl<T, U, const N: usize> PartialEq<[U]> for SmallVec<T, N>
+where
+    T: PartialEq<U>,
+{
+    #[inline]
+    fn eq(&self, other: &[U]) -> bool {
+        self[..] == other[..]
+    }
+}
+
+#[unstable(feature = "small_vec", issue = "none")]
+impl<T, U, const N: usize> PartialEq<&[U]> for SmallVec<T, N>
+where
+    T: PartialEq<U>,
+{
+    #[inline]
+    fn eq(&self, other: &&[U]) -> bool {
+        self[..] == other[..]
+    }
+}
+
+#[unstable(feature = "small_vec", issue = "none")]
+impl<T, U, const N: usize, const M: usize> PartialEq<[U; M]> for SmallVec<T, N>
+where
+    T: PartialEq<U>,
+{
+    #[inline]
+    fn eq(&self, other: &[U; M]) -> bool {
+        self[..] == other[..]
+    }
+}
+
+#[unstable(feature = "small_vec", issue = "none")]
+impl<T, U, const N: usize> PartialEq<Vec<U>> for SmallVec<T, N>
+where
+    T: PartialEq<U>,
+{
+    #[inline]
+    fn eq(&self, other: &Vec<U>) -> bool {
+        self[..] == other[..]
+    }
+}
+
+#[unstable(feature = "small_vec", issue = "none")]
+impl<T: Eq, const N: usize> Eq for SmallVec<T, N> {}
+
+#[unstable(feature = "small_vec", issue = "none")]
+impl<T: PartialOrd, const N: usize> PartialOrd for SmallVec<T, N> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        PartialOrd::partial_cmp(&**self, &**other)
+    }
+}
+
+#[unstable(feature = "small_vec", issue = "none")]
+impl<T: Ord, const N: usize> Ord for SmallVec<T, N> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        Ord::cmp(&**self, &**other)
+    }
+}
+
+#[unstable(feature = "small_vec", issue = "none")]
+impl<T, const N: usize> AsRef<[T]> for SmallVec<T, N> {
+    fn as_ref(&self) -> &[T] {
+        self
+    }
+}
+
+#[unstable(feature = "small_vec", issue = "none")]
+impl<T, const N: usize> AsMut<[T]> for SmallVec<T, N> {
+    fn as_mut(&mut self) -> &mut [T] {
+        self
+    }
+}
+
+#[unstable(feature = "small_vec", issue = "none")]
+impl<T, const N: usize> Borrow<[T]> for SmallVec<T, N> {
+    fn borrow(&self) -> &[T] {
+        &self[..]
+    }
+}
+
+#[unstable(feature = "small_vec", issue = "none")]
+impl<T, const N: usize> BorrowMut<[T]> for SmallVec<T, N> {
+    fn borrow_mut(&mut self) -> &mut [T] {
+        &mut self[..]
+    }
+}
+
+#[unstable(feature = "small_vec", issue = "none")]
+impl<T, const N: usize> From<[T; N]> for SmallVec<T, N> {
+    /// Moves the array into the inline storage, without allocating.
+    fn from(array: [T; N]) -> Self {
+        SmallVec { repr: Repr::Inline { len: N, buf: array.map(MaybeUninit::new) } }
+    }
+}
+
+#[unstable(feature = "small_vec", issue = "none")]
+impl<T, const N: usize> From<Vec<T>> for SmallVec<T, N> {
+    /// Takes over the buffer of the `Vec`, without reallocating, even if the
+    /// elements would fit inline. Call [`shrink_to_fit`] to move them.
+    ///
+    /// [`shrink_to_fit`]: SmallVec::shrink_to_fit
+    fn from(vec: Vec<T>) -> Self {
+        SmallVec { repr: Repr::Heap(vec) }
+    }
+}
+
+#[unstable(feature = "small_vec", issue = "none")]
+impl<T: Clone, const N: usize> From<&[T]> for SmallVec<T, N> {
+    #[track_caller]
+    fn from(slice: &[T]) -> Self {
+        let mut vec = SmallVec::with_capacity(slice.len());
+        vec.extend_from_slice(slice);
+        vec
+    }
+}
+
+#[unstable(feature = "small_vec", issue = "none")]
+impl<T, const N: usize> From<SmallVec<T, N>> for Vec<T> {
+    /// Converts the vector with [`SmallVec::into_vec`].
+    fn from(vec: SmallVec<T, N>) -> Self {
+        vec.into_vec()
+    }
+}
+
+#[unstable(feature = "small_vec", issue = "none")]
+impl<T, const N: usize> FromIterator<T> for SmallVec<T, N> {
+    #[inline]
+    #[track_caller]
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut vec = SmallVec::new();
+        vec.spec_extend(iter.into_iter());
+        vec
+    }
+}
+
+#[unstable(feature = "small_vec", issue = "none")]
+impl<T, const N: usize> Extend<T> for SmallVec<T, N> {
+    #[inline]
+    #[track_caller]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.spec_extend(iter.into_iter())
+    }
+}
+
+#[unstable(feature = "small_vec", issue = "none")]
+impl<'a, T: Copy + 'a, const N: usize> Extend<&'a T> for SmallVec<T, N> {
+    #[track_caller]
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.spec_extend(iter.into_iter().copied())
+    }
+}
+
+#[unstable(feature = "small_vec", issue = "none")]
+impl<T, const N: usize> IntoIterator for SmallVec<T, N> {
+    type Item = T;
+    type IntoIter = IntoIter<T, N>;
+
+    /// Creates a consuming iterator, that is, one that moves each value out
+    /// of the vector (from start to end).
+    fn into_iter(self) -> IntoIter<T, N> {
+        let mut this = ManuallyDrop::new(self);
+        match &mut this.repr {
+            Repr::Heap(vec) => IntoIter::from_vec(mem::take(vec)),
+            Repr::Inline { len, buf } => unsafe { IntoIter::from_inline(ptr::read(buf), *len) },
+        }
+    }
+}
+
+#[unstable(feature = "small_vec", issue = "none")]
+impl<'a, T, const N: usize> IntoIterator for &'a SmallVec<T, N> {
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+
+    fn into_iter(self) -> slice::Iter<'a, T> {
+        self.iter()
+    }
+}
+
+#[unstable(feature = "small_vec", issue = "none")]
+impl<'a, T, const N: usize> IntoIterator for &'a mut SmallVec<T, N> {
+    type Item = &'a mut T;
+    type IntoIter = slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> slice::IterMut<'a, T> {
+        self.iter_mut()
+    }
+}
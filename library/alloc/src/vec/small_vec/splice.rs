@@ -0,0 +1,126 @@
+use core::ptr::{self};
+use core::slice::{self};
+
+use super::Drain;
+use crate::vec::Vec;
+
+/// A splicing iterator for `SmallVec`.
+///
+/// This struct is created by [`SmallVec::splice()`].
+/// See its documentation for more.
+#[derive(Debug)]
+#[unstable(feature = "small_vec", issue = "none")]
+pub struct Splice<'a, I: Iterator + 'a, const N: usize> {
+    pub(super) drain: Drain<'a, I::Item, N>,
+    pub(super) replace_with: I,
+}
+
+#[unstable(feature = "small_vec", issue = "none")]
+impl<I: Iterator, const N: usize> Iterator for Splice<'_, I, N> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.drain.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.drain.size_hint()
+    }
+}
+
+#[unstable(feature = "small_vec", issue = "none")]
+impl<I: Iterator, const N: usize> DoubleEndedIterator for Splice<'_, I, N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.drain.next_back()
+    }
+}
+
+#[unstable(feature = "small_vec", issue = "none")]
+impl<I: Iterator, const N: usize> ExactSizeIterator for Splice<'_, I, N> {}
+
+#[unstable(feature = "small_vec", issue = "none")]
+impl<I: Iterator, const N: usize> Drop for Splice<'_, I, N> {
+    #[track_caller]
+    fn drop(&mut self) {
+        self.drain.by_ref().for_each(drop);
+        // Draining is done, and growing the vector below may move the inline
+        // storage to the heap, so don't leave `Drain::drop` a pointer into it.
+        self.drain.iter = (&[]).iter();
+
+        unsafe {
+            if self.drain.tail_len == 0 {
+                self.drain.vec.as_mut().extend(self.replace_with.by_ref());
+                return;
+            }
+
+            // First fill the range left by drain().
+            if !self.drain.fill(&mut self.replace_with) {
+                return;
+            }
+
+            // There may be more elements. Use the lower bound as an estimate.
+            let (lower_bound, _upper_bound) = self.replace_with.size_hint();
+            if lower_bound > 0 {
+                self.drain.move_tail(lower_bound);
+                if !self.drain.fill(&mut self.replace_with) {
+                    return;
+                }
+            }
+
+            // Collect any remaining elements.
+            // This is a zero-length vector which does not allocate if `lower_bound` was exact.
+            let mut collected = self.replace_with.by_ref().collect::<Vec<I::Item>>().into_iter();
+            // Now we have an exact count.
+            if collected.len() > 0 {
+                self.drain.move_tail(collected.len());
+                let filled = self.drain.fill(&mut collected);
+                debug_assert!(filled);
+                debug_assert_eq!(collected.len(), 0);
+            }
+        }
+        // Let `Drain::drop` move the tail back if necessary and restore the length.
+    }
+}
+
+/// Private helper methods for `Splice::drop`
+impl<T, const N: usize> Drain<'_, T, N> {
+    /// The range from the vector's length to `self.tail_start` contains
+    /// elements that have been moved out. Fill that range as much as possible
+    /// with new elements from the `replace_with` iterator.
+    /// Returns `true` if we filled the entire range.
+    unsafe fn fill<I: Iterator<Item = T>>(&mut self, replace_with: &mut I) -> bool {
+        let vec = unsafe { self.vec.as_mut() };
+        let (ptr, len, _) = vec.triple_mut();
+        let range_start = *len;
+        let range_end = self.tail_start;
+        let range_slice =
+            unsafe { slice::from_raw_parts_mut(ptr.add(range_start), range_end - range_start) };
+
+        for place in range_slice {
+            if let Some(new_item) = replace_with.next() {
+                unsafe { ptr::write(place, new_item) };
+                *len += 1;
+            } else {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Makes room for inserting more elements before the tail, spilling
+    /// the vector to the heap if needed.
+    #[track_caller]
+    unsafe fn move_tail(&mut self, additional: usize) {
+        let vec = unsafe { self.vec.as_mut() };
+        let used = self.tail_start + self.tail_len;
+        unsafe { vec.reserve_past(used, additional, false) };
+
+        let new_tail_start = self.tail_start + additional;
+        unsafe {
+            let src = vec.as_ptr().add(self.tail_start);
+            let dst = vec.as_mut_ptr().add(new_tail_start);
+            ptr::copy(src, dst, self.tail_len);
+        }
+        self.tail_start = new_tail_start;
+    }
+}
@@ -0,0 +1,48 @@
+use std::num::BigUint;
+
+use rand::RngCore;
+use test::{Bencher, black_box};
+
+fn random(limbs: usize) -> BigUint {
+    let mut bytes = vec![0; limbs * 8];
+    crate::bench_rng().fill_bytes(&mut bytes);
+    BigUint::from_le_bytes(&bytes)
+}
+
+macro_rules! mul_div_benches {
+    ($($mul:ident, $div:ident: $limbs:expr),*) => {$(
+        #[bench]
+        fn $mul(b: &mut Bencher) {
+            let (x, y) = (random($limbs), random($limbs) + BigUint::from(1u8));
+            b.iter(|| black_box(&x) * black_box(&y));
+        }
+
+        #[bench]
+        fn $div(b: &mut Bencher) {
+            let x = random(2 * $limbs);
+            let y = random($limbs) + BigUint::from(1u8);
+            b.iter(|| black_box(&x).div_rem(black_box(&y)));
+        }
+    )*};
+}
+
+mul_div_benches! {
+    mul_4, div_4: 4,
+    mul_32, div_32: 32,
+    mul_256, div_256: 256,
+    mul_1024, div_1024: 1024
+}
+
+#[bench]
+fn pow_mod_2048_bits(b: &mut Bencher) {
+    let (base, exponent) = (random(32), random(32));
+    let mut modulus = random(32);
+    modulus.set_bit(0, true);
+    b.iter(|| black_box(&base).pow_mod(black_box(&exponent), black_box(&modulus)));
+}
+
+#[bench]
+fn to_string_1024(b: &mut Bencher) {
+    let x = random(1024);
+    b.iter(|| black_box(&x).to_string());
+}
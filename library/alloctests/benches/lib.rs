@@ -1,5 +1,6 @@
 // Disabling in Miri as these would take too long.
 #![cfg(not(miri))]
+#![feature(bigint)]
 #![feature(btree_extract_if)]
 #![feature(iter_next_chunk)]
 #![feature(repr_simd)]
@@ -10,6 +11,7 @@
 
 extern crate test;
 
+mod bigint;
 mod binary_heap;
 mod btree;
 mod linked_list;
@@ -0,0 +1,278 @@
+use std::num::{BigInt, BigUint, IntErrorKind, ParseBigIntError};
+
+/// A small xorshift generator, so that the tests are deterministic.
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn u128(&mut self) -> u128 {
+        // Vary the magnitude so that small and single-limb values are covered too.
+        let n = (self.next() as u128) << 64 | self.next() as u128;
+        n >> (self.next() % 128)
+    }
+
+    fn biguint(&mut self, limbs: usize) -> BigUint {
+        let mut bytes = Vec::with_capacity(limbs * 8);
+        for _ in 0..limbs {
+            bytes.extend_from_slice(&self.next().to_le_bytes());
+        }
+        BigUint::from_le_bytes(&bytes)
+    }
+}
+
+#[test]
+fn biguint_matches_u128() {
+    let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+    for _ in 0..2000 {
+        let (a, b) = (rng.u128(), rng.u128());
+        let (x, y) = (BigUint::from(a), BigUint::from(b));
+        assert_eq!(
+            BigUint::from(a >> 1) + BigUint::from(b >> 1),
+            BigUint::from((a >> 1) + (b >> 1))
+        );
+        if a >= b {
+            assert_eq!(&x - &y, BigUint::from(a - b));
+        } else {
+            assert_eq!(x.checked_sub(&y), None);
+        }
+        assert_eq!(
+            BigUint::from(a as u64) * BigUint::from(b as u64),
+            BigUint::from(a as u64 as u128 * b as u64 as u128)
+        );
+        if b != 0 {
+            assert_eq!(&x / &y, BigUint::from(a / b));
+            assert_eq!(&x % &y, BigUint::from(a % b));
+        }
+        assert_eq!(&x & &y, BigUint::from(a & b));
+        assert_eq!(&x | &y, BigUint::from(a | b));
+        assert_eq!(&x ^ &y, BigUint::from(a ^ b));
+        let s = (b % 128) as usize;
+        assert_eq!(&x >> s, BigUint::from(a >> s));
+        assert_eq!(&(&x << s) >> s, x);
+        assert_eq!(x.cmp(&y), a.cmp(&b));
+        assert_eq!(x.bits(), (128 - a.leading_zeros()) as u64);
+        assert_eq!(x.count_ones(), a.count_ones() as u64);
+        assert_eq!(u128::try_from(&x), Ok(a));
+        assert_eq!(u64::try_from(&x).ok(), u64::try_from(a).ok());
+        assert_eq!(x.to_string(), a.to_string());
+        assert_eq!(format!("{x:x}"), format!("{a:x}"));
+        assert_eq!(format!("{x:#X}"), format!("{a:#X}"));
+        assert_eq!(format!("{x:o}"), format!("{a:o}"));
+        assert_eq!(format!("{x:b}"), format!("{a:b}"));
+        assert_eq!(format!("{x:>50}"), format!("{a:>50}"));
+        assert_eq!(x.to_f64(), a as f64);
+        assert_eq!(x.to_f32(), a as f32);
+        for radix in [2, 3, 7, 10, 16, 32, 36] {
+            assert_eq!(BigUint::from_str_radix(&x.to_str_radix(radix), radix), Ok(x.clone()));
+            assert_eq!(u128::from_str_radix(&x.to_str_radix(radix), radix), Ok(a));
+        }
+    }
+}
+
+#[test]
+fn bigint_matches_i128() {
+    let mut rng = Rng(0x9e37_79b9_7f4a_7c15);
+    for _ in 0..2000 {
+        let a = (rng.u128() as i128) >> 2;
+        let b = (rng.u128() as i128) >> 2;
+        let (a, b) =
+            (if rng.next() & 1 == 0 { a } else { -a }, if rng.next() & 1 == 0 { b } else { -b });
+        let (x, y) = (BigInt::from(a), BigInt::from(b));
+        assert_eq!(&x + &y, BigInt::from(a + b));
+        assert_eq!(&x - &y, BigInt::from(a - b));
+        assert_eq!(
+            BigInt::from(a as i64) * BigInt::from(b as i64),
+            BigInt::from(a as i64 as i128 * b as i64 as i128)
+        );
+        if b != 0 {
+            assert_eq!(&x / &y, BigInt::from(a / b));
+            assert_eq!(&x % &y, BigInt::from(a % b));
+            assert_eq!(x.div_euclid(&y), BigInt::from(a.div_euclid(b)));
+            assert_eq!(x.rem_euclid(&y), BigInt::from(a.rem_euclid(b)));
+        }
+        assert_eq!(&x & &y, BigInt::from(a & b));
+        assert_eq!(&x | &y, BigInt::from(a | b));
+        assert_eq!(&x ^ &y, BigInt::from(a ^ b));
+        assert_eq!(!&x, BigInt::from(!a));
+        assert_eq!(-&x, BigInt::from(-a));
+        let s = (b.unsigned_abs() % 128) as usize;
+        assert_eq!(&x >> s, BigInt::from(a >> s));
+        assert_eq!(&x << 2, BigInt::from(a << 2));
+        assert_eq!(x.cmp(&y), a.cmp(&b));
+        assert_eq!(i128::try_from(&x), Ok(a));
+        assert_eq!(i64::try_from(&x).ok(), i64::try_from(a).ok());
+        assert_eq!(u128::try_from(&x).ok(), u128::try_from(a).ok());
+        assert_eq!(x.to_string(), a.to_string());
+        assert_eq!(format!("{x:+}"), format!("{a:+}"));
+        assert_eq!(format!("{x:010}"), format!("{a:010}"));
+        assert_eq!(x.to_f64(), a as f64);
+        assert_eq!(BigInt::from_str_radix(&x.to_str_radix(7), 7), Ok(x.clone()));
+        assert_eq!(x.to_str_radix(16), if a < 0 { format!("-{:x}", -a) } else { format!("{a:x}") });
+    }
+}
+
+#[test]
+fn extremes() {
+    for n in [i128::MIN, i128::MIN + 1, -1, 0, 1, i128::MAX] {
+        assert_eq!(i128::try_from(BigInt::from(n)), Ok(n));
+    }
+    assert!(i128::try_from(BigInt::from(i128::MIN) - BigInt::from(1)).is_err());
+    assert!(u128::try_from(BigUint::from(u128::MAX) + BigUint::from(1u8)).is_err());
+    assert!(BigUint::try_from(BigInt::from(-1)).is_err());
+    assert!(u8::try_from(BigInt::from(-1)).is_err());
+    assert_eq!(i8::try_from(BigInt::from(-128)), Ok(-128));
+    assert!(i8::try_from(BigInt::from(128)).is_err());
+    assert_eq!(BigInt::from(-5) + BigInt::from(5), BigInt::ZERO);
+    assert!(!(BigInt::from(-5) + BigInt::from(5)).is_negative());
+    assert_eq!(-BigInt::ZERO, BigInt::ZERO);
+    assert_eq!(BigInt::from(-1) >> 1000, BigInt::from(-1));
+    assert_eq!(BigInt::from(-1) << 100 >> 100, BigInt::from(-1));
+}
+
+#[test]
+fn parse_errors() {
+    let kind = |e: ParseBigIntError| *e.kind();
+    assert_eq!(BigUint::from_str_radix("", 10).map_err(kind), Err(IntErrorKind::Empty));
+    assert_eq!(BigUint::from_str_radix("+", 10).map_err(kind), Err(IntErrorKind::InvalidDigit));
+    assert_eq!(BigInt::from_str_radix("-", 10).map_err(kind), Err(IntErrorKind::InvalidDigit));
+    assert_eq!(BigUint::from_str_radix("-1", 10).map_err(kind), Err(IntErrorKind::InvalidDigit));
+    assert_eq!(BigUint::from_str_radix("12a", 10).map_err(kind), Err(IntErrorKind::InvalidDigit));
+    assert_eq!("-0".parse::<BigInt>(), Ok(BigInt::ZERO));
+    assert_eq!("000123".parse::<BigUint>(), Ok(BigUint::from(123u8)));
+    assert_eq!(BigUint::from_str_radix("Ff", 16), Ok(BigUint::from(255u8)));
+}
+
+#[test]
+fn large_mul_div() {
+    let mut rng = Rng(0xdead_beef_cafe_f00d);
+    for (la, lb) in [(1, 1), (3, 2), (40, 33), (64, 64), (100, 35), (200, 150), (257, 31), (300, 1)]
+    {
+        let a = rng.biguint(la);
+        let b = rng.biguint(lb);
+        let p = &a * &b;
+        // Distributivity across the Karatsuba split, and consistency with division.
+        let c = rng.biguint(lb);
+        assert_eq!(&a * &(&b + &c), &p + &(&a * &c));
+        assert_eq!(&p / &b, a);
+        assert_eq!(&p % &b, BigUint::ZERO);
+        let r = rng.biguint(lb) % &b;
+        let (q, rem) = (&p + &r).div_rem(&b);
+        assert_eq!((q, rem), (a.clone(), r));
+        let (q, rem) = a.div_rem(&b);
+        assert!(rem < b);
+        assert_eq!(&q * &b + rem, a);
+        // Squaring agrees with the generic path.
+        assert_eq!(&a * &a, a.pow(2));
+        for radix in [10, 16, 36] {
+            assert_eq!(BigUint::from_str_radix(&p.to_str_radix(radix), radix), Ok(p.clone()));
+        }
+        assert_eq!(BigUint::from_be_bytes(&p.to_be_bytes()), p);
+    }
+}
+
+/// Knuth's algorithm D has rare branches for estimates that are off by two, exercised by
+/// divisors with many high one bits.
+#[test]
+fn div_edge_cases() {
+    let max = |n: usize| BigUint::from_le_bytes(&vec![0xff; n * 8]);
+    for n in 1..6 {
+        for m in 1..6 {
+            let a = max(n + m);
+            let b = max(m);
+            let (q, r) = a.div_rem(&b);
+            assert!(r < b);
+            assert_eq!(&q * &b + &r, a);
+            let b2 = &b << 63;
+            let (q, r) = a.div_rem(&b2);
+            assert!(r < b2);
+            assert_eq!(&q * &b2 + &r, a);
+            let b3 = (BigUint::from(1u8) << (64 * m)) + BigUint::from(1u8);
+            let (q, r) = a.div_rem(&b3);
+            assert_eq!(&q * &b3 + &r, a);
+        }
+    }
+}
+
+fn pow_mod_slow(base: &BigUint, exp: &BigUint, m: &BigUint) -> BigUint {
+    let mut result = BigUint::from(1u8) % m;
+    let mut base = base % m;
+    for i in 0..exp.bits() {
+        if exp.bit(i) {
+            result = &result * &base % m;
+        }
+        base = &base * &base % m;
+    }
+    result
+}
+
+#[test]
+fn pow_mod() {
+    let mut rng = Rng(0x1234_5678_9abc_def1);
+    for limbs in [1, 2, 5, 17] {
+        for _ in 0..5 {
+            let base = rng.biguint(limbs + 1);
+            let exp = rng.biguint(2);
+            let mut odd = rng.biguint(limbs);
+            odd.set_bit(0, true);
+            let even = &odd + &BigUint::from(1u8);
+            assert_eq!(base.pow_mod(&exp, &odd), pow_mod_slow(&base, &exp, &odd));
+            assert_eq!(base.pow_mod(&exp, &even), pow_mod_slow(&base, &exp, &even));
+            let neg = -BigInt::from(base.clone());
+            let r = neg.pow_mod(&exp, &odd);
+            let expected = BigInt::from(pow_mod_slow(&base, &exp, &odd));
+            let expected = if exp.bit(0) {
+                (-expected).rem_euclid(&BigInt::from(odd.clone()))
+            } else {
+                expected
+            };
+            assert_eq!(BigInt::from(r), expected);
+        }
+    }
+    assert_eq!(BigUint::from(5u8).pow_mod(&BigUint::ZERO, &BigUint::from(1u8)), BigUint::ZERO);
+    assert_eq!(BigUint::from(5u8).pow_mod(&BigUint::ZERO, &BigUint::from(7u8)), BigUint::from(1u8));
+    assert_eq!(
+        BigUint::from(4u8).pow_mod(&BigUint::from(13u8), &BigUint::from(497u16)),
+        BigUint::from(445u16)
+    );
+}
+
+#[test]
+fn floats() {
+    assert_eq!(BigUint::try_from(1e30f64).unwrap().to_string(), "1000000000000000019884624838656");
+    assert_eq!(BigInt::try_from(-2.9f64), Ok(BigInt::from(-2)));
+    assert_eq!(BigInt::try_from(-0.5f32), Ok(BigInt::ZERO));
+    assert!(BigUint::try_from(-1.0f64).is_err());
+    assert!(BigInt::try_from(f64::NAN).is_err());
+    assert!(BigInt::try_from(f32::INFINITY).is_err());
+    let big = BigUint::try_from(f64::MAX).unwrap();
+    assert_eq!(big.to_f64(), f64::MAX);
+    assert_eq!((&big << 1).to_f64(), f64::INFINITY);
+    assert_eq!(big.to_f32(), f32::INFINITY);
+    // Ties round to even, and a sticky bit far below the rounding point breaks a tie.
+    let tie = (BigUint::from(1u8) << 53) + BigUint::from(1u8);
+    assert_eq!(tie.to_f64(), 9007199254740992.0);
+    let above = ((BigUint::from(1u8) << 53) + BigUint::from(1u8)) << 100;
+    let above = above + BigUint::from(1u8);
+    assert_eq!(above.to_f64(), (9007199254740994.0f64) * 2f64.powi(100));
+    assert_eq!(BigInt::try_from(f64::MIN).unwrap().to_f64(), f64::MIN);
+}
+
+#[test]
+fn sum_product() {
+    let v: Vec<BigInt> = (1..=30).map(BigInt::from).collect();
+    let fact: BigInt = v.iter().product();
+    assert_eq!(fact.to_string(), "265252859812191058636308480000000");
+    let s: BigInt = v.iter().map(|x| -x).sum();
+    assert_eq!(s, BigInt::from(-465));
+    assert_eq!(BigInt::from(-3).pow(3), BigInt::from(-27));
+    assert_eq!(
+        BigUint::from(3u8).pow(100).to_string(),
+        "515377520732011331036461129765621272702107522001"
+    );
+}
@@ -578,3 +578,105 @@ fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
         }
     }
 }
+
+#[test]
+fn test_indexed_push_pop() {
+    let mut max = IndexedBinaryHeap::new();
+    let mut min = IndexedBinaryHeap::with_capacity_min(10);
+    for x in [5, 3, 9, 1, 7, 3] {
+        max.push(x);
+        min.push(x);
+    }
+    assert!(!max.is_min_heap());
+    assert!(min.is_min_heap());
+    assert_eq!(max.peek(), Some(&9));
+    assert_eq!(min.peek(), Some(&1));
+    assert_eq!(std::iter::from_fn(|| max.pop()).collect::<Vec<_>>(), [9, 7, 5, 3, 3, 1]);
+    assert_eq!(std::iter::from_fn(|| min.pop()).collect::<Vec<_>>(), [1, 3, 3, 5, 7, 9]);
+    assert_eq!(max.pop(), None);
+}
+
+#[test]
+fn test_indexed_handles() {
+    let mut heap = IndexedBinaryHeap::new_min();
+    let a = heap.push(10);
+    let b = heap.push(20);
+    let c = heap.push(30);
+    assert_eq!(heap.peek_with_handle(), Some((a, &10)));
+
+    // Decrease and increase keys.
+    assert_eq!(heap.change_priority(c, 5), 30);
+    assert_eq!(heap.peek_with_handle(), Some((c, &5)));
+    assert_eq!(heap.change_priority(c, 25), 5);
+    assert_eq!(heap.peek_with_handle(), Some((a, &10)));
+
+    assert_eq!(heap.remove(b), Some(20));
+    assert_eq!(heap.remove(b), None);
+    assert!(!heap.contains(b));
+    assert_eq!(heap.get(b), None);
+
+    // The slot of `b` is reused, but `b` does not refer to the new element.
+    let d = heap.push(1);
+    assert_ne!(b, d);
+    assert_eq!(heap.get(b), None);
+    assert_eq!(heap.get(d), Some(&1));
+
+    assert_eq!(heap.pop_with_handle(), Some((d, 1)));
+    let mut rest: Vec<_> = heap.iter().map(|(h, &x)| (h, x)).collect();
+    rest.sort_by_key(|&(_, x)| x);
+    assert_eq!(rest, [(a, 10), (c, 25)]);
+
+    heap.clear();
+    assert!(heap.is_empty());
+    assert!(!heap.contains(a));
+}
+
+#[test]
+#[should_panic = "handle is not in the heap"]
+fn test_indexed_change_priority_stale() {
+    let mut heap = IndexedBinaryHeap::new();
+    let a = heap.push(1);
+    heap.pop();
+    heap.change_priority(a, 2);
+}
+
+#[test]
+fn test_indexed_random_against_model() {
+    use rand::Rng;
+
+    let mut rng = crate::test_rng();
+    let mut heap = IndexedBinaryHeap::new();
+    let mut model: Vec<(Handle, u32)> = Vec::new();
+    for _ in 0..if cfg!(miri) { 200 } else { 5000 } {
+        match rng.random_range(0..4) {
+            0 | 1 => {
+                let x = rng.random_range(0..100);
+                model.push((heap.push(x), x));
+            }
+            2 if !model.is_empty() => {
+                let i = rng.random_range(0..model.len());
+                let x = rng.random_range(0..100);
+                assert_eq!(heap.change_priority(model[i].0, x), model[i].1);
+                model[i].1 = x;
+            }
+            _ if !model.is_empty() => {
+                let i = rng.random_range(0..model.len());
+                if rng.random() {
+                    let (h, x) = model.swap_remove(i);
+                    assert_eq!(heap.remove(h), Some(x));
+                } else {
+                    let max = model.iter().map(|&(_, x)| x).max().unwrap();
+                    let (h, x) = heap.pop_with_handle().unwrap();
+                    assert_eq!(x, max);
+                    let i = model.iter().position(|&(mh, _)| mh == h).unwrap();
+                    assert_eq!(model.swap_remove(i).1, x);
+                }
+            }
+            _ => {}
+        }
+        assert_eq!(heap.len(), model.len());
+    }
+    for &(h, x) in &model {
+        assert_eq!(heap.get(h), Some(&x));
+    }
+}
@@ -1,4 +1,4 @@
-use std::alloc::{Allocator, Global, Layout, System};
+use std::alloc::{Allocator, Bump, Global, Layout, System};
 
 /// Issue #45955 and #62251.
 #[test]
@@ -42,3 +42,127 @@ fn check_overalign_requests<T: Allocator>(allocator: T) {
         }
     }
 }
+
+#[test]
+fn bump_overaligned_request() {
+    check_overalign_requests(&Bump::new())
+}
+
+#[test]
+fn bump_collections() {
+    let bump = Bump::new();
+    let mut v = Vec::new_in(&bump);
+    v.extend(0..1000u32);
+    let b = Box::new_in(v.iter().sum::<u32>(), &bump);
+    assert_eq!(*b, 499500);
+    let mut s = String::new();
+    s.push_str("abc");
+    let chars: Vec<char, _> = s.chars().collect::<Vec<_>>().to_vec_in(&bump);
+    assert_eq!(chars, ['a', 'b', 'c']);
+    // The first chunk is a page, and later ones double in size.
+    assert!(bump.allocated_bytes() >= 4000);
+}
+
+#[test]
+fn bump_last_allocation_in_place() {
+    let bump = Bump::with_capacity(1024);
+    let bytes = bump.allocated_bytes();
+    let layout = Layout::new::<[u64; 4]>();
+    let bigger = Layout::new::<[u64; 8]>();
+    unsafe {
+        let a = bump.allocate(layout).unwrap().as_non_null_ptr();
+        // Freeing and reallocating the most recent allocation reuses it.
+        bump.deallocate(a, layout);
+        let b = bump.allocate(layout).unwrap().as_non_null_ptr();
+        assert_eq!(a, b);
+        // So does growing and shrinking it.
+        let c = bump.grow(b, layout, bigger).unwrap().as_non_null_ptr();
+        assert_eq!(b, c);
+        let d = bump.shrink(c, bigger, layout).unwrap().as_non_null_ptr();
+        assert_eq!(c, d);
+        // Other allocations are moved.
+        let e = bump.allocate(layout).unwrap().as_non_null_ptr();
+        let f = bump.grow(d, layout, bigger).unwrap().as_non_null_ptr();
+        assert_ne!(d, f);
+        assert!(f > e);
+    }
+    assert_eq!(bump.allocated_bytes(), bytes);
+}
+
+#[test]
+fn bump_large_allocation() {
+    let bump = Bump::new();
+    let v: Vec<u8, _> = Vec::with_capacity_in(1 << 22, &bump);
+    assert!(v.capacity() >= 1 << 22);
+    assert!(bump.allocated_bytes() > 1 << 22);
+    let z: Vec<(), _> = Vec::with_capacity_in(10, &bump);
+    assert_eq!(z.capacity(), usize::MAX);
+}
+
+#[test]
+fn bump_reset() {
+    let mut bump = Bump::new();
+    {
+        let boxes: Vec<_> = (0..10_000u64).map(|i| Box::new_in(i, &bump)).collect();
+        assert_eq!(*boxes[9999], 9999);
+    }
+    let before = bump.allocated_bytes();
+    bump.reset();
+    let after = bump.allocated_bytes();
+    assert!(after < before);
+    // The kept chunk is reused.
+    let v: Vec<u8, _> = Vec::with_capacity_in(after / 2, &bump);
+    drop(v);
+    assert_eq!(bump.allocated_bytes(), after);
+}
+
+#[test]
+fn bump_scope() {
+    let mut bump = Bump::new();
+    let mut addrs = Vec::new();
+    for _ in 0..2 {
+        let scope = bump.scope();
+        let a = Box::new_in(1u64, &scope);
+        let b = Box::new_in(2u64, &scope);
+        addrs.push(&*a as *const u64);
+        // `a` is not the most recent allocation, so only the scope frees it.
+        drop(a);
+        drop(b);
+    }
+    assert_eq!(addrs[0], addrs[1]);
+
+    let mut scope = bump.scope();
+    {
+        let inner = scope.scope();
+        let v: Vec<u64, _> = Vec::with_capacity_in(1 << 20, &inner);
+        assert!(inner.allocated_bytes() >= 8 << 20);
+        drop(v);
+    }
+    // The large chunk is kept, and reused by the following scopes.
+    let kept = scope.allocated_bytes();
+    let mut addrs = Vec::new();
+    for _ in 0..10 {
+        let inner = scope.scope();
+        let v: Vec<u64, _> = Vec::with_capacity_in(1 << 20, &inner);
+        addrs.push(v.as_ptr());
+        drop(v);
+        drop(inner);
+        assert_eq!(scope.allocated_bytes(), kept);
+    }
+    assert!(addrs.iter().all(|&ptr| ptr == addrs[0]));
+}
+
+#[test]
+fn bump_scope_drops_values() {
+    use std::rc::Rc;
+
+    let rc = Rc::new(());
+    let mut bump = Bump::new();
+    {
+        let scope = bump.scope();
+        let v: Vec<Rc<()>, _> = vec![rc.clone(); 100].to_vec_in(&scope);
+        assert_eq!(Rc::strong_count(&rc), 101);
+        drop(v);
+    }
+    assert_eq!(Rc::strong_count(&rc), 1);
+}
@@ -37,8 +37,15 @@
 #![feature(str_as_str)]
 #![feature(strict_provenance_lints)]
 #![feature(vec_deque_pop_if)]
+#![feature(indexed_binary_heap)]
+#![feature(small_vec)]
+#![feature(fallible_collections)]
+#![feature(bump_allocator)]
+#![feature(slice_radix_sort)]
+#![feature(str_multi_pattern)]
 #![feature(unique_rc_arc)]
 #![feature(macro_metavar_expr_concat)]
+#![feature(bigint)]
 #![allow(internal_features)]
 #![deny(fuzzy_provenance_casts)]
 #![deny(unsafe_op_in_unsafe_fn)]
@@ -51,6 +58,7 @@
 mod alloc_test;
 mod arc;
 mod autotraits;
+mod bigint;
 mod borrow;
 mod boxed;
 mod btree_set_hash;
@@ -65,6 +73,7 @@
 mod misc_tests;
 mod rc;
 mod slice;
+mod small_vec;
 mod sort;
 mod str;
 mod string;
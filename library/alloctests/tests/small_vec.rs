@@ -0,0 +1,299 @@
+use std::cell::Cell;
+use std::panic::{AssertUnwindSafe, catch_unwind};
+use std::rc::Rc;
+use std::vec::SmallVec;
+
+#[test]
+fn test_push_pop_spill() {
+    let mut v: SmallVec<i32, 3> = SmallVec::new();
+    assert_eq!(v.capacity(), 3);
+    for i in 0..3 {
+        v.push(i);
+    }
+    assert!(!v.spilled());
+    v.push(3);
+    assert!(v.spilled());
+    assert!(v.capacity() >= 6);
+    assert_eq!(v, [0, 1, 2, 3]);
+    assert_eq!(v.pop(), Some(3));
+    assert!(v.spilled());
+    v.shrink_to_fit();
+    assert!(!v.spilled());
+    assert_eq!(v, [0, 1, 2]);
+    assert_eq!(v.pop(), Some(2));
+    assert_eq!(v.pop(), Some(1));
+    assert_eq!(v.pop(), Some(0));
+    assert_eq!(v.pop(), None);
+}
+
+#[test]
+fn test_with_capacity() {
+    let v: SmallVec<u8, 4> = SmallVec::with_capacity(4);
+    assert!(!v.spilled());
+    let v: SmallVec<u8, 4> = SmallVec::with_capacity(5);
+    assert!(v.spilled());
+    assert!(v.capacity() >= 5);
+}
+
+#[test]
+fn test_zero_inline_capacity() {
+    let mut v: SmallVec<String, 0> = SmallVec::new();
+    assert_eq!(v.capacity(), 0);
+    v.push("a".to_string());
+    assert!(v.spilled());
+    assert_eq!(v, ["a"]);
+    v.clear();
+    v.shrink_to_fit();
+    assert!(!v.spilled());
+}
+
+#[test]
+fn test_zst() {
+    let mut v: SmallVec<(), 2> = SmallVec::new();
+    assert_eq!(v.capacity(), usize::MAX);
+    v.extend(std::iter::repeat_n((), 10));
+    assert!(!v.spilled());
+    assert_eq!(v.len(), 10);
+    v.drain(2..5);
+    assert_eq!(v.len(), 7);
+    assert_eq!(v.into_iter().count(), 7);
+}
+
+#[test]
+fn test_insert_remove() {
+    let mut v: SmallVec<i32, 4> = SmallVec::from([1, 2, 4, 5]);
+    v.insert(2, 3);
+    assert!(v.spilled());
+    assert_eq!(v, [1, 2, 3, 4, 5]);
+    v.insert(5, 6);
+    assert_eq!(v.remove(0), 1);
+    assert_eq!(v.swap_remove(0), 2);
+    assert_eq!(v, [6, 3, 4, 5]);
+}
+
+#[test]
+#[should_panic(expected = "insertion index (is 2) should be <= len (is 1)")]
+fn test_insert_out_of_bounds() {
+    let mut v: SmallVec<i32, 4> = SmallVec::new();
+    v.push(1);
+    v.insert(2, 0);
+}
+
+#[test]
+fn test_extend() {
+    let mut v: SmallVec<i32, 4> = SmallVec::new();
+    // Not `TrustedLen`, so the lower bound of the size hint is all we know.
+    v.extend((0..3).filter(|_| true));
+    assert!(!v.spilled());
+    v.extend((3..10).filter(|_| true));
+    assert!(v.spilled());
+    assert_eq!(v, (0..10).collect::<Vec<_>>());
+
+    let mut v: SmallVec<i32, 4> = SmallVec::new();
+    v.extend(&[1, 2]);
+    v.extend_from_slice(&[3, 4]);
+    assert!(!v.spilled());
+    v.extend(5..=8);
+    assert_eq!(v, [1, 2, 3, 4, 5, 6, 7, 8]);
+}
+
+#[test]
+fn test_retain() {
+    let mut v: SmallVec<i32, 8> = (0..8).collect();
+    v.retain(|&x| x % 3 != 0);
+    assert_eq!(v, [1, 2, 4, 5, 7]);
+    v.retain_mut(|x| {
+        *x *= 10;
+        *x > 20
+    });
+    assert_eq!(v, [40, 50, 70]);
+}
+
+#[test]
+fn test_retain_drop_panic() {
+    let drops = Rc::new(Cell::new(0));
+    struct D(Rc<Cell<u32>>, bool);
+    impl Drop for D {
+        fn drop(&mut self) {
+            self.0.set(self.0.get() + 1);
+            if self.1 {
+                panic!("panic in `drop`");
+            }
+        }
+    }
+
+    let mut v: SmallVec<D, 4> = (0..4).map(|i| D(drops.clone(), i == 1)).collect();
+    let mut index = 0;
+    catch_unwind(AssertUnwindSafe(|| {
+        v.retain(|_| {
+            index += 1;
+            index % 2 == 1
+        })
+    }))
+    .unwrap_err();
+    // The panicking element is dropped, the rest are kept.
+    assert_eq!(drops.get(), 1);
+    assert_eq!(v.len(), 3);
+    drop(v);
+    assert_eq!(drops.get(), 4);
+}
+
+#[test]
+fn test_dedup() {
+    let mut v: SmallVec<i32, 4> = SmallVec::from([1, 1, 2, 2]);
+    v.extend([2, 3, 1, 1]);
+    v.dedup();
+    assert_eq!(v, [1, 2, 3, 1]);
+    v.dedup_by_key(|x| *x / 2);
+    assert_eq!(v, [1, 2, 1]);
+}
+
+#[test]
+fn test_resize() {
+    let mut v: SmallVec<i32, 4> = SmallVec::new();
+    v.resize(3, 7);
+    assert_eq!(v, [7, 7, 7]);
+    let mut n = 0;
+    v.resize_with(6, || {
+        n += 1;
+        n
+    });
+    assert_eq!(v, [7, 7, 7, 1, 2, 3]);
+    v.resize(1, 0);
+    assert_eq!(v, [7]);
+}
+
+#[test]
+fn test_drain() {
+    let mut v: SmallVec<i32, 8> = (0..6).collect();
+    let drained: Vec<_> = v.drain(1..4).rev().collect();
+    assert_eq!(drained, [3, 2, 1]);
+    assert_eq!(v, [0, 4, 5]);
+
+    let mut v: SmallVec<i32, 2> = (0..6).collect();
+    let mut drain = v.drain(2..);
+    assert_eq!(drain.as_slice(), [2, 3, 4, 5]);
+    assert_eq!(drain.next(), Some(2));
+    drop(drain);
+    assert_eq!(v, [0, 1]);
+}
+
+#[test]
+fn test_drain_leak() {
+    let mut v: SmallVec<String, 4> = ["a", "b", "c"].map(String::from).into_iter().collect();
+    std::mem::forget(v.drain(1..2));
+    // Like `Vec`, a leaked `Drain` truncates the vector.
+    assert_eq!(v, ["a"]);
+}
+
+#[test]
+fn test_append() {
+    let mut a: SmallVec<i32, 2> = SmallVec::from([1, 2]);
+    let mut b: SmallVec<i32, 4> = SmallVec::from([3, 4, 5, 6]);
+    a.append(&mut b);
+    assert_eq!(a, [1, 2, 3, 4, 5, 6]);
+    assert!(b.is_empty());
+}
+
+#[test]
+fn test_splice() {
+    // Shrinking, stays inline.
+    let mut v: SmallVec<i32, 4> = SmallVec::from([1, 2, 3, 4]);
+    let removed: Vec<_> = v.splice(1..3, [9]).collect();
+    assert_eq!(removed, [2, 3]);
+    assert_eq!(v, [1, 9, 4]);
+    assert!(!v.spilled());
+
+    // Growing past the inline capacity moves the tail to the heap.
+    let mut v: SmallVec<i32, 4> = SmallVec::from([1, 2, 3, 4]);
+    v.splice(1..2, [7, 8, 9]);
+    assert!(v.spilled());
+    assert_eq!(v, [1, 7, 8, 9, 3, 4]);
+
+    // An iterator with an inexact size hint.
+    let mut v: SmallVec<i32, 4> = SmallVec::from([1, 2, 3, 4]);
+    v.splice(..1, (10..20).filter(|x| x % 2 == 0));
+    assert_eq!(v, [10, 12, 14, 16, 18, 2, 3, 4]);
+
+    // Replacing the tail.
+    let mut v: SmallVec<i32, 4> = SmallVec::from([1, 2, 3, 4]);
+    v.splice(2.., 5..9);
+    assert_eq!(v, [1, 2, 5, 6, 7, 8]);
+}
+
+#[test]
+fn test_into_iter() {
+    let v: SmallVec<String, 4> = ["a", "b", "c"].map(String::from).into_iter().collect();
+    let mut iter = v.into_iter();
+    assert_eq!(iter.len(), 3);
+    assert_eq!(iter.next().as_deref(), Some("a"));
+    let clone = iter.clone();
+    assert_eq!(iter.next_back().as_deref(), Some("c"));
+    assert_eq!(iter.as_slice(), ["b"]);
+    assert_eq!(clone.collect::<Vec<_>>(), ["b", "c"]);
+
+    let v: SmallVec<i32, 2> = (0..5).collect();
+    assert!(v.spilled());
+    assert_eq!(v.into_iter().rev().collect::<Vec<_>>(), [4, 3, 2, 1, 0]);
+}
+
+#[test]
+fn test_drop() {
+    let rc = Rc::new(());
+    let v: SmallVec<Rc<()>, 4> = std::iter::repeat_n(rc.clone(), 3).collect();
+    let w = v.clone();
+    assert_eq!(Rc::strong_count(&rc), 7);
+    drop(v);
+    let mut iter = w.into_iter();
+    iter.next();
+    drop(iter);
+    assert_eq!(Rc::strong_count(&rc), 1);
+}
+
+#[test]
+fn test_conversions() {
+    let v: SmallVec<i32, 3> = SmallVec::from(vec![1, 2]);
+    assert!(v.spilled());
+    assert_eq!(v.into_vec(), [1, 2]);
+
+    let v: SmallVec<i32, 3> = SmallVec::from(&[1, 2, 3][..]);
+    assert!(!v.spilled());
+    assert_eq!(Vec::from(v.clone()), [1, 2, 3]);
+    assert_eq!(v.into_inner(), Ok([1, 2, 3]));
+
+    let v: SmallVec<i32, 3> = SmallVec::from(vec![1, 2, 3]);
+    assert_eq!(v.into_inner(), Ok([1, 2, 3]));
+    let v: SmallVec<i32, 3> = SmallVec::from(&[1][..]);
+    assert_eq!(v.into_inner().unwrap_err(), [1]);
+}
+
+#[test]
+fn test_try_reserve() {
+    let mut v: SmallVec<u8, 4> = SmallVec::new();
+    assert!(v.try_reserve(4).is_ok());
+    assert!(!v.spilled());
+    assert!(v.try_reserve_exact(5).is_ok());
+    assert_eq!(v.capacity(), 5);
+    assert!(v.try_reserve(usize::MAX).is_err());
+}
+
+#[test]
+fn test_traits() {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    fn hash<T: Hash>(t: &T) -> u64 {
+        let mut s = DefaultHasher::new();
+        t.hash(&mut s);
+        s.finish()
+    }
+
+    let a: SmallVec<i32, 2> = SmallVec::from([1, 2]);
+    let b: SmallVec<i32, 8> = SmallVec::from_iter([1, 2]);
+    assert_eq!(a, b);
+    assert_eq!(a, vec![1, 2]);
+    assert_eq!(hash(&a), hash(&vec![1, 2]));
+    assert!(a < SmallVec::from([1, 3]));
+    assert_eq!(format!("{a:?}"), "[1, 2]");
+    assert_eq!(SmallVec::<i32, 2>::default(), []);
+}
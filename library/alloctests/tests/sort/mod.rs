@@ -13,5 +13,6 @@ fn sort_by<T, F>(v: &mut [T], compare: F)
 mod ffi_types;
 mod known_good_stable_sort;
 mod patterns;
+mod radix;
 mod tests;
 mod zipf;
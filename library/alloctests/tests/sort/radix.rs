@@ -0,0 +1,81 @@
+use std::fmt::Debug;
+
+use crate::sort::patterns;
+
+const TEST_LENGTHS: &[usize] = &[0, 1, 2, 3, 10, 100, 255, 256, 257, 1_000, 10_000, 100_000];
+
+fn check_sort_by_radix_key<T, K>(v: &[T], f: impl Fn(&T) -> K + Copy)
+where
+    T: Clone + Debug + PartialEq,
+    K: std::slice::RadixKey,
+{
+    let mut expected = v.to_vec();
+    expected.sort_by_key(f);
+
+    let mut calls = 0;
+    let mut sorted = v.to_vec();
+    sorted.sort_by_radix_key(|x| {
+        calls += 1;
+        f(x)
+    });
+    assert_eq!(calls, if v.len() < 2 { 0 } else { v.len() });
+    assert!(sorted == expected, "sort_by_radix_key disagrees with sort_by_key: {v:?}");
+}
+
+#[test]
+fn radix_patterns() {
+    for &len in TEST_LENGTHS {
+        for v in [
+            patterns::random(len),
+            patterns::random_uniform(len, 0..16),
+            patterns::all_equal(len),
+            patterns::ascending(len),
+            patterns::descending(len),
+        ] {
+            check_sort_by_radix_key(&v, |&x| x);
+            check_sort_by_radix_key(&v, |&x| x as u32);
+            check_sort_by_radix_key(&v, |&x| x as i8);
+            check_sort_by_radix_key(&v, |&x| (x as i128) << 64);
+            check_sort_by_radix_key(&v, |&x| x.to_be_bytes());
+        }
+    }
+}
+
+#[test]
+fn radix_stable() {
+    for &len in TEST_LENGTHS {
+        let v: Vec<(u8, usize)> = patterns::random_uniform(len, 0..8)
+            .into_iter()
+            .enumerate()
+            .map(|(i, x)| (x as u8, i))
+            .collect();
+
+        let mut sorted = v.clone();
+        sorted.sort_by_radix_key(|&(k, _)| k);
+        for w in sorted.windows(2) {
+            assert!(w[0].0 < w[1].0 || (w[0].0 == w[1].0 && w[0].1 < w[1].1));
+        }
+    }
+}
+
+#[test]
+fn radix_key_types() {
+    let v = patterns::random(10_000);
+
+    check_sort_by_radix_key(&v, |&x| x % 2 == 0);
+    check_sort_by_radix_key(&v, |&x| char::from_u32(x as u32 % 0x11_0000).unwrap_or('\u{fffd}'));
+    check_sort_by_radix_key(&v, |&x| (x as u16, x >> 16));
+    check_sort_by_radix_key(&v, |&x| (x % 3 == 0, x as u8, x as isize));
+    check_sort_by_radix_key(&v, |&x| [x as u8, (x >> 8) as u8, (x >> 16) as u8]);
+    check_sort_by_radix_key(&v, |&x| [(x as u16, x as i8); 2]);
+    check_sort_by_radix_key(&v, |_| [0u8; 0]);
+}
+
+#[test]
+fn radix_int_edge() {
+    let v = [i64::MIN, -1, 0, 1, i64::MAX, i64::MIN + 1, i64::MAX - 1].repeat(100);
+    check_sort_by_radix_key(&v, |&x| x);
+    check_sort_by_radix_key(&v, |&x| x as u64);
+    check_sort_by_radix_key(&v, |&x| x as i128);
+    check_sort_by_radix_key(&v, |&x| x as usize);
+}
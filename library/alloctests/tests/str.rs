@@ -987,6 +987,51 @@ macro_rules! test {
     test!(b"A\xC3\xA9 \xF1\x80\x80 ", 4, Some(3));
 }
 
+#[test]
+fn from_utf8_error_in_long_input() {
+    // Long stretches of non-ASCII text are validated in chunks, make sure that
+    // errors are still reported exactly wherever they occur.
+    let text = "Съешь же ещё этих мягких французских булок, 我能吞下玻璃而不伤身体 🦀. ".repeat(4);
+    for (bad, error_len) in
+        [(&b"\xFF"[..], Some(1)), (b"\xE0\x9F", Some(1)), (b"\xF1\x80 ", Some(2))]
+    {
+        for i in (0..text.len()).filter(|&i| text.is_char_boundary(i)) {
+            let mut data = text.as_bytes()[..i].to_vec();
+            data.extend_from_slice(bad);
+            data.extend_from_slice(text.as_bytes());
+            let error = from_utf8(&data).unwrap_err();
+            assert_eq!(error.valid_up_to(), i);
+            assert_eq!(error.error_len(), error_len);
+        }
+
+        // Truncated sequences at the end of the input.
+        let mut data = text.as_bytes().to_vec();
+        data.extend_from_slice(&bad[..1]);
+        let error = from_utf8(&data).unwrap_err();
+        assert_eq!(error.valid_up_to(), text.len());
+        assert_eq!(error.error_len(), if bad[0] == 0xFF { Some(1) } else { None });
+    }
+
+    const _: () = {
+        let text = "Съешь же ещё этих мягких французских булок 🦀".as_bytes();
+        let mut data = [0; 200];
+        let mut i = 0;
+        while i < text.len() {
+            data[i] = text[i];
+            data[i + text.len()] = text[i];
+            i += 1;
+        }
+        data[text.len() + 2] = 0x80;
+        match from_utf8(&data) {
+            Err(error) => {
+                assert!(error.valid_up_to() == text.len() + 2);
+                assert!(matches!(error.error_len(), Some(1)));
+            }
+            Ok(_) => unreachable!(),
+        }
+    };
+}
+
 #[test]
 fn test_as_bytes() {
     // no null
@@ -2459,3 +2504,137 @@ fn check_many(s: &str, arg: impl IntoIterator<Item = usize>, ret: usize) {
     // above len
     check_many("hello", 5..=10, 5);
 }
+
+mod multi_pattern {
+    use std::str::MultiPattern;
+    use std::str::pattern::SearchStep::{self, Done, Match, Reject};
+    use std::str::pattern::{Pattern, Searcher};
+
+    fn search_steps(pattern: &MultiPattern, haystack: &str) -> Vec<SearchStep> {
+        let mut searcher = pattern.into_searcher(haystack);
+        let mut steps = vec![];
+        loop {
+            match searcher.next() {
+                Done => break,
+                step => steps.push(step),
+            }
+        }
+        steps
+    }
+
+    /// Finds the non-overlapping leftmost-longest matches by trying every needle at every
+    /// char boundary.
+    fn naive_matches<'a>(needles: &[&str], haystack: &'a str) -> Vec<(usize, &'a str)> {
+        let mut matches = vec![];
+        let mut from = 0;
+        while from <= haystack.len() {
+            let found = haystack[from..]
+                .char_indices()
+                .map(|(i, _)| from + i)
+                .chain([haystack.len()])
+                .find_map(|start| {
+                    let rest = &haystack[start..];
+                    let len =
+                        needles.iter().filter(|n| rest.starts_with(**n)).map(|n| n.len()).max()?;
+                    Some((start, len))
+                });
+            let Some((start, len)) = found else { break };
+            matches.push((start, &haystack[start..start + len]));
+            from = if len > 0 {
+                start + len
+            } else {
+                match haystack[start..].chars().next() {
+                    Some(c) => start + c.len_utf8(),
+                    None => break,
+                }
+            };
+        }
+        matches
+    }
+
+    #[test]
+    fn search_steps_cover_haystack() {
+        let pattern = MultiPattern::new(["bb", "c"]);
+        assert_eq!(
+            search_steps(&pattern, "abbcbbd"),
+            [Reject(0, 1), Match(1, 3), Match(3, 4), Match(4, 6), Reject(6, 7)]
+        );
+        assert_eq!(search_steps(&pattern, ""), []);
+        assert_eq!(search_steps(&pattern, "xyz"), [Reject(0, 3)]);
+
+        let pattern = MultiPattern::new(["", "b"]);
+        assert_eq!(
+            search_steps(&pattern, "abé"),
+            [Match(0, 0), Reject(0, 1), Match(1, 2), Match(2, 2), Reject(2, 4), Match(4, 4)]
+        );
+
+        let pattern = MultiPattern::new::<[&str; 0]>([]);
+        assert_eq!(search_steps(&pattern, "abc"), [Reject(0, 3)]);
+    }
+
+    #[test]
+    fn leftmost_longest() {
+        // The leftmost match wins, even if a longer one starts later.
+        let pattern = MultiPattern::new(["abcd", "bc"]);
+        assert_eq!("xabcx".find(&pattern), Some(2));
+        assert_eq!("xabcdx".match_indices(&pattern).collect::<Vec<_>>(), [(1, "abcd")]);
+
+        // Among matches at the same position, the longest one wins.
+        for needles in [["he", "hello", "hell"], ["hello", "hell", "he"]] {
+            let pattern = MultiPattern::new(needles);
+            assert_eq!(
+                "hello hell help".matches(&pattern).collect::<Vec<_>>(),
+                ["hello", "hell", "he"]
+            );
+        }
+
+        // Overlapping needles are matched without overlap.
+        let pattern = MultiPattern::new(["aa", "aaa"]);
+        assert_eq!("aaaaaaa".matches(&pattern).collect::<Vec<_>>(), ["aaa", "aaa"]);
+    }
+
+    #[test]
+    fn str_methods() {
+        let pattern = MultiPattern::new(["ERROR", "WARN", "é"]);
+        let log = "INFO ok\nWARN disk\nERROR crashé\n";
+
+        assert!(log.contains(&pattern));
+        assert!(!"INFO".contains(&pattern));
+        assert!("WARNING".starts_with(&pattern));
+        assert_eq!(log.find(&pattern), Some(8));
+        assert_eq!(
+            log.split(&pattern).collect::<Vec<_>>(),
+            ["INFO ok\n", " disk\n", " crash", "\n"]
+        );
+        assert_eq!(
+            log.splitn(2, &pattern).collect::<Vec<_>>(),
+            ["INFO ok\n", " disk\nERROR crashé\n"]
+        );
+        assert_eq!(log.replace(&pattern, "_"), "INFO ok\n_ disk\n_ crash_\n");
+        assert_eq!(log.replacen(&pattern, "_", 1), "INFO ok\n_ disk\nERROR crashé\n");
+        assert_eq!(log.matches(&pattern).count(), 3);
+        assert_eq!("WARNWARN!".trim_start_matches(&pattern), "!");
+        assert_eq!("ERROR: x".strip_prefix(&pattern), Some(": x"));
+
+        let mut s = String::from("ééa");
+        s.remove_matches(&pattern);
+        assert_eq!(s, "a");
+    }
+
+    #[test]
+    fn matches_naive_search() {
+        let needles = ["", "a", "ab", "abc", "bca", "cab", "ç", "çç", "bç", "b"];
+        let haystacks = ["", "abcabcab", "ccccc", "abçbççab", "cabcabca", "xyzç", "bcaabcçab"];
+        for i in 0..needles.len() {
+            for j in i..needles.len() {
+                let needles = &needles[i..=j];
+                let pattern = MultiPattern::new(needles);
+                for haystack in haystacks {
+                    let expected = naive_matches(needles, haystack);
+                    let found: Vec<_> = haystack.match_indices(&pattern).collect();
+                    assert_eq!(found, expected, "{needles:?} in {haystack:?}");
+                }
+            }
+        }
+    }
+}
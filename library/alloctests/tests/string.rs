@@ -836,6 +836,16 @@ fn test_try_reserve() {
     }
 }
 
+#[test]
+fn test_try_push() {
+    let mut s = String::new();
+    s.try_push('a').unwrap();
+    s.try_push('é').unwrap();
+    s.try_push_str("bc").unwrap();
+    s.try_push('😀').unwrap();
+    assert_eq!(s, "aébc😀");
+}
+
 #[test]
 #[cfg_attr(miri, ignore)] // Miri does not support signalling OOM
 fn test_try_reserve_exact() {
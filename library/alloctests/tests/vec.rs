@@ -1826,6 +1826,45 @@ fn test_try_reserve() {
     }
 }
 
+#[test]
+fn test_try_push_insert_extend() {
+    let mut v = Vec::new();
+    v.try_push(1).unwrap();
+    v.try_extend_from_slice(&[3, 4]).unwrap();
+    v.try_insert(1, 2).unwrap();
+    v.try_insert(4, 5).unwrap();
+    assert_eq!(v, [1, 2, 3, 4, 5]);
+
+    // An allocator that always fails.
+    struct NoAlloc;
+    unsafe impl Allocator for NoAlloc {
+        fn allocate(&self, _layout: Layout) -> Result<NonNull<[u8]>, std::alloc::AllocError> {
+            Err(std::alloc::AllocError)
+        }
+        unsafe fn deallocate(&self, _ptr: NonNull<u8>, _layout: Layout) {
+            unreachable!()
+        }
+    }
+
+    let mut v: Vec<u8, _> = Vec::new_in(NoAlloc);
+    assert_matches!(v.try_push(1).map_err(|e| e.kind()), Err(AllocError { .. }));
+    assert_matches!(v.try_insert(0, 1).map_err(|e| e.kind()), Err(AllocError { .. }));
+    assert_matches!(v.try_extend_from_slice(&[1]).map_err(|e| e.kind()), Err(AllocError { .. }));
+    assert_eq!(v.try_extend_from_slice(&[]).map_err(|e| e.kind()), Ok(()));
+    assert!(v.is_empty());
+
+    let mut v = vec![(); usize::MAX];
+    assert_eq!(v.try_push(()).map_err(|e| e.kind()), Err(CapacityOverflow));
+    assert_eq!(v.len(), usize::MAX);
+}
+
+#[test]
+#[should_panic(expected = "insertion index (is 2) should be <= len (is 1)")]
+fn test_try_insert_out_of_bounds() {
+    let mut v = vec![1];
+    let _ = v.try_insert(2, 2);
+}
+
 #[test]
 #[cfg_attr(miri, ignore)] // Miri does not support signalling OOM
 fn test_try_reserve_exact() {
@@ -1330,6 +1330,21 @@ fn test_try_reserve() {
     }
 }
 
+#[test]
+fn test_try_push() {
+    let mut v: VecDeque<u32> = VecDeque::new();
+    for i in 0..10 {
+        v.try_push_back(i).unwrap();
+        v.try_push_front(i).unwrap();
+    }
+    assert_eq!(v, [9, 8, 7, 6, 5, 4, 3, 2, 1, 0, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+
+    let mut v: VecDeque<u8, _> = VecDeque::new_in(NoAlloc);
+    assert_matches!(v.try_push_back(1).map_err(|e| e.kind()), Err(AllocError { .. }));
+    assert_matches!(v.try_push_front(1).map_err(|e| e.kind()), Err(AllocError { .. }));
+    assert!(v.is_empty());
+}
+
 #[test]
 #[cfg_attr(miri, ignore)] // Miri does not support signalling OOM
 fn test_try_reserve_exact() {
@@ -1421,6 +1436,20 @@ fn test_try_reserve_exact() {
 
 #[test]
 fn test_rotate_nop() {
+    // An allocator that always fails.
+    struct NoAlloc;
+    unsafe impl std::alloc::Allocator for NoAlloc {
+        fn allocate(
+            &self,
+            _layout: std::alloc::Layout,
+        ) -> Result<std::ptr::NonNull<[u8]>, std::alloc::AllocError> {
+            Err(std::alloc::AllocError)
+        }
+        unsafe fn deallocate(&self, _ptr: std::ptr::NonNull<u8>, _layout: std::alloc::Layout) {
+            unreachable!()
+        }
+    }
+
     let mut v: VecDeque<_> = (0..10).collect();
     assert_unchanged(&v);
 
@@ -1427,6 +1427,92 @@ pub trait UpperExp {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result;
 }
 
+/// A custom format spec, as written in a format string.
+///
+/// For every placeholder with a format spec that is not one of the built-in ones, like `{:iso8601}`
+/// or `{:>12.3mm}`, `format_args!` generates a fresh type that implements this trait, with the
+/// spec (`iso8601` and `mm`) as [`SPEC`]. The type of the formatted argument gets to parse it with
+/// [`ParseCustomSpec`], and is formatted with [`CustomFormat`].
+///
+/// This trait is implemented by the compiler, there is no need to implement it by hand.
+///
+/// [`SPEC`]: CustomSpec::SPEC
+#[unstable(feature = "custom_format_spec", issue = "none")]
+pub trait CustomSpec {
+    /// The format spec, without the fill, alignment, sign, width and precision.
+    const SPEC: &'static str;
+}
+
+/// `?`-like formatting driven by a format spec that the type parses itself.
+///
+/// A type that implements `CustomFormat` and [`ParseCustomSpec`] can be formatted with any format
+/// spec that its parser accepts. The spec is parsed at compile time: a parser that panics on an
+/// invalid spec turns it into a compilation error, reported when the formatting code is
+/// monomorphized.
+///
+/// The usual options in front of the spec, like width and alignment, are available from the
+/// [`Formatter`] as for the other formatting traits.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(custom_format_spec)]
+///
+/// use std::fmt::{self, CustomFormat, CustomSpec, ParseCustomSpec};
+///
+/// struct Length(f64);
+///
+/// enum Unit {
+///     Meters,
+///     Millimeters,
+/// }
+///
+/// impl CustomFormat for Length {
+///     type Spec = Unit;
+///
+///     fn fmt_custom(&self, unit: &Unit, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+///         match unit {
+///             Unit::Meters => write!(f, "{}m", self.0),
+///             Unit::Millimeters => write!(f, "{}mm", self.0 * 1000.0),
+///         }
+///     }
+/// }
+///
+/// impl<S: CustomSpec> ParseCustomSpec<S> for Length {
+///     const PARSED: Unit = match S::SPEC.as_bytes() {
+///         b"m" => Unit::Meters,
+///         b"mm" => Unit::Millimeters,
+///         _ => panic!("unknown unit"),
+///     };
+/// }
+///
+/// let l = Length(1.5);
+/// assert_eq!(format!("{l:m} = {l:mm}"), "1.5m = 1500mm");
+/// ```
+#[unstable(feature = "custom_format_spec", issue = "none")]
+pub trait CustomFormat {
+    /// The parsed format spec.
+    type Spec;
+
+    /// Formats the value using the given formatter and parsed format spec.
+    fn fmt_custom(&self, spec: &Self::Spec, f: &mut Formatter<'_>) -> Result;
+}
+
+/// Compile-time parsing of the custom format spec `S`.
+///
+/// See [`CustomFormat`] for details. Implementations are expected to be generic over `S`, and to
+/// panic if they don't accept the spec.
+#[unstable(feature = "custom_format_spec", issue = "none")]
+#[rustc_on_unimplemented(
+    message = "`{Self}` doesn't support custom format specs",
+    label = "`{Self}` cannot be formatted with a custom format spec",
+    note = "implement `CustomFormat` and `ParseCustomSpec` to parse custom format specs"
+)]
+pub trait ParseCustomSpec<S: CustomSpec>: CustomFormat {
+    /// The format spec `S::SPEC`, parsed.
+    const PARSED: Self::Spec;
+}
+
 /// Takes an output stream and an `Arguments` struct that can be precompiled with
 /// the `format_args!` macro.
 ///
@@ -1810,7 +1896,10 @@ pub(crate) fn padding(
     /// # Safety
     ///
     /// Any `numfmt::Part::Copy` parts in `formatted` must contain valid UTF-8.
-    unsafe fn pad_formatted_parts(&mut self, formatted: &numfmt::Formatted<'_>) -> Result {
+    pub(crate) unsafe fn pad_formatted_parts(
+        &mut self,
+        formatted: &numfmt::Formatted<'_>,
+    ) -> Result {
         if self.options.width == 0 {
             // this is the common case and we take a shortcut
             // SAFETY: Per the precondition.
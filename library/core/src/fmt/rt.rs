@@ -147,6 +147,10 @@ pub fn new_upper_exp<T: UpperExp>(x: &T) -> Argument<'_> {
         Self::new(x, UpperExp::fmt)
     }
     #[inline]
+    pub fn new_custom<T: ParseCustomSpec<S>, S: CustomSpec>(x: &T, _spec: S) -> Argument<'_> {
+        Self::new(x, |x, f| x.fmt_custom(&<T as ParseCustomSpec<S>>::PARSED, f))
+    }
+    #[inline]
     #[track_caller]
     pub const fn from_usize(x: &usize) -> Argument<'_> {
         if *x > u16::MAX as usize {
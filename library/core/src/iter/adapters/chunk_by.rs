@@ -0,0 +1,99 @@
+use crate::iter::{Fuse, FusedIterator};
+use crate::marker::PhantomData;
+use crate::{cmp, fmt};
+
+/// An iterator that collects runs of elements into collections, using a
+/// predicate to separate them.
+///
+/// This `struct` is created by [`Iterator::chunk_runs_by`]. See its documentation
+/// for more information.
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+#[unstable(feature = "iter_chunk_by", reason = "recently added", issue = "none")]
+pub struct ChunkRunsBy<I: Iterator, B, P> {
+    iter: Fuse<I>,
+    // The element that ended the previous chunk, if it has been read already.
+    next: Option<I::Item>,
+    predicate: P,
+    _marker: PhantomData<fn() -> B>,
+}
+
+impl<I: Iterator, B, P> ChunkRunsBy<I, B, P> {
+    pub(in crate::iter) fn new(iter: I, predicate: P) -> Self {
+        Self { iter: iter.fuse(), next: None, predicate, _marker: PhantomData }
+    }
+}
+
+#[unstable(feature = "iter_chunk_by", reason = "recently added", issue = "none")]
+impl<I, B, P> Iterator for ChunkRunsBy<I, B, P>
+where
+    I: Iterator,
+    B: Default + Extend<I::Item>,
+    P: FnMut(&I::Item, &I::Item) -> bool,
+{
+    type Item = B;
+
+    fn next(&mut self) -> Option<B> {
+        let mut prev = match self.next.take() {
+            Some(prev) => prev,
+            None => self.iter.next()?,
+        };
+        let mut chunk = B::default();
+        for x in self.iter.by_ref() {
+            // Compare before `prev` is moved into the chunk.
+            let same_chunk = (self.predicate)(&prev, &x);
+            chunk.extend_one(prev);
+            if !same_chunk {
+                self.next = Some(x);
+                return Some(chunk);
+            }
+            prev = x;
+        }
+        chunk.extend_one(prev);
+        Some(chunk)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let pending = self.next.is_some() as usize;
+        let (lower, upper) = self.iter.size_hint();
+        // Every remaining element may belong to the same chunk.
+        let lower = cmp::min(lower.saturating_add(pending), 1);
+        (lower, upper.and_then(|upper| upper.checked_add(pending)))
+    }
+}
+
+#[unstable(feature = "iter_chunk_by", reason = "recently added", issue = "none")]
+impl<I, B, P> FusedIterator for ChunkRunsBy<I, B, P>
+where
+    I: Iterator,
+    B: Default + Extend<I::Item>,
+    P: FnMut(&I::Item, &I::Item) -> bool,
+{
+}
+
+#[unstable(feature = "iter_chunk_by", reason = "recently added", issue = "none")]
+impl<I, B, P> Clone for ChunkRunsBy<I, B, P>
+where
+    I: Iterator + Clone,
+    I::Item: Clone,
+    P: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+            next: self.next.clone(),
+            predicate: self.predicate.clone(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+#[unstable(feature = "iter_chunk_by", reason = "recently added", issue = "none")]
+impl<I, B, P> fmt::Debug for ChunkRunsBy<I, B, P>
+where
+    I: Iterator + fmt::Debug,
+    I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ChunkRunsBy").field("iter", &self.iter).field("next", &self.next).finish()
+    }
+}
@@ -0,0 +1,254 @@
+use crate::iter::{Fuse, FusedIterator};
+use crate::{cmp, fmt};
+
+/// The state shared by the dedup adapters: the underlying iterator and the
+/// element that ended the previous run, if it has been read already.
+#[derive(Clone, Debug)]
+struct Runs<I, T> {
+    iter: Fuse<I>,
+    next: Option<T>,
+}
+
+impl<I: Iterator<Item = T>, T> Runs<I, T> {
+    fn new(iter: I) -> Self {
+        Self { iter: iter.fuse(), next: None }
+    }
+
+    /// Reads the next run of elements for which `same(first, element)` holds
+    /// and returns its first element along with the length of the run.
+    fn next_run(&mut self, mut same: impl FnMut(&T, &T) -> bool) -> Option<(usize, T)> {
+        let first = match self.next.take() {
+            Some(first) => first,
+            None => self.iter.next()?,
+        };
+        let mut count = 1;
+        for x in self.iter.by_ref() {
+            if !same(&first, &x) {
+                self.next = Some(x);
+                break;
+            }
+            count += 1;
+        }
+        Some((count, first))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let pending = self.next.is_some() as usize;
+        let (lower, upper) = self.iter.size_hint();
+        // Every remaining element may belong to the same run.
+        let lower = cmp::min(lower.saturating_add(pending), 1);
+        (lower, upper.and_then(|upper| upper.checked_add(pending)))
+    }
+}
+
+/// An iterator that removes consecutive repeated elements.
+///
+/// This `struct` is created by [`Iterator::dedup_adjacent`]. See its documentation
+/// for more information.
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+#[unstable(feature = "iter_dedup", reason = "recently added", issue = "none")]
+#[derive(Clone, Debug)]
+pub struct DedupAdjacent<I: Iterator> {
+    runs: Runs<I, I::Item>,
+}
+
+impl<I: Iterator> DedupAdjacent<I> {
+    pub(in crate::iter) fn new(iter: I) -> Self {
+        Self { runs: Runs::new(iter) }
+    }
+}
+
+#[unstable(feature = "iter_dedup", reason = "recently added", issue = "none")]
+impl<I> Iterator for DedupAdjacent<I>
+where
+    I: Iterator,
+    I::Item: PartialEq,
+{
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<I::Item> {
+        self.runs.next_run(|a, b| a == b).map(|(_, x)| x)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.runs.size_hint()
+    }
+}
+
+#[unstable(feature = "iter_dedup", reason = "recently added", issue = "none")]
+impl<I> FusedIterator for DedupAdjacent<I>
+where
+    I: Iterator,
+    I::Item: PartialEq,
+{
+}
+
+/// An iterator that removes consecutive elements satisfying an equivalence
+/// relation.
+///
+/// This `struct` is created by [`Iterator::dedup_adjacent_by`]. See its documentation
+/// for more information.
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+#[unstable(feature = "iter_dedup", reason = "recently added", issue = "none")]
+#[derive(Clone)]
+pub struct DedupAdjacentBy<I: Iterator, F> {
+    runs: Runs<I, I::Item>,
+    same_bucket: F,
+}
+
+impl<I: Iterator, F> DedupAdjacentBy<I, F> {
+    pub(in crate::iter) fn new(iter: I, same_bucket: F) -> Self {
+        Self { runs: Runs::new(iter), same_bucket }
+    }
+}
+
+#[unstable(feature = "iter_dedup", reason = "recently added", issue = "none")]
+impl<I, F> Iterator for DedupAdjacentBy<I, F>
+where
+    I: Iterator,
+    F: FnMut(&I::Item, &I::Item) -> bool,
+{
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<I::Item> {
+        self.runs.next_run(&mut self.same_bucket).map(|(_, x)| x)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.runs.size_hint()
+    }
+}
+
+#[unstable(feature = "iter_dedup", reason = "recently added", issue = "none")]
+impl<I, F> FusedIterator for DedupAdjacentBy<I, F>
+where
+    I: Iterator,
+    F: FnMut(&I::Item, &I::Item) -> bool,
+{
+}
+
+#[unstable(feature = "iter_dedup", reason = "recently added", issue = "none")]
+impl<I, F> fmt::Debug for DedupAdjacentBy<I, F>
+where
+    I: Iterator + fmt::Debug,
+    I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DedupAdjacentBy").field("runs", &self.runs).finish()
+    }
+}
+
+/// An iterator that removes consecutive elements that resolve to the same
+/// key.
+///
+/// This `struct` is created by [`Iterator::dedup_adjacent_by_key`]. See its
+/// documentation for more information.
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+#[unstable(feature = "iter_dedup", reason = "recently added", issue = "none")]
+#[derive(Clone)]
+pub struct DedupAdjacentByKey<I: Iterator, F> {
+    runs: Runs<I, I::Item>,
+    key: F,
+}
+
+impl<I: Iterator, F> DedupAdjacentByKey<I, F> {
+    pub(in crate::iter) fn new(iter: I, key: F) -> Self {
+        Self { runs: Runs::new(iter), key }
+    }
+}
+
+#[unstable(feature = "iter_dedup", reason = "recently added", issue = "none")]
+impl<I, F, K> Iterator for DedupAdjacentByKey<I, F>
+where
+    I: Iterator,
+    F: FnMut(&I::Item) -> K,
+    K: PartialEq,
+{
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<I::Item> {
+        let key = &mut self.key;
+        let mut run_key = None;
+        self.runs
+            .next_run(|first, x| {
+                let run_key = run_key.get_or_insert_with(|| key(first));
+                *run_key == key(x)
+            })
+            .map(|(_, x)| x)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.runs.size_hint()
+    }
+}
+
+#[unstable(feature = "iter_dedup", reason = "recently added", issue = "none")]
+impl<I, F, K> FusedIterator for DedupAdjacentByKey<I, F>
+where
+    I: Iterator,
+    F: FnMut(&I::Item) -> K,
+    K: PartialEq,
+{
+}
+
+#[unstable(feature = "iter_dedup", reason = "recently added", issue = "none")]
+impl<I, F> fmt::Debug for DedupAdjacentByKey<I, F>
+where
+    I: Iterator + fmt::Debug,
+    I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DedupAdjacentByKey").field("runs", &self.runs).finish()
+    }
+}
+
+/// An iterator that collapses runs of equal consecutive elements into one
+/// element and the length of the run.
+///
+/// This `struct` is created by [`Iterator::dedup_adjacent_with_count`]. See its
+/// documentation for more information.
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+#[unstable(feature = "iter_dedup", reason = "recently added", issue = "none")]
+#[derive(Clone, Debug)]
+pub struct DedupAdjacentWithCount<I: Iterator> {
+    runs: Runs<I, I::Item>,
+}
+
+impl<I: Iterator> DedupAdjacentWithCount<I> {
+    pub(in crate::iter) fn new(iter: I) -> Self {
+        Self { runs: Runs::new(iter) }
+    }
+}
+
+#[unstable(feature = "iter_dedup", reason = "recently added", issue = "none")]
+impl<I> Iterator for DedupAdjacentWithCount<I>
+where
+    I: Iterator,
+    I::Item: PartialEq,
+{
+    type Item = (usize, I::Item);
+
+    #[inline]
+    fn next(&mut self) -> Option<(usize, I::Item)> {
+        self.runs.next_run(|a, b| a == b)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.runs.size_hint()
+    }
+}
+
+#[unstable(feature = "iter_dedup", reason = "recently added", issue = "none")]
+impl<I> FusedIterator for DedupAdjacentWithCount<I>
+where
+    I: Iterator,
+    I::Item: PartialEq,
+{
+}
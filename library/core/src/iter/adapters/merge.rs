@@ -0,0 +1,418 @@
+use crate::fmt;
+use crate::iter::{FusedIterator, TrustedLen};
+
+/// The state shared by [`MergeSorted`] and [`MergeSortedBy`].
+#[derive(Clone, Debug)]
+struct MergeState<I, J, T> {
+    a: I,
+    b: J,
+    // The front element of each iterator: `None` if it has not been read yet,
+    // `Some(None)` once the iterator is exhausted. Only the side whose element
+    // was taken is read again, so exhausted iterators are never polled.
+    a_head: Option<Option<T>>,
+    b_head: Option<Option<T>>,
+}
+
+impl<I, J, T> MergeState<I, J, T>
+where
+    I: Iterator<Item = T>,
+    J: Iterator<Item = T>,
+{
+    fn new(a: I, b: J) -> Self {
+        Self { a, b, a_head: None, b_head: None }
+    }
+
+    /// Returns the front element of `a` if `first(a, b)` holds for the front
+    /// elements of both iterators, or else the front element of `b`.
+    fn next_by(&mut self, first: impl FnOnce(&T, &T) -> bool) -> Option<T> {
+        let a = self.a_head.get_or_insert_with(|| self.a.next());
+        let b = self.b_head.get_or_insert_with(|| self.b.next());
+        let take_a = match (a, b) {
+            (Some(a), Some(b)) => first(a, b),
+            (Some(_), None) => true,
+            (None, Some(_)) => false,
+            (None, None) => return None,
+        };
+        if take_a { self.a_head.take().flatten() } else { self.b_head.take().flatten() }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        fn side<T>(iter: &impl Iterator, head: &Option<Option<T>>) -> (usize, Option<usize>) {
+            match head {
+                None => iter.size_hint(),
+                Some(None) => (0, Some(0)),
+                Some(Some(_)) => {
+                    let (lower, upper) = iter.size_hint();
+                    (lower.saturating_add(1), upper.and_then(|upper| upper.checked_add(1)))
+                }
+            }
+        }
+
+        let (a_lower, a_upper) = side(&self.a, &self.a_head);
+        let (b_lower, b_upper) = side(&self.b, &self.b_head);
+        let lower = a_lower.saturating_add(b_lower);
+        let upper = match (a_upper, b_upper) {
+            (Some(x), Some(y)) => x.checked_add(y),
+            _ => None,
+        };
+        (lower, upper)
+    }
+}
+
+/// An iterator that merges two sorted iterators into one sorted iterator.
+///
+/// This `struct` is created by [`Iterator::merge_sorted`]. See its documentation
+/// for more information.
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+#[unstable(feature = "iter_merge", reason = "recently added", issue = "none")]
+#[derive(Clone, Debug)]
+pub struct MergeSorted<I: Iterator, J: Iterator> {
+    state: MergeState<I, J, I::Item>,
+}
+
+impl<I, J> MergeSorted<I, J>
+where
+    I: Iterator,
+    J: Iterator<Item = I::Item>,
+{
+    pub(in crate::iter) fn new(a: I, b: J) -> Self {
+        Self { state: MergeState::new(a, b) }
+    }
+}
+
+#[unstable(feature = "iter_merge", reason = "recently added", issue = "none")]
+impl<I, J> Iterator for MergeSorted<I, J>
+where
+    I: Iterator,
+    J: Iterator<Item = I::Item>,
+    I::Item: PartialOrd,
+{
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<I::Item> {
+        self.state.next_by(|a, b| a <= b)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.state.size_hint()
+    }
+}
+
+#[unstable(feature = "iter_merge", reason = "recently added", issue = "none")]
+impl<I, J> FusedIterator for MergeSorted<I, J>
+where
+    I: Iterator,
+    J: Iterator<Item = I::Item>,
+    I::Item: PartialOrd,
+{
+}
+
+#[unstable(feature = "trusted_len", issue = "37572")]
+unsafe impl<I, J> TrustedLen for MergeSorted<I, J>
+where
+    I: TrustedLen,
+    J: TrustedLen<Item = I::Item>,
+    I::Item: PartialOrd,
+{
+}
+
+/// An iterator that merges two iterators sorted by a comparison function
+/// into one sorted iterator.
+///
+/// This `struct` is created by [`Iterator::merge_sorted_by`]. See its documentation
+/// for more information.
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+#[unstable(feature = "iter_merge", reason = "recently added", issue = "none")]
+#[derive(Clone)]
+pub struct MergeSortedBy<I: Iterator, J: Iterator, F> {
+    state: MergeState<I, J, I::Item>,
+    is_first: F,
+}
+
+impl<I, J, F> MergeSortedBy<I, J, F>
+where
+    I: Iterator,
+    J: Iterator<Item = I::Item>,
+{
+    pub(in crate::iter) fn new(a: I, b: J, is_first: F) -> Self {
+        Self { state: MergeState::new(a, b), is_first }
+    }
+}
+
+#[unstable(feature = "iter_merge", reason = "recently added", issue = "none")]
+impl<I, J, F> Iterator for MergeSortedBy<I, J, F>
+where
+    I: Iterator,
+    J: Iterator<Item = I::Item>,
+    F: FnMut(&I::Item, &I::Item) -> bool,
+{
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<I::Item> {
+        self.state.next_by(&mut self.is_first)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.state.size_hint()
+    }
+}
+
+#[unstable(feature = "iter_merge", reason = "recently added", issue = "none")]
+impl<I, J, F> FusedIterator for MergeSortedBy<I, J, F>
+where
+    I: Iterator,
+    J: Iterator<Item = I::Item>,
+    F: FnMut(&I::Item, &I::Item) -> bool,
+{
+}
+
+#[unstable(feature = "trusted_len", issue = "37572")]
+unsafe impl<I, J, F> TrustedLen for MergeSortedBy<I, J, F>
+where
+    I: TrustedLen,
+    J: TrustedLen<Item = I::Item>,
+    F: FnMut(&I::Item, &I::Item) -> bool,
+{
+}
+
+#[unstable(feature = "iter_merge", reason = "recently added", issue = "none")]
+impl<I, J, F> fmt::Debug for MergeSortedBy<I, J, F>
+where
+    I: Iterator + fmt::Debug,
+    J: Iterator + fmt::Debug,
+    I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MergeSortedBy").field("state", &self.state).finish()
+    }
+}
+
+/// Converts the arguments to iterators and merges them into one sorted
+/// iterator, assuming each of them is sorted.
+///
+/// Elements that compare equal are yielded in the order of the iterators in
+/// the array, so the merge is stable. Finding each element takes `N - 1`
+/// comparisons, which makes this suited to merging a handful of iterators.
+///
+/// See the documentation of [`Iterator::merge_sorted`] for merging two iterators of
+/// different types.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(iter_merge)]
+///
+/// use std::iter::merge_all;
+///
+/// let merged: Vec<_> = merge_all([vec![1, 4, 7], vec![2, 5, 8], vec![3, 6, 9]]).collect();
+/// assert_eq!(merged, [1, 2, 3, 4, 5, 6, 7, 8, 9]);
+/// ```
+#[unstable(feature = "iter_merge", reason = "recently added", issue = "none")]
+pub fn merge_all<I, const N: usize>(iters: [I; N]) -> MergeAll<I::IntoIter, N>
+where
+    I: IntoIterator,
+    I::Item: PartialOrd,
+{
+    MergeAll { state: MergeAllState::new(iters.map(IntoIterator::into_iter)) }
+}
+
+/// Converts the arguments to iterators and merges them into one iterator,
+/// assuming each of them is sorted by the given comparison function.
+///
+/// `is_first(a, b)` returns whether `a` should be yielded before `b`, where
+/// `a` is from an iterator earlier in the array than `b`. It must return `true`
+/// if the elements are equivalent for the merge to be stable.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(iter_merge)]
+///
+/// use std::iter::merge_all_by;
+///
+/// let descending = [[9, 6, 3], [8, 5, 2], [7, 4, 1]];
+/// let merged: Vec<_> = merge_all_by(descending, |a, b| a >= b).collect();
+/// assert_eq!(merged, [9, 8, 7, 6, 5, 4, 3, 2, 1]);
+/// ```
+#[unstable(feature = "iter_merge", reason = "recently added", issue = "none")]
+pub fn merge_all_by<I, F, const N: usize>(
+    iters: [I; N],
+    is_first: F,
+) -> MergeAllBy<I::IntoIter, F, N>
+where
+    I: IntoIterator,
+    F: FnMut(&I::Item, &I::Item) -> bool,
+{
+    MergeAllBy { state: MergeAllState::new(iters.map(IntoIterator::into_iter)), is_first }
+}
+
+/// The state shared by [`MergeAll`] and [`MergeAllBy`].
+#[derive(Clone, Debug)]
+struct MergeAllState<I, T, const N: usize> {
+    iters: [I; N],
+    // The front element of each iterator, or `None` once it is exhausted.
+    heads: [Option<T>; N],
+    started: bool,
+    // The iterator whose front element was taken by the last call to `next`.
+    // It is read again lazily, on the following call.
+    refill: Option<usize>,
+}
+
+impl<I: Iterator<Item = T>, T, const N: usize> MergeAllState<I, T, N> {
+    fn new(iters: [I; N]) -> Self {
+        Self { iters, heads: [const { None }; N], started: false, refill: None }
+    }
+
+    fn next_by(&mut self, mut is_first: impl FnMut(&T, &T) -> bool) -> Option<T> {
+        if !self.started {
+            self.started = true;
+            for (head, iter) in self.heads.iter_mut().zip(&mut self.iters) {
+                *head = iter.next();
+            }
+        } else if let Some(i) = self.refill.take() {
+            self.heads[i] = self.iters[i].next();
+        }
+
+        let mut min: Option<(usize, &T)> = None;
+        for (i, head) in self.heads.iter().enumerate() {
+            if let Some(x) = head {
+                match min {
+                    Some((_, m)) if is_first(m, x) => {}
+                    _ => min = Some((i, x)),
+                }
+            }
+        }
+        let (i, _) = min?;
+        self.refill = Some(i);
+        self.heads[i].take()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let mut lower = 0usize;
+        let mut upper = Some(0usize);
+        for (i, iter) in self.iters.iter().enumerate() {
+            // Iterators that ran out are not read again.
+            if self.started && self.heads[i].is_none() && self.refill != Some(i) {
+                continue;
+            }
+            let (l, u) = iter.size_hint();
+            let head = self.heads[i].is_some() as usize;
+            lower = lower.saturating_add(l).saturating_add(head);
+            upper = match (upper, u) {
+                (Some(x), Some(y)) => x.checked_add(y).and_then(|n| n.checked_add(head)),
+                _ => None,
+            };
+        }
+        (lower, upper)
+    }
+}
+
+/// An iterator that merges an array of sorted iterators into one sorted
+/// iterator.
+///
+/// This `struct` is created by [`merge_all`]. See its documentation for more
+/// information.
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+#[unstable(feature = "iter_merge", reason = "recently added", issue = "none")]
+#[derive(Clone, Debug)]
+pub struct MergeAll<I: Iterator, const N: usize> {
+    state: MergeAllState<I, I::Item, N>,
+}
+
+#[unstable(feature = "iter_merge", reason = "recently added", issue = "none")]
+impl<I, const N: usize> Iterator for MergeAll<I, N>
+where
+    I: Iterator,
+    I::Item: PartialOrd,
+{
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<I::Item> {
+        self.state.next_by(|a, b| a <= b)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.state.size_hint()
+    }
+}
+
+#[unstable(feature = "iter_merge", reason = "recently added", issue = "none")]
+impl<I, const N: usize> FusedIterator for MergeAll<I, N>
+where
+    I: Iterator,
+    I::Item: PartialOrd,
+{
+}
+
+#[unstable(feature = "trusted_len", issue = "37572")]
+unsafe impl<I, const N: usize> TrustedLen for MergeAll<I, N>
+where
+    I: TrustedLen,
+    I::Item: PartialOrd,
+{
+}
+
+/// An iterator that merges an array of iterators sorted by a comparison
+/// function into one sorted iterator.
+///
+/// This `struct` is created by [`merge_all_by`]. See its documentation for
+/// more information.
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+#[unstable(feature = "iter_merge", reason = "recently added", issue = "none")]
+#[derive(Clone)]
+pub struct MergeAllBy<I: Iterator, F, const N: usize> {
+    state: MergeAllState<I, I::Item, N>,
+    is_first: F,
+}
+
+#[unstable(feature = "iter_merge", reason = "recently added", issue = "none")]
+impl<I, F, const N: usize> Iterator for MergeAllBy<I, F, N>
+where
+    I: Iterator,
+    F: FnMut(&I::Item, &I::Item) -> bool,
+{
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<I::Item> {
+        self.state.next_by(&mut self.is_first)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.state.size_hint()
+    }
+}
+
+#[unstable(feature = "iter_merge", reason = "recently added", issue = "none")]
+impl<I, F, const N: usize> FusedIterator for MergeAllBy<I, F, N>
+where
+    I: Iterator,
+    F: FnMut(&I::Item, &I::Item) -> bool,
+{
+}
+
+#[unstable(feature = "trusted_len", issue = "37572")]
+unsafe impl<I, F, const N: usize> TrustedLen for MergeAllBy<I, F, N>
+where
+    I: TrustedLen,
+    F: FnMut(&I::Item, &I::Item) -> bool,
+{
+}
+
+#[unstable(feature = "iter_merge", reason = "recently added", issue = "none")]
+impl<I, F, const N: usize> fmt::Debug for MergeAllBy<I, F, N>
+where
+    I: Iterator + fmt::Debug,
+    I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MergeAllBy").field("state", &self.state).finish()
+    }
+}
@@ -5,9 +5,11 @@
 mod array_chunks;
 mod by_ref_sized;
 mod chain;
+mod chunk_by;
 mod cloned;
 mod copied;
 mod cycle;
+mod dedup;
 mod enumerate;
 mod filter;
 mod filter_map;
@@ -18,6 +20,7 @@
 mod map;
 mod map_while;
 mod map_windows;
+mod merge;
 mod peekable;
 mod rev;
 mod scan;
@@ -34,10 +37,14 @@
 pub use self::by_ref_sized::ByRefSized;
 #[unstable(feature = "iter_chain", reason = "recently added", issue = "125964")]
 pub use self::chain::chain;
+#[unstable(feature = "iter_chunk_by", reason = "recently added", issue = "none")]
+pub use self::chunk_by::ChunkRunsBy;
 #[stable(feature = "iter_cloned", since = "1.1.0")]
 pub use self::cloned::Cloned;
 #[stable(feature = "iter_copied", since = "1.36.0")]
 pub use self::copied::Copied;
+#[unstable(feature = "iter_dedup", reason = "recently added", issue = "none")]
+pub use self::dedup::{DedupAdjacent, DedupAdjacentBy, DedupAdjacentByKey, DedupAdjacentWithCount};
 #[stable(feature = "iterator_flatten", since = "1.29.0")]
 pub use self::flatten::Flatten;
 #[unstable(feature = "iter_intersperse", reason = "recently added", issue = "79524")]
@@ -46,6 +53,8 @@
 pub use self::map_while::MapWhile;
 #[unstable(feature = "iter_map_windows", reason = "recently added", issue = "87155")]
 pub use self::map_windows::MapWindows;
+#[unstable(feature = "iter_merge", reason = "recently added", issue = "none")]
+pub use self::merge::{MergeAll, MergeAllBy, MergeSorted, MergeSortedBy, merge_all, merge_all_by};
 #[stable(feature = "iterator_step_by", since = "1.28.0")]
 pub use self::step_by::StepBy;
 #[unstable(feature = "trusted_random_access", issue = "none")]
@@ -384,6 +384,8 @@ fn $fold<AAA, FFF>(mut self, init: AAA, fold: FFF) -> AAA
 pub use self::adapters::ArrayChunks;
 #[unstable(feature = "std_internals", issue = "none")]
 pub use self::adapters::ByRefSized;
+#[unstable(feature = "iter_chunk_by", reason = "recently added", issue = "none")]
+pub use self::adapters::ChunkRunsBy;
 #[stable(feature = "iter_cloned", since = "1.1.0")]
 pub use self::adapters::Cloned;
 #[stable(feature = "iter_copied", since = "1.36.0")]
@@ -412,8 +414,16 @@ fn $fold<AAA, FFF>(mut self, init: AAA, fold: FFF) -> AAA
     Chain, Cycle, Enumerate, Filter, FilterMap, FlatMap, Fuse, Inspect, Map, Peekable, Rev, Scan,
     Skip, SkipWhile, Take, TakeWhile, Zip,
 };
+#[unstable(feature = "iter_dedup", reason = "recently added", issue = "none")]
+pub use self::adapters::{
+    DedupAdjacent, DedupAdjacentBy, DedupAdjacentByKey, DedupAdjacentWithCount,
+};
 #[unstable(feature = "iter_intersperse", reason = "recently added", issue = "79524")]
 pub use self::adapters::{Intersperse, IntersperseWith};
+#[unstable(feature = "iter_merge", reason = "recently added", issue = "none")]
+pub use self::adapters::{
+    MergeAll, MergeAllBy, MergeSorted, MergeSortedBy, merge_all, merge_all_by,
+};
 #[unstable(
     feature = "step_trait",
     reason = "likely to be replaced by finer-grained traits",
@@ -1,8 +1,9 @@
 use super::super::{
-    ArrayChunks, ByRefSized, Chain, Cloned, Copied, Cycle, Enumerate, Filter, FilterMap, FlatMap,
-    Flatten, Fuse, Inspect, Intersperse, IntersperseWith, Map, MapWhile, MapWindows, Peekable,
-    Product, Rev, Scan, Skip, SkipWhile, StepBy, Sum, Take, TakeWhile, TrustedRandomAccessNoCoerce,
-    Zip, try_process,
+    ArrayChunks, ByRefSized, Chain, ChunkRunsBy, Cloned, Copied, Cycle, DedupAdjacent,
+    DedupAdjacentBy, DedupAdjacentByKey, DedupAdjacentWithCount, Enumerate, Filter, FilterMap,
+    FlatMap, Flatten, Fuse, Inspect, Intersperse, IntersperseWith, Map, MapWhile, MapWindows,
+    MergeSorted, MergeSortedBy, Peekable, Product, Rev, Scan, Skip, SkipWhile, StepBy, Sum, Take,
+    TakeWhile, TrustedRandomAccessNoCoerce, Zip, try_process,
 };
 use crate::array;
 use crate::cmp::{self, Ordering};
@@ -591,6 +592,73 @@ fn zip<U>(self, other: U) -> Zip<Self, U::IntoIter>
         Zip::new(self, other.into_iter())
     }
 
+    /// Merges two sorted iterators into one sorted iterator.
+    ///
+    /// `merge_sorted()` takes an iterator sorted in ascending order and returns a new
+    /// iterator that yields the elements of both `self` and `other` in
+    /// ascending order. Elements that compare equal are yielded from `self`
+    /// first, so the merge is stable.
+    ///
+    /// If either iterator is not sorted, the elements are still all yielded
+    /// exactly once, but in an unspecified order.
+    ///
+    /// To merge more than two iterators of the same type, see
+    /// [`merge_all`].
+    ///
+    /// [`merge_all`]: crate::iter::merge_all
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(iter_merge)]
+    ///
+    /// let a = [1, 3, 5, 7];
+    /// let b = [2, 3, 6];
+    ///
+    /// let merged: Vec<_> = a.into_iter().merge_sorted(b).collect();
+    /// assert_eq!(merged, [1, 2, 3, 3, 5, 6, 7]);
+    /// ```
+    #[inline]
+    #[unstable(feature = "iter_merge", reason = "recently added", issue = "none")]
+    fn merge_sorted<J>(self, other: J) -> MergeSorted<Self, J::IntoIter>
+    where
+        Self: Sized,
+        J: IntoIterator<Item = Self::Item>,
+        Self::Item: PartialOrd,
+    {
+        MergeSorted::new(self, other.into_iter())
+    }
+
+    /// Merges two iterators sorted by a comparison function into one sorted
+    /// iterator.
+    ///
+    /// `is_first(a, b)` is called with the next element `a` of `self` and the
+    /// next element `b` of `other`, and returns whether `a` should be yielded
+    /// before `b`. It must return `true` if the elements are equivalent for
+    /// the merge to be stable.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(iter_merge)]
+    ///
+    /// let a = ["x", "abc", "defgh"];
+    /// let b = ["yz", "uvw"];
+    ///
+    /// let merged: Vec<_> = a.into_iter().merge_sorted_by(b, |a, b| a.len() <= b.len()).collect();
+    /// assert_eq!(merged, ["x", "yz", "abc", "uvw", "defgh"]);
+    /// ```
+    #[inline]
+    #[unstable(feature = "iter_merge", reason = "recently added", issue = "none")]
+    fn merge_sorted_by<J, F>(self, other: J, is_first: F) -> MergeSortedBy<Self, J::IntoIter, F>
+    where
+        Self: Sized,
+        J: IntoIterator<Item = Self::Item>,
+        F: FnMut(&Self::Item, &Self::Item) -> bool,
+    {
+        MergeSortedBy::new(self, other.into_iter(), is_first)
+    }
+
     /// Creates a new iterator which places a copy of `separator` between adjacent
     /// items of the original iterator.
     ///
@@ -1675,6 +1743,168 @@ fn map_windows<F, R, const N: usize>(self, f: F) -> MapWindows<Self, F, N>
         MapWindows::new(self, f)
     }
 
+    /// Creates an iterator which removes consecutive repeated elements.
+    ///
+    /// Only the first element of each run of equal elements is yielded. If
+    /// the iterator is sorted, this removes all duplicates.
+    ///
+    /// This is the iterator counterpart of [`Vec::dedup`].
+    ///
+    /// [`Vec::dedup`]: ../../std/vec/struct.Vec.html#method.dedup
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(iter_dedup)]
+    ///
+    /// let a = [1, 1, 2, 3, 3, 3, 1];
+    ///
+    /// let deduped: Vec<_> = a.into_iter().dedup_adjacent().collect();
+    /// assert_eq!(deduped, [1, 2, 3, 1]);
+    /// ```
+    #[inline]
+    #[unstable(feature = "iter_dedup", reason = "recently added", issue = "none")]
+    fn dedup_adjacent(self) -> DedupAdjacent<Self>
+    where
+        Self: Sized,
+        Self::Item: PartialEq,
+    {
+        DedupAdjacent::new(self)
+    }
+
+    /// Creates an iterator which removes consecutive elements satisfying a
+    /// given equality relation.
+    ///
+    /// `same_bucket(a, b)` is called with the first element `a` of the current
+    /// run and a following element `b`. If it returns `true`, `b` is removed;
+    /// otherwise `b` starts a new run.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(iter_dedup)]
+    ///
+    /// let words = ["foo", "Foo", "FOO", "bar", "baz", "BAZ"];
+    ///
+    /// let deduped: Vec<_> =
+    ///     words.into_iter().dedup_adjacent_by(|a, b| a.eq_ignore_ascii_case(b)).collect();
+    /// assert_eq!(deduped, ["foo", "bar", "baz"]);
+    /// ```
+    #[inline]
+    #[unstable(feature = "iter_dedup", reason = "recently added", issue = "none")]
+    fn dedup_adjacent_by<F>(self, same_bucket: F) -> DedupAdjacentBy<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item, &Self::Item) -> bool,
+    {
+        DedupAdjacentBy::new(self, same_bucket)
+    }
+
+    /// Creates an iterator which removes consecutive elements that resolve to
+    /// the same key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(iter_dedup)]
+    ///
+    /// let a = [10, 11, 20, 21, 22, 30, 11];
+    ///
+    /// let deduped: Vec<_> = a.into_iter().dedup_adjacent_by_key(|x| x / 10).collect();
+    /// assert_eq!(deduped, [10, 20, 30, 11]);
+    /// ```
+    #[inline]
+    #[unstable(feature = "iter_dedup", reason = "recently added", issue = "none")]
+    fn dedup_adjacent_by_key<F, K>(self, key: F) -> DedupAdjacentByKey<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item) -> K,
+        K: PartialEq,
+    {
+        DedupAdjacentByKey::new(self, key)
+    }
+
+    /// Creates an iterator which yields the first element of each run of equal
+    /// consecutive elements along with the length of the run.
+    ///
+    /// This is a run-length encoding of the iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(iter_dedup)]
+    ///
+    /// let runs: Vec<_> = "aaabccdddd".chars().dedup_adjacent_with_count().collect();
+    /// assert_eq!(runs, [(3, 'a'), (1, 'b'), (2, 'c'), (4, 'd')]);
+    /// ```
+    #[inline]
+    #[unstable(feature = "iter_dedup", reason = "recently added", issue = "none")]
+    fn dedup_adjacent_with_count(self) -> DedupAdjacentWithCount<Self>
+    where
+        Self: Sized,
+        Self::Item: PartialEq,
+    {
+        DedupAdjacentWithCount::new(self)
+    }
+
+    /// Creates an iterator which collects runs of elements into collections,
+    /// using a predicate to separate them.
+    ///
+    /// The predicate is called for every pair of consecutive elements, in
+    /// order. A new chunk starts between two elements when it returns `false`.
+    /// Every chunk is collected into a new `B` using [`Extend`], so chunks are
+    /// never empty.
+    ///
+    /// This is the iterator counterpart of [`slice::chunk_by`]. Chunking by a
+    /// key is done by comparing the keys of both elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(iter_chunk_by)]
+    ///
+    /// let a = [1, 1, 1, 3, 3, 2, 2, 2];
+    ///
+    /// let chunks: Vec<Vec<_>> = a.into_iter().chunk_runs_by(|a, b| a == b).collect();
+    /// assert_eq!(chunks, [&[1, 1, 1][..], &[3, 3], &[2, 2, 2]]);
+    /// ```
+    ///
+    /// Splitting into sorted runs:
+    ///
+    /// ```
+    /// #![feature(iter_chunk_by)]
+    ///
+    /// let a = [1, 2, 3, 2, 3, 1, 4];
+    ///
+    /// let runs: Vec<Vec<_>> = a.into_iter().chunk_runs_by(|a, b| a <= b).collect();
+    /// assert_eq!(runs, [&[1, 2, 3][..], &[2, 3], &[1, 4]]);
+    /// ```
+    ///
+    /// Grouping by a key:
+    ///
+    /// ```
+    /// #![feature(iter_chunk_by)]
+    ///
+    /// let words = ["apple", "avocado", "banana", "blueberry", "cherry"];
+    ///
+    /// let groups: Vec<String> = words
+    ///     .into_iter()
+    ///     .chunk_runs_by::<Vec<_>, _>(|a, b| a.chars().next() == b.chars().next())
+    ///     .map(|group| group.join("+"))
+    ///     .collect();
+    /// assert_eq!(groups, ["apple+avocado", "banana+blueberry", "cherry"]);
+    /// ```
+    #[inline]
+    #[unstable(feature = "iter_chunk_by", reason = "recently added", issue = "none")]
+    fn chunk_runs_by<B, P>(self, predicate: P) -> ChunkRunsBy<Self, B, P>
+    where
+        Self: Sized,
+        B: Default + Extend<Self::Item>,
+        P: FnMut(&Self::Item, &Self::Item) -> bool,
+    {
+        ChunkRunsBy::new(self, predicate)
+    }
+
     /// Creates an iterator which ends after the first [`None`].
     ///
     /// After an iterator returns [`None`], future calls may or may not yield
@@ -96,7 +96,7 @@
 use crate::fmt;
 use crate::str::FromStr;
 
-mod common;
+pub(crate) mod common;
 pub mod decimal;
 pub mod decimal_seq;
 mod fpu;
@@ -14,7 +14,7 @@
 ///
 /// This is based off the algorithm described in "Fast numeric string to
 /// int", available here: <https://johnnylee-sde.github.io/Fast-numeric-string-to-int/>.
-fn parse_8digits(mut v: u64) -> u64 {
+pub(crate) fn parse_8digits(mut v: u64) -> u64 {
     const MASK: u64 = 0x0000_00FF_0000_00FF;
     const MUL1: u64 = 0x000F_4240_0000_0064;
     const MUL2: u64 = 0x0000_2710_0000_0001;
@@ -69,7 +69,7 @@ fn try_parse_19digits(s_ref: &mut &[u8], x: &mut u64) {
 }
 
 /// Parse the scientific notation component of a float.
-fn parse_scientific(s_ref: &mut &[u8]) -> Option<i64> {
+pub(crate) fn parse_scientific(s_ref: &mut &[u8]) -> Option<i64> {
     let mut exponent = 0i64;
     let mut negative = false;
 
@@ -0,0 +1,1436 @@
+//! A fixed-point decimal number type.
+
+use crate::cmp::Ordering;
+use crate::error::Error;
+use crate::fmt;
+use crate::hash::{Hash, Hasher};
+use crate::iter::{Product, Sum};
+use crate::mem::MaybeUninit;
+use crate::num::dec2flt::common::{ByteSlice, is_8digits};
+use crate::num::dec2flt::parse::{parse_8digits, parse_scientific};
+use crate::num::fmt as numfmt;
+use crate::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+use crate::str::FromStr;
+
+/// The powers of ten that fit in a `u128`.
+const POW10: [u128; 39] = {
+    let mut table = [1; 39];
+    let mut i = 1;
+    while i < table.len() {
+        table[i] = table[i - 1] * 10;
+        i += 1;
+    }
+    table
+};
+
+/// A decimal number with a 128-bit significand and a base-10 scale.
+///
+/// A `Decimal` is a `mantissa` and a `scale`, and stands for the exact value
+/// `mantissa * 10^-scale`. The mantissa is an [`i128`], which holds any number with up to 38
+/// significant digits, and the scale is at most [`Decimal::MAX_SCALE`]. Unlike [`f64`], every
+/// number written with up to 38 digits is represented exactly, which makes `Decimal` suitable for
+/// amounts of money and other quantities that are defined in base 10.
+///
+/// Numbers with the same value but different scales, such as `1.5` and `1.50`, compare equal, but
+/// they keep their scale, which is what [`Display`] prints. Parsing a string with [`FromStr`] and
+/// formatting the result with [`Display`] gives back the same string, for strings without an
+/// exponent, leading zeros, or a `+` sign.
+///
+/// Addition, subtraction, and multiplication are exact: the result of `a + b` and `a - b` has the
+/// larger of the two scales, and `a * b` has the sum of the scales, with trailing zeros dropped
+/// if it does not fit otherwise. A product can be rounded to a given scale in any [`RoundingMode`]
+/// with [`mul_round`]. Division keeps as many digits as fit, or can be rounded to a given scale
+/// with [`div_round`].
+///
+/// The arithmetic operators panic if the result of an addition, subtraction, or multiplication
+/// cannot be represented exactly, if the integer part of a quotient overflows the mantissa, or
+/// for division by zero. The [`checked_add`] family of methods returns `None` instead, and the
+/// [`saturating_add`] family saturates at [`Decimal::MIN`] and [`Decimal::MAX`].
+///
+/// [`Display`]: fmt::Display
+/// [`mul_round`]: Decimal::mul_round
+/// [`div_round`]: Decimal::div_round
+/// [`checked_add`]: Decimal::checked_add
+/// [`saturating_add`]: Decimal::saturating_add
+///
+/// # Examples
+///
+/// ```
+/// #![feature(decimal)]
+///
+/// use std::num::{Decimal, RoundingMode};
+///
+/// let price: Decimal = "19.99".parse().unwrap();
+/// let quantity = Decimal::from(3);
+/// let rate: Decimal = "0.0825".parse().unwrap();
+///
+/// let subtotal = price * quantity;
+/// assert_eq!(subtotal.to_string(), "59.97");
+/// let tax = subtotal * rate;
+/// assert_eq!(tax.to_string(), "4.947525");
+/// let total = subtotal + tax.round(2, RoundingMode::HalfAwayFromZero);
+/// assert_eq!(total.to_string(), "64.92");
+///
+/// // Exact, unlike `0.1 + 0.2` with floats.
+/// let a: Decimal = "0.1".parse().unwrap();
+/// let b: Decimal = "0.2".parse().unwrap();
+/// assert_eq!(a + b, "0.3".parse().unwrap());
+/// ```
+#[derive(Clone, Copy)]
+#[unstable(feature = "decimal", issue = "none")]
+pub struct Decimal {
+    mantissa: i128,
+    scale: u8,
+}
+
+/// How to round a [`Decimal`] that has more digits than a result can hold.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(decimal)]
+///
+/// use std::num::{Decimal, RoundingMode};
+///
+/// let x: Decimal = "-2.5".parse().unwrap();
+/// let round = |mode| x.round(0, mode).to_string();
+/// assert_eq!(round(RoundingMode::TowardZero), "-2");
+/// assert_eq!(round(RoundingMode::AwayFromZero), "-3");
+/// assert_eq!(round(RoundingMode::Floor), "-3");
+/// assert_eq!(round(RoundingMode::Ceiling), "-2");
+/// assert_eq!(round(RoundingMode::HalfToEven), "-2");
+/// assert_eq!(round(RoundingMode::HalfAwayFromZero), "-3");
+/// assert_eq!(round(RoundingMode::HalfTowardZero), "-2");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[unstable(feature = "decimal", issue = "none")]
+pub enum RoundingMode {
+    /// Round towards zero, discarding the extra digits.
+    TowardZero,
+    /// Round away from zero.
+    AwayFromZero,
+    /// Round towards negative infinity.
+    Floor,
+    /// Round towards positive infinity.
+    Ceiling,
+    /// Round to the nearest number, and ties to the one with an even last digit. This is also
+    /// known as banker's rounding, and does not favor either direction on average.
+    HalfToEven,
+    /// Round to the nearest number, and ties away from zero, as commonly taught in school.
+    HalfAwayFromZero,
+    /// Round to the nearest number, and ties towards zero.
+    HalfTowardZero,
+}
+
+/// How the digits discarded by rounding compare to half a unit in the last place that is kept.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Discarded {
+    Zero,
+    BelowHalf,
+    Half,
+    AboveHalf,
+}
+
+impl Discarded {
+    /// Classifies a remainder `r` of a division by `d`.
+    fn of_remainder(r: u128, d: u128) -> Self {
+        if r == 0 {
+            return Discarded::Zero;
+        }
+        match r.cmp(&(d - r)) {
+            Ordering::Less => Discarded::BelowHalf,
+            Ordering::Equal => Discarded::Half,
+            Ordering::Greater => Discarded::AboveHalf,
+        }
+    }
+}
+
+impl RoundingMode {
+    /// Returns whether a magnitude with the given sign, parity, and discarded digits is rounded up
+    /// to the next magnitude.
+    fn rounds_up(self, negative: bool, odd: bool, discarded: Discarded) -> bool {
+        if discarded == Discarded::Zero {
+            return false;
+        }
+        match self {
+            RoundingMode::TowardZero => false,
+            RoundingMode::AwayFromZero => true,
+            RoundingMode::Floor => negative,
+            RoundingMode::Ceiling => !negative,
+            RoundingMode::HalfToEven => {
+                discarded == Discarded::AboveHalf || (discarded == Discarded::Half && odd)
+            }
+            RoundingMode::HalfAwayFromZero => discarded >= Discarded::Half,
+            RoundingMode::HalfTowardZero => discarded == Discarded::AboveHalf,
+        }
+    }
+}
+
+/// An unsigned 256-bit integer, wide enough for the product of two mantissas.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+struct Wide {
+    // The field order makes the derived `Ord` numeric.
+    hi: u128,
+    lo: u128,
+}
+
+impl Wide {
+    const fn new(lo: u128) -> Self {
+        Wide { hi: 0, lo }
+    }
+
+    /// Returns `a * b`.
+    fn mul(a: u128, b: u128) -> Self {
+        let (lo, hi) = a.carrying_mul(b, 0);
+        Wide { hi, lo }
+    }
+
+    /// Returns `self + rhs`, which must not overflow.
+    fn add(self, rhs: Self) -> Self {
+        let (lo, carry) = self.lo.overflowing_add(rhs.lo);
+        Wide { hi: self.hi + rhs.hi + carry as u128, lo }
+    }
+
+    /// Returns `self - rhs`, where `self >= rhs`.
+    fn sub(self, rhs: Self) -> Self {
+        let (lo, borrow) = self.lo.overflowing_sub(rhs.lo);
+        Wide { hi: self.hi - rhs.hi - borrow as u128, lo }
+    }
+
+    /// Returns the quotient and remainder of `self` divided by `d`.
+    fn div_rem_u64(self, d: u64) -> (Self, u64) {
+        let d = d as u128;
+        let mut limbs =
+            [(self.hi >> 64) as u64, self.hi as u64, (self.lo >> 64) as u64, self.lo as u64];
+        let mut rem = 0;
+        for limb in &mut limbs {
+            let n = rem << 64 | *limb as u128;
+            *limb = (n / d) as u64;
+            rem = n % d;
+        }
+        let [a, b, c, d] = limbs.map(|l| l as u128);
+        (Wide { hi: a << 64 | b, lo: c << 64 | d }, rem as u64)
+    }
+
+    /// Returns `self % d`, for nonzero `d`, by binary long division.
+    fn rem(self, d: Self) -> Self {
+        if self < d {
+            return self;
+        } else if self.hi == 0 {
+            return Wide::new(self.lo % d.lo);
+        }
+        let mut rem = Wide::new(0);
+        for i in (0..256).rev() {
+            let bit = if i >= 128 { self.hi >> (i - 128) & 1 } else { self.lo >> i & 1 };
+            // `rem < d`, so doubling it fits in 257 bits; the overflowing bit means `rem >= d`.
+            let overflow = rem.hi >> 127 == 1;
+            rem = Wide { hi: rem.hi << 1 | rem.lo >> 127, lo: rem.lo << 1 | bit };
+            if overflow || rem >= d {
+                rem = Wide {
+                    hi: rem.hi.wrapping_sub(d.hi).wrapping_sub((rem.lo < d.lo) as u128),
+                    lo: rem.lo.wrapping_sub(d.lo),
+                };
+            }
+        }
+        rem
+    }
+
+    /// Drops the lowest `n` decimal digits, for `1 <= n <= 19`, and classifies them together with
+    /// the digits that were dropped before.
+    fn drop_digits(self, n: u32, before: Discarded) -> (Self, Discarded) {
+        let (q, r) = self.div_rem_u64(POW10[n as usize] as u64);
+        let half = 5 * POW10[n as usize - 1] as u64;
+        let discarded = match r.cmp(&half) {
+            Ordering::Greater => Discarded::AboveHalf,
+            Ordering::Equal if before == Discarded::Zero => Discarded::Half,
+            Ordering::Equal => Discarded::AboveHalf,
+            Ordering::Less if r == 0 && before == Discarded::Zero => Discarded::Zero,
+            Ordering::Less => Discarded::BelowHalf,
+        };
+        (q, discarded)
+    }
+}
+
+/// Returns the next digit of the long division of `r` by `d`, where `r < d`, and the new
+/// remainder.
+fn next_digit(r: u128, d: u128) -> (u128, u128) {
+    if let Some(r10) = r.checked_mul(10) {
+        return (r10 / d, r10 % d);
+    }
+    // `10 * r < 10 * d`, so the quotient is a single digit: find it by subtraction.
+    let (mut lo, mut hi) = r.carrying_mul(10, 0);
+    let mut digit = 0;
+    while hi != 0 || lo >= d {
+        let (l, borrow) = lo.overflowing_sub(d);
+        lo = l;
+        hi -= borrow as u128;
+        digit += 1;
+    }
+    (digit, lo)
+}
+
+impl Decimal {
+    /// The largest scale of a `Decimal`, which is the number of digits of [`i128::MAX`] minus one.
+    #[unstable(feature = "decimal", issue = "none")]
+    pub const MAX_SCALE: u32 = 38;
+
+    /// Zero.
+    #[unstable(feature = "decimal", issue = "none")]
+    pub const ZERO: Self = Decimal { mantissa: 0, scale: 0 };
+
+    /// One.
+    #[unstable(feature = "decimal", issue = "none")]
+    pub const ONE: Self = Decimal { mantissa: 1, scale: 0 };
+
+    /// The smallest value of a `Decimal`, equal to [`i128::MIN`].
+    #[unstable(feature = "decimal", issue = "none")]
+    pub const MIN: Self = Decimal { mantissa: i128::MIN, scale: 0 };
+
+    /// The largest value of a `Decimal`, equal to [`i128::MAX`].
+    #[unstable(feature = "decimal", issue = "none")]
+    pub const MAX: Self = Decimal { mantissa: i128::MAX, scale: 0 };
+
+    /// Creates the decimal `mantissa * 10^-scale`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `scale` is greater than [`Decimal::MAX_SCALE`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(decimal)]
+    ///
+    /// use std::num::Decimal;
+    ///
+    /// let x = Decimal::new(-12345, 2);
+    /// assert_eq!(x.to_string(), "-123.45");
+    /// assert_eq!((x.mantissa(), x.scale()), (-12345, 2));
+    /// ```
+    #[unstable(feature = "decimal", issue = "none")]
+    #[must_use]
+    #[inline]
+    #[track_caller]
+    pub const fn new(mantissa: i128, scale: u32) -> Self {
+        assert!(scale <= Self::MAX_SCALE, "decimal scale is greater than `Decimal::MAX_SCALE`");
+        Decimal { mantissa, scale: scale as u8 }
+    }
+
+    /// Returns the mantissa, which is the value times `10^scale`.
+    #[unstable(feature = "decimal", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn mantissa(self) -> i128 {
+        self.mantissa
+    }
+
+    /// Returns the scale, which is the number of digits after the decimal point.
+    #[unstable(feature = "decimal", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn scale(self) -> u32 {
+        self.scale as u32
+    }
+
+    /// Returns `true` if `self` is zero, at any scale.
+    #[unstable(feature = "decimal", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn is_zero(self) -> bool {
+        self.mantissa == 0
+    }
+
+    /// Returns `true` if `self` is negative.
+    #[unstable(feature = "decimal", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn is_negative(self) -> bool {
+        self.mantissa < 0
+    }
+
+    /// Returns `true` if `self` is positive.
+    #[unstable(feature = "decimal", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn is_positive(self) -> bool {
+        self.mantissa > 0
+    }
+
+    /// Returns the sign, absolute value of the mantissa, and scale of `self`.
+    fn parts(self) -> (bool, u128, u32) {
+        (self.mantissa < 0, self.mantissa.unsigned_abs(), self.scale as u32)
+    }
+
+    /// Creates a number from its sign, magnitude, and scale, where the magnitude fits.
+    fn from_parts(negative: bool, magnitude: u128, scale: u32) -> Self {
+        debug_assert!(magnitude <= max_magnitude(negative) && scale <= Self::MAX_SCALE);
+        let mantissa =
+            if negative { (magnitude as i128).wrapping_neg() } else { magnitude as i128 };
+        Decimal { mantissa, scale: scale as u8 }
+    }
+
+    /// Returns the magnitude of `self` at a scale that is not smaller than its own.
+    fn magnitude_at(self, scale: u32) -> Wide {
+        Wide::mul(self.mantissa.unsigned_abs(), POW10[(scale - self.scale as u32) as usize])
+    }
+
+    /// Rounds `magnitude * 10^-scale` with the given sign, where `discarded` describes any digits
+    /// that were already dropped from `magnitude`, to at most `max_scale` digits after the
+    /// decimal point, and to fewer if the result does not fit otherwise. Returns `None` if the
+    /// integer part of the result does not fit.
+    fn round_wide(
+        negative: bool,
+        mut magnitude: Wide,
+        mut scale: u32,
+        mut discarded: Discarded,
+        max_scale: u32,
+        mode: RoundingMode,
+    ) -> Option<Self> {
+        let max = max_magnitude(negative);
+        loop {
+            while magnitude > Wide::new(max) || scale > max_scale {
+                if scale == 0 {
+                    return None;
+                }
+                let n = if scale > max_scale { (scale - max_scale).min(19) } else { 1 };
+                (magnitude, discarded) = magnitude.drop_digits(n, discarded);
+                scale -= n;
+            }
+            let m = magnitude.lo;
+            if !mode.rounds_up(negative, m & 1 == 1, discarded) {
+                return Some(Self::from_parts(negative, m, scale));
+            } else if m < max {
+                return Some(Self::from_parts(negative, m + 1, scale));
+            }
+            // Rounding up would overflow, so the result needs one digit fewer.
+            if scale == 0 {
+                return None;
+            }
+            (magnitude, discarded) = magnitude.drop_digits(1, discarded);
+            scale -= 1;
+        }
+    }
+
+    /// Returns `magnitude * 10^-scale` with the given sign, dropping trailing zeros after the
+    /// decimal point if it does not fit otherwise. Returns `None` if the number cannot be
+    /// represented exactly.
+    fn exact_wide(negative: bool, mut magnitude: Wide, mut scale: u32) -> Option<Self> {
+        let max = Wide::new(max_magnitude(negative));
+        while magnitude > max || scale > Self::MAX_SCALE {
+            if scale == 0 {
+                return None;
+            }
+            let n = if scale > Self::MAX_SCALE { (scale - Self::MAX_SCALE).min(19) } else { 1 };
+            let discarded;
+            (magnitude, discarded) = magnitude.drop_digits(n, Discarded::Zero);
+            if discarded != Discarded::Zero {
+                return None;
+            }
+            scale -= n;
+        }
+        Some(Self::from_parts(negative, magnitude.lo, scale))
+    }
+
+    /// Returns `magnitude * 10^-scale` with the given sign, exactly if `mode` is `None`, and
+    /// rounded to as many digits as fit with `mode` otherwise.
+    fn fit_wide(
+        negative: bool,
+        magnitude: Wide,
+        scale: u32,
+        mode: Option<RoundingMode>,
+    ) -> Option<Self> {
+        match mode {
+            Some(mode) => {
+                Self::round_wide(negative, magnitude, scale, Discarded::Zero, Self::MAX_SCALE, mode)
+            }
+            None => Self::exact_wide(negative, magnitude, scale),
+        }
+    }
+
+    /// Returns `self + rhs`, or `self - rhs` if `negate_rhs` is set, exactly or rounded with
+    /// `mode` as for `fit_wide`.
+    fn add_impl(self, rhs: Self, negate_rhs: bool, mode: Option<RoundingMode>) -> Option<Self> {
+        let scale = self.scale.max(rhs.scale) as u32;
+        let (a, b) = (self.magnitude_at(scale), rhs.magnitude_at(scale));
+        let (a_negative, b_negative) = (self.is_negative(), rhs.is_negative() != negate_rhs);
+        let (negative, magnitude) = if a_negative == b_negative {
+            (a_negative, a.add(b))
+        } else if a >= b {
+            (a_negative, a.sub(b))
+        } else {
+            (b_negative, b.sub(a))
+        };
+        Self::fit_wide(negative, magnitude, scale, mode)
+    }
+
+    /// Returns the sign, magnitude, and scale of the exact product `self * rhs`.
+    fn mul_wide(self, rhs: Self) -> (bool, Wide, u32) {
+        let (a_negative, a, a_scale) = self.parts();
+        let (b_negative, b, b_scale) = rhs.parts();
+        (a_negative != b_negative, Wide::mul(a, b), a_scale + b_scale)
+    }
+
+    /// Divides `self` by `rhs`, keeping `scale` digits, or as many as fit and are needed if
+    /// `scale` is `None`.
+    fn div_impl(self, rhs: Self, scale: Option<u32>, mode: RoundingMode) -> Option<Self> {
+        let (a_negative, a, a_scale) = self.parts();
+        let (b_negative, b, b_scale) = rhs.parts();
+        if b == 0 {
+            return None;
+        }
+        let negative = a_negative != b_negative;
+        let max = max_magnitude(negative);
+        let target = scale.unwrap_or(Self::MAX_SCALE) as i32;
+
+        // Long division, starting with the integer quotient of the mantissas.
+        let (mut q, mut r) = (a / b, a % b);
+        let mut q_scale = a_scale as i32 - b_scale as i32;
+        while q_scale < target && (scale.is_some() || r != 0 || q_scale < 0) {
+            let (digit, rem) = next_digit(r, b);
+            match q.checked_mul(10).and_then(|q| q.checked_add(digit)).filter(|&q| q <= max) {
+                Some(next) => (q, r) = (next, rem),
+                // Digits before the decimal point, or that were asked for, must fit.
+                None if q_scale < 0 || scale.is_some() => return None,
+                None => break,
+            }
+            q_scale += 1;
+        }
+
+        let discarded = Discarded::of_remainder(r, b);
+        let max_scale = target as u32;
+        let result =
+            Self::round_wide(negative, Wide::new(q), q_scale as u32, discarded, max_scale, mode)?;
+        match scale {
+            Some(scale) if result.scale() != scale => None,
+            _ => Some(result),
+        }
+    }
+
+    /// Checked addition. Computes `self + rhs`, returning `None` if the result cannot be
+    /// represented exactly.
+    ///
+    /// The result has the larger of the two scales, or a smaller one if it only fits without some
+    /// of its trailing zeros.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(decimal)]
+    ///
+    /// use std::num::Decimal;
+    ///
+    /// assert_eq!(Decimal::new(150, 2).checked_add(Decimal::new(25, 1)), Some(Decimal::new(400, 2)));
+    /// assert_eq!(Decimal::MAX.checked_add(Decimal::ONE), None);
+    /// // The exact sum needs 39 significant digits.
+    /// assert_eq!(Decimal::new(10_i128.pow(38), 0).checked_add(Decimal::new(5, 1)), None);
+    /// ```
+    #[unstable(feature = "decimal", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        self.add_impl(rhs, false, None)
+    }
+
+    /// Checked subtraction. Computes `self - rhs`, returning `None` if the result cannot be
+    /// represented exactly.
+    ///
+    /// The result has the larger of the two scales, or a smaller one if it only fits without some
+    /// of its trailing zeros.
+    #[unstable(feature = "decimal", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        self.add_impl(rhs, true, None)
+    }
+
+    /// Checked multiplication. Computes `self * rhs`, returning `None` if the result cannot be
+    /// represented exactly.
+    ///
+    /// The result has the sum of the two scales, or a smaller one if it only fits without some of
+    /// its trailing zeros. Use [`Decimal::checked_mul_round`] to round the product instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(decimal)]
+    ///
+    /// use std::num::Decimal;
+    ///
+    /// assert_eq!(Decimal::new(15, 1).checked_mul(Decimal::new(-15, 1)), Some(Decimal::new(-225, 2)));
+    /// assert_eq!(Decimal::MAX.checked_mul(Decimal::new(2, 0)), None);
+    /// // The exact product has 40 digits after the decimal point.
+    /// assert_eq!(Decimal::new(1, 20).checked_mul(Decimal::new(1, 20)), None);
+    /// ```
+    #[unstable(feature = "decimal", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        let (negative, product, scale) = self.mul_wide(rhs);
+        Self::exact_wide(negative, product, scale)
+    }
+
+    /// Checked division. Computes `self / rhs`, returning `None` if `rhs` is zero or the integer
+    /// part of the result does not fit.
+    ///
+    /// If the quotient has a finite decimal expansion that fits, the result is exact, with at least
+    /// as many digits as `self.scale() - rhs.scale()`. Otherwise, it is rounded half to even to as
+    /// many digits as fit, up to [`Decimal::MAX_SCALE`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(decimal)]
+    ///
+    /// use std::num::Decimal;
+    ///
+    /// assert_eq!(Decimal::new(1, 0).checked_div(Decimal::new(8, 0)).unwrap().to_string(), "0.125");
+    /// assert_eq!(
+    ///     Decimal::new(2, 0).checked_div(Decimal::new(3, 0)).unwrap().to_string(),
+    ///     "0.66666666666666666666666666666666666667",
+    /// );
+    /// assert_eq!(Decimal::ONE.checked_div(Decimal::ZERO), None);
+    /// ```
+    #[unstable(feature = "decimal", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        self.div_impl(rhs, None, RoundingMode::HalfToEven)
+    }
+
+    /// Checked remainder. Computes `self % rhs`, returning `None` if `rhs` is zero.
+    ///
+    /// Like for the primitive integers, the remainder is `self - rhs * q`, where `q` is the
+    /// quotient rounded towards zero, so it has the sign of `self`. It is always exact.
+    #[unstable(feature = "decimal", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    pub fn checked_rem(self, rhs: Self) -> Option<Self> {
+        if rhs.is_zero() {
+            return None;
+        }
+        let scale = self.scale.max(rhs.scale) as u32;
+        let rem = self.magnitude_at(scale).rem(rhs.magnitude_at(scale));
+        // The remainder is smaller than both operands, so it fits.
+        Some(Self::from_parts(self.is_negative(), rem.lo, scale))
+    }
+
+    /// Checked negation. Computes `-self`, returning `None` if `self == Decimal::MIN`.
+    #[unstable(feature = "decimal", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn checked_neg(self) -> Option<Self> {
+        match self.mantissa.checked_neg() {
+            Some(mantissa) => Some(Decimal { mantissa, scale: self.scale }),
+            None => None,
+        }
+    }
+
+    /// Checked absolute value. Computes `self.abs()`, returning `None` if
+    /// `self == Decimal::MIN`.
+    #[unstable(feature = "decimal", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn checked_abs(self) -> Option<Self> {
+        if self.is_negative() { self.checked_neg() } else { Some(self) }
+    }
+
+    /// Saturating addition. Computes `self + rhs`, saturating at [`Decimal::MIN`] and
+    /// [`Decimal::MAX`] instead of overflowing.
+    ///
+    /// If the exact sum does not fit but its integer part does, it is rounded half to even to as
+    /// many digits as fit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(decimal)]
+    ///
+    /// use std::num::Decimal;
+    ///
+    /// assert_eq!(Decimal::MAX.saturating_add(Decimal::ONE), Decimal::MAX);
+    /// assert_eq!(Decimal::MIN.saturating_add(-Decimal::ONE), Decimal::MIN);
+    /// ```
+    #[unstable(feature = "decimal", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub fn saturating_add(self, rhs: Self) -> Self {
+        // Only operands of the same sign can overflow.
+        let sum = self.add_impl(rhs, false, Some(RoundingMode::HalfToEven));
+        sum.unwrap_or(saturate(self.is_negative()))
+    }
+
+    /// Saturating subtraction. Computes `self - rhs`, saturating at [`Decimal::MIN`] and
+    /// [`Decimal::MAX`] instead of overflowing.
+    ///
+    /// If the exact difference does not fit but its integer part does, it is rounded half to even
+    /// to as many digits as fit.
+    #[unstable(feature = "decimal", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub fn saturating_sub(self, rhs: Self) -> Self {
+        // Only operands of different signs can overflow.
+        let difference = self.add_impl(rhs, true, Some(RoundingMode::HalfToEven));
+        difference.unwrap_or(saturate(self.is_negative()))
+    }
+
+    /// Saturating multiplication. Computes `self * rhs`, saturating at [`Decimal::MIN`] and
+    /// [`Decimal::MAX`] instead of overflowing.
+    ///
+    /// If the exact product does not fit but its integer part does, it is rounded half to even to
+    /// as many digits as fit.
+    #[unstable(feature = "decimal", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub fn saturating_mul(self, rhs: Self) -> Self {
+        let (negative, product, scale) = self.mul_wide(rhs);
+        Self::fit_wide(negative, product, scale, Some(RoundingMode::HalfToEven))
+            .unwrap_or(saturate(negative))
+    }
+
+    /// Saturating division. Computes `self / rhs`, saturating at [`Decimal::MIN`] and
+    /// [`Decimal::MAX`] instead of overflowing.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `rhs` is zero.
+    #[unstable(feature = "decimal", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    #[track_caller]
+    pub fn saturating_div(self, rhs: Self) -> Self {
+        if rhs.is_zero() {
+            panic!("attempt to divide by zero");
+        }
+        self.checked_div(rhs).unwrap_or(saturate(self.is_negative() != rhs.is_negative()))
+    }
+
+    /// Saturating negation. Computes `-self`, returning [`Decimal::MAX`] if
+    /// `self == Decimal::MIN` instead of overflowing.
+    #[unstable(feature = "decimal", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn saturating_neg(self) -> Self {
+        match self.checked_neg() {
+            Some(x) => x,
+            None => Self::MAX,
+        }
+    }
+
+    /// Saturating absolute value. Computes `self.abs()`, returning [`Decimal::MAX`] if
+    /// `self == Decimal::MIN` instead of overflowing.
+    #[unstable(feature = "decimal", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub const fn saturating_abs(self) -> Self {
+        match self.checked_abs() {
+            Some(x) => x,
+            None => Self::MAX,
+        }
+    }
+
+    /// Computes the absolute value of `self`.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `self == Decimal::MIN`.
+    #[unstable(feature = "decimal", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    #[track_caller]
+    pub const fn abs(self) -> Self {
+        match self.checked_abs() {
+            Some(x) => x,
+            None => panic!("attempt to negate with overflow"),
+        }
+    }
+
+    /// Multiplies `self` by `rhs`, rounding the product to `scale` digits after the decimal point
+    /// with the given rounding mode.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the result does not fit at `scale`, which is also the case if
+    /// `scale` is greater than [`Decimal::MAX_SCALE`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(decimal)]
+    ///
+    /// use std::num::{Decimal, RoundingMode};
+    ///
+    /// let price = Decimal::new(1999, 2);
+    /// let rate = Decimal::new(1925, 4);
+    /// assert_eq!(price.mul_round(rate, 2, RoundingMode::HalfAwayFromZero).to_string(), "3.85");
+    /// assert_eq!(price.mul_round(Decimal::new(3, 0), 4, RoundingMode::Floor).to_string(), "59.9700");
+    /// ```
+    #[unstable(feature = "decimal", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[track_caller]
+    pub fn mul_round(self, rhs: Self, scale: u32, mode: RoundingMode) -> Self {
+        match self.checked_mul_round(rhs, scale, mode) {
+            Some(x) => x,
+            None => panic!("attempt to multiply with overflow"),
+        }
+    }
+
+    /// Multiplies `self` by `rhs`, rounding the product to `scale` digits after the decimal point
+    /// with the given rounding mode. Returns `None` if the result does not fit at `scale`.
+    #[unstable(feature = "decimal", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    pub fn checked_mul_round(self, rhs: Self, scale: u32, mode: RoundingMode) -> Option<Self> {
+        if scale > Self::MAX_SCALE {
+            return None;
+        }
+        let (negative, product, product_scale) = self.mul_wide(rhs);
+        if product_scale <= scale {
+            return Self::exact_wide(negative, product, product_scale)?
+                .checked_rescale(scale, mode);
+        }
+        let result =
+            Self::round_wide(negative, product, product_scale, Discarded::Zero, scale, mode)?;
+        // Fewer digits are kept if the result does not fit at `scale`.
+        if result.scale() == scale { Some(result) } else { None }
+    }
+
+    /// Divides `self` by `rhs`, rounding the quotient to `scale` digits after the decimal point
+    /// with the given rounding mode.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `rhs` is zero, or if the result does not fit at `scale`, which
+    /// is also the case if `scale` is greater than [`Decimal::MAX_SCALE`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(decimal)]
+    ///
+    /// use std::num::{Decimal, RoundingMode};
+    ///
+    /// let total = Decimal::new(10000, 2);
+    /// let share = total.div_round(Decimal::new(3, 0), 2, RoundingMode::Floor);
+    /// assert_eq!(share.to_string(), "33.33");
+    /// assert_eq!(total.div_round(Decimal::new(8, 0), 4, RoundingMode::HalfToEven).to_string(), "12.5000");
+    /// ```
+    #[unstable(feature = "decimal", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[track_caller]
+    pub fn div_round(self, rhs: Self, scale: u32, mode: RoundingMode) -> Self {
+        if rhs.is_zero() {
+            panic!("attempt to divide by zero");
+        }
+        match self.checked_div_round(rhs, scale, mode) {
+            Some(x) => x,
+            None => panic!("attempt to divide with overflow"),
+        }
+    }
+
+    /// Divides `self` by `rhs`, rounding the quotient to `scale` digits after the decimal point
+    /// with the given rounding mode. Returns `None` if `rhs` is zero, or if the result does not
+    /// fit at `scale`.
+    #[unstable(feature = "decimal", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub fn checked_div_round(self, rhs: Self, scale: u32, mode: RoundingMode) -> Option<Self> {
+        if scale > Self::MAX_SCALE {
+            return None;
+        }
+        self.div_impl(rhs, Some(scale), mode)
+    }
+
+    /// Rounds `self` to at most `scale` digits after the decimal point, with the given rounding
+    /// mode. Numbers that already have no more digits are returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(decimal)]
+    ///
+    /// use std::num::{Decimal, RoundingMode};
+    ///
+    /// let x = Decimal::new(2345, 3);
+    /// assert_eq!(x.round(2, RoundingMode::HalfToEven).to_string(), "2.34");
+    /// assert_eq!(x.round(2, RoundingMode::HalfAwayFromZero).to_string(), "2.35");
+    /// assert_eq!(x.round(5, RoundingMode::HalfToEven).to_string(), "2.345");
+    /// ```
+    #[unstable(feature = "decimal", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    pub fn round(self, scale: u32, mode: RoundingMode) -> Self {
+        if scale >= self.scale() {
+            return self;
+        }
+        let (negative, magnitude, self_scale) = self.parts();
+        // Dropping at least one digit leaves room to round up, so this cannot overflow.
+        Self::round_wide(negative, Wide::new(magnitude), self_scale, Discarded::Zero, scale, mode)
+            .unwrap()
+    }
+
+    /// Returns `self` with exactly `scale` digits after the decimal point, rounding with the given
+    /// rounding mode if there are more. Returns `None` if the result does not fit, which is also
+    /// the case if `scale` is greater than [`Decimal::MAX_SCALE`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(decimal)]
+    ///
+    /// use std::num::{Decimal, RoundingMode};
+    ///
+    /// let x = Decimal::new(15, 1);
+    /// assert_eq!(x.checked_rescale(3, RoundingMode::HalfToEven).unwrap().to_string(), "1.500");
+    /// assert_eq!(x.checked_rescale(0, RoundingMode::HalfToEven).unwrap().to_string(), "2");
+    /// assert_eq!(Decimal::MAX.checked_rescale(1, RoundingMode::HalfToEven), None);
+    /// ```
+    #[unstable(feature = "decimal", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    pub fn checked_rescale(self, scale: u32, mode: RoundingMode) -> Option<Self> {
+        if scale > Self::MAX_SCALE {
+            return None;
+        }
+        if scale <= self.scale() {
+            return Some(self.round(scale, mode));
+        }
+        let mantissa = self.mantissa.checked_mul(POW10[(scale - self.scale()) as usize] as i128)?;
+        Some(Decimal { mantissa, scale: scale as u8 })
+    }
+
+    /// Returns the integer part of `self`, rounding towards zero.
+    #[unstable(feature = "decimal", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub fn trunc(self) -> Self {
+        self.round(0, RoundingMode::TowardZero)
+    }
+
+    /// Returns the fractional part of `self`, which has the sign of `self`.
+    #[unstable(feature = "decimal", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub fn fract(self) -> Self {
+        let unit = POW10[self.scale as usize] as i128;
+        Decimal { mantissa: self.mantissa % unit, scale: self.scale }
+    }
+
+    /// Returns the largest integer less than or equal to `self`.
+    #[unstable(feature = "decimal", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub fn floor(self) -> Self {
+        self.round(0, RoundingMode::Floor)
+    }
+
+    /// Returns the smallest integer greater than or equal to `self`.
+    #[unstable(feature = "decimal", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    #[inline]
+    pub fn ceil(self) -> Self {
+        self.round(0, RoundingMode::Ceiling)
+    }
+
+    /// Returns `self` with the trailing zeros after the decimal point removed, which is the
+    /// representation of its value with the smallest scale.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(decimal)]
+    ///
+    /// use std::num::Decimal;
+    ///
+    /// assert_eq!(Decimal::new(12500, 3).normalize().to_string(), "12.5");
+    /// assert_eq!(Decimal::new(0, 5).normalize().to_string(), "0");
+    /// ```
+    #[unstable(feature = "decimal", issue = "none")]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    pub const fn normalize(self) -> Self {
+        let Decimal { mut mantissa, mut scale } = self;
+        while scale > 0 && mantissa % 10 == 0 {
+            mantissa /= 10;
+            scale -= 1;
+        }
+        Decimal { mantissa, scale }
+    }
+}
+
+/// Returns the largest magnitude of a mantissa with the given sign.
+const fn max_magnitude(negative: bool) -> u128 {
+    if negative { i128::MIN.unsigned_abs() } else { i128::MAX as u128 }
+}
+
+/// Returns the bound that an overflowing result with the given sign saturates at.
+const fn saturate(negative: bool) -> Decimal {
+    if negative { Decimal::MIN } else { Decimal::MAX }
+}
+
+#[unstable(feature = "decimal", issue = "none")]
+impl Default for Decimal {
+    #[inline]
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+#[unstable(feature = "decimal", issue = "none")]
+impl PartialEq for Decimal {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+#[unstable(feature = "decimal", issue = "none")]
+impl Eq for Decimal {}
+
+#[unstable(feature = "decimal", issue = "none")]
+impl PartialOrd for Decimal {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[unstable(feature = "decimal", issue = "none")]
+impl Ord for Decimal {
+    fn cmp(&self, other: &Self) -> Ordering {
+        if self.scale == other.scale {
+            return self.mantissa.cmp(&other.mantissa);
+        }
+        match (self.is_negative(), other.is_negative()) {
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            (negative, _) => {
+                let scale = self.scale.max(other.scale) as u32;
+                let ordering = self.magnitude_at(scale).cmp(&other.magnitude_at(scale));
+                if negative { ordering.reverse() } else { ordering }
+            }
+        }
+    }
+}
+
+#[unstable(feature = "decimal", issue = "none")]
+impl Hash for Decimal {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // Equal numbers have the same normalized form.
+        let Decimal { mantissa, scale } = self.normalize();
+        mantissa.hash(state);
+        scale.hash(state);
+    }
+}
+
+#[unstable(feature = "decimal", issue = "none")]
+impl Add for Decimal {
+    type Output = Decimal;
+
+    /// Adds like [`Decimal::checked_add`], and panics if the result cannot be represented
+    /// exactly.
+    #[inline]
+    #[track_caller]
+    fn add(self, rhs: Decimal) -> Decimal {
+        match self.checked_add(rhs) {
+            Some(x) => x,
+            None => panic!("attempt to add with overflow"),
+        }
+    }
+}
+forward_ref_binop! { impl Add, add for Decimal, Decimal,
+#[unstable(feature = "decimal", issue = "none")] }
+
+#[unstable(feature = "decimal", issue = "none")]
+impl Sub for Decimal {
+    type Output = Decimal;
+
+    /// Subtracts like [`Decimal::checked_sub`], and panics if the result cannot be represented
+    /// exactly.
+    #[inline]
+    #[track_caller]
+    fn sub(self, rhs: Decimal) -> Decimal {
+        match self.checked_sub(rhs) {
+            Some(x) => x,
+            None => panic!("attempt to subtract with overflow"),
+        }
+    }
+}
+forward_ref_binop! { impl Sub, sub for Decimal, Decimal,
+#[unstable(feature = "decimal", issue = "none")] }
+
+#[unstable(feature = "decimal", issue = "none")]
+impl Mul for Decimal {
+    type Output = Decimal;
+
+    /// Multiplies like [`Decimal::checked_mul`], and panics if the result cannot be represented
+    /// exactly.
+    #[inline]
+    #[track_caller]
+    fn mul(self, rhs: Decimal) -> Decimal {
+        match self.checked_mul(rhs) {
+            Some(x) => x,
+            None => panic!("attempt to multiply with overflow"),
+        }
+    }
+}
+forward_ref_binop! { impl Mul, mul for Decimal, Decimal,
+#[unstable(feature = "decimal", issue = "none")] }
+
+#[unstable(feature = "decimal", issue = "none")]
+impl Div for Decimal {
+    type Output = Decimal;
+
+    /// Divides like [`Decimal::checked_div`], and panics if `rhs` is zero or the integer part of
+    /// the result does not fit.
+    #[inline]
+    #[track_caller]
+    fn div(self, rhs: Decimal) -> Decimal {
+        if rhs.is_zero() {
+            panic!("attempt to divide by zero");
+        }
+        match self.checked_div(rhs) {
+            Some(x) => x,
+            None => panic!("attempt to divide with overflow"),
+        }
+    }
+}
+forward_ref_binop! { impl Div, div for Decimal, Decimal,
+#[unstable(feature = "decimal", issue = "none")] }
+
+#[unstable(feature = "decimal", issue = "none")]
+impl Rem for Decimal {
+    type Output = Decimal;
+
+    #[inline]
+    #[track_caller]
+    fn rem(self, rhs: Decimal) -> Decimal {
+        match self.checked_rem(rhs) {
+            Some(x) => x,
+            None => panic!("attempt to calculate the remainder with a divisor of zero"),
+        }
+    }
+}
+forward_ref_binop! { impl Rem, rem for Decimal, Decimal,
+#[unstable(feature = "decimal", issue = "none")] }
+
+#[unstable(feature = "decimal", issue = "none")]
+impl Neg for Decimal {
+    type Output = Decimal;
+
+    #[inline]
+    #[track_caller]
+    fn neg(self) -> Decimal {
+        match self.checked_neg() {
+            Some(x) => x,
+            None => panic!("attempt to negate with overflow"),
+        }
+    }
+}
+forward_ref_unop! { impl Neg, neg for Decimal,
+#[unstable(feature = "decimal", issue = "none")] }
+
+macro_rules! decimal_op_assign {
+    ($($Op:ident, $op:ident, $OpAssign:ident, $op_assign:ident;)*) => {$(
+        #[unstable(feature = "decimal", issue = "none")]
+        impl $OpAssign for Decimal {
+            #[inline]
+            #[track_caller]
+            fn $op_assign(&mut self, rhs: Decimal) {
+                *self = $Op::$op(*self, rhs);
+            }
+        }
+        forward_ref_op_assign! { impl $OpAssign, $op_assign for Decimal, Decimal,
+        #[unstable(feature = "decimal", issue = "none")] }
+    )*};
+}
+
+decimal_op_assign! {
+    Add, add, AddAssign, add_assign;
+    Sub, sub, SubAssign, sub_assign;
+    Mul, mul, MulAssign, mul_assign;
+    Div, div, DivAssign, div_assign;
+    Rem, rem, RemAssign, rem_assign;
+}
+
+#[unstable(feature = "decimal", issue = "none")]
+impl Sum for Decimal {
+    fn sum<I: Iterator<Item = Decimal>>(iter: I) -> Self {
+        iter.fold(Decimal::ZERO, |a, b| a + b)
+    }
+}
+
+#[unstable(feature = "decimal", issue = "none")]
+impl<'a> Sum<&'a Decimal> for Decimal {
+    fn sum<I: Iterator<Item = &'a Decimal>>(iter: I) -> Self {
+        iter.fold(Decimal::ZERO, |a, b| a + b)
+    }
+}
+
+#[unstable(feature = "decimal", issue = "none")]
+impl Product for Decimal {
+    fn product<I: Iterator<Item = Decimal>>(iter: I) -> Self {
+        iter.fold(Decimal::ONE, |a, b| a * b)
+    }
+}
+
+#[unstable(feature = "decimal", issue = "none")]
+impl<'a> Product<&'a Decimal> for Decimal {
+    fn product<I: Iterator<Item = &'a Decimal>>(iter: I) -> Self {
+        iter.fold(Decimal::ONE, |a, b| a * b)
+    }
+}
+
+macro_rules! decimal_from_int {
+    ($($t:ty)*) => {$(
+        #[unstable(feature = "decimal", issue = "none")]
+        impl From<$t> for Decimal {
+            #[inline]
+            fn from(n: $t) -> Self {
+                Decimal { mantissa: n as i128, scale: 0 }
+            }
+        }
+    )*};
+}
+
+decimal_from_int! { i8 i16 i32 i64 i128 u8 u16 u32 u64 }
+
+#[unstable(feature = "decimal", issue = "none")]
+impl TryFrom<u128> for Decimal {
+    type Error = crate::num::TryFromIntError;
+
+    #[inline]
+    fn try_from(n: u128) -> Result<Self, Self::Error> {
+        Ok(Decimal { mantissa: i128::try_from(n)?, scale: 0 })
+    }
+}
+
+/// An error which can be returned when parsing a [`Decimal`].
+///
+/// This error is used as the error type for the [`FromStr`] implementation for [`Decimal`].
+///
+/// # Example
+///
+/// ```
+/// #![feature(decimal)]
+///
+/// use std::num::Decimal;
+///
+/// if let Err(e) = "1.2.3".parse::<Decimal>() {
+///     println!("Failed conversion to Decimal: {e}");
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[unstable(feature = "decimal", issue = "none")]
+pub struct ParseDecimalError {
+    kind: DecimalErrorKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum DecimalErrorKind {
+    Empty,
+    Invalid,
+    Overflow,
+    TooPrecise,
+}
+
+#[unstable(feature = "decimal", issue = "none")]
+impl fmt::Display for ParseDecimalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self.kind {
+            DecimalErrorKind::Empty => "cannot parse decimal from empty string",
+            DecimalErrorKind::Invalid => "invalid decimal literal",
+            DecimalErrorKind::Overflow => "number too large to fit in a decimal",
+            DecimalErrorKind::TooPrecise => "number has too many digits after the decimal point",
+        })
+    }
+}
+
+#[unstable(feature = "decimal", issue = "none")]
+impl Error for ParseDecimalError {}
+
+/// Accumulates decimal digits into `x`, eight at a time where possible.
+fn accumulate_digits(mut s: &[u8], x: &mut u128) -> Option<()> {
+    while s.len() >= 8 && is_8digits(s.read_u64()) {
+        *x = x.checked_mul(1_0000_0000)?.checked_add(parse_8digits(s.read_u64()) as u128)?;
+        s = &s[8..];
+    }
+    for &c in s {
+        *x = x.checked_mul(10)?.checked_add((c - b'0') as u128)?;
+    }
+    Some(())
+}
+
+#[unstable(feature = "decimal", issue = "none")]
+impl FromStr for Decimal {
+    type Err = ParseDecimalError;
+
+    /// Parses a decimal number, with an optional sign, digits with an optional decimal point,
+    /// and an optional exponent, such as `-12.50` or `1.25e-3`.
+    ///
+    /// The scale of the result is the number of digits after the decimal point, minus the
+    /// exponent. Trailing zeros are only dropped if the scale would be greater than
+    /// [`Decimal::MAX_SCALE`], and the parsed number is always exact: numbers with too many
+    /// digits are an error rather than rounded.
+    fn from_str(src: &str) -> Result<Self, ParseDecimalError> {
+        let err = |kind| Err(ParseDecimalError { kind });
+        let mut s = src.as_bytes();
+        let negative = match s.first() {
+            None => return err(DecimalErrorKind::Empty),
+            Some(&c) => c == b'-',
+        };
+        if let Some((b'-' | b'+', rest)) = s.split_first() {
+            s = rest;
+        }
+
+        let int_start = s;
+        s = s.parse_digits(|_| {});
+        let int = &int_start[..int_start.len() - s.len()];
+        let mut frac: &[u8] = &[];
+        if let Some((b'.', rest)) = s.split_first() {
+            s = rest.parse_digits(|_| {});
+            frac = &rest[..rest.len() - s.len()];
+        }
+        if int.is_empty() && frac.is_empty() {
+            return err(DecimalErrorKind::Invalid);
+        }
+        let mut exponent = 0;
+        if let Some((b'e' | b'E', rest)) = s.split_first() {
+            s = rest;
+            let Some(e) = parse_scientific(&mut s) else { return err(DecimalErrorKind::Invalid) };
+            exponent = e;
+        }
+        if !s.is_empty() {
+            return err(DecimalErrorKind::Invalid);
+        }
+
+        // The digits stand for `digits * 10^-scale`. Drop trailing zeros that would make the
+        // scale too large, then make a negative scale zero.
+        let mut digits = (int, frac);
+        let mut scale = frac.len() as i64 - exponent;
+        while scale > Self::MAX_SCALE as i64 {
+            match digits {
+                (int, [frac @ .., b'0']) => digits = (int, frac),
+                ([int @ .., b'0'], []) => digits = (int, &[]),
+                // Zero has any scale.
+                _ if digits.0.iter().chain(digits.1).all(|&c| c == b'0') => return Ok(Self::ZERO),
+                _ => return err(DecimalErrorKind::TooPrecise),
+            }
+            scale -= 1;
+        }
+
+        let mut magnitude = 0;
+        let fits = accumulate_digits(digits.0, &mut magnitude)
+            .and_then(|()| accumulate_digits(digits.1, &mut magnitude))
+            .and_then(|()| {
+                if scale >= 0 {
+                    return Some(magnitude);
+                }
+                if magnitude == 0 {
+                    return Some(0);
+                }
+                magnitude.checked_mul(*POW10.get(scale.unsigned_abs() as usize)?)
+            })
+            .filter(|&m| m <= max_magnitude(negative));
+        match fits {
+            Some(magnitude) => Ok(Self::from_parts(negative, magnitude, scale.max(0) as u32)),
+            None => err(DecimalErrorKind::Overflow),
+        }
+    }
+}
+
+#[unstable(feature = "decimal", issue = "none")]
+impl fmt::Display for Decimal {
+    /// Formats the number with all the digits of its scale, or rounded half to even to the
+    /// precision of the formatter if that is smaller, and padded with zeros if it is larger.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let x = match f.precision() {
+            Some(precision) if precision < self.scale() as usize => {
+                self.round(precision as u32, RoundingMode::HalfToEven)
+            }
+            _ => *self,
+        };
+        let (negative, mut magnitude, scale) = x.parts();
+        let scale = scale as usize;
+
+        // The digits of the magnitude, most significant first.
+        let mut buf = [MaybeUninit::<u8>::uninit(); 39];
+        let mut start = buf.len();
+        loop {
+            start -= 1;
+            buf[start].write(b'0' + (magnitude % 10) as u8);
+            magnitude /= 10;
+            if magnitude == 0 {
+                break;
+            }
+        }
+        // SAFETY: `buf[start..]` was initialized above.
+        let digits = unsafe { buf[start..].assume_init_ref() };
+
+        let mut parts = [MaybeUninit::<numfmt::Part<'_>>::uninit(); 4];
+        let mut len = 0;
+        let mut push = |part| {
+            parts[len].write(part);
+            len += 1;
+        };
+        if scale == 0 {
+            push(numfmt::Part::Copy(digits));
+        } else if digits.len() > scale {
+            let (int, frac) = digits.split_at(digits.len() - scale);
+            push(numfmt::Part::Copy(int));
+            push(numfmt::Part::Copy(b"."));
+            push(numfmt::Part::Copy(frac));
+        } else {
+            push(numfmt::Part::Copy(b"0."));
+            push(numfmt::Part::Zero(scale - digits.len()));
+            push(numfmt::Part::Copy(digits));
+        }
+        let padding = f.precision().map_or(0, |precision| precision.saturating_sub(scale));
+        if padding > 0 {
+            if scale == 0 {
+                push(numfmt::Part::Copy(b"."));
+            }
+            push(numfmt::Part::Zero(padding));
+        }
+
+        let sign = if negative {
+            "-"
+        } else if f.sign_plus() {
+            "+"
+        } else {
+            ""
+        };
+        // SAFETY: `parts[..len]` was initialized above.
+        let formatted =
+            numfmt::Formatted { sign, parts: unsafe { parts[..len].assume_init_ref() } };
+        // SAFETY: the parts only contain ASCII digits and dots.
+        unsafe { f.pad_formatted_parts(&formatted) }
+    }
+}
+
+#[unstable(feature = "decimal", issue = "none")]
+impl fmt::Debug for Decimal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
@@ -43,6 +43,8 @@ macro_rules! sign_dependent_expr {
 #[macro_use]
 mod uint_macros; // import uint_impl!
 
+#[cfg(not(no_fp_fmt_parse))]
+mod decimal;
 mod error;
 mod int_log10;
 mod int_sqrt;
@@ -58,6 +60,9 @@ macro_rules! sign_dependent_expr {
 #[stable(feature = "rust1", since = "1.0.0")]
 #[cfg(not(no_fp_fmt_parse))]
 pub use dec2flt::ParseFloatError;
+#[unstable(feature = "decimal", issue = "none")]
+#[cfg(not(no_fp_fmt_parse))]
+pub use decimal::{Decimal, ParseDecimalError, RoundingMode};
 #[stable(feature = "int_error_matching", since = "1.55.0")]
 pub use error::IntErrorKind;
 #[stable(feature = "rust1", since = "1.0.0")]
@@ -2,12 +2,71 @@
 //!
 //! The [`Random`] trait allows generating a random value for a type using a
 //! given [`RandomSource`].
+//!
+//! [`ChaCha20Rng`] and [`Xoshiro256PlusPlus`] are deterministic sources that always produce the
+//! same values for the same seed, which makes them suitable for reproducible simulations and
+//! tests. Values can be sampled uniformly from a range with [`RandomSource::random_range`].
+
+use crate::ops::{Range, RangeInclusive};
+
+mod chacha;
+mod xoshiro;
+
+#[unstable(feature = "seedable_random", issue = "none")]
+pub use chacha::ChaCha20Rng;
+#[unstable(feature = "seedable_random", issue = "none")]
+pub use xoshiro::Xoshiro256PlusPlus;
 
 /// A source of randomness.
 #[unstable(feature = "random", issue = "130703")]
 pub trait RandomSource {
     /// Fills `bytes` with random bytes.
     fn fill_bytes(&mut self, bytes: &mut [u8]);
+
+    /// Generates a value uniformly distributed over `range`.
+    ///
+    /// Unlike reducing a random integer with a modulo operation, this is free of bias: every
+    /// value in the range is equally likely. Floating-point ranges are sampled by scaling a
+    /// random value in `[0, 1)`, see [`SampleRange`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` is empty, or if it is a floating-point range whose size is not finite.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(random, seedable_random, random_sampling)]
+    ///
+    /// use std::random::{RandomSource, Xoshiro256PlusPlus};
+    ///
+    /// let mut rng = Xoshiro256PlusPlus::seed_from_u64(7);
+    /// let die = rng.random_range(1..=6);
+    /// assert!((1..=6).contains(&die));
+    /// let angle = rng.random_range(0.0..std::f64::consts::TAU);
+    /// assert!((0.0..std::f64::consts::TAU).contains(&angle));
+    /// ```
+    #[unstable(feature = "random_sampling", issue = "none")]
+    fn random_range<T, R: SampleRange<T>>(&mut self, range: R) -> T
+    where
+        Self: Sized,
+    {
+        range.sample_single(self)
+    }
+}
+
+/// A range that values can be sampled from uniformly, used by [`RandomSource::random_range`].
+///
+/// This is implemented for [`Range`] and [`RangeInclusive`] of all primitive integer types, and
+/// for [`Range`] of `f32` and `f64`.
+#[unstable(feature = "random_sampling", issue = "none")]
+pub trait SampleRange<T> {
+    /// Generates a value uniformly distributed over `self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is empty.
+    fn sample_single(self, source: &mut (impl RandomSource + ?Sized)) -> T;
 }
 
 /// A trait for getting a random value for a type.
@@ -40,9 +99,11 @@ impl Random for $t {
             /// values can become more likely than others. Use audited crates when in
             /// doubt.
             fn random(source: &mut (impl RandomSource + ?Sized)) -> Self {
-                let mut bytes = (0 as Self).to_ne_bytes();
+                // Little-endian, so that a deterministic source produces the same values on
+                // every target.
+                let mut bytes = (0 as Self).to_le_bytes();
                 source.fill_bytes(&mut bytes);
-                Self::from_ne_bytes(bytes)
+                Self::from_le_bytes(bytes)
             }
         }
     };
@@ -60,3 +121,106 @@ fn random(source: &mut (impl RandomSource + ?Sized)) -> Self {
 impl_primitive!(i128);
 impl_primitive!(usize);
 impl_primitive!(isize);
+
+/// Returns the next value of the SplitMix64 sequence, used to expand 64-bit seeds.
+fn splitmix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9e3779b97f4a7c15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+    z ^ (z >> 31)
+}
+
+impl Random for f32 {
+    /// Generates a random value uniformly distributed in `[0, 1)`.
+    ///
+    /// Every multiple of 2<sup>-24</sup> in this interval is equally likely.
+    fn random(source: &mut (impl RandomSource + ?Sized)) -> Self {
+        (u32::random(source) >> (u32::BITS - f32::MANTISSA_DIGITS)) as f32
+            * (1.0 / (1u32 << f32::MANTISSA_DIGITS) as f32)
+    }
+}
+
+impl Random for f64 {
+    /// Generates a random value uniformly distributed in `[0, 1)`.
+    ///
+    /// Every multiple of 2<sup>-53</sup> in this interval is equally likely.
+    fn random(source: &mut (impl RandomSource + ?Sized)) -> Self {
+        (u64::random(source) >> (u64::BITS - f64::MANTISSA_DIGITS)) as f64
+            * (1.0 / (1u64 << f64::MANTISSA_DIGITS) as f64)
+    }
+}
+
+macro_rules! impl_sample_range_int {
+    ($($t:ty => $u:ty),*) => {$(
+        #[unstable(feature = "random_sampling", issue = "none")]
+        impl SampleRange<$t> for Range<$t> {
+            fn sample_single(self, source: &mut (impl RandomSource + ?Sized)) -> $t {
+                assert!(self.start < self.end, "cannot sample empty range");
+                let max = self.end.wrapping_sub(self.start) as $u - 1;
+                self.start.wrapping_add(sample_up_to!($u, max, source) as $t)
+            }
+        }
+
+        #[unstable(feature = "random_sampling", issue = "none")]
+        impl SampleRange<$t> for RangeInclusive<$t> {
+            fn sample_single(self, source: &mut (impl RandomSource + ?Sized)) -> $t {
+                assert!(!self.is_empty(), "cannot sample empty range");
+                let (start, end) = self.into_inner();
+                let max = end.wrapping_sub(start) as $u;
+                start.wrapping_add(sample_up_to!($u, max, source) as $t)
+            }
+        }
+    )*};
+}
+
+/// Generates a value of the unsigned type `$u` uniformly distributed in `0..=$max`.
+///
+/// Random values are masked to the bit width of `$max` and rejected if they are too large, which
+/// avoids the bias of a modulo reduction and needs fewer than two attempts on average.
+macro_rules! sample_up_to {
+    ($u:ty, $max:expr, $source:expr) => {{
+        let max: $u = $max;
+        // `checked_shr` handles `max == 0`, whose leading zeros are the full bit width.
+        let mask = <$u>::MAX.checked_shr(max.leading_zeros()).unwrap_or(0);
+        loop {
+            let x = <$u>::random($source) & mask;
+            if x <= max {
+                break x;
+            }
+        }
+    }};
+}
+
+impl_sample_range_int!(
+    u8 => u8, i8 => u8, u16 => u16, i16 => u16, u32 => u32, i32 => u32,
+    u64 => u64, i64 => u64, u128 => u128, i128 => u128, usize => usize, isize => usize
+);
+
+macro_rules! impl_sample_range_float {
+    ($($t:ty),*) => {$(
+        #[unstable(feature = "random_sampling", issue = "none")]
+        impl SampleRange<$t> for Range<$t> {
+            /// Generates a value uniformly distributed over `self`, by scaling a random value in
+            /// `[0, 1)` to the size of the range.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the range is empty or its size is not finite.
+            fn sample_single(self, source: &mut (impl RandomSource + ?Sized)) -> $t {
+                let Range { start, end } = self;
+                let size = end - start;
+                assert!(start < end && size.is_finite(), "cannot sample range {start}..{end}");
+                loop {
+                    // Rounding can produce `end`, which is excluded from the range.
+                    let x = start + size * <$t>::random(source);
+                    if x < end {
+                        break x;
+                    }
+                }
+            }
+        }
+    )*};
+}
+
+impl_sample_range_float!(f32, f64);
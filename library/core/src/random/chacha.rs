@@ -0,0 +1,178 @@
+use super::{Random, RandomSource};
+use crate::fmt;
+
+/// A deterministic random number generator based on the ChaCha20 stream cipher.
+///
+/// The output of this generator is the ChaCha20 keystream for the 256-bit seed as key, with a
+/// 64-bit block counter and a 64-bit stream identifier as nonce, as in the original design by
+/// Daniel J. Bernstein. The bytes it produces are therefore fully determined by its seed and
+/// stream, and the same on every platform and in every version of the standard library.
+///
+/// ChaCha20 is a cryptographically secure generator, but this type makes no attempt to protect
+/// its state: it can be cloned and is not zeroed on drop. [`Xoshiro256PlusPlus`] is several times
+/// faster and a better choice when the output only needs to be reproducible, not unpredictable.
+///
+/// [`Xoshiro256PlusPlus`]: super::Xoshiro256PlusPlus
+///
+/// # Examples
+///
+/// ```
+/// #![feature(random, seedable_random)]
+///
+/// use std::random::{ChaCha20Rng, Random};
+///
+/// let mut a = ChaCha20Rng::from_seed([7; 32]);
+/// let mut b = ChaCha20Rng::from_seed([7; 32]);
+/// assert_eq!(u64::random(&mut a), u64::random(&mut b));
+/// ```
+#[derive(Clone)]
+#[unstable(feature = "seedable_random", issue = "none")]
+pub struct ChaCha20Rng {
+    key: [u32; 8],
+    counter: u64,
+    stream: u64,
+    block: [u8; BLOCK_LEN],
+    /// The index of the next unused byte of `block`.
+    index: usize,
+}
+
+const BLOCK_LEN: usize = 64;
+const CONSTANTS: [u32; 4] = [0x6170_7865, 0x3320_646e, 0x7962_2d32, 0x6b20_6574];
+
+impl ChaCha20Rng {
+    /// Creates a generator from a 256-bit seed, which is used as the ChaCha20 key.
+    #[unstable(feature = "seedable_random", issue = "none")]
+    #[must_use]
+    pub fn from_seed(seed: [u8; 32]) -> Self {
+        let mut key = [0; 8];
+        for (word, bytes) in key.iter_mut().zip(seed.as_chunks::<4>().0) {
+            *word = u32::from_le_bytes(*bytes);
+        }
+        ChaCha20Rng { key, counter: 0, stream: 0, block: [0; BLOCK_LEN], index: BLOCK_LEN }
+    }
+
+    /// Creates a generator from a 64-bit seed.
+    ///
+    /// The seed is expanded to 256 bits with SplitMix64, so that similar seeds still lead to
+    /// unrelated outputs.
+    #[unstable(feature = "seedable_random", issue = "none")]
+    #[must_use]
+    pub fn seed_from_u64(seed: u64) -> Self {
+        let mut bytes = [0; 32];
+        let mut state = seed;
+        for chunk in bytes.as_chunks_mut::<8>().0 {
+            *chunk = super::splitmix64(&mut state).to_le_bytes();
+        }
+        Self::from_seed(bytes)
+    }
+
+    /// Switches the generator to the independent stream `stream`, restarting at its beginning.
+    ///
+    /// Generators with the same seed but different streams produce unrelated outputs, which
+    /// makes it easy to give every thread or task of a reproducible computation its own
+    /// generator. New generators start with stream 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(random, seedable_random)]
+    ///
+    /// use std::random::{ChaCha20Rng, Random};
+    ///
+    /// let mut workers: Vec<_> = (0..4)
+    ///     .map(|i| {
+    ///         let mut rng = ChaCha20Rng::seed_from_u64(1234);
+    ///         rng.set_stream(i);
+    ///         rng
+    ///     })
+    ///     .collect();
+    /// assert_ne!(u64::random(&mut workers[0]), u64::random(&mut workers[1]));
+    /// ```
+    #[unstable(feature = "seedable_random", issue = "none")]
+    pub fn set_stream(&mut self, stream: u64) {
+        self.stream = stream;
+        self.counter = 0;
+        self.index = BLOCK_LEN;
+    }
+
+    /// Returns the stream the generator is currently producing.
+    #[unstable(feature = "seedable_random", issue = "none")]
+    #[must_use]
+    pub fn stream(&self) -> u64 {
+        self.stream
+    }
+
+    /// Computes the next keystream block and advances the block counter.
+    fn refill(&mut self) {
+        let mut input = [0; 16];
+        input[..4].copy_from_slice(&CONSTANTS);
+        input[4..12].copy_from_slice(&self.key);
+        input[12] = self.counter as u32;
+        input[13] = (self.counter >> 32) as u32;
+        input[14] = self.stream as u32;
+        input[15] = (self.stream >> 32) as u32;
+
+        let mut x = input;
+        for _ in 0..10 {
+            quarter_round(&mut x, 0, 4, 8, 12);
+            quarter_round(&mut x, 1, 5, 9, 13);
+            quarter_round(&mut x, 2, 6, 10, 14);
+            quarter_round(&mut x, 3, 7, 11, 15);
+            quarter_round(&mut x, 0, 5, 10, 15);
+            quarter_round(&mut x, 1, 6, 11, 12);
+            quarter_round(&mut x, 2, 7, 8, 13);
+            quarter_round(&mut x, 3, 4, 9, 14);
+        }
+
+        for ((bytes, x), input) in self.block.as_chunks_mut::<4>().0.iter_mut().zip(x).zip(input) {
+            *bytes = x.wrapping_add(input).to_le_bytes();
+        }
+        self.counter = self.counter.wrapping_add(1);
+        self.index = 0;
+    }
+}
+
+#[inline(always)]
+fn quarter_round(x: &mut [u32; 16], a: usize, b: usize, c: usize, d: usize) {
+    x[a] = x[a].wrapping_add(x[b]);
+    x[d] = (x[d] ^ x[a]).rotate_left(16);
+    x[c] = x[c].wrapping_add(x[d]);
+    x[b] = (x[b] ^ x[c]).rotate_left(12);
+    x[a] = x[a].wrapping_add(x[b]);
+    x[d] = (x[d] ^ x[a]).rotate_left(8);
+    x[c] = x[c].wrapping_add(x[d]);
+    x[b] = (x[b] ^ x[c]).rotate_left(7);
+}
+
+#[unstable(feature = "seedable_random", issue = "none")]
+impl RandomSource for ChaCha20Rng {
+    fn fill_bytes(&mut self, mut bytes: &mut [u8]) {
+        while !bytes.is_empty() {
+            if self.index == BLOCK_LEN {
+                self.refill();
+            }
+            let n = bytes.len().min(BLOCK_LEN - self.index);
+            let (head, tail) = bytes.split_at_mut(n);
+            head.copy_from_slice(&self.block[self.index..self.index + n]);
+            self.index += n;
+            bytes = tail;
+        }
+    }
+}
+
+#[unstable(feature = "seedable_random", issue = "none")]
+impl Random for ChaCha20Rng {
+    /// Creates a generator seeded from `source`.
+    fn random(source: &mut (impl RandomSource + ?Sized)) -> Self {
+        let mut seed = [0; 32];
+        source.fill_bytes(&mut seed);
+        Self::from_seed(seed)
+    }
+}
+
+#[unstable(feature = "seedable_random", issue = "none")]
+impl fmt::Debug for ChaCha20Rng {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ChaCha20Rng").finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,140 @@
+use super::{Random, RandomSource};
+
+/// A fast deterministic random number generator based on xoshiro256++.
+///
+/// xoshiro256++ by David Blackman and Sebastiano Vigna has 256 bits of state, a period of
+/// 2<sup>256</sup> − 1 and passes all common statistical test suites, while only taking a few
+/// instructions per 64-bit output. The bytes it produces are fully determined by its seed, and
+/// the same on every platform and in every version of the standard library.
+///
+/// This generator is **not** cryptographically secure: its state can be recovered from a small
+/// amount of output. Use [`ChaCha20Rng`] if the output needs to be unpredictable.
+///
+/// [`ChaCha20Rng`]: super::ChaCha20Rng
+///
+/// # Examples
+///
+/// ```
+/// #![feature(random, seedable_random, random_sampling)]
+///
+/// use std::random::{RandomSource, Xoshiro256PlusPlus};
+///
+/// let mut rng = Xoshiro256PlusPlus::seed_from_u64(42);
+/// let rolls: Vec<u8> = (0..10).map(|_| rng.random_range(1..=6)).collect();
+/// assert!(rolls.iter().all(|roll| (1..=6).contains(roll)));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[unstable(feature = "seedable_random", issue = "none")]
+pub struct Xoshiro256PlusPlus {
+    s: [u64; 4],
+}
+
+impl Xoshiro256PlusPlus {
+    /// Creates a generator from a 256-bit seed, which is used as the generator state.
+    ///
+    /// The all-zero state is a fixed point of xoshiro, so an all-zero `seed` is replaced by
+    /// the state [`seed_from_u64(0)`](Self::seed_from_u64) would produce.
+    #[unstable(feature = "seedable_random", issue = "none")]
+    #[must_use]
+    pub fn from_seed(seed: [u8; 32]) -> Self {
+        if seed == [0; 32] {
+            return Self::seed_from_u64(0);
+        }
+        let mut s = [0; 4];
+        for (word, bytes) in s.iter_mut().zip(seed.as_chunks::<8>().0) {
+            *word = u64::from_le_bytes(*bytes);
+        }
+        Xoshiro256PlusPlus { s }
+    }
+
+    /// Creates a generator from a 64-bit seed.
+    ///
+    /// The seed is expanded to 256 bits with SplitMix64, as recommended by the authors of
+    /// xoshiro, so that similar seeds still lead to unrelated outputs.
+    #[unstable(feature = "seedable_random", issue = "none")]
+    #[must_use]
+    pub fn seed_from_u64(seed: u64) -> Self {
+        let mut state = seed;
+        let s = [(); 4].map(|()| super::splitmix64(&mut state));
+        Xoshiro256PlusPlus { s }
+    }
+
+    /// Returns the next 64 bits of output.
+    fn next(&mut self) -> u64 {
+        let s = &mut self.s;
+        let result = s[0].wrapping_add(s[3]).rotate_left(23).wrapping_add(s[0]);
+        let t = s[1] << 17;
+        s[2] ^= s[0];
+        s[3] ^= s[1];
+        s[1] ^= s[2];
+        s[0] ^= s[3];
+        s[2] ^= t;
+        s[3] = s[3].rotate_left(45);
+        result
+    }
+
+    /// Advances the generator by 2<sup>128</sup> steps.
+    ///
+    /// This can be used to split one seed into 2<sup>128</sup> non-overlapping sequences of
+    /// 2<sup>128</sup> outputs each, for example one per thread of a parallel simulation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(seedable_random)]
+    ///
+    /// use std::random::Xoshiro256PlusPlus;
+    ///
+    /// let mut rng = Xoshiro256PlusPlus::seed_from_u64(1234);
+    /// let workers: Vec<_> = (0..4)
+    ///     .map(|_| {
+    ///         let worker = rng.clone();
+    ///         rng.jump();
+    ///         worker
+    ///     })
+    ///     .collect();
+    /// assert_ne!(workers[0], workers[1]);
+    /// ```
+    #[unstable(feature = "seedable_random", issue = "none")]
+    pub fn jump(&mut self) {
+        const JUMP: [u64; 4] =
+            [0x180ec6d33cfd0aba, 0xd5a61266f0c9392c, 0xa9582618e03fc9aa, 0x39abdc4529b1661c];
+
+        let mut s = [0; 4];
+        for word in JUMP {
+            for bit in 0..64 {
+                if word & (1 << bit) != 0 {
+                    for (s, t) in s.iter_mut().zip(self.s) {
+                        *s ^= t;
+                    }
+                }
+                self.next();
+            }
+        }
+        self.s = s;
+    }
+}
+
+#[unstable(feature = "seedable_random", issue = "none")]
+impl RandomSource for Xoshiro256PlusPlus {
+    fn fill_bytes(&mut self, bytes: &mut [u8]) {
+        let (chunks, rest) = bytes.as_chunks_mut::<8>();
+        for chunk in chunks {
+            *chunk = self.next().to_le_bytes();
+        }
+        if !rest.is_empty() {
+            let last = self.next().to_le_bytes();
+            rest.copy_from_slice(&last[..rest.len()]);
+        }
+    }
+}
+
+#[unstable(feature = "seedable_random", issue = "none")]
+impl Random for Xoshiro256PlusPlus {
+    /// Creates a generator seeded from `source`.
+    fn random(source: &mut (impl RandomSource + ?Sized)) -> Self {
+        let mut seed = [0; 32];
+        source.fill_bytes(&mut seed);
+        Self::from_seed(seed)
+    }
+}
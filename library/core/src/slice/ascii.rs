@@ -3,7 +3,6 @@
 use core::ascii::EscapeDefault;
 
 use crate::fmt::{self, Write};
-#[cfg(not(all(target_arch = "x86_64", target_feature = "sse2")))]
 use crate::intrinsics::const_eval_select;
 use crate::{ascii, iter, ops};
 
@@ -53,6 +52,7 @@ pub const fn as_ascii(&self) -> Option<&[ascii::Char]> {
     /// but without allocating and copying temporaries.
     #[stable(feature = "ascii_methods_on_intrinsics", since = "1.23.0")]
     #[rustc_const_unstable(feature = "const_eq_ignore_ascii_case", issue = "131719")]
+    #[rustc_allow_const_fn_unstable(const_eval_select)] // fallback impl has same behavior
     #[must_use]
     #[inline]
     pub const fn eq_ignore_ascii_case(&self, other: &[u8]) -> bool {
@@ -60,22 +60,44 @@ pub const fn eq_ignore_ascii_case(&self, other: &[u8]) -> bool {
             return false;
         }
 
-        // FIXME(const-hack): This implementation can be reverted when
-        // `core::iter::zip` is allowed in const. The original implementation:
-        //  self.len() == other.len() && iter::zip(self, other).all(|(a, b)| a.eq_ignore_ascii_case(b))
-        let mut a = self;
-        let mut b = other;
+        const_eval_select!(
+            @capture { a: &[u8] = self, b: &[u8] = other } -> bool:
+            if const {
+                // FIXME(const-hack): This implementation can be reverted when
+                // `core::iter::zip` is allowed in const. The original implementation:
+                //  self.len() == other.len() && iter::zip(self, other).all(|(a, b)| a.eq_ignore_ascii_case(b))
+                let mut a = a;
+                let mut b = b;
+
+                while let ([first_a, rest_a @ ..], [first_b, rest_b @ ..]) = (a, b) {
+                    if first_a.eq_ignore_ascii_case(&first_b) {
+                        a = rest_a;
+                        b = rest_b;
+                    } else {
+                        return false;
+                    }
+                }
 
-        while let ([first_a, rest_a @ ..], [first_b, rest_b @ ..]) = (a, b) {
-            if first_a.eq_ignore_ascii_case(&first_b) {
-                a = rest_a;
-                b = rest_b;
+                true
             } else {
-                return false;
+                // Compare whole chunks without an early exit per byte, so that
+                // the comparison of a chunk gets vectorized.
+                const CHUNK_SIZE: usize = 16;
+
+                let (a_chunks, a_rest) = a.as_chunks::<CHUNK_SIZE>();
+                let (b_chunks, b_rest) = b.as_chunks::<CHUNK_SIZE>();
+                for (a, b) in iter::zip(a_chunks, b_chunks) {
+                    let mut equal = true;
+                    for i in 0..CHUNK_SIZE {
+                        equal &= a[i].to_ascii_lowercase() == b[i].to_ascii_lowercase();
+                    }
+                    if !equal {
+                        return false;
+                    }
+                }
+                iter::zip(a_rest, b_rest).all(|(a, b)| a.eq_ignore_ascii_case(b))
             }
-        }
-
-        true
+        )
     }
 
     /// Converts this slice to its ASCII upper case equivalent in-place.
@@ -12,6 +12,7 @@
 use crate::num::NonZero;
 use crate::ops::{OneSidedRange, OneSidedRangeBound, Range, RangeBounds, RangeInclusive};
 use crate::panic::const_panic;
+use crate::random::{RandomSource, SampleRange};
 use crate::simd::{self, Simd};
 use crate::ub_checks::assert_unsafe_precondition;
 use crate::{fmt, hint, ptr, range, slice};
@@ -75,6 +76,8 @@
 pub use raw::{from_mut_ptr_range, from_ptr_range};
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use raw::{from_raw_parts, from_raw_parts_mut};
+#[unstable(feature = "slice_radix_sort", issue = "none")]
+pub use sort::stable::radix::RadixKey;
 
 /// Calculates the direction and split point of a one-sided range.
 ///
@@ -1027,6 +1030,37 @@ const fn revswap<T>(a: &mut [T], b: &mut [T], n: usize) {
         }
     }
 
+    /// Shuffles the slice in place, so that every permutation of its elements is equally likely.
+    ///
+    /// This uses the Fisher-Yates shuffle, which takes *O*(*n*) time and draws one value from
+    /// `source` per element. With a deterministic source such as
+    /// [`Xoshiro256PlusPlus`](crate::random::Xoshiro256PlusPlus), the resulting order only
+    /// depends on the seed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(random_sampling, seedable_random)]
+    ///
+    /// use std::random::Xoshiro256PlusPlus;
+    ///
+    /// let mut rng = Xoshiro256PlusPlus::seed_from_u64(42);
+    /// let mut v = [1, 2, 3, 4, 5];
+    /// v.shuffle(&mut rng);
+    ///
+    /// v.sort();
+    /// assert_eq!(v, [1, 2, 3, 4, 5]);
+    /// ```
+    #[unstable(feature = "random_sampling", issue = "none")]
+    pub fn shuffle(&mut self, source: &mut (impl RandomSource + ?Sized)) {
+        for i in (1..self.len()).rev() {
+            // Indices are sampled as `u64` so that the order does not depend on the width of
+            // `usize`. `j <= i`, so narrowing it back is lossless.
+            let j = (0..=i as u64).sample_single(source) as usize;
+            self.swap(i, j);
+        }
+    }
+
     /// Returns an iterator over the slice.
     ///
     /// The iterator yields all items from start to end.
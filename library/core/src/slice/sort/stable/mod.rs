@@ -10,6 +10,7 @@
 use crate::{cfg_match, intrinsics};
 
 pub(crate) mod merge;
+pub mod radix;
 
 #[cfg(not(any(feature = "optimize_for_size", target_pointer_width = "16")))]
 pub(crate) mod drift;
@@ -0,0 +1,220 @@
+//! This module contains a stable LSD radix sort, used by `slice::sort_by_radix_key`.
+
+use crate::mem::{self, MaybeUninit};
+use crate::slice::sort::stable::BufGuard;
+use crate::{intrinsics, ptr};
+
+mod private {
+    #[unstable(feature = "slice_radix_sort", issue = "none")]
+    pub trait Sealed {}
+}
+
+/// A key type that can be sorted byte by byte, used by [`sort_by_radix_key`].
+///
+/// This trait is implemented for the primitive integer types, `bool` and `char`, for arrays of
+/// radix keys (which makes `[u8; N]` a fixed-size byte string key), and for pairs and triples of
+/// radix keys. The byte-wise order of every implementation agrees with its [`Ord`] implementation.
+///
+/// This trait is sealed and cannot be implemented outside of the standard library.
+///
+/// [`sort_by_radix_key`]: ../../std/primitive.slice.html#method.sort_by_radix_key
+#[unstable(feature = "slice_radix_sort", issue = "none")]
+pub trait RadixKey: Copy + Ord + private::Sealed {
+    /// The number of bytes a key is made of.
+    #[doc(hidden)]
+    const LEVELS: usize;
+
+    /// Returns the byte of the key at `level`, where level 0 is the most significant byte.
+    ///
+    /// `level` is always less than `Self::LEVELS`.
+    #[doc(hidden)]
+    fn byte(&self, level: usize) -> u8;
+}
+
+macro_rules! radix_key_unsigned {
+    ($($t:ty)*) => {$(
+        #[unstable(feature = "slice_radix_sort", issue = "none")]
+        impl private::Sealed for $t {}
+
+        #[unstable(feature = "slice_radix_sort", issue = "none")]
+        impl RadixKey for $t {
+            const LEVELS: usize = size_of::<$t>();
+
+            #[inline]
+            fn byte(&self, level: usize) -> u8 {
+                (*self >> ((Self::LEVELS - 1 - level) * 8)) as u8
+            }
+        }
+    )*};
+}
+
+macro_rules! radix_key_signed {
+    ($($t:ty => $u:ty)*) => {$(
+        #[unstable(feature = "slice_radix_sort", issue = "none")]
+        impl private::Sealed for $t {}
+
+        #[unstable(feature = "slice_radix_sort", issue = "none")]
+        impl RadixKey for $t {
+            const LEVELS: usize = size_of::<$t>();
+
+            #[inline]
+            fn byte(&self, level: usize) -> u8 {
+                // Flipping the sign bit maps `MIN..=MAX` onto `0..=<$u>::MAX` in order.
+                ((*self as $u) ^ (1 << (<$u>::BITS - 1))).byte(level)
+            }
+        }
+    )*};
+}
+
+radix_key_unsigned! { u8 u16 u32 u64 u128 usize }
+radix_key_signed! { i8 => u8 i16 => u16 i32 => u32 i64 => u64 i128 => u128 isize => usize }
+
+#[unstable(feature = "slice_radix_sort", issue = "none")]
+impl private::Sealed for bool {}
+
+#[unstable(feature = "slice_radix_sort", issue = "none")]
+impl RadixKey for bool {
+    const LEVELS: usize = 1;
+
+    #[inline]
+    fn byte(&self, _level: usize) -> u8 {
+        *self as u8
+    }
+}
+
+#[unstable(feature = "slice_radix_sort", issue = "none")]
+impl private::Sealed for char {}
+
+#[unstable(feature = "slice_radix_sort", issue = "none")]
+impl RadixKey for char {
+    // The most significant byte of a `char` is always zero.
+    const LEVELS: usize = 3;
+
+    #[inline]
+    fn byte(&self, level: usize) -> u8 {
+        (*self as u32).byte(level + 1)
+    }
+}
+
+#[unstable(feature = "slice_radix_sort", issue = "none")]
+impl<K: RadixKey, const N: usize> private::Sealed for [K; N] {}
+
+#[unstable(feature = "slice_radix_sort", issue = "none")]
+impl<K: RadixKey, const N: usize> RadixKey for [K; N] {
+    const LEVELS: usize = N * K::LEVELS;
+
+    #[inline]
+    fn byte(&self, level: usize) -> u8 {
+        self[level / K::LEVELS].byte(level % K::LEVELS)
+    }
+}
+
+#[unstable(feature = "slice_radix_sort", issue = "none")]
+impl<A: RadixKey, B: RadixKey> private::Sealed for (A, B) {}
+
+#[unstable(feature = "slice_radix_sort", issue = "none")]
+impl<A: RadixKey, B: RadixKey> RadixKey for (A, B) {
+    const LEVELS: usize = A::LEVELS + B::LEVELS;
+
+    #[inline]
+    fn byte(&self, level: usize) -> u8 {
+        if level < A::LEVELS { self.0.byte(level) } else { self.1.byte(level - A::LEVELS) }
+    }
+}
+
+#[unstable(feature = "slice_radix_sort", issue = "none")]
+impl<A: RadixKey, B: RadixKey, C: RadixKey> private::Sealed for (A, B, C) {}
+
+#[unstable(feature = "slice_radix_sort", issue = "none")]
+impl<A: RadixKey, B: RadixKey, C: RadixKey> RadixKey for (A, B, C) {
+    const LEVELS: usize = A::LEVELS + B::LEVELS + C::LEVELS;
+
+    #[inline]
+    fn byte(&self, level: usize) -> u8 {
+        if level < A::LEVELS {
+            self.0.byte(level)
+        } else {
+            (self.1, self.2).byte(level - A::LEVELS)
+        }
+    }
+}
+
+/// Stably sorts `v` by the first element of each pair.
+///
+/// Inputs that are short compared to the width of the key are sorted with the regular stable
+/// sort, as a handful of comparisons is cheaper than a full counting pass per key byte. Otherwise
+/// an LSD radix sort is used, which performs one counting and one scatter pass per key byte,
+/// skipping bytes that are the same for every key. It needs scratch space for `v.len()` pairs.
+#[inline(never)]
+pub fn sort<K: RadixKey, V: Copy, BufT: BufGuard<(K, V)>>(v: &mut [(K, V)]) {
+    let len = v.len();
+    if K::LEVELS == 0 || intrinsics::likely(len < 2) {
+        return;
+    }
+
+    // Below this length the counting arrays alone outweigh the elements.
+    const MIN_RADIX_LEN: usize = 256;
+    let log2_len = (usize::BITS - len.leading_zeros()) as usize;
+    if len < MIN_RADIX_LEN || K::LEVELS > 2 * log2_len {
+        super::sort::<(K, V), _, BufT>(v, &mut |a, b| a.0.lt(&b.0));
+        return;
+    }
+
+    let mut buf = BufT::with_capacity(len);
+    let scratch = &mut buf.as_uninit_slice_mut()[..len];
+
+    lsd_radix_sort(v, scratch);
+}
+
+/// Every pass moves all elements from `src` to `dst`, so whichever of the two `src` points to
+/// holds a permutation of the input. The elements are `Copy` and `RadixKey::byte` is only
+/// implemented in this module and cannot panic, so no element is ever dropped or observably
+/// duplicated.
+fn lsd_radix_sort<K: RadixKey, V: Copy>(v: &mut [(K, V)], scratch: &mut [MaybeUninit<(K, V)>]) {
+    let len = v.len();
+    assert!(scratch.len() >= len);
+    let v_base = v.as_mut_ptr();
+    let mut src = v_base;
+    let mut dst = scratch.as_mut_ptr().cast::<(K, V)>();
+
+    for level in (0..K::LEVELS).rev() {
+        let mut offsets = [0usize; 256];
+        for i in 0..len {
+            // SAFETY: `i < len` and all elements in `src` are initialized.
+            let key = unsafe { &(*src.add(i)).0 };
+            offsets[key.byte(level) as usize] += 1;
+        }
+
+        // All keys share this byte, so this pass would not move anything.
+        if offsets.contains(&len) {
+            continue;
+        }
+
+        let mut sum = 0;
+        for offset in &mut offsets {
+            let count = *offset;
+            *offset = sum;
+            sum += count;
+        }
+
+        for i in 0..len {
+            // SAFETY: `i < len` and all elements in `src` are initialized. The offsets of each
+            // byte value span exactly as many slots as there are keys with that byte, so
+            // `offsets[b]` stays below `len`.
+            unsafe {
+                let elem = src.add(i).read();
+                let slot = &mut offsets[elem.0.byte(level) as usize];
+                dst.add(*slot).write(elem);
+                *slot += 1;
+            }
+        }
+
+        mem::swap(&mut src, &mut dst);
+    }
+
+    if src != v_base {
+        // SAFETY: the sorted elements are in `scratch`, which is valid for `len` elements and
+        // does not overlap `v`.
+        unsafe { ptr::copy_nonoverlapping(src, v_base, len) };
+    }
+}
@@ -121,10 +121,223 @@ const fn contains_nonascii(x: usize) -> bool {
 
 /// Walks through `v` checking that it's a valid UTF-8 sequence,
 /// returning `Ok(())` in that case, or, if it is invalid, `Err(err)`.
+///
+/// Runs of ASCII are skipped a word at a time. Everything else goes through
+/// a shift-based DFA, which has no data-dependent branches and is therefore
+/// much faster than a byte-wise state machine on non-ASCII text, all the more
+/// so because two instances of it run side by side. The DFA only tells whether
+/// the input is valid, so on invalid input the precise error is determined by
+/// [`run_utf8_validation_from`], starting at the last position known to be a
+/// char boundary.
 #[inline(always)]
 #[rustc_allow_const_fn_unstable(const_eval_select)] // fallback impl has same behavior
 pub(super) const fn run_utf8_validation(v: &[u8]) -> Result<(), Utf8Error> {
+    // The DFA checks for rejection once per chunk, to keep the inner loop
+    // free of branches.
+    const CHUNK_SIZE: usize = 32;
+
+    let len = v.len();
+    // Below, we safely fall back to a slower codepath if the offset is `usize::MAX`,
+    // so the end-to-end behavior is the same at compiletime and runtime.
+    let align = const_eval_select!(
+        @capture { v: &[u8] } -> usize:
+        if const {
+            usize::MAX
+        } else {
+            v.as_ptr().align_offset(size_of::<usize>())
+        }
+    );
+
     let mut index = 0;
+    let mut state = ACCEPT;
+    // A char boundary at or before the first invalid byte.
+    let mut boundary = 0;
+    while index < len {
+        if state & STATE_MASK == ACCEPT {
+            index = skip_ascii(v, index, align);
+            boundary = index;
+            if index == len {
+                break;
+            }
+        }
+
+        if len - index >= 2 * CHUNK_SIZE {
+            // Every step of the DFA depends on the previous one, so split the
+            // next two chunks at a char boundary and run a second DFA over the
+            // second part at the same time. If there is no char boundary among
+            // the four candidates, the second DFA rejects.
+            let mut mid = index + CHUNK_SIZE;
+            while mid > index + CHUNK_SIZE - 3 && utf8_is_cont_byte(v[mid]) {
+                mid -= 1;
+            }
+            let (_, rest) = v.split_at(index);
+            let (first, rest) = rest.split_at(mid - index);
+            let (second, _) = rest.split_at(CHUNK_SIZE);
+            let mut second_state = ACCEPT;
+            let mut i = 0;
+            while i < first.len() {
+                state = dfa_step(state, first[i]);
+                second_state = dfa_step(second_state, second[i]);
+                i += 1;
+            }
+            while i < second.len() {
+                second_state = dfa_step(second_state, second[i]);
+                i += 1;
+            }
+            // `mid` is a char boundary, so the first part must end in one.
+            if state & STATE_MASK != ACCEPT {
+                break;
+            }
+            state = second_state;
+            index = mid + CHUNK_SIZE;
+        } else {
+            let (_, chunk) = v.split_at(index);
+            let mut i = 0;
+            while i < chunk.len() {
+                state = dfa_step(state, chunk[i]);
+                i += 1;
+            }
+            index = len;
+        }
+        if state & STATE_MASK == REJECT {
+            break;
+        }
+    }
+
+    if state & STATE_MASK == ACCEPT {
+        Ok(())
+    } else {
+        let result = run_utf8_validation_from(v, boundary);
+        debug_assert!(result.is_err());
+        result
+    }
+}
+
+/// Returns the index of the first non-ASCII byte in `v` at or after `index`,
+/// or `v.len()` if there is none.
+///
+/// `align` is the offset of the first `usize`-aligned byte of `v`, or
+/// `usize::MAX` to check byte by byte.
+#[inline(always)]
+const fn skip_ascii(v: &[u8], mut index: usize, align: usize) -> usize {
+    const USIZE_BYTES: usize = size_of::<usize>();
+
+    let len = v.len();
+    let ascii_block_size = 2 * USIZE_BYTES;
+    let blocks_end = if len >= ascii_block_size { len - ascii_block_size + 1 } else { 0 };
+
+    if align != usize::MAX && index < blocks_end {
+        while align.wrapping_sub(index) % USIZE_BYTES != 0 {
+            if v[index] >= 128 {
+                return index;
+            }
+            index += 1;
+        }
+        // When the pointer is aligned, read 2 words of data per iteration
+        // until we find a word containing a non-ascii byte.
+        let ptr = v.as_ptr();
+        while index < blocks_end {
+            // SAFETY: since `align - index` and `ascii_block_size` are
+            // multiples of `USIZE_BYTES`, `block = ptr.add(index)` is
+            // always aligned with a `usize` so it's safe to dereference
+            // both `block` and `block.add(1)`.
+            unsafe {
+                let block = ptr.add(index) as *const usize;
+                // break if there is a nonascii byte
+                let zu = contains_nonascii(*block);
+                let zv = contains_nonascii(*block.add(1));
+                if zu || zv {
+                    break;
+                }
+            }
+            index += ascii_block_size;
+        }
+    }
+    // step from the point where the wordwise loop stopped
+    while index < len && v[index] < 128 {
+        index += 1;
+    }
+    index
+}
+
+// States of the UTF-8 DFA, see `dfa_step`. Each state is the bit offset of its
+// column in the transition table.
+const ACCEPT: u64 = 0;
+const REJECT: u64 = 6;
+/// One continuation byte is left.
+const CONT1: u64 = 12;
+/// Two continuation bytes are left.
+const CONT2: u64 = 18;
+/// Three continuation bytes are left.
+const CONT3: u64 = 24;
+/// After `E0`, which needs `A0..=BF` to not be overlong.
+const AFTER_E0: u64 = 30;
+/// After `ED`, which needs `80..=9F` to not be a surrogate.
+const AFTER_ED: u64 = 36;
+/// After `F0`, which needs `90..=BF` to not be overlong.
+const AFTER_F0: u64 = 42;
+/// After `F4`, which needs `80..=8F` to stay below `U+110000`.
+const AFTER_F4: u64 = 48;
+/// Masks the state out of the value returned by `dfa_step`.
+const STATE_MASK: u64 = 0b11_1111;
+
+/// The transition table of the UTF-8 DFA. The entry for a byte packs the next
+/// state for every current state `s` into the six bits at offset `s`, so that
+/// a transition is a single load, shift and mask.
+const DFA_TABLE: &[u64; 256] = &{
+    const fn next_state(state: u64, byte: u8) -> u64 {
+        match (state, byte) {
+            (ACCEPT, 0x00..=0x7F) => ACCEPT,
+            (ACCEPT, 0xC2..=0xDF) => CONT1,
+            (ACCEPT, 0xE0) => AFTER_E0,
+            (ACCEPT, 0xE1..=0xEC | 0xEE..=0xEF) => CONT2,
+            (ACCEPT, 0xED) => AFTER_ED,
+            (ACCEPT, 0xF0) => AFTER_F0,
+            (ACCEPT, 0xF1..=0xF3) => CONT3,
+            (ACCEPT, 0xF4) => AFTER_F4,
+            (CONT1, 0x80..=0xBF) => ACCEPT,
+            (CONT2, 0x80..=0xBF) | (AFTER_E0, 0xA0..=0xBF) | (AFTER_ED, 0x80..=0x9F) => CONT1,
+            (CONT3, 0x80..=0xBF) | (AFTER_F0, 0x90..=0xBF) | (AFTER_F4, 0x80..=0x8F) => CONT2,
+            _ => REJECT,
+        }
+    }
+
+    const STATES: [u64; 9] =
+        [ACCEPT, REJECT, CONT1, CONT2, CONT3, AFTER_E0, AFTER_ED, AFTER_F0, AFTER_F4];
+
+    let mut table = [0; 256];
+    let mut byte = 0;
+    while byte < 256 {
+        let mut i = 0;
+        while i < STATES.len() {
+            let state = STATES[i];
+            table[byte] |= next_state(state, byte as u8) << state;
+            i += 1;
+        }
+        byte += 1;
+    }
+    table
+};
+
+/// Feeds `byte` to the DFA in `state`.
+///
+/// Only the low six bits of the result are the new state, the bits above them
+/// are left over from the table entry. They are harmless because shifts mask
+/// their amount to six bits, which saves masking on every step; compare with
+/// `state & STATE_MASK`.
+#[inline(always)]
+const fn dfa_step(state: u64, byte: u8) -> u64 {
+    DFA_TABLE[byte as usize].wrapping_shr(state as u32)
+}
+
+/// Walks through `v` from `index`, which must be a char boundary, checking
+/// that it's a valid UTF-8 sequence, returning `Ok(())` in that case, or, if
+/// it is invalid, `Err(err)`.
+///
+/// This is a byte-wise state machine, which is slower than the DFA used by
+/// [`run_utf8_validation`] on non-ASCII text, but pinpoints the error.
+#[rustc_allow_const_fn_unstable(const_eval_select)] // fallback impl has same behavior
+const fn run_utf8_validation_from(v: &[u8], mut index: usize) -> Result<(), Utf8Error> {
     let len = v.len();
 
     const USIZE_BYTES: usize = size_of::<usize>();
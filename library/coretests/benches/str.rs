@@ -6,6 +6,7 @@
 mod corpora;
 mod debug;
 mod iter;
+mod validate;
 
 #[bench]
 fn str_validate_emoji(b: &mut Bencher) {
@@ -0,0 +1,65 @@
+use std::str;
+
+use test::{Bencher, black_box};
+
+use super::corpora;
+
+macro_rules! define_benches {
+    ($( fn $name: ident($arg: ident: &str) $body: block )+) => {
+        define_benches!(mod en $($name $arg $body)+);
+        define_benches!(mod zh $($name $arg $body)+);
+        define_benches!(mod ru $($name $arg $body)+);
+        define_benches!(mod emoji $($name $arg $body)+);
+    };
+    (mod $corpus: ident $($name: ident $arg: ident $body: block)+) => {
+        mod $corpus {
+            use super::*;
+            $(
+                #[bench]
+                fn $name(bencher: &mut Bencher) {
+                    let $arg: &str = corpora::$corpus::HUGE;
+                    bencher.bytes = $arg.len() as u64;
+                    bencher.iter(|| black_box($body))
+                }
+            )+
+        }
+    };
+}
+
+define_benches! {
+    fn from_utf8(s: &str) {
+        str::from_utf8(black_box(s.as_bytes())).is_ok()
+    }
+
+    fn from_utf8_unaligned(s: &str) {
+        let start = s.chars().next().unwrap().len_utf8();
+        str::from_utf8(black_box(&s.as_bytes()[start..])).is_ok()
+    }
+
+    fn utf8_chunks(s: &str) {
+        black_box(s.as_bytes()).utf8_chunks().count()
+    }
+
+    fn eq_ignore_ascii_case(s: &str) {
+        black_box(s).eq_ignore_ascii_case(black_box(s))
+    }
+}
+
+#[bench]
+fn from_utf8_error_at_end(b: &mut Bencher) {
+    let mut bytes = corpora::ru::HUGE.as_bytes().to_vec();
+    bytes.push(0xFF);
+    b.bytes = bytes.len() as u64;
+    b.iter(|| str::from_utf8(black_box(&bytes)).unwrap_err().valid_up_to());
+}
+
+#[bench]
+fn utf8_chunks_invalid(b: &mut Bencher) {
+    // A mostly valid input, with an invalid byte every few hundred bytes.
+    let mut bytes = corpora::zh::HUGE.as_bytes().to_vec();
+    for i in (0..bytes.len()).step_by(401) {
+        bytes[i] = 0xFF;
+    }
+    b.bytes = bytes.len() as u64;
+    b.iter(|| black_box(&bytes).utf8_chunks().count());
+}
@@ -112,6 +112,26 @@ fn test_eq_ignore_ascii_case() {
     }
 }
 
+#[test]
+fn test_eq_ignore_ascii_case_long() {
+    let a = b"The Quick Brown Fox Jumps Over The Lazy Dog, 0123456789 @[`{ \xC3\x9C".repeat(3);
+    let b = a.to_ascii_lowercase();
+    assert!(a.eq_ignore_ascii_case(&b));
+    assert!(!a.eq_ignore_ascii_case(&b[1..]));
+
+    // A difference anywhere is found, whether in a full chunk or in the tail.
+    for i in 0..a.len() {
+        for (x, y) in [(b'a', b'b'), (b'@', b'`'), (b'[', b'{'), (0xC3, 0xE3)] {
+            let mut a = a.clone();
+            let mut b = b.clone();
+            a[i] = x;
+            b[i] = y;
+            assert!(!a.eq_ignore_ascii_case(&b));
+            assert!(!b.eq_ignore_ascii_case(&a));
+        }
+    }
+}
+
 #[test]
 fn inference_works() {
     let x = "a".to_string();
@@ -0,0 +1,70 @@
+use core::iter::*;
+
+use super::*;
+
+#[test]
+fn test_chunk_by() {
+    let v: Vec<Vec<_>> = empty::<i32>().chunk_runs_by(|a, b| a == b).collect();
+    assert!(v.is_empty());
+
+    let v: Vec<Vec<_>> =
+        [1, 1, 1, 3, 3, 2, 2, 2].into_iter().chunk_runs_by(|a, b| a == b).collect();
+    assert_eq!(v, [&[1, 1, 1][..], &[3, 3], &[2, 2, 2]]);
+
+    let v: Vec<Vec<_>> = [1, 2, 3].into_iter().chunk_runs_by(|_, _| false).collect();
+    assert_eq!(v, [[1], [2], [3]]);
+
+    let v: Vec<Vec<_>> = [1, 2, 3].into_iter().chunk_runs_by(|_, _| true).collect();
+    assert_eq!(v, [[1, 2, 3]]);
+}
+
+#[test]
+fn test_chunk_by_adjacent_pairs() {
+    // The predicate sees consecutive elements, not the first element of the chunk.
+    let v: Vec<Vec<_>> = [1, 2, 3, 5, 6, 8].into_iter().chunk_runs_by(|a, b| a + 1 == *b).collect();
+    assert_eq!(v, [&[1, 2, 3][..], &[5, 6], &[8]]);
+
+    let mut calls = Vec::new();
+    let _: Vec<Vec<_>> = [1, 2, 4]
+        .into_iter()
+        .chunk_runs_by(|&a, &b| {
+            calls.push((a, b));
+            a + 1 == b
+        })
+        .collect();
+    assert_eq!(calls, [(1, 2), (2, 4)]);
+}
+
+#[test]
+fn test_chunk_by_collections() {
+    let v: Vec<String> = "aabbbc".chars().chunk_runs_by(|a, b| a == b).collect();
+    assert_eq!(v, ["aa", "bbb", "c"]);
+
+    // Elements don't need to be `Clone`.
+    let v: Vec<Vec<String>> = ["a", "b", "cc", "dd", "e"]
+        .into_iter()
+        .map(String::from)
+        .chunk_runs_by(|a, b| a.len() == b.len())
+        .collect();
+    assert_eq!(v, [vec!["a", "b"], vec!["cc", "dd"], vec!["e"]]);
+}
+
+#[test]
+fn test_chunk_by_size_hint() {
+    let mut iter = [1, 1, 2].into_iter().chunk_runs_by::<Vec<_>, _>(|a, b| a == b);
+    assert_eq!(iter.size_hint(), (1, Some(3)));
+    assert_eq!(iter.next(), Some(vec![1, 1]));
+    assert_eq!(iter.size_hint(), (1, Some(1)));
+    assert_eq!(iter.next(), Some(vec![2]));
+    assert_eq!(iter.size_hint(), (0, Some(0)));
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn test_chunk_by_fused() {
+    let mut iter = NonFused::new([1, 2, 2].into_iter()).chunk_runs_by::<Vec<_>, _>(|a, b| a == b);
+    assert_eq!(iter.next(), Some(vec![1]));
+    assert_eq!(iter.next(), Some(vec![2, 2]));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next(), None);
+}
@@ -0,0 +1,101 @@
+use core::iter::*;
+
+use super::*;
+
+#[test]
+fn test_dedup() {
+    let v: Vec<i32> = empty().dedup_adjacent().collect();
+    assert_eq!(v, []);
+
+    let v: Vec<_> = [1, 1, 2, 2, 2, 3, 1, 1].into_iter().dedup_adjacent().collect();
+    assert_eq!(v, [1, 2, 3, 1]);
+
+    let v: Vec<_> = [1, 2, 3].into_iter().dedup_adjacent().collect();
+    assert_eq!(v, [1, 2, 3]);
+
+    let v: Vec<_> = repeat_n(7, 5).dedup_adjacent().collect();
+    assert_eq!(v, [7]);
+}
+
+#[test]
+fn test_dedup_by() {
+    // The first element of each run is kept and compared with the rest of it.
+    let v: Vec<_> = [1, 2, 3, 4, 7, 8, 9].into_iter().dedup_adjacent_by(|a, b| b - a < 3).collect();
+    assert_eq!(v, [1, 4, 7]);
+
+    let mut calls = Vec::new();
+    let v: Vec<_> = [1, 1, 2]
+        .into_iter()
+        .dedup_adjacent_by(|&a, &b| {
+            calls.push((a, b));
+            a == b
+        })
+        .collect();
+    assert_eq!(v, [1, 2]);
+    assert_eq!(calls, [(1, 1), (1, 2)]);
+}
+
+#[test]
+fn test_dedup_by_key() {
+    let v: Vec<_> =
+        [10, 15, 21, 29, 30, 11].into_iter().dedup_adjacent_by_key(|x| x / 10).collect();
+    assert_eq!(v, [10, 21, 30, 11]);
+
+    // The key of the first element of a run is computed once for the whole run.
+    let mut calls = 0;
+    let v: Vec<_> = [1, 1, 1, 2]
+        .into_iter()
+        .dedup_adjacent_by_key(|&x| {
+            calls += 1;
+            x
+        })
+        .collect();
+    assert_eq!(v, [1, 2]);
+    assert_eq!(calls, 4);
+}
+
+#[test]
+fn test_dedup_with_count() {
+    let v: Vec<(usize, char)> = "".chars().dedup_adjacent_with_count().collect();
+    assert_eq!(v, []);
+
+    let v: Vec<_> = "aaabccdddd".chars().dedup_adjacent_with_count().collect();
+    assert_eq!(v, [(3, 'a'), (1, 'b'), (2, 'c'), (4, 'd')]);
+
+    let counts: usize = "aaabccdddd".chars().dedup_adjacent_with_count().map(|(n, _)| n).sum();
+    assert_eq!(counts, 10);
+}
+
+#[test]
+fn test_dedup_size_hint() {
+    let mut iter = [1, 1, 2, 3].into_iter().dedup_adjacent();
+    assert_eq!(iter.size_hint(), (1, Some(4)));
+    assert_eq!(iter.next(), Some(1));
+    // `2` has been read to end the first run.
+    assert_eq!(iter.size_hint(), (1, Some(2)));
+    assert_eq!(iter.next(), Some(2));
+    assert_eq!(iter.size_hint(), (1, Some(1)));
+    assert_eq!(iter.next(), Some(3));
+    assert_eq!(iter.size_hint(), (0, Some(0)));
+    assert_eq!(iter.next(), None);
+
+    let iter = empty::<i32>().dedup_adjacent();
+    assert_eq!(iter.size_hint(), (0, Some(0)));
+
+    let iter = (0..).dedup_adjacent();
+    assert_eq!(iter.size_hint(), (1, None));
+}
+
+#[test]
+fn test_dedup_fused() {
+    let mut iter = NonFused::new([1, 1, 2].into_iter()).dedup_adjacent();
+    assert_eq!(iter.next(), Some(1));
+    assert_eq!(iter.next(), Some(2));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next(), None);
+
+    let mut iter = NonFused::new([1, 1].into_iter()).dedup_adjacent_with_count();
+    assert_eq!(iter.next(), Some((2, 1)));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next(), None);
+}
@@ -0,0 +1,108 @@
+use core::iter::*;
+
+use super::*;
+
+#[test]
+fn test_merge() {
+    let v: Vec<i32> = empty().merge_sorted(empty()).collect();
+    assert_eq!(v, []);
+
+    let v: Vec<_> = [1, 3, 5].into_iter().merge_sorted(empty()).collect();
+    assert_eq!(v, [1, 3, 5]);
+
+    let v: Vec<_> = empty().merge_sorted([2, 4]).collect();
+    assert_eq!(v, [2, 4]);
+
+    let v: Vec<_> = [1, 3, 5, 7].into_iter().merge_sorted([2, 3, 6]).collect();
+    assert_eq!(v, [1, 2, 3, 3, 5, 6, 7]);
+
+    // Any `IntoIterator` with the same item type can be merged in.
+    let v: Vec<_> = (0..10).step_by(3).merge_sorted(vec![1, 2, 10]).collect();
+    assert_eq!(v, [0, 1, 2, 3, 6, 9, 10]);
+}
+
+#[test]
+fn test_merge_stable() {
+    let a = [(1, 'a'), (2, 'a'), (2, 'b')];
+    let b = [(1, 'c'), (2, 'c')];
+    let v: Vec<_> = a.into_iter().merge_sorted_by(b, |x, y| x.0 <= y.0).collect();
+    assert_eq!(v, [(1, 'a'), (1, 'c'), (2, 'a'), (2, 'b'), (2, 'c')]);
+}
+
+#[test]
+fn test_merge_by() {
+    let v: Vec<_> = [9, 5, 1].into_iter().merge_sorted_by([8, 6, 2, 0], |a, b| a >= b).collect();
+    assert_eq!(v, [9, 8, 6, 5, 2, 1, 0]);
+}
+
+#[test]
+fn test_merge_size_hint() {
+    let mut iter = [1, 4].into_iter().merge_sorted([2, 3, 5]);
+    assert_eq!(iter.size_hint(), (5, Some(5)));
+    assert_eq!(iter.next(), Some(1));
+    assert_eq!(iter.size_hint(), (4, Some(4)));
+    assert_eq!(iter.by_ref().count(), 4);
+    assert_eq!(iter.size_hint(), (0, Some(0)));
+
+    let iter = (0..).merge_sorted(0..10);
+    assert_eq!(iter.size_hint(), (usize::MAX, None));
+
+    let iter = (0..10).filter(|_| true).merge_sorted([1]);
+    assert_eq!(iter.size_hint(), (1, Some(11)));
+}
+
+#[test]
+fn test_merge_trusted_len() {
+    fn assert_trusted_len<I: TrustedLen>(_: &I) {}
+
+    let iter = [1, 3].into_iter().merge_sorted(0..2);
+    assert_trusted_len(&iter);
+    let iter = merge_all([0..2, 1..3]);
+    assert_trusted_len(&iter);
+}
+
+#[test]
+fn test_merge_fused() {
+    // Exhausted iterators are never polled again.
+    let mut iter = NonFused::new([1, 3].into_iter()).merge_sorted(NonFused::new([2].into_iter()));
+    assert_eq!(iter.next(), Some(1));
+    assert_eq!(iter.next(), Some(2));
+    assert_eq!(iter.next(), Some(3));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next(), None);
+
+    let mut iter = merge_all([NonFused::new(0..1), NonFused::new(0..0), NonFused::new(1..2)]);
+    assert_eq!(iter.next(), Some(0));
+    assert_eq!(iter.next(), Some(1));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn test_merge_all() {
+    let v: Vec<i32> = merge_all::<Vec<i32>, 0>([]).collect();
+    assert_eq!(v, []);
+
+    let v: Vec<_> = merge_all([vec![1, 4, 7], vec![], vec![2, 5, 8], vec![3, 6, 9]]).collect();
+    assert_eq!(v, [1, 2, 3, 4, 5, 6, 7, 8, 9]);
+
+    let v: Vec<_> =
+        merge_all([[(1, 'a'), (2, 'a')], [(1, 'b'), (2, 'b')], [(0, 'c'), (2, 'c')]]).collect();
+    assert_eq!(v, [(0, 'c'), (1, 'a'), (1, 'b'), (2, 'a'), (2, 'b'), (2, 'c')]);
+}
+
+#[test]
+fn test_merge_all_by() {
+    let v: Vec<_> = merge_all_by([vec![9, 3], vec![8, 5, 1], vec![10]], |a, b| a >= b).collect();
+    assert_eq!(v, [10, 9, 8, 5, 3, 1]);
+}
+
+#[test]
+fn test_merge_all_size_hint() {
+    let mut iter = merge_all([0..3, 2..4, 4..4]);
+    assert_eq!(iter.size_hint(), (5, Some(5)));
+    assert_eq!(iter.next(), Some(0));
+    assert_eq!(iter.size_hint(), (4, Some(4)));
+    assert_eq!(iter.by_ref().collect::<Vec<_>>(), [1, 2, 2, 3]);
+    assert_eq!(iter.size_hint(), (0, Some(0)));
+}
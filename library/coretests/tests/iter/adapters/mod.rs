@@ -1,9 +1,11 @@
 mod array_chunks;
 mod by_ref_sized;
 mod chain;
+mod chunk_by;
 mod cloned;
 mod copied;
 mod cycle;
+mod dedup;
 mod enumerate;
 mod filter;
 mod filter_map;
@@ -14,6 +16,7 @@
 mod intersperse;
 mod map;
 mod map_windows;
+mod merge;
 mod peekable;
 mod scan;
 mod skip;
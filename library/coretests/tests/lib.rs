@@ -24,6 +24,7 @@
 #![feature(core_private_bignum)]
 #![feature(core_private_diy_float)]
 #![feature(dec2flt)]
+#![feature(decimal)]
 #![feature(duration_constants)]
 #![feature(duration_constructors)]
 #![feature(error_generic_member_access)]
@@ -48,10 +49,13 @@
 #![feature(iter_advance_by)]
 #![feature(iter_array_chunks)]
 #![feature(iter_chain)]
+#![feature(iter_chunk_by)]
 #![feature(iter_collect_into)]
+#![feature(iter_dedup)]
 #![feature(iter_intersperse)]
 #![feature(iter_is_partitioned)]
 #![feature(iter_map_windows)]
+#![feature(iter_merge)]
 #![feature(iter_next_chunk)]
 #![feature(iter_order_by)]
 #![feature(iter_partition_in_place)]
@@ -69,6 +73,9 @@
 #![feature(pointer_is_aligned_to)]
 #![feature(portable_simd)]
 #![feature(ptr_metadata)]
+#![feature(random)]
+#![feature(random_sampling)]
+#![feature(seedable_random)]
 #![feature(slice_from_ptr_range)]
 #![feature(slice_internals)]
 #![feature(slice_partition_dedup)]
@@ -164,6 +171,7 @@ fn $test() $block
 mod pin;
 mod pin_macro;
 mod ptr;
+mod random;
 mod result;
 mod simd;
 mod slice;
@@ -0,0 +1,366 @@
+use core::num::{Decimal, RoundingMode};
+use std::hash::{BuildHasher, RandomState};
+
+fn d(s: &str) -> Decimal {
+    s.parse().unwrap()
+}
+
+#[test]
+fn parse_display_round_trip() {
+    for s in [
+        "0",
+        "1",
+        "-1",
+        "0.5",
+        "-0.5",
+        "1.50",
+        "123.456",
+        "0.00001",
+        "0.000",
+        "100",
+        "170141183460469231731687303715884105727",
+        "-170141183460469231731687303715884105728",
+        "1.70141183460469231731687303715884105727",
+        "0.00000000000000000000000000000000000001",
+        "-0.17014118346046923173168730371588410572",
+    ] {
+        assert_eq!(d(s).to_string(), s);
+        assert_eq!(format!("{:?}", d(s)), s);
+    }
+    assert_eq!(d("-0.000").to_string(), "0.000");
+    assert_eq!(d("+007.10").to_string(), "7.10");
+    assert_eq!(d(".5").to_string(), "0.5");
+    assert_eq!(d("5.").to_string(), "5");
+    assert_eq!(d("1.5e3").to_string(), "1500");
+    assert_eq!(d("1.5E-3").to_string(), "0.0015");
+    assert_eq!(d("12e-1").to_string(), "1.2");
+    assert_eq!(d("0e-100").to_string(), "0");
+    assert_eq!(
+        d("1.000000000000000000000000000000000000000000").to_string(),
+        "1.00000000000000000000000000000000000000"
+    );
+    assert!("1234567890123456789012345678901234567890e-40".parse::<Decimal>().is_err());
+}
+
+#[test]
+fn parse_errors() {
+    for s in [
+        "", "-", "+", ".", "-.", "e5", "1e", "1e+", "1.2.3", "1,5", "0x10", " 1", "1 ", "--1",
+        "inf", "NaN",
+    ] {
+        assert!(s.parse::<Decimal>().is_err(), "{s:?}");
+    }
+    assert_eq!(
+        "".parse::<Decimal>().unwrap_err().to_string(),
+        "cannot parse decimal from empty string"
+    );
+    assert_eq!("x".parse::<Decimal>().unwrap_err().to_string(), "invalid decimal literal");
+    let overflow = "170141183460469231731687303715884105728".parse::<Decimal>().unwrap_err();
+    assert_eq!(overflow.to_string(), "number too large to fit in a decimal");
+    assert!("-170141183460469231731687303715884105729".parse::<Decimal>().is_err());
+    assert!("1e39".parse::<Decimal>().is_err());
+    assert!("1e38".parse::<Decimal>().is_ok());
+    assert_eq!(
+        "0.000000000000000000000000000000000000001".parse::<Decimal>().unwrap_err().to_string(),
+        "number has too many digits after the decimal point"
+    );
+}
+
+#[test]
+fn display_options() {
+    let x = d("-12.345");
+    assert_eq!(format!("{x:.2}"), "-12.34");
+    assert_eq!(format!("{:.2}", d("12.355")), "12.36");
+    assert_eq!(format!("{x:.0}"), "-12");
+    assert_eq!(format!("{x:.5}"), "-12.34500");
+    assert_eq!(format!("{:.3}", d("7")), "7.000");
+    assert_eq!(format!("{:.70}", d("0.5")).len(), 72);
+    assert_eq!(format!("{x:>10}"), "   -12.345");
+    assert_eq!(format!("{x:<10}|"), "-12.345   |");
+    assert_eq!(format!("{x:010}"), "-00012.345");
+    assert_eq!(format!("{:+}", d("1.5")), "+1.5");
+    assert_eq!(format!("{:+.1}", d("0.01")), "+0.0");
+}
+
+#[test]
+fn matches_integers() {
+    let values = [
+        0i128,
+        1,
+        -1,
+        7,
+        -13,
+        99,
+        1000,
+        -1234567,
+        999_999_999_999,
+        i64::MAX as i128,
+        i64::MIN as i128,
+    ];
+    for &a in &values {
+        for &b in &values {
+            for (sa, sb) in [(0, 0), (2, 0), (0, 3), (4, 4), (10, 1)] {
+                let (x, y) = (Decimal::new(a, sa), Decimal::new(b, sb));
+                let s = sa.max(sb);
+                let (ax, by) = (a * 10i128.pow(s - sa), b * 10i128.pow(s - sb));
+                assert_eq!(x + y, Decimal::new(ax + by, s));
+                assert_eq!((x + y).scale(), s);
+                assert_eq!(x - y, Decimal::new(ax - by, s));
+                assert_eq!(x * y, Decimal::new(a * b, sa + sb));
+                assert_eq!((x * y).scale(), sa + sb);
+                assert_eq!(x.cmp(&y), ax.cmp(&by));
+                if b != 0 {
+                    assert_eq!(x % y, Decimal::new(ax % by, s));
+                    // The quotient, multiplied back, is within one unit in its last place. The
+                    // exact products may have too many digits, so they are rounded.
+                    let q = x / y;
+                    let unit = Decimal::new(1, q.scale());
+                    let back = q.saturating_mul(y);
+                    let error = back.saturating_sub(x).abs();
+                    assert!(error <= unit.saturating_mul(y).abs(), "{x} / {y} = {q}");
+                }
+            }
+        }
+    }
+}
+
+/// Reference rounding of `n / d` for small integers.
+fn round_ref(n: i128, d: i128, mode: RoundingMode) -> i128 {
+    let (q, r) = (n / d, n % d);
+    if r == 0 {
+        return q;
+    }
+    let negative = (n < 0) != (d < 0);
+    let away = if negative { q - 1 } else { q + 1 };
+    let twice = (2 * r.abs()).cmp(&d.abs());
+    let up = match mode {
+        RoundingMode::TowardZero => false,
+        RoundingMode::AwayFromZero => true,
+        RoundingMode::Floor => negative,
+        RoundingMode::Ceiling => !negative,
+        RoundingMode::HalfToEven => twice.is_gt() || (twice.is_eq() && q % 2 != 0),
+        RoundingMode::HalfAwayFromZero => twice.is_ge(),
+        RoundingMode::HalfTowardZero => twice.is_gt(),
+    };
+    if up { away } else { q }
+}
+
+const MODES: [RoundingMode; 7] = [
+    RoundingMode::TowardZero,
+    RoundingMode::AwayFromZero,
+    RoundingMode::Floor,
+    RoundingMode::Ceiling,
+    RoundingMode::HalfToEven,
+    RoundingMode::HalfAwayFromZero,
+    RoundingMode::HalfTowardZero,
+];
+
+#[test]
+fn rounding() {
+    for n in -300..=300 {
+        for d in [1, 2, 3, 4, 5, 7, 8, 10, 20, 25, 40, 100, 1000] {
+            for mode in MODES {
+                for scale in 0..3 {
+                    let x = Decimal::new(n, 2);
+                    let expected =
+                        Decimal::new(round_ref(n * 10i128.pow(scale), d * 100, mode), scale);
+                    let q = x.div_round(Decimal::from(d as i32), scale, mode);
+                    assert_eq!((q, q.scale()), (expected, scale), "{x} / {d} at {scale} {mode:?}");
+                    let q = x.div_round(Decimal::from(-d as i32), scale, mode);
+                    let expected =
+                        Decimal::new(round_ref(n * 10i128.pow(scale), -d * 100, mode), scale);
+                    assert_eq!(q, expected);
+                }
+                if d == 1 || d == 10 || d == 100 {
+                    let x = Decimal::new(n, 2);
+                    let places = match d {
+                        1 => 2,
+                        10 => 1,
+                        _ => 0,
+                    };
+                    let r = x.round(places, mode);
+                    assert_eq!(r, Decimal::new(round_ref(n, d, mode), places));
+                }
+            }
+        }
+    }
+    assert_eq!(d("2.5").round(0, RoundingMode::HalfToEven), d("2"));
+    assert_eq!(d("3.5").round(0, RoundingMode::HalfToEven), d("4"));
+    assert_eq!(d("-3.5").round(0, RoundingMode::HalfToEven), d("-4"));
+    assert_eq!(d("2.5000001").round(0, RoundingMode::HalfToEven), d("3"));
+    assert_eq!(d("-7.9").trunc(), d("-7"));
+    assert_eq!(d("-7.9").floor(), d("-8"));
+    assert_eq!(d("-7.9").ceil(), d("-7"));
+    assert_eq!(d("-7.95").fract(), d("-0.95"));
+    assert_eq!(d("3").round(2, RoundingMode::Floor).scale(), 0);
+    assert_eq!(d("3").checked_rescale(2, RoundingMode::Floor).unwrap().to_string(), "3.00");
+    assert_eq!(d("3.456").checked_rescale(1, RoundingMode::Ceiling).unwrap().to_string(), "3.5");
+    assert_eq!(d("3").checked_rescale(39, RoundingMode::Floor), None);
+    assert_eq!(d("12.3400").normalize().to_string(), "12.34");
+    assert_eq!(d("1200").normalize().to_string(), "1200");
+}
+
+#[test]
+fn division() {
+    assert_eq!((d("1") / d("3")).to_string(), "0.33333333333333333333333333333333333333");
+    assert_eq!((d("10") / d("4")).to_string(), "2.5");
+    assert_eq!((d("1.00") / d("4")).to_string(), "0.25");
+    assert_eq!((d("6.00") / d("2")).to_string(), "3.00");
+    assert_eq!((d("1") / d("0.01")).to_string(), "100");
+    assert_eq!((d("1e37") / d("0.1")).to_string(), "100000000000000000000000000000000000000");
+    assert_eq!(d("1e38").checked_div(d("0.1")), None);
+    assert_eq!((d("100000") / d("3")).to_string(), "33333.333333333333333333333333333333333");
+    assert_eq!(Decimal::MAX / Decimal::MAX, Decimal::ONE);
+    assert_eq!(Decimal::MIN / Decimal::MAX, d("-1.00000000000000000000000000000000000001"));
+    assert_eq!(Decimal::MIN.checked_div(d("-1")), None);
+    assert_eq!(Decimal::MIN.checked_div(d("-1.0")), None);
+    assert_eq!(
+        Decimal::MIN.checked_div(d("-10")).unwrap().to_string(),
+        "17014118346046923173168730371588410573"
+    );
+    assert_eq!(Decimal::MIN.saturating_div(d("-1")), Decimal::MAX);
+    assert_eq!(d("1").checked_div(Decimal::ZERO), None);
+    assert_eq!(d("2").checked_div_round(d("3"), 39, RoundingMode::Floor), None);
+    assert_eq!(Decimal::MAX.checked_div_round(d("1"), 1, RoundingMode::Floor), None);
+    // The largest divisor, where the long division needs more than 128 bits per digit.
+    let big = Decimal::new(i128::MAX - 1, 0);
+    let q = (big - d("1")) / big;
+    assert_eq!(q.to_string(), "0.99999999999999999999999999999999999999");
+    assert_eq!((big / Decimal::MAX).to_string(), "0.99999999999999999999999999999999999999");
+    assert_eq!(
+        Decimal::MAX.div_round(big, 38, RoundingMode::TowardZero).to_string(),
+        "1.00000000000000000000000000000000000000"
+    );
+}
+
+#[test]
+#[should_panic(expected = "attempt to divide by zero")]
+fn div_by_zero() {
+    let _ = d("1") / Decimal::ZERO;
+}
+
+#[test]
+#[should_panic(expected = "attempt to multiply with overflow")]
+fn inexact_mul() {
+    let _ = d("1e-20") * d("1e-20");
+}
+
+#[test]
+#[should_panic(expected = "attempt to add with overflow")]
+fn inexact_add() {
+    let _ = d("1e38") + d("0.5");
+}
+
+#[test]
+fn wide_results() {
+    // Products are exact, or are rounded only when asked to.
+    let x = d("1.23456789012345678901234567890123456789");
+    assert_eq!(x.checked_mul(x), None);
+    let p = x.mul_round(x, 38, RoundingMode::HalfToEven);
+    assert_eq!(p.to_string(), "1.52415787532388367504953515625666819450");
+    assert_eq!(x.saturating_mul(x), p);
+    assert_eq!(d("0.1") * d("0.1"), d("0.01"));
+    assert_eq!(d("1e-20").checked_mul(d("1e-20")), None);
+    assert_eq!(d("1e-20").mul_round(d("1e-20"), 38, RoundingMode::HalfToEven), Decimal::ZERO);
+    assert_eq!(
+        d("1e-20").mul_round(d("1e-20"), 38, RoundingMode::Ceiling).to_string(),
+        "0.00000000000000000000000000000000000001"
+    );
+    assert_eq!(
+        d("5e-20").mul_round(d("1e-19"), 38, RoundingMode::HalfToEven).to_string(),
+        "0.00000000000000000000000000000000000000"
+    );
+    assert_eq!(
+        d("15e-20").mul_round(d("1e-19"), 38, RoundingMode::HalfToEven).to_string(),
+        "0.00000000000000000000000000000000000002"
+    );
+    assert_eq!(d("1.5").mul_round(d("2"), 3, RoundingMode::Floor).to_string(), "3.000");
+    assert_eq!(d("1.5").checked_mul_round(d("2"), 39, RoundingMode::Floor), None);
+    assert_eq!(Decimal::MAX.checked_mul_round(d("1"), 1, RoundingMode::Floor), None);
+    // Trailing zeros are dropped to make an exact result fit.
+    let tiny = d("0.00000000000000000000000000000000000010");
+    assert_eq!((tiny * d("1.0")).to_string(), "0.00000000000000000000000000000000000010");
+    assert_eq!((tiny * d("0.1")).to_string(), "0.00000000000000000000000000000000000001");
+    assert_eq!(tiny.checked_mul(d("0.01")), None);
+    assert_eq!(Decimal::MAX.checked_mul(d("1.0")), Some(Decimal::MAX));
+    assert_eq!(Decimal::MAX.checked_mul(d("1.1")), None);
+    assert_eq!(Decimal::MAX.saturating_mul(d("-1.1")), Decimal::MIN);
+    assert_eq!(Decimal::MIN.checked_mul(d("-1")), None);
+    assert_eq!(Decimal::MIN.checked_mul(d("1")), Some(Decimal::MIN));
+    // Sums that need more digits than fit are not representable, but saturate by rounding.
+    let a = d("100000000000000000000000000000000000000");
+    assert_eq!(a.checked_add(d("0.5")), None);
+    assert_eq!(
+        a.checked_add(d("1.0")).unwrap().to_string(),
+        "100000000000000000000000000000000000001"
+    );
+    assert_eq!(a.saturating_add(d("0.5")).to_string(), "100000000000000000000000000000000000000");
+    assert_eq!(a.saturating_add(d("1.5")).to_string(), "100000000000000000000000000000000000002");
+    assert_eq!(Decimal::MAX.checked_sub(d("0.4")), None);
+    assert_eq!(
+        Decimal::MAX.saturating_sub(d("0.4")).to_string(),
+        "170141183460469231731687303715884105727"
+    );
+    assert_eq!(Decimal::MAX.checked_add(d("0.5")), None);
+    assert_eq!(Decimal::MAX.checked_add(d("0.4")), None);
+    assert_eq!(Decimal::MAX.saturating_add(d("0.4")), Decimal::MAX);
+    assert_eq!(Decimal::MIN.checked_sub(d("0.5")), None);
+    assert_eq!(Decimal::MIN.saturating_sub(d("0.5")), Decimal::MIN);
+    assert_eq!(Decimal::MIN.saturating_sub(d("1")), Decimal::MIN);
+    assert_eq!(Decimal::MAX.saturating_sub(d("-1")), Decimal::MAX);
+    assert_eq!(Decimal::MIN.checked_add(Decimal::MAX), Some(d("-1")));
+    // Rounding up at the largest mantissa drops another digit.
+    let m = d("17014118346046923173168730371588410572.7");
+    assert_eq!(m.checked_add(d("0.09")), None);
+    assert_eq!(m.saturating_add(d("0.09")).to_string(), "17014118346046923173168730371588410573");
+    assert_eq!(Decimal::MIN.checked_neg(), None);
+    assert_eq!(Decimal::MIN.saturating_neg(), Decimal::MAX);
+    assert_eq!(Decimal::MIN.saturating_abs(), Decimal::MAX);
+    assert_eq!(d("-2.5").abs(), d("2.5"));
+    assert_eq!(Decimal::MIN % d("-1"), Decimal::ZERO);
+    assert_eq!(Decimal::MIN % d("0.3"), d("-0.2"));
+    assert_eq!(d("-7.5") % d("2"), d("-1.5"));
+    assert_eq!(d("7.5") % d("-2"), d("1.5"));
+    assert_eq!(
+        Decimal::MAX % d("0.00000000000000000000000000000000000007"),
+        d("0.00000000000000000000000000000000000002")
+    );
+}
+
+#[test]
+fn comparison_and_hash() {
+    let s = RandomState::new();
+    for (a, b) in [("1.5", "1.50"), ("0", "-0.000"), ("100", "100.0"), ("-2", "-2.00000")] {
+        assert_eq!(d(a), d(b));
+        assert_eq!(s.hash_one(d(a)), s.hash_one(d(b)));
+    }
+    let mut v: Vec<Decimal> =
+        ["3", "-1.5", "0.001", "-1.50001", "2.9999", "0", "-0"].map(d).to_vec();
+    v.sort();
+    assert_eq!(
+        v.iter().map(|x| x.to_string()).collect::<Vec<_>>(),
+        ["-1.50001", "-1.5", "0", "0", "0.001", "2.9999", "3"]
+    );
+    assert!(Decimal::MAX > d("1.70141183460469231731687303715884105727"));
+    assert!(Decimal::MIN < d("-0.00000000000000000000000000000000000001"));
+}
+
+#[test]
+fn conversions_and_iterators() {
+    assert_eq!(Decimal::from(-5i8), d("-5"));
+    assert_eq!(Decimal::from(u64::MAX).to_string(), u64::MAX.to_string());
+    assert_eq!(Decimal::try_from(u128::MAX).ok(), None);
+    assert_eq!(Decimal::try_from(5u128).ok(), Some(d("5")));
+    let items = ["19.99", "5.01", "0.50"].map(d);
+    assert_eq!(items.iter().sum::<Decimal>().to_string(), "25.50");
+    assert_eq!(items.into_iter().product::<Decimal>().to_string(), "50.074950");
+    let mut x = d("10");
+    x += d("0.5");
+    x *= d("2");
+    x -= d("1");
+    x /= d("4");
+    x %= d("3");
+    assert_eq!(x.to_string(), "2.0");
+    assert_eq!(-&d("1.5"), d("-1.5"));
+    assert_eq!(&d("1") + &d("2"), d("3"));
+}
@@ -25,6 +25,7 @@
 
 mod const_from;
 mod dec2flt;
+mod decimal;
 mod flt2dec;
 mod int_log;
 mod int_sqrt;
@@ -0,0 +1,209 @@
+use core::random::{ChaCha20Rng, Random, RandomSource, Xoshiro256PlusPlus};
+
+fn next_u64(source: &mut impl RandomSource) -> u64 {
+    let mut bytes = [0; 8];
+    source.fill_bytes(&mut bytes);
+    u64::from_le_bytes(bytes)
+}
+
+#[test]
+fn chacha20_keystream() {
+    // RFC 7539, appendix A.1, test vectors #1 and #2: the first two blocks for the all-zero key.
+    #[rustfmt::skip]
+    let expected: [u8; 128] = [
+        0x76, 0xb8, 0xe0, 0xad, 0xa0, 0xf1, 0x3d, 0x90, 0x40, 0x5d, 0x6a, 0xe5, 0x53, 0x86, 0xbd, 0x28,
+        0xbd, 0xd2, 0x19, 0xb8, 0xa0, 0x8d, 0xed, 0x1a, 0xa8, 0x36, 0xef, 0xcc, 0x8b, 0x77, 0x0d, 0xc7,
+        0xda, 0x41, 0x59, 0x7c, 0x51, 0x57, 0x48, 0x8d, 0x77, 0x24, 0xe0, 0x3f, 0xb8, 0xd8, 0x4a, 0x37,
+        0x6a, 0x43, 0xb8, 0xf4, 0x15, 0x18, 0xa1, 0x1c, 0xc3, 0x87, 0xb6, 0x69, 0xb2, 0xee, 0x65, 0x86,
+        0x9f, 0x07, 0xe7, 0xbe, 0x55, 0x51, 0x38, 0x7a, 0x98, 0xba, 0x97, 0x7c, 0x73, 0x2d, 0x08, 0x0d,
+        0xcb, 0x0f, 0x29, 0xa0, 0x48, 0xe3, 0x65, 0x69, 0x12, 0xc6, 0x53, 0x3e, 0x32, 0xee, 0x7a, 0xed,
+        0x29, 0xb7, 0x21, 0x76, 0x9c, 0xe6, 0x4e, 0x43, 0xd5, 0x71, 0x33, 0xb0, 0x74, 0xd8, 0x39, 0xd5,
+        0x31, 0xed, 0x1f, 0x28, 0x51, 0x0a, 0xfb, 0x45, 0xac, 0xe1, 0x0a, 0x1f, 0x4b, 0x79, 0x4d, 0x6f,
+    ];
+
+    let mut rng = ChaCha20Rng::from_seed([0; 32]);
+    let mut bytes = [0; 128];
+    rng.fill_bytes(&mut bytes);
+    assert_eq!(bytes, expected);
+
+    // Reading in pieces that do not line up with the blocks yields the same stream.
+    let mut rng = ChaCha20Rng::from_seed([0; 32]);
+    let mut bytes = [0; 128];
+    for chunk in bytes.chunks_mut(7) {
+        rng.fill_bytes(chunk);
+    }
+    assert_eq!(bytes, expected);
+}
+
+#[test]
+fn chacha20_streams() {
+    let mut a = ChaCha20Rng::seed_from_u64(1);
+    let mut b = ChaCha20Rng::seed_from_u64(1);
+    b.set_stream(1);
+    assert_eq!(b.stream(), 1);
+    assert_ne!(next_u64(&mut a), next_u64(&mut b));
+
+    let first = next_u64(&mut ChaCha20Rng::seed_from_u64(1));
+    b.set_stream(0);
+    assert_eq!(next_u64(&mut b), first);
+
+    assert_ne!(first, next_u64(&mut ChaCha20Rng::seed_from_u64(2)));
+}
+
+#[test]
+fn xoshiro256plusplus_reference() {
+    // Reference output of the C implementation for the state [1, 2, 3, 4].
+    let mut seed = [0; 32];
+    for (i, chunk) in seed.chunks_mut(8).enumerate() {
+        chunk.copy_from_slice(&(i as u64 + 1).to_le_bytes());
+    }
+    let mut rng = Xoshiro256PlusPlus::from_seed(seed);
+    let expected = [
+        41943041,
+        58720359,
+        3588806011781223,
+        3591011842654386,
+        9228616714210784205,
+        9973669472204895162,
+        14011001112246962877,
+        12406186145184390807,
+        15849039046786891736,
+        10450023813501588000,
+    ];
+    for x in expected {
+        assert_eq!(next_u64(&mut rng), x);
+    }
+}
+
+#[test]
+fn xoshiro256plusplus_seeding() {
+    let mut zero = Xoshiro256PlusPlus::from_seed([0; 32]);
+    assert_eq!(zero, Xoshiro256PlusPlus::seed_from_u64(0));
+    assert_ne!(next_u64(&mut zero), next_u64(&mut zero));
+
+    let mut a = Xoshiro256PlusPlus::seed_from_u64(5);
+    let mut b = a.clone();
+    b.jump();
+    assert_ne!(a, b);
+    let mut c = a.clone();
+    c.jump();
+    assert_eq!(b, c);
+    assert_ne!(next_u64(&mut a), next_u64(&mut b));
+
+    // Seeding from another source.
+    let mut chacha = ChaCha20Rng::seed_from_u64(9);
+    let a = Xoshiro256PlusPlus::random(&mut chacha.clone());
+    let b = Xoshiro256PlusPlus::random(&mut chacha);
+    assert_eq!(a, b);
+}
+
+#[test]
+fn random_range_int() {
+    let mut rng = Xoshiro256PlusPlus::seed_from_u64(0);
+    for _ in 0..1000 {
+        assert!((10..20).contains(&rng.random_range(10u8..20)));
+        assert!((-5..=5).contains(&rng.random_range(-5i32..=5)));
+        assert!((i64::MIN..0).contains(&rng.random_range(i64::MIN..0)));
+        assert!((1..=u128::MAX).contains(&rng.random_range(1u128..=u128::MAX)));
+        assert_eq!(rng.random_range(7usize..8), 7);
+        assert_eq!(rng.random_range(-3isize..=-3), -3);
+    }
+
+    // The full range is allowed for inclusive ranges.
+    let mut seen = [false; 256];
+    for _ in 0..10_000 {
+        seen[rng.random_range(i8::MIN..=i8::MAX) as u8 as usize] = true;
+    }
+    assert!(seen.iter().all(|&seen| seen));
+}
+
+#[test]
+fn random_range_uniform() {
+    let mut rng = ChaCha20Rng::seed_from_u64(0);
+    let mut counts = [0; 6];
+    for _ in 0..60_000 {
+        counts[rng.random_range(0usize..6)] += 1;
+    }
+    for count in counts {
+        assert!((9_000..11_000).contains(&count), "{counts:?}");
+    }
+}
+
+#[test]
+#[should_panic = "cannot sample empty range"]
+fn random_range_empty() {
+    Xoshiro256PlusPlus::seed_from_u64(0).random_range(3..3);
+}
+
+#[test]
+#[should_panic = "cannot sample empty range"]
+fn random_range_inclusive_empty() {
+    let (start, end) = (3, 2);
+    Xoshiro256PlusPlus::seed_from_u64(0).random_range(start..=end);
+}
+
+#[test]
+fn random_float() {
+    let mut rng = Xoshiro256PlusPlus::seed_from_u64(0);
+    let mut sum = 0.0;
+    for _ in 0..10_000 {
+        let x = f64::random(&mut rng);
+        assert!((0.0..1.0).contains(&x));
+        sum += x;
+        assert!((0.0..1.0).contains(&f32::random(&mut rng)));
+        assert!((-2.5..-2.0).contains(&rng.random_range(-2.5..-2.0)));
+        assert!((1e30..1e31).contains(&rng.random_range(1e30f32..1e31)));
+    }
+    assert!((4_800.0..5_200.0).contains(&sum));
+}
+
+#[test]
+#[should_panic = "cannot sample range"]
+fn random_float_infinite_range() {
+    Xoshiro256PlusPlus::seed_from_u64(0).random_range(f64::MIN..f64::MAX);
+}
+
+#[test]
+fn shuffle() {
+    let mut rng = Xoshiro256PlusPlus::seed_from_u64(0);
+    let mut v: [u32; 100] = core::array::from_fn(|i| i as u32);
+    v.shuffle(&mut rng);
+    assert_ne!(v, core::array::from_fn(|i| i as u32));
+    let mut sorted = v;
+    sorted.sort_unstable();
+    assert_eq!(sorted, core::array::from_fn(|i| i as u32));
+
+    // Shuffling is reproducible.
+    let mut w: [u32; 100] = core::array::from_fn(|i| i as u32);
+    w.shuffle(&mut Xoshiro256PlusPlus::seed_from_u64(0));
+    assert_eq!(v, w);
+
+    // Every element is equally likely to end up first.
+    let mut counts = [0; 4];
+    for _ in 0..40_000 {
+        let mut v = [0, 1, 2, 3];
+        v.shuffle(&mut rng);
+        counts[v[0]] += 1;
+    }
+    for count in counts {
+        assert!((9_000..11_000).contains(&count), "{counts:?}");
+    }
+
+    let mut empty: [u8; 0] = [];
+    empty.shuffle(&mut rng);
+}
+
+#[test]
+fn fixed_seed_output() {
+    // Values and indices are drawn independently of the target's endianness and pointer width,
+    // so a seeded source produces the same results everywhere.
+    let mut rng = Xoshiro256PlusPlus::seed_from_u64(42);
+    assert_eq!(u32::random(&mut rng), 1148610719);
+    assert_eq!(u16::random(&mut rng), 14225);
+    assert_eq!(i64::random(&mut rng), -297100157724070516);
+    assert_eq!(rng.random_range(0u64..1000), 440);
+
+    let mut v: [u32; 10] = core::array::from_fn(|i| i as u32);
+    v.shuffle(&mut Xoshiro256PlusPlus::seed_from_u64(42));
+    assert_eq!(v, [6, 7, 0, 9, 2, 4, 5, 3, 8, 1]);
+}
@@ -71,6 +71,32 @@ macro_rules! assert_chunks {
     );
 }
 
+#[test]
+fn chunks_long() {
+    // Invalid sequences between long stretches of valid non-ASCII text.
+    let text = "Съешь же ещё этих мягких французских булок, 我能吞下玻璃而不伤身体 🦀. ";
+    let invalid: [&[u8]; 4] = [b"\xFF", b"\xE0\x9F", b"\xF1\x80\x80", b"\xC2"];
+    let mut bytes = Vec::new();
+    for invalid in invalid {
+        bytes.extend_from_slice(text.as_bytes());
+        bytes.extend_from_slice(invalid);
+    }
+
+    let mut iter = bytes.utf8_chunks();
+    for (valid, invalid) in [
+        (text, &b"\xFF"[..]),
+        (text, b"\xE0"),
+        ("", b"\x9F"),
+        (text, b"\xF1\x80\x80"),
+        (text, b"\xC2"),
+    ] {
+        let chunk = iter.next().expect("missing chunk");
+        assert_eq!(valid, chunk.valid());
+        assert_eq!(invalid, chunk.invalid());
+    }
+    assert_eq!(None, iter.next());
+}
+
 #[test]
 fn debug() {
     assert_eq!(
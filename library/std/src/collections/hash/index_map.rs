@@ -0,0 +1,1825 @@
+#[cfg(test)]
+mod tests;
+
+use hashbrown::HashTable;
+
+use crate::borrow::Borrow;
+use crate::cmp::Ordering;
+use crate::collections::TryReserveError;
+use crate::fmt::{self, Debug};
+use crate::hash::{BuildHasher, Hash, RandomState};
+use crate::iter::FusedIterator;
+use crate::ops::{Index, IndexMut, RangeBounds};
+use crate::{mem, slice, vec};
+
+/// A hash map that remembers the order in which its keys were inserted.
+///
+/// `IndexMap` stores its key-value pairs densely in a vector, in insertion
+/// order, next to a [`HashMap`]-style hash table that maps each key to its
+/// position in that vector. Lookups by key therefore cost the same as for a
+/// `HashMap`, while iteration follows insertion order and every entry can
+/// also be reached through its index, much like a [`Vec`].
+///
+/// Inserting a new key appends it at the end. Updating the value of a key
+/// that is already present leaves its position untouched. There are two
+/// ways of removing an entry, which differ in what happens to the order of
+/// the other entries:
+///
+/// * [`swap_remove`] moves the last entry into the hole, like
+///   [`Vec::swap_remove`]. This disturbs the order, but takes O(1) time.
+/// * [`shift_remove`] shifts all following entries down by one, like
+///   [`Vec::remove`]. This preserves the order, but takes O(n) time.
+///
+/// The requirements on keys, and the choice of hasher, are the same as for
+/// [`HashMap`]. It is a logic error for a key to be modified in such a way
+/// that its hash or equality changes while it is in the map.
+///
+/// [`HashMap`]: crate::collections::HashMap
+/// [`swap_remove`]: IndexMap::swap_remove
+/// [`shift_remove`]: IndexMap::shift_remove
+///
+/// # Examples
+///
+/// ```
+/// #![feature(index_map)]
+/// use std::collections::IndexMap;
+///
+/// let mut letters = IndexMap::new();
+/// for ch in "a short treatise on fungi".chars().filter(|ch| !ch.is_whitespace()) {
+///     *letters.entry(ch).or_insert(0) += 1;
+/// }
+///
+/// // Iteration follows the order of first occurrence.
+/// assert_eq!(letters.keys().take(4).collect::<String>(), "asho");
+/// assert_eq!(letters[&'t'], 3);
+/// assert_eq!(letters.get_index(0), Some((&'a', &2)));
+///
+/// letters.shift_remove(&'s');
+/// assert_eq!(letters.keys().take(4).collect::<String>(), "ahor");
+///
+/// letters.sort_keys();
+/// assert_eq!(letters.keys().collect::<String>(), "aefghinortu");
+/// ```
+#[unstable(feature = "index_map", issue = "none")]
+pub struct IndexMap<K, V, S = RandomState> {
+    core: Core<K, V>,
+    hash_builder: S,
+}
+
+/// An entry of the dense vector, along with the cached hash of its key.
+#[derive(Clone)]
+struct Bucket<K, V> {
+    hash: u64,
+    key: K,
+    value: V,
+}
+
+impl<K, V> Bucket<K, V> {
+    fn refs(&self) -> (&K, &V) {
+        (&self.key, &self.value)
+    }
+
+    fn ref_mut(&mut self) -> (&K, &mut V) {
+        (&self.key, &mut self.value)
+    }
+
+    fn key_value(self) -> (K, V) {
+        (self.key, self.value)
+    }
+}
+
+/// The part of an `IndexMap` that does not depend on the hasher: the dense
+/// entries and the table of indices into them.
+///
+/// The table holds exactly one index for every entry, stored under the hash
+/// of that entry's key. Because the hash is cached in the entry, the table
+/// can be updated after entries move around without hashing any keys.
+#[derive(Clone)]
+struct Core<K, V> {
+    entries: Vec<Bucket<K, V>>,
+    indices: HashTable<usize>,
+}
+
+/// Returns the hasher the table uses to rehash its indices when it grows.
+fn get_hash<K, V>(entries: &[Bucket<K, V>]) -> impl Fn(&usize) -> u64 + '_ {
+    move |&i| entries[i].hash
+}
+
+/// Returns the closure the table uses to find the index of `key`.
+fn equivalent<'a, K, V, Q>(key: &'a Q, entries: &'a [Bucket<K, V>]) -> impl Fn(&usize) -> bool + 'a
+where
+    K: Borrow<Q>,
+    Q: ?Sized + Eq,
+{
+    move |&i| entries[i].key.borrow() == key
+}
+
+impl<K, V> Core<K, V> {
+    fn new() -> Self {
+        Core { entries: Vec::new(), indices: HashTable::new() }
+    }
+
+    fn with_capacity(capacity: usize) -> Self {
+        Core { entries: Vec::with_capacity(capacity), indices: HashTable::with_capacity(capacity) }
+    }
+
+    fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    fn capacity(&self) -> usize {
+        Ord::min(self.entries.capacity(), self.indices.capacity())
+    }
+
+    fn clear(&mut self) {
+        self.indices.clear();
+        self.entries.clear();
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.indices.reserve(additional, get_hash(&self.entries));
+        self.entries.reserve(additional);
+    }
+
+    fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.indices
+            .try_reserve(additional, get_hash(&self.entries))
+            .map_err(super::map::map_try_reserve_error)?;
+        self.entries.try_reserve(additional)
+    }
+
+    fn shrink_to(&mut self, min_capacity: usize) {
+        self.indices.shrink_to(min_capacity, get_hash(&self.entries));
+        self.entries.shrink_to(min_capacity);
+    }
+
+    /// Refills the table from scratch, for after the entries were reordered.
+    fn rebuild(&mut self) {
+        self.indices.clear();
+        for (i, bucket) in self.entries.iter().enumerate() {
+            self.indices.insert_unique(bucket.hash, i, get_hash(&self.entries));
+        }
+    }
+
+    /// Replaces the index `old` stored under `hash` by `new`.
+    fn update_index(&mut self, hash: u64, old: usize, new: usize) {
+        *self.indices.find_mut(hash, move |&i| i == old).expect("index not found") = new;
+    }
+
+    /// Removes the index `index` stored under `hash` from the table, leaving
+    /// the entries untouched.
+    fn erase_index(&mut self, hash: u64, index: usize) {
+        match self.indices.find_entry(hash, move |&i| i == index) {
+            Ok(entry) => {
+                entry.remove();
+            }
+            Err(_) => unreachable!("index not found"),
+        }
+    }
+
+    fn find<Q: ?Sized + Eq>(&self, hash: u64, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+    {
+        self.indices.find(hash, equivalent(key, &self.entries)).copied()
+    }
+
+    /// Appends a new entry, whose key must not be in the map yet.
+    fn push(&mut self, hash: u64, key: K, value: V) -> usize {
+        let index = self.entries.len();
+        self.indices.insert_unique(hash, index, get_hash(&self.entries));
+        self.entries.push(Bucket { hash, key, value });
+        index
+    }
+
+    fn pop(&mut self) -> Option<(K, V)> {
+        let hash = self.entries.last()?.hash;
+        self.erase_index(hash, self.entries.len() - 1);
+        self.entries.pop().map(Bucket::key_value)
+    }
+
+    /// Removes the entry whose index was already erased from the table, by
+    /// moving the last entry into its place.
+    fn swap_remove_finish(&mut self, index: usize) -> (K, V) {
+        let bucket = self.entries.swap_remove(index);
+        if let Some(moved) = self.entries.get(index) {
+            let old = self.entries.len();
+            self.update_index(moved.hash, old, index);
+        }
+        bucket.key_value()
+    }
+
+    /// Removes the entry whose index was already erased from the table, by
+    /// shifting all following entries down.
+    fn shift_remove_finish(&mut self, index: usize) -> (K, V) {
+        let bucket = self.entries.remove(index);
+        self.decrement_indices(index + 1, self.entries.len() + 1);
+        bucket.key_value()
+    }
+
+    /// Decrements the indices of the entries that used to be at `start..end`
+    /// and have already been shifted down by one in the entries vector.
+    fn decrement_indices(&mut self, start: usize, end: usize) {
+        if end - start > self.indices.len() / 2 {
+            // Most indices change, so walking the table beats probing it.
+            for i in self.indices.iter_mut() {
+                if start <= *i && *i < end {
+                    *i -= 1;
+                }
+            }
+        } else {
+            for old in start..end {
+                let hash = self.entries[old - 1].hash;
+                self.update_index(hash, old, old - 1);
+            }
+        }
+    }
+
+    /// Increments the indices of the entries that used to be at `start..end`
+    /// and have already been shifted up by one in the entries vector.
+    fn increment_indices(&mut self, start: usize, end: usize) {
+        if end - start > self.indices.len() / 2 {
+            for i in self.indices.iter_mut() {
+                if start <= *i && *i < end {
+                    *i += 1;
+                }
+            }
+        } else {
+            // Go backwards so that no two entries ever share an index.
+            for old in (start..end).rev() {
+                let hash = self.entries[old + 1].hash;
+                self.update_index(hash, old, old + 1);
+            }
+        }
+    }
+
+    fn swap_remove_index(&mut self, index: usize) -> Option<(K, V)> {
+        let hash = self.entries.get(index)?.hash;
+        self.erase_index(hash, index);
+        Some(self.swap_remove_finish(index))
+    }
+
+    fn shift_remove_index(&mut self, index: usize) -> Option<(K, V)> {
+        let hash = self.entries.get(index)?.hash;
+        self.erase_index(hash, index);
+        Some(self.shift_remove_finish(index))
+    }
+
+    fn swap_remove_full<Q: ?Sized + Eq>(&mut self, hash: u64, key: &Q) -> Option<(usize, K, V)>
+    where
+        K: Borrow<Q>,
+    {
+        let entry = self.indices.find_entry(hash, equivalent(key, &self.entries)).ok()?;
+        let (index, _) = entry.remove();
+        let (key, value) = self.swap_remove_finish(index);
+        Some((index, key, value))
+    }
+
+    fn shift_remove_full<Q: ?Sized + Eq>(&mut self, hash: u64, key: &Q) -> Option<(usize, K, V)>
+    where
+        K: Borrow<Q>,
+    {
+        let entry = self.indices.find_entry(hash, equivalent(key, &self.entries)).ok()?;
+        let (index, _) = entry.remove();
+        let (key, value) = self.shift_remove_finish(index);
+        Some((index, key, value))
+    }
+
+    fn swap_indices(&mut self, a: usize, b: usize) {
+        let (hash_a, hash_b) = (self.entries[a].hash, self.entries[b].hash);
+        if a == b {
+            return;
+        }
+        self.update_index(hash_a, a, usize::MAX);
+        self.update_index(hash_b, b, a);
+        self.update_index(hash_a, usize::MAX, b);
+        self.entries.swap(a, b);
+    }
+
+    fn move_index(&mut self, from: usize, to: usize) {
+        let (hash, len) = (self.entries[from].hash, self.entries.len());
+        assert!(to < len, "destination index (is {to}) should be < len (is {len})");
+        // Park the moving entry's index out of the way while the others shift.
+        self.update_index(hash, from, usize::MAX);
+        if from < to {
+            self.entries[from..=to].rotate_left(1);
+            self.decrement_indices(from + 1, to + 1);
+        } else if to < from {
+            self.entries[to..=from].rotate_right(1);
+            self.increment_indices(to, from);
+        }
+        self.update_index(hash, usize::MAX, to);
+    }
+
+    fn truncate(&mut self, len: usize) {
+        if len < self.entries.len() {
+            self.indices.retain(|&mut i| i < len);
+            self.entries.truncate(len);
+        }
+    }
+
+    fn drain(&mut self, range: impl RangeBounds<usize>) -> vec::Drain<'_, Bucket<K, V>> {
+        let range = slice::range(range, ..self.entries.len());
+        if range.is_empty() {
+            return self.entries.drain(range);
+        }
+        if range.len() == self.entries.len() {
+            self.indices.clear();
+        } else {
+            let (start, end) = (range.start, range.end);
+            self.indices.retain(move |i| {
+                if *i >= end {
+                    *i -= end - start;
+                    true
+                } else {
+                    *i < start
+                }
+            });
+        }
+        self.entries.drain(range)
+    }
+
+    fn reverse(&mut self) {
+        let len = self.entries.len();
+        for i in self.indices.iter_mut() {
+            *i = len - 1 - *i;
+        }
+        self.entries.reverse();
+    }
+
+    /// Gives access to the entries for arbitrary reordering or removal, and
+    /// rebuilds the table afterwards, even when `f` panics.
+    fn with_entries<R>(&mut self, f: impl FnOnce(&mut Vec<Bucket<K, V>>) -> R) -> R {
+        struct RebuildOnDrop<'a, K, V>(&'a mut Core<K, V>);
+
+        impl<K, V> Drop for RebuildOnDrop<'_, K, V> {
+            fn drop(&mut self) {
+                self.0.rebuild();
+            }
+        }
+
+        let guard = RebuildOnDrop(self);
+        f(&mut guard.0.entries)
+    }
+}
+
+impl<K: Eq, V> Core<K, V> {
+    fn insert_full(&mut self, hash: u64, key: K, value: V) -> (usize, Option<V>) {
+        let eq = |&i: &usize| self.entries[i].key == key;
+        match self.indices.entry(hash, eq, get_hash(&self.entries)) {
+            hashbrown::hash_table::Entry::Occupied(entry) => {
+                let index = *entry.get();
+                (index, Some(mem::replace(&mut self.entries[index].value, value)))
+            }
+            hashbrown::hash_table::Entry::Vacant(entry) => {
+                let index = self.entries.len();
+                entry.insert(index);
+                self.entries.push(Bucket { hash, key, value });
+                (index, None)
+            }
+        }
+    }
+
+    fn entry(&mut self, hash: u64, key: K) -> Entry<'_, K, V> {
+        match self.find(hash, &key) {
+            Some(index) => Entry::Occupied(OccupiedEntry { core: self, index }),
+            None => Entry::Vacant(VacantEntry { core: self, hash, key }),
+        }
+    }
+}
+
+impl<K, V> IndexMap<K, V, RandomState> {
+    /// Creates an empty `IndexMap`.
+    ///
+    /// The map is initially created with a capacity of 0, so it will not
+    /// allocate until it is first inserted into.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(index_map)]
+    /// use std::collections::IndexMap;
+    /// let mut map: IndexMap<&str, i32> = IndexMap::new();
+    /// ```
+    #[inline]
+    #[must_use]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn new() -> IndexMap<K, V, RandomState> {
+        Default::default()
+    }
+
+    /// Creates an empty `IndexMap` with at least the specified capacity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(index_map)]
+    /// use std::collections::IndexMap;
+    /// let mut map: IndexMap<&str, i32> = IndexMap::with_capacity(10);
+    /// assert!(map.capacity() >= 10);
+    /// ```
+    #[inline]
+    #[must_use]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn with_capacity(capacity: usize) -> IndexMap<K, V, RandomState> {
+        IndexMap::with_capacity_and_hasher(capacity, Default::default())
+    }
+}
+
+impl<K, V, S> IndexMap<K, V, S> {
+    /// Creates an empty `IndexMap` which will use the given hash builder to
+    /// hash keys.
+    ///
+    /// See [`HashMap::with_hasher`](crate::collections::HashMap::with_hasher)
+    /// for the caveats about choosing a hasher.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn with_hasher(hash_builder: S) -> IndexMap<K, V, S> {
+        IndexMap { core: Core::new(), hash_builder }
+    }
+
+    /// Creates an empty `IndexMap` with at least the specified capacity,
+    /// using `hasher` to hash the keys.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn with_capacity_and_hasher(capacity: usize, hasher: S) -> IndexMap<K, V, S> {
+        IndexMap { core: Core::with_capacity(capacity), hash_builder: hasher }
+    }
+
+    /// Returns the number of elements the map can hold without reallocating.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn capacity(&self) -> usize {
+        self.core.capacity()
+    }
+
+    /// Returns a reference to the map's [`BuildHasher`].
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn hasher(&self) -> &S {
+        &self.hash_builder
+    }
+
+    /// Returns the number of elements in the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(index_map)]
+    /// use std::collections::IndexMap;
+    ///
+    /// let mut a = IndexMap::new();
+    /// assert_eq!(a.len(), 0);
+    /// a.insert(1, "a");
+    /// assert_eq!(a.len(), 1);
+    /// ```
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn len(&self) -> usize {
+        self.core.len()
+    }
+
+    /// Returns `true` if the map contains no elements.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// An iterator visiting all key-value pairs in order.
+    /// The iterator element type is `(&'a K, &'a V)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(index_map)]
+    /// use std::collections::IndexMap;
+    ///
+    /// let map = IndexMap::from([("c", 3), ("a", 1), ("b", 2)]);
+    /// let pairs: Vec<_> = map.iter().collect();
+    /// assert_eq!(pairs, [(&"c", &3), (&"a", &1), (&"b", &2)]);
+    /// ```
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter { iter: self.core.entries.iter() }
+    }
+
+    /// An iterator visiting all key-value pairs in order, with mutable
+    /// references to the values.
+    /// The iterator element type is `(&'a K, &'a mut V)`.
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn iter_mut(&mut self) -> IterMut<'_, K, V> {
+        IterMut { iter: self.core.entries.iter_mut() }
+    }
+
+    /// An iterator visiting all keys in order.
+    /// The iterator element type is `&'a K`.
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn keys(&self) -> Keys<'_, K, V> {
+        Keys { iter: self.core.entries.iter() }
+    }
+
+    /// Creates a consuming iterator visiting all the keys in order.
+    /// The iterator element type is `K`.
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn into_keys(self) -> IntoKeys<K, V> {
+        IntoKeys { iter: self.core.entries.into_iter() }
+    }
+
+    /// An iterator visiting all values in order.
+    /// The iterator element type is `&'a V`.
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn values(&self) -> Values<'_, K, V> {
+        Values { iter: self.core.entries.iter() }
+    }
+
+    /// An iterator visiting all values mutably in order.
+    /// The iterator element type is `&'a mut V`.
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn values_mut(&mut self) -> ValuesMut<'_, K, V> {
+        ValuesMut { iter: self.core.entries.iter_mut() }
+    }
+
+    /// Creates a consuming iterator visiting all the values in order.
+    /// The iterator element type is `V`.
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn into_values(self) -> IntoValues<K, V> {
+        IntoValues { iter: self.core.entries.into_iter() }
+    }
+
+    /// Clears the map, removing all key-value pairs. Keeps the allocated
+    /// memory for reuse.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn clear(&mut self) {
+        self.core.clear();
+    }
+
+    /// Removes the key-value pairs in the specified range of positions from
+    /// the map, and returns them in order as an iterator. Entries after the
+    /// range shift down to close the gap.
+    ///
+    /// If the returned iterator is dropped before being fully consumed, it
+    /// drops the remaining removed elements.
+    ///
+    /// The returned iterator keeps a mutable borrow on the map to optimize
+    /// its implementation. As with [`Vec::drain`], if it goes out of scope
+    /// without being dropped (due to [`mem::forget`], for example), the map
+    /// may have lost and leaked elements arbitrarily, including elements
+    /// outside the range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the starting point is greater than the end point or if
+    /// the end point is greater than the length of the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(index_map)]
+    /// use std::collections::IndexMap;
+    ///
+    /// let mut map = IndexMap::from([(1, 'a'), (2, 'b'), (3, 'c'), (4, 'd')]);
+    /// let drained: Vec<_> = map.drain(1..3).collect();
+    /// assert_eq!(drained, [(2, 'b'), (3, 'c')]);
+    /// assert_eq!(map.get_index_of(&4), Some(1));
+    /// ```
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn drain<R>(&mut self, range: R) -> Drain<'_, K, V>
+    where
+        R: RangeBounds<usize>,
+    {
+        Drain { iter: self.core.drain(range) }
+    }
+
+    /// Shortens the map, keeping the first `len` elements and dropping the
+    /// rest. Does nothing if `len` is greater than or equal to the map's
+    /// current length.
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn truncate(&mut self, len: usize) {
+        self.core.truncate(len);
+    }
+
+    /// Retains only the elements specified by the predicate, preserving the
+    /// order of the retained elements.
+    ///
+    /// In other words, remove all pairs `(k, v)` for which `f(&k, &mut v)`
+    /// returns `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(index_map)]
+    /// use std::collections::IndexMap;
+    ///
+    /// let mut map: IndexMap<i32, i32> = (0..8).map(|x| (x, x * 10)).collect();
+    /// map.retain(|&k, _| k % 2 == 0);
+    /// assert_eq!(map.keys().copied().collect::<Vec<_>>(), [0, 2, 4, 6]);
+    /// ```
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&K, &mut V) -> bool,
+    {
+        self.core.with_entries(|entries| entries.retain_mut(|b| f(&b.key, &mut b.value)));
+    }
+
+    /// Returns the key-value pair at position `index`, or `None` if `index`
+    /// is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(index_map)]
+    /// use std::collections::IndexMap;
+    ///
+    /// let map = IndexMap::from([("a", 1), ("b", 2)]);
+    /// assert_eq!(map.get_index(1), Some((&"b", &2)));
+    /// assert_eq!(map.get_index(2), None);
+    /// ```
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn get_index(&self, index: usize) -> Option<(&K, &V)> {
+        self.core.entries.get(index).map(Bucket::refs)
+    }
+
+    /// Returns the key and a mutable reference to the value at position
+    /// `index`, or `None` if `index` is out of bounds.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn get_index_mut(&mut self, index: usize) -> Option<(&K, &mut V)> {
+        self.core.entries.get_mut(index).map(Bucket::ref_mut)
+    }
+
+    /// Returns the first key-value pair, or `None` if the map is empty.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn first(&self) -> Option<(&K, &V)> {
+        self.core.entries.first().map(Bucket::refs)
+    }
+
+    /// Returns the last key-value pair, or `None` if the map is empty.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn last(&self) -> Option<(&K, &V)> {
+        self.core.entries.last().map(Bucket::refs)
+    }
+
+    /// Removes and returns the last key-value pair, or `None` if the map is
+    /// empty.
+    ///
+    /// This takes O(1) time.
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn pop(&mut self) -> Option<(K, V)> {
+        self.core.pop()
+    }
+
+    /// Removes the key-value pair at position `index` and returns it, or
+    /// `None` if `index` is out of bounds. The last entry takes its place.
+    ///
+    /// This takes O(1) time, but disturbs the order of the map.
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn swap_remove_index(&mut self, index: usize) -> Option<(K, V)> {
+        self.core.swap_remove_index(index)
+    }
+
+    /// Removes the key-value pair at position `index` and returns it, or
+    /// `None` if `index` is out of bounds. All following entries shift down.
+    ///
+    /// This preserves the order of the map, but takes O(n) time.
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn shift_remove_index(&mut self, index: usize) -> Option<(K, V)> {
+        self.core.shift_remove_index(index)
+    }
+
+    /// Swaps the positions of the entries at `a` and `b`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` or `b` are out of bounds.
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn swap_indices(&mut self, a: usize, b: usize) {
+        self.core.swap_indices(a, b);
+    }
+
+    /// Moves the entry at position `from` to position `to`, shifting the
+    /// entries in between by one to make room.
+    ///
+    /// This takes time proportional to the distance between `from` and `to`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `from` or `to` are out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(index_map)]
+    /// use std::collections::IndexMap;
+    ///
+    /// let mut map = IndexMap::from([('a', 0), ('b', 1), ('c', 2), ('d', 3)]);
+    /// map.move_index(0, 2);
+    /// assert_eq!(map.keys().collect::<String>(), "bcad");
+    /// assert_eq!(map.get_index_of(&'a'), Some(2));
+    /// ```
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn move_index(&mut self, from: usize, to: usize) {
+        self.core.move_index(from, to);
+    }
+
+    /// Reverses the order of the entries in place.
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn reverse(&mut self) {
+        self.core.reverse();
+    }
+
+    /// Sorts the map by key.
+    ///
+    /// This sort is stable and takes O(n \* log(n)) time, plus O(n) time to
+    /// rebuild the hash table of indices.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(index_map)]
+    /// use std::collections::IndexMap;
+    ///
+    /// let mut map = IndexMap::from([(3, 'c'), (1, 'a'), (2, 'b')]);
+    /// map.sort_keys();
+    /// assert_eq!(map.values().collect::<String>(), "abc");
+    /// assert_eq!(map.get_index_of(&3), Some(2));
+    /// ```
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn sort_keys(&mut self)
+    where
+        K: Ord,
+    {
+        self.core.with_entries(|entries| entries.sort_by(|a, b| K::cmp(&a.key, &b.key)));
+    }
+
+    /// Sorts the map with a comparator function on the key-value pairs.
+    ///
+    /// The comparator is called as `compare(key1, value1, key2, value2)`.
+    /// This sort is stable.
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn sort_by<F>(&mut self, mut compare: F)
+    where
+        F: FnMut(&K, &V, &K, &V) -> Ordering,
+    {
+        self.core.with_entries(|entries| {
+            entries.sort_by(|a, b| compare(&a.key, &a.value, &b.key, &b.value))
+        });
+    }
+
+    /// Sorts the map by key, but might not preserve the order of equal keys.
+    ///
+    /// As keys are unique, this only makes a difference when their [`Ord`]
+    /// implementation is coarser than their [`Eq`] implementation.
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn sort_unstable_keys(&mut self)
+    where
+        K: Ord,
+    {
+        self.core.with_entries(|entries| entries.sort_unstable_by(|a, b| K::cmp(&a.key, &b.key)));
+    }
+
+    /// Sorts the map with a comparator function on the key-value pairs, but
+    /// might not preserve the order of equal elements.
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn sort_unstable_by<F>(&mut self, mut compare: F)
+    where
+        F: FnMut(&K, &V, &K, &V) -> Ordering,
+    {
+        self.core.with_entries(|entries| {
+            entries.sort_unstable_by(|a, b| compare(&a.key, &a.value, &b.key, &b.value))
+        });
+    }
+}
+
+impl<K, V, S> IndexMap<K, V, S>
+where
+    K: Eq + Hash,
+    S: BuildHasher,
+{
+    fn hash<Q: ?Sized + Hash>(&self, key: &Q) -> u64 {
+        self.hash_builder.hash_one(key)
+    }
+
+    /// Reserves capacity for at least `additional` more elements to be
+    /// inserted in the map.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new allocation size overflows [`usize`].
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn reserve(&mut self, additional: usize) {
+        self.core.reserve(additional)
+    }
+
+    /// Tries to reserve capacity for at least `additional` more elements to
+    /// be inserted in the map.
+    ///
+    /// # Errors
+    ///
+    /// If the capacity overflows, or the allocator reports a failure, then
+    /// an error is returned.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.core.try_reserve(additional)
+    }
+
+    /// Shrinks the capacity of the map as much as possible.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn shrink_to_fit(&mut self) {
+        self.core.shrink_to(0);
+    }
+
+    /// Shrinks the capacity of the map with a lower limit.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn shrink_to(&mut self, min_capacity: usize) {
+        self.core.shrink_to(min_capacity);
+    }
+
+    /// Gets the given key's corresponding entry in the map for in-place
+    /// manipulation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(index_map)]
+    /// use std::collections::IndexMap;
+    ///
+    /// let mut letters = IndexMap::new();
+    /// for ch in "a short treatise on fungi".chars() {
+    ///     letters.entry(ch).and_modify(|counter| *counter += 1).or_insert(1);
+    /// }
+    ///
+    /// assert_eq!(letters.get_index(0), Some((&'a', &2)));
+    /// assert_eq!(letters.entry('y').index(), letters.len());
+    /// ```
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn entry(&mut self, key: K) -> Entry<'_, K, V> {
+        let hash = self.hash(&key);
+        self.core.entry(hash, key)
+    }
+
+    /// Inserts a key-value pair into the map.
+    ///
+    /// If the map did not have this key present, the pair is appended at the
+    /// end and [`None`] is returned.
+    ///
+    /// If the map did have this key present, the value is updated in place,
+    /// and the old value is returned. The key and its position are not
+    /// updated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(index_map)]
+    /// use std::collections::IndexMap;
+    ///
+    /// let mut map = IndexMap::new();
+    /// assert_eq!(map.insert(37, "a"), None);
+    /// assert_eq!(map.insert(42, "b"), None);
+    ///
+    /// assert_eq!(map.insert(37, "c"), Some("a"));
+    /// assert_eq!(map.get_index(0), Some((&37, &"c")));
+    /// ```
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        self.insert_full(key, value).1
+    }
+
+    /// Inserts a key-value pair into the map, and returns the position of
+    /// the key along with the old value, if any.
+    ///
+    /// See [`insert`](Self::insert) for more details.
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn insert_full(&mut self, key: K, value: V) -> (usize, Option<V>) {
+        let hash = self.hash(&key);
+        self.core.insert_full(hash, key, value)
+    }
+
+    /// Returns the position of the key in the map, if it is present.
+    ///
+    /// The key may be any borrowed form of the map's key type, but
+    /// [`Hash`] and [`Eq`] on the borrowed form *must* match those for
+    /// the key type.
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn get_index_of<Q: ?Sized>(&self, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq,
+    {
+        match self.len() {
+            0 => None,
+            _ => self.core.find(self.hash(key), key),
+        }
+    }
+
+    /// Returns a reference to the value corresponding to the key.
+    ///
+    /// The key may be any borrowed form of the map's key type, but
+    /// [`Hash`] and [`Eq`] on the borrowed form *must* match those for
+    /// the key type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(index_map)]
+    /// use std::collections::IndexMap;
+    ///
+    /// let mut map = IndexMap::new();
+    /// map.insert(1, "a");
+    /// assert_eq!(map.get(&1), Some(&"a"));
+    /// assert_eq!(map.get(&2), None);
+    /// ```
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn get<Q: ?Sized>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq,
+    {
+        self.get_full(key).map(|(_, _, value)| value)
+    }
+
+    /// Returns the key-value pair corresponding to the supplied key.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn get_key_value<Q: ?Sized>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq,
+    {
+        self.get_full(key).map(|(_, key, value)| (key, value))
+    }
+
+    /// Returns the position and key-value pair corresponding to the supplied
+    /// key.
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn get_full<Q: ?Sized>(&self, key: &Q) -> Option<(usize, &K, &V)>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq,
+    {
+        let index = self.get_index_of(key)?;
+        let bucket = &self.core.entries[index];
+        Some((index, &bucket.key, &bucket.value))
+    }
+
+    /// Returns a mutable reference to the value corresponding to the key.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn get_mut<Q: ?Sized>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq,
+    {
+        let index = self.get_index_of(key)?;
+        Some(&mut self.core.entries[index].value)
+    }
+
+    /// Returns `true` if the map contains a value for the specified key.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn contains_key<Q: ?Sized>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq,
+    {
+        self.get_index_of(key).is_some()
+    }
+
+    /// Removes a key from the map, returning the value at the key if the key
+    /// was previously in the map. The last entry takes the place of the
+    /// removed one.
+    ///
+    /// This takes O(1) time, but disturbs the order of the map. Use
+    /// [`shift_remove`](Self::shift_remove) to preserve the order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(index_map)]
+    /// use std::collections::IndexMap;
+    ///
+    /// let mut map = IndexMap::from([('a', 1), ('b', 2), ('c', 3)]);
+    /// assert_eq!(map.swap_remove(&'a'), Some(1));
+    /// assert_eq!(map.swap_remove(&'a'), None);
+    /// assert_eq!(map.keys().collect::<String>(), "cb");
+    /// ```
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn swap_remove<Q: ?Sized>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq,
+    {
+        self.swap_remove_full(key).map(|(_, _, value)| value)
+    }
+
+    /// Removes a key from the map like [`swap_remove`](Self::swap_remove),
+    /// returning the stored key and value.
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn swap_remove_entry<Q: ?Sized>(&mut self, key: &Q) -> Option<(K, V)>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq,
+    {
+        self.swap_remove_full(key).map(|(_, key, value)| (key, value))
+    }
+
+    /// Removes a key from the map like [`swap_remove`](Self::swap_remove),
+    /// returning the position it had along with the stored key and value.
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn swap_remove_full<Q: ?Sized>(&mut self, key: &Q) -> Option<(usize, K, V)>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq,
+    {
+        match self.len() {
+            0 => None,
+            _ => self.core.swap_remove_full(self.hash(key), key),
+        }
+    }
+
+    /// Removes a key from the map, returning the value at the key if the key
+    /// was previously in the map. All entries after the removed one shift
+    /// down.
+    ///
+    /// This preserves the order of the map, but takes O(n) time. Use
+    /// [`swap_remove`](Self::swap_remove) if the order does not matter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(index_map)]
+    /// use std::collections::IndexMap;
+    ///
+    /// let mut map = IndexMap::from([('a', 1), ('b', 2), ('c', 3)]);
+    /// assert_eq!(map.shift_remove(&'a'), Some(1));
+    /// assert_eq!(map.shift_remove(&'a'), None);
+    /// assert_eq!(map.keys().collect::<String>(), "bc");
+    /// ```
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn shift_remove<Q: ?Sized>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq,
+    {
+        self.shift_remove_full(key).map(|(_, _, value)| value)
+    }
+
+    /// Removes a key from the map like [`shift_remove`](Self::shift_remove),
+    /// returning the stored key and value.
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn shift_remove_entry<Q: ?Sized>(&mut self, key: &Q) -> Option<(K, V)>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq,
+    {
+        self.shift_remove_full(key).map(|(_, key, value)| (key, value))
+    }
+
+    /// Removes a key from the map like [`shift_remove`](Self::shift_remove),
+    /// returning the position it had along with the stored key and value.
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn shift_remove_full<Q: ?Sized>(&mut self, key: &Q) -> Option<(usize, K, V)>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq,
+    {
+        match self.len() {
+            0 => None,
+            _ => self.core.shift_remove_full(self.hash(key), key),
+        }
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<K, V, S> Clone for IndexMap<K, V, S>
+where
+    K: Clone,
+    V: Clone,
+    S: Clone,
+{
+    fn clone(&self) -> Self {
+        IndexMap { core: self.core.clone(), hash_builder: self.hash_builder.clone() }
+    }
+}
+
+/// Maps are equal if they hold the same key-value pairs, regardless of the
+/// order they are in, like two [`HashMap`](crate::collections::HashMap)s.
+#[unstable(feature = "index_map", issue = "none")]
+impl<K, V, S> PartialEq for IndexMap<K, V, S>
+where
+    K: Eq + Hash,
+    V: PartialEq,
+    S: BuildHasher,
+{
+    fn eq(&self, other: &IndexMap<K, V, S>) -> bool {
+        if self.len() != other.len() {
+            return false;
+        }
+
+        self.iter().all(|(key, value)| other.get(key).map_or(false, |v| *value == *v))
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<K, V, S> Eq for IndexMap<K, V, S>
+where
+    K: Eq + Hash,
+    V: Eq,
+    S: BuildHasher,
+{
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<K, V, S> Debug for IndexMap<K, V, S>
+where
+    K: Debug,
+    V: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<K, V, S> Default for IndexMap<K, V, S>
+where
+    S: Default,
+{
+    /// Creates an empty `IndexMap<K, V, S>`, with the `Default` value for the hasher.
+    #[inline]
+    fn default() -> IndexMap<K, V, S> {
+        IndexMap::with_hasher(Default::default())
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<K, Q: ?Sized, V, S> Index<&Q> for IndexMap<K, V, S>
+where
+    K: Eq + Hash + Borrow<Q>,
+    Q: Eq + Hash,
+    S: BuildHasher,
+{
+    type Output = V;
+
+    /// Returns a reference to the value corresponding to the supplied key.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the key is not present in the `IndexMap`.
+    #[inline]
+    fn index(&self, key: &Q) -> &V {
+        self.get(key).expect("no entry found for key")
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<K, V, S> Index<usize> for IndexMap<K, V, S> {
+    type Output = V;
+
+    /// Returns a reference to the value at the supplied position.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    #[inline]
+    fn index(&self, index: usize) -> &V {
+        &self.core.entries[index].value
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<K, V, S> IndexMut<usize> for IndexMap<K, V, S> {
+    /// Returns a mutable reference to the value at the supplied position.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut V {
+        &mut self.core.entries[index].value
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<K, V, const N: usize> From<[(K, V); N]> for IndexMap<K, V, RandomState>
+where
+    K: Eq + Hash,
+{
+    /// Converts a `[(K, V); N]` into an `IndexMap<K, V>`, keeping the order
+    /// of the array.
+    ///
+    /// If any entries in the array have equal keys, all but one of the
+    /// corresponding values will be dropped, and the first key keeps its
+    /// position.
+    fn from(arr: [(K, V); N]) -> Self {
+        IndexMap::from_iter(arr)
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<K, V, S> FromIterator<(K, V)> for IndexMap<K, V, S>
+where
+    K: Eq + Hash,
+    S: BuildHasher + Default,
+{
+    fn from_iter<T: IntoIterator<Item = (K, V)>>(iter: T) -> IndexMap<K, V, S> {
+        let mut map = IndexMap::with_hasher(Default::default());
+        map.extend(iter);
+        map
+    }
+}
+
+/// Inserts all new key-values from the iterator and replaces values with
+/// existing keys with new values returned from the iterator.
+#[unstable(feature = "index_map", issue = "none")]
+impl<K, V, S> Extend<(K, V)> for IndexMap<K, V, S>
+where
+    K: Eq + Hash,
+    S: BuildHasher,
+{
+    fn extend<T: IntoIterator<Item = (K, V)>>(&mut self, iter: T) {
+        let iter = iter.into_iter();
+        // Like `HashMap`, only reserve half if the map is not empty, in case
+        // most keys are already present.
+        let reserve =
+            if self.is_empty() { iter.size_hint().0 } else { (iter.size_hint().0 + 1) / 2 };
+        self.reserve(reserve);
+        iter.for_each(move |(k, v)| {
+            self.insert(k, v);
+        });
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<'a, K, V, S> Extend<(&'a K, &'a V)> for IndexMap<K, V, S>
+where
+    K: Eq + Hash + Copy,
+    V: Copy,
+    S: BuildHasher,
+{
+    fn extend<T: IntoIterator<Item = (&'a K, &'a V)>>(&mut self, iter: T) {
+        self.extend(iter.into_iter().map(|(&key, &value)| (key, value)));
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<'a, K, V, S> IntoIterator for &'a IndexMap<K, V, S> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    #[inline]
+    fn into_iter(self) -> Iter<'a, K, V> {
+        self.iter()
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<'a, K, V, S> IntoIterator for &'a mut IndexMap<K, V, S> {
+    type Item = (&'a K, &'a mut V);
+    type IntoIter = IterMut<'a, K, V>;
+
+    #[inline]
+    fn into_iter(self) -> IterMut<'a, K, V> {
+        self.iter_mut()
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<K, V, S> IntoIterator for IndexMap<K, V, S> {
+    type Item = (K, V);
+    type IntoIter = IntoIter<K, V>;
+
+    /// Creates a consuming iterator, that is, one that moves each key-value
+    /// pair out of the map in order. The map cannot be used after calling
+    /// this.
+    #[inline]
+    fn into_iter(self) -> IntoIter<K, V> {
+        IntoIter { iter: self.core.entries.into_iter() }
+    }
+}
+
+/// A view into a single entry in a map, which may either be vacant or occupied.
+///
+/// This `enum` is constructed from the [`entry`] method on [`IndexMap`].
+///
+/// [`entry`]: IndexMap::entry
+#[unstable(feature = "index_map", issue = "none")]
+pub enum Entry<'a, K: 'a, V: 'a> {
+    /// An occupied entry.
+    #[unstable(feature = "index_map", issue = "none")]
+    Occupied(#[unstable(feature = "index_map", issue = "none")] OccupiedEntry<'a, K, V>),
+
+    /// A vacant entry.
+    #[unstable(feature = "index_map", issue = "none")]
+    Vacant(#[unstable(feature = "index_map", issue = "none")] VacantEntry<'a, K, V>),
+}
+
+/// A view into an occupied entry in an `IndexMap`.
+/// It is part of the [`Entry`] enum.
+#[unstable(feature = "index_map", issue = "none")]
+pub struct OccupiedEntry<'a, K: 'a, V: 'a> {
+    core: &'a mut Core<K, V>,
+    index: usize,
+}
+
+/// A view into a vacant entry in an `IndexMap`.
+/// It is part of the [`Entry`] enum.
+#[unstable(feature = "index_map", issue = "none")]
+pub struct VacantEntry<'a, K: 'a, V: 'a> {
+    core: &'a mut Core<K, V>,
+    hash: u64,
+    key: K,
+}
+
+impl<'a, K, V> Entry<'a, K, V> {
+    /// Ensures a value is in the entry by inserting the default if empty, and
+    /// returns a mutable reference to the value in the entry.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn or_insert(self, default: V) -> &'a mut V {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(default),
+        }
+    }
+
+    /// Ensures a value is in the entry by inserting the result of the default
+    /// function if empty, and returns a mutable reference to the value in the
+    /// entry.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn or_insert_with<F: FnOnce() -> V>(self, default: F) -> &'a mut V {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(default()),
+        }
+    }
+
+    /// Ensures a value is in the entry by inserting, if empty, the result of
+    /// the default function, which is passed a reference to the key.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn or_insert_with_key<F: FnOnce(&K) -> V>(self, default: F) -> &'a mut V {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => {
+                let value = default(entry.key());
+                entry.insert(value)
+            }
+        }
+    }
+
+    /// Ensures a value is in the entry by inserting the default value if
+    /// empty, and returns a mutable reference to the value in the entry.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn or_default(self) -> &'a mut V
+    where
+        V: Default,
+    {
+        self.or_insert_with(Default::default)
+    }
+
+    /// Provides in-place mutable access to an occupied entry before any
+    /// potential inserts into the map.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn and_modify<F>(mut self, f: F) -> Self
+    where
+        F: FnOnce(&mut V),
+    {
+        if let Entry::Occupied(entry) = &mut self {
+            f(entry.get_mut());
+        }
+        self
+    }
+
+    /// Returns a reference to this entry's key.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn key(&self) -> &K {
+        match self {
+            Entry::Occupied(entry) => entry.key(),
+            Entry::Vacant(entry) => entry.key(),
+        }
+    }
+
+    /// Returns the position of this entry: where it is for an occupied
+    /// entry, or where it would be inserted for a vacant one.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn index(&self) -> usize {
+        match self {
+            Entry::Occupied(entry) => entry.index(),
+            Entry::Vacant(entry) => entry.index(),
+        }
+    }
+}
+
+impl<'a, K, V> OccupiedEntry<'a, K, V> {
+    /// Gets a reference to the key in the entry.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn key(&self) -> &K {
+        &self.core.entries[self.index].key
+    }
+
+    /// Returns the position of the entry in the map.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Gets a reference to the value in the entry.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn get(&self) -> &V {
+        &self.core.entries[self.index].value
+    }
+
+    /// Gets a mutable reference to the value in the entry.
+    ///
+    /// If you need a reference to the `OccupiedEntry` which may outlive the
+    /// destruction of the `Entry` value, see [`into_mut`](Self::into_mut).
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn get_mut(&mut self) -> &mut V {
+        &mut self.core.entries[self.index].value
+    }
+
+    /// Converts the entry into a mutable reference to the value in the entry
+    /// with a lifetime bound to the map itself.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn into_mut(self) -> &'a mut V {
+        &mut self.core.entries[self.index].value
+    }
+
+    /// Sets the value of the entry, and returns the entry's old value.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn insert(&mut self, value: V) -> V {
+        mem::replace(self.get_mut(), value)
+    }
+
+    /// Takes the value out of the entry, moving the last entry of the map
+    /// into its place. See [`IndexMap::swap_remove`].
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn swap_remove(self) -> V {
+        self.swap_remove_entry().1
+    }
+
+    /// Takes the key and value out of the entry, moving the last entry of
+    /// the map into its place. See [`IndexMap::swap_remove_entry`].
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn swap_remove_entry(self) -> (K, V) {
+        self.core.swap_remove_index(self.index).unwrap()
+    }
+
+    /// Takes the value out of the entry, shifting all following entries of
+    /// the map down. See [`IndexMap::shift_remove`].
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn shift_remove(self) -> V {
+        self.shift_remove_entry().1
+    }
+
+    /// Takes the key and value out of the entry, shifting all following
+    /// entries of the map down. See [`IndexMap::shift_remove_entry`].
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn shift_remove_entry(self) -> (K, V) {
+        self.core.shift_remove_index(self.index).unwrap()
+    }
+}
+
+impl<'a, K: 'a, V: 'a> VacantEntry<'a, K, V> {
+    /// Gets a reference to the key that would be used when inserting a value
+    /// through the `VacantEntry`.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    /// Takes ownership of the key.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn into_key(self) -> K {
+        self.key
+    }
+
+    /// Returns the position the entry would have once inserted, which is the
+    /// current length of the map.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn index(&self) -> usize {
+        self.core.len()
+    }
+
+    /// Appends the entry to the map with the `VacantEntry`'s key, and returns
+    /// a mutable reference to its value.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn insert(self, value: V) -> &'a mut V {
+        let index = self.core.push(self.hash, self.key, value);
+        &mut self.core.entries[index].value
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<K: Debug, V: Debug> Debug for Entry<'_, K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Entry::Vacant(ref v) => f.debug_tuple("Entry").field(v).finish(),
+            Entry::Occupied(ref o) => f.debug_tuple("Entry").field(o).finish(),
+        }
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<K: Debug, V: Debug> Debug for OccupiedEntry<'_, K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OccupiedEntry")
+            .field("key", self.key())
+            .field("value", self.get())
+            .field("index", &self.index)
+            .finish()
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<K: Debug, V> Debug for VacantEntry<'_, K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("VacantEntry").field(self.key()).finish()
+    }
+}
+
+/// Implements the iterator traits for an iterator over buckets, mapping each
+/// bucket through `$map`.
+macro_rules! bucket_iterator {
+    ($name:ident<$($lt:lifetime,)? K, V>, $item:ty, $map:expr) => {
+        #[unstable(feature = "index_map", issue = "none")]
+        impl<$($lt,)? K, V> Iterator for $name<$($lt,)? K, V> {
+            type Item = $item;
+
+            #[inline]
+            fn next(&mut self) -> Option<$item> {
+                self.iter.next().map($map)
+            }
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                self.iter.size_hint()
+            }
+            #[inline]
+            fn count(self) -> usize {
+                self.iter.len()
+            }
+            #[inline]
+            fn nth(&mut self, n: usize) -> Option<$item> {
+                self.iter.nth(n).map($map)
+            }
+        }
+
+        #[unstable(feature = "index_map", issue = "none")]
+        impl<$($lt,)? K, V> DoubleEndedIterator for $name<$($lt,)? K, V> {
+            #[inline]
+            fn next_back(&mut self) -> Option<$item> {
+                self.iter.next_back().map($map)
+            }
+        }
+
+        #[unstable(feature = "index_map", issue = "none")]
+        impl<$($lt,)? K, V> ExactSizeIterator for $name<$($lt,)? K, V> {
+            #[inline]
+            fn len(&self) -> usize {
+                self.iter.len()
+            }
+        }
+
+        #[unstable(feature = "index_map", issue = "none")]
+        impl<$($lt,)? K, V> FusedIterator for $name<$($lt,)? K, V> {}
+    };
+}
+
+/// An iterator over the entries of an `IndexMap`, in order.
+///
+/// This `struct` is created by the [`iter`] method on [`IndexMap`]. See its
+/// documentation for more.
+///
+/// [`iter`]: IndexMap::iter
+#[unstable(feature = "index_map", issue = "none")]
+pub struct Iter<'a, K: 'a, V: 'a> {
+    iter: slice::Iter<'a, Bucket<K, V>>,
+}
+
+bucket_iterator!(Iter<'a, K, V>, (&'a K, &'a V), Bucket::refs);
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<K, V> Clone for Iter<'_, K, V> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Iter { iter: self.iter.clone() }
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<K: Debug, V: Debug> Debug for Iter<'_, K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+/// A mutable iterator over the entries of an `IndexMap`, in order.
+///
+/// This `struct` is created by the [`iter_mut`] method on [`IndexMap`]. See
+/// its documentation for more.
+///
+/// [`iter_mut`]: IndexMap::iter_mut
+#[unstable(feature = "index_map", issue = "none")]
+pub struct IterMut<'a, K: 'a, V: 'a> {
+    iter: slice::IterMut<'a, Bucket<K, V>>,
+}
+
+bucket_iterator!(IterMut<'a, K, V>, (&'a K, &'a mut V), Bucket::ref_mut);
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<K: Debug, V: Debug> Debug for IterMut<'_, K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter.as_slice().iter().map(Bucket::refs)).finish()
+    }
+}
+
+/// An owning iterator over the entries of an `IndexMap`, in order.
+///
+/// This `struct` is created by the [`into_iter`] method on [`IndexMap`]
+/// (provided by the [`IntoIterator`] trait). See its documentation for more.
+///
+/// [`into_iter`]: IntoIterator::into_iter
+#[unstable(feature = "index_map", issue = "none")]
+pub struct IntoIter<K, V> {
+    iter: vec::IntoIter<Bucket<K, V>>,
+}
+
+bucket_iterator!(IntoIter<K, V>, (K, V), Bucket::key_value);
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<K: Debug, V: Debug> Debug for IntoIter<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter.as_slice().iter().map(Bucket::refs)).finish()
+    }
+}
+
+/// A draining iterator over a range of entries of an `IndexMap`, in order.
+///
+/// This `struct` is created by the [`drain`] method on [`IndexMap`]. See its
+/// documentation for more.
+///
+/// [`drain`]: IndexMap::drain
+#[unstable(feature = "index_map", issue = "none")]
+pub struct Drain<'a, K: 'a, V: 'a> {
+    iter: vec::Drain<'a, Bucket<K, V>>,
+}
+
+bucket_iterator!(Drain<'a, K, V>, (K, V), Bucket::key_value);
+
+impl<K, V> Drain<'_, K, V> {
+    /// Returns the keys that are yet to be drained, for `IndexSet`.
+    pub(super) fn keys(&self) -> impl Iterator<Item = &K> {
+        self.iter.as_slice().iter().map(|bucket| &bucket.key)
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<K: Debug, V: Debug> Debug for Drain<'_, K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter.as_slice().iter().map(Bucket::refs)).finish()
+    }
+}
+
+/// An iterator over the keys of an `IndexMap`, in order.
+///
+/// This `struct` is created by the [`keys`] method on [`IndexMap`]. See its
+/// documentation for more.
+///
+/// [`keys`]: IndexMap::keys
+#[unstable(feature = "index_map", issue = "none")]
+pub struct Keys<'a, K: 'a, V: 'a> {
+    iter: slice::Iter<'a, Bucket<K, V>>,
+}
+
+bucket_iterator!(Keys<'a, K, V>, &'a K, |bucket| &bucket.key);
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<K, V> Clone for Keys<'_, K, V> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Keys { iter: self.iter.clone() }
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<K: Debug, V> Debug for Keys<'_, K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+/// An owning iterator over the keys of an `IndexMap`, in order.
+///
+/// This `struct` is created by the [`into_keys`] method on [`IndexMap`].
+/// See its documentation for more.
+///
+/// [`into_keys`]: IndexMap::into_keys
+#[unstable(feature = "index_map", issue = "none")]
+pub struct IntoKeys<K, V> {
+    iter: vec::IntoIter<Bucket<K, V>>,
+}
+
+bucket_iterator!(IntoKeys<K, V>, K, |bucket| bucket.key);
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<K: Debug, V> Debug for IntoKeys<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter.as_slice().iter().map(|bucket| &bucket.key)).finish()
+    }
+}
+
+/// An iterator over the values of an `IndexMap`, in order.
+///
+/// This `struct` is created by the [`values`] method on [`IndexMap`]. See
+/// its documentation for more.
+///
+/// [`values`]: IndexMap::values
+#[unstable(feature = "index_map", issue = "none")]
+pub struct Values<'a, K: 'a, V: 'a> {
+    iter: slice::Iter<'a, Bucket<K, V>>,
+}
+
+bucket_iterator!(Values<'a, K, V>, &'a V, |bucket| &bucket.value);
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<K, V> Clone for Values<'_, K, V> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Values { iter: self.iter.clone() }
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<K, V: Debug> Debug for Values<'_, K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+/// A mutable iterator over the values of an `IndexMap`, in order.
+///
+/// This `struct` is created by the [`values_mut`] method on [`IndexMap`].
+/// See its documentation for more.
+///
+/// [`values_mut`]: IndexMap::values_mut
+#[unstable(feature = "index_map", issue = "none")]
+pub struct ValuesMut<'a, K: 'a, V: 'a> {
+    iter: slice::IterMut<'a, Bucket<K, V>>,
+}
+
+bucket_iterator!(ValuesMut<'a, K, V>, &'a mut V, |bucket| &mut bucket.value);
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<K, V: Debug> Debug for ValuesMut<'_, K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter.as_slice().iter().map(|bucket| &bucket.value)).finish()
+    }
+}
+
+/// An owning iterator over the values of an `IndexMap`, in order.
+///
+/// This `struct` is created by the [`into_values`] method on [`IndexMap`].
+/// See its documentation for more.
+///
+/// [`into_values`]: IndexMap::into_values
+#[unstable(feature = "index_map", issue = "none")]
+pub struct IntoValues<K, V> {
+    iter: vec::IntoIter<Bucket<K, V>>,
+}
+
+bucket_iterator!(IntoValues<K, V>, V, |bucket| bucket.value);
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<K, V: Debug> Debug for IntoValues<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter.as_slice().iter().map(|bucket| &bucket.value)).finish()
+    }
+}
@@ -0,0 +1,242 @@
+use rand::Rng;
+use rand::seq::SliceRandom;
+
+use super::Entry::{Occupied, Vacant};
+use super::IndexMap;
+use crate::panic::{AssertUnwindSafe, catch_unwind};
+use crate::test_helpers::test_rng;
+
+/// Checks that every key is found at the position it is iterated at.
+fn check_indices<K, V>(map: &IndexMap<K, V>)
+where
+    K: crate::hash::Hash + Eq + crate::fmt::Debug,
+{
+    for (i, (k, _)) in map.iter().enumerate() {
+        assert_eq!(map.get_index_of(k), Some(i), "key {k:?} at {i}");
+    }
+    assert_eq!(map.core.indices.len(), map.len());
+}
+
+#[test]
+fn test_insert_keeps_order() {
+    let mut m = IndexMap::new();
+    for i in (0..100).rev() {
+        assert_eq!(m.insert(i, i * 2), None);
+    }
+    assert_eq!(m.insert(50, 0), Some(100));
+    assert_eq!(m.insert_full(3, 1), (96, Some(6)));
+    assert_eq!(m.insert_full(100, 1), (100, None));
+    assert_eq!(m.len(), 101);
+    assert!(m.keys().copied().eq((0..100).rev().chain([100])));
+    assert_eq!(m[&50], 0);
+    assert_eq!(m[49], 0);
+    assert_eq!(m.first(), Some((&99, &198)));
+    assert_eq!(m.last(), Some((&100, &1)));
+    check_indices(&m);
+}
+
+#[test]
+fn test_swap_remove() {
+    let mut m: IndexMap<_, _> = (0..10).map(|i| (i, i)).collect();
+    assert_eq!(m.swap_remove(&3), Some(3));
+    assert_eq!(m.swap_remove(&3), None);
+    assert_eq!(m.swap_remove_full(&9), Some((3, 9, 9)));
+    assert_eq!(m.swap_remove_full(&0), Some((0, 0, 0)));
+    assert_eq!(m.swap_remove_entry(&8), Some((8, 8)));
+    assert_eq!(m.swap_remove_index(6), None);
+    assert_eq!(m.swap_remove_index(5), Some((5, 5)));
+    assert_eq!(m.keys().copied().collect::<Vec<_>>(), [7, 1, 2, 6, 4]);
+    check_indices(&m);
+}
+
+#[test]
+fn test_shift_remove() {
+    let mut m: IndexMap<_, _> = (0..10).map(|i| (i, i)).collect();
+    assert_eq!(m.shift_remove(&3), Some(3));
+    assert_eq!(m.shift_remove(&3), None);
+    assert_eq!(m.shift_remove_full(&0), Some((0, 0, 0)));
+    assert_eq!(m.shift_remove_entry(&9), Some((9, 9)));
+    assert_eq!(m.shift_remove_index(2), Some((4, 4)));
+    assert_eq!(m.pop(), Some((8, 8)));
+    assert_eq!(m.keys().copied().collect::<Vec<_>>(), [1, 2, 5, 6, 7]);
+    check_indices(&m);
+}
+
+#[test]
+fn test_shift_remove_front_of_large_map() {
+    // Exercises the path that walks the table instead of probing it.
+    let mut m: IndexMap<_, _> = (0..1000).map(|i| (i, ())).collect();
+    for i in 0..500 {
+        assert_eq!(m.shift_remove_full(&i), Some((0, i, ())));
+    }
+    assert!(m.keys().copied().eq(500..1000));
+    check_indices(&m);
+}
+
+#[test]
+fn test_entry() {
+    let mut m: IndexMap<_, _> = [(1, 10), (2, 20), (3, 30)].into();
+
+    match m.entry(1) {
+        Vacant(_) => unreachable!(),
+        Occupied(mut view) => {
+            assert_eq!(view.index(), 0);
+            assert_eq!(view.get(), &10);
+            assert_eq!(view.insert(100), 10);
+        }
+    }
+    assert_eq!(m[&1], 100);
+
+    match m.entry(4) {
+        Occupied(_) => unreachable!(),
+        Vacant(view) => {
+            assert_eq!(view.index(), 3);
+            assert_eq!(*view.insert(40), 40);
+        }
+    }
+    assert_eq!(m.get_index(3), Some((&4, &40)));
+
+    match m.entry(2) {
+        Vacant(_) => unreachable!(),
+        Occupied(view) => assert_eq!(view.shift_remove_entry(), (2, 20)),
+    }
+    match m.entry(1) {
+        Vacant(_) => unreachable!(),
+        Occupied(view) => assert_eq!(view.swap_remove(), 100),
+    }
+    assert_eq!(m.keys().copied().collect::<Vec<_>>(), [4, 3]);
+    *m.entry(5).or_default() += 1;
+    *m.entry(4).and_modify(|v| *v += 1).or_insert(0) += 1;
+    assert_eq!(m.iter().collect::<Vec<_>>(), [(&4, &42), (&3, &30), (&5, &1)]);
+    check_indices(&m);
+}
+
+#[test]
+fn test_positional() {
+    let mut m: IndexMap<_, _> = "abcdef".chars().map(|c| (c, c as u32)).collect();
+    m.swap_indices(0, 5);
+    assert_eq!(m.keys().collect::<String>(), "fbcdea");
+    m.move_index(1, 4);
+    assert_eq!(m.keys().collect::<String>(), "fcdeba");
+    m.move_index(5, 0);
+    assert_eq!(m.keys().collect::<String>(), "afcdeb");
+    m.move_index(2, 2);
+    m.swap_indices(3, 3);
+    assert_eq!(m.keys().collect::<String>(), "afcdeb");
+    *m.get_index_mut(1).unwrap().1 = 0;
+    m[2] = 1;
+    assert_eq!(m[&'f'], 0);
+    assert_eq!(m[&'c'], 1);
+    check_indices(&m);
+    m.reverse();
+    assert_eq!(m.keys().collect::<String>(), "bedcfa");
+    check_indices(&m);
+}
+
+#[test]
+fn test_sort() {
+    let mut rng = test_rng();
+    let mut keys: Vec<u32> = (0..200).collect();
+    keys.shuffle(&mut rng);
+    let mut m: IndexMap<_, _> = keys.iter().map(|&k| (k, rng.random::<u8>())).collect();
+
+    m.sort_keys();
+    assert!(m.keys().copied().eq(0..200));
+    check_indices(&m);
+
+    m.sort_by(|_, v1, _, v2| v1.cmp(v2));
+    assert!(m.values().is_sorted());
+    check_indices(&m);
+
+    m.sort_unstable_by(|k1, _, k2, _| k2.cmp(k1));
+    assert!(m.keys().copied().eq((0..200).rev()));
+    check_indices(&m);
+
+    m.sort_unstable_keys();
+    assert!(m.keys().copied().eq(0..200));
+}
+
+#[test]
+fn test_sort_panic_keeps_map_consistent() {
+    let mut m: IndexMap<_, _> = (0..50).rev().map(|i| (i, ())).collect();
+    let mut calls = 0;
+    let r = catch_unwind(AssertUnwindSafe(|| {
+        m.sort_by(|k1, _, k2, _| {
+            calls += 1;
+            if calls == 20 {
+                panic!();
+            }
+            k1.cmp(k2)
+        })
+    }));
+    assert!(r.is_err());
+    assert_eq!(m.len(), 50);
+    check_indices(&m);
+}
+
+#[test]
+fn test_retain_truncate_drain() {
+    let mut m: IndexMap<_, _> = (0..20).map(|i| (i, i * 10)).collect();
+    m.retain(|&k, v| {
+        *v += 1;
+        k % 3 != 0
+    });
+    assert_eq!(m.len(), 13);
+    assert_eq!(m.get_index(0), Some((&1, &11)));
+    check_indices(&m);
+
+    let drained: Vec<_> = m.drain(2..5).collect();
+    assert_eq!(drained, [(4, 41), (5, 51), (7, 71)]);
+    assert!(!m.contains_key(&5));
+    assert_eq!(m.get_index_of(&8), Some(2));
+    check_indices(&m);
+
+    m.truncate(3);
+    assert_eq!(m.keys().copied().collect::<Vec<_>>(), [1, 2, 8]);
+    check_indices(&m);
+
+    m.drain(..).for_each(drop);
+    assert!(m.is_empty());
+    assert_eq!(m.get(&2), None);
+}
+
+#[test]
+fn test_randomized_against_vec() {
+    let mut rng = test_rng();
+    let mut m = IndexMap::new();
+    let mut model: Vec<(u8, u32)> = Vec::new();
+    for i in 0..2000 {
+        let k: u8 = rng.random();
+        match rng.random_range(0..4) {
+            0 | 1 => {
+                if let Some(pos) = model.iter().position(|e| e.0 == k) {
+                    model[pos].1 = i;
+                } else {
+                    model.push((k, i));
+                }
+                m.insert(k, i);
+            }
+            2 => {
+                let expected = model.iter().position(|e| e.0 == k).map(|pos| model.remove(pos).1);
+                assert_eq!(m.shift_remove(&k), expected);
+            }
+            _ => {
+                let expected =
+                    model.iter().position(|e| e.0 == k).map(|pos| model.swap_remove(pos).1);
+                assert_eq!(m.swap_remove(&k), expected);
+            }
+        }
+    }
+    assert!(m.iter().map(|(&k, &v)| (k, v)).eq(model.iter().copied()));
+    check_indices(&m);
+}
+
+#[test]
+fn test_eq_ignores_order() {
+    let a: IndexMap<_, _> = [(1, 'a'), (2, 'b')].into();
+    let b: IndexMap<_, _> = [(2, 'b'), (1, 'a')].into();
+    let c: IndexMap<_, _> = [(2, 'b'), (1, 'c')].into();
+    assert_eq!(a, b);
+    assert_ne!(a, c);
+    assert_eq!(format!("{a:?}"), "{1: 'a', 2: 'b'}");
+}
@@ -0,0 +1,758 @@
+#[cfg(test)]
+mod tests;
+
+use super::index_map::{self, IndexMap};
+use crate::borrow::Borrow;
+use crate::cmp::Ordering;
+use crate::collections::TryReserveError;
+use crate::fmt;
+use crate::hash::{BuildHasher, Hash, RandomState};
+use crate::iter::FusedIterator;
+use crate::ops::{Index, RangeBounds};
+
+/// A hash set that remembers the order in which its values were inserted,
+/// implemented as an [`IndexMap`] where the value is `()`.
+///
+/// Values are stored densely in insertion order and can be reached by their
+/// position as well as by lookup. See [`IndexMap`] for the difference
+/// between [`swap_remove`] and [`shift_remove`], and for the requirements on
+/// the values.
+///
+/// [`swap_remove`]: IndexSet::swap_remove
+/// [`shift_remove`]: IndexSet::shift_remove
+///
+/// # Examples
+///
+/// ```
+/// #![feature(index_map)]
+/// use std::collections::IndexSet;
+///
+/// let mut books = IndexSet::new();
+/// books.insert("A Dance With Dragons");
+/// books.insert("To Kill a Mockingbird");
+/// books.insert("The Odyssey");
+/// assert!(!books.insert("To Kill a Mockingbird"));
+///
+/// assert_eq!(books.get_index_of("The Odyssey"), Some(2));
+/// assert_eq!(books[0], "A Dance With Dragons");
+///
+/// books.sort();
+/// assert_eq!(books.iter().copied().collect::<Vec<_>>(), [
+///     "A Dance With Dragons",
+///     "The Odyssey",
+///     "To Kill a Mockingbird",
+/// ]);
+/// ```
+#[unstable(feature = "index_map", issue = "none")]
+pub struct IndexSet<T, S = RandomState> {
+    map: IndexMap<T, (), S>,
+}
+
+impl<T> IndexSet<T, RandomState> {
+    /// Creates an empty `IndexSet`.
+    ///
+    /// The set is initially created with a capacity of 0, so it will not
+    /// allocate until it is first inserted into.
+    #[inline]
+    #[must_use]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn new() -> IndexSet<T, RandomState> {
+        Default::default()
+    }
+
+    /// Creates an empty `IndexSet` with at least the specified capacity.
+    #[inline]
+    #[must_use]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn with_capacity(capacity: usize) -> IndexSet<T, RandomState> {
+        IndexSet { map: IndexMap::with_capacity(capacity) }
+    }
+}
+
+impl<T, S> IndexSet<T, S> {
+    /// Creates an empty `IndexSet` which will use the given hasher to hash
+    /// values.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn with_hasher(hasher: S) -> IndexSet<T, S> {
+        IndexSet { map: IndexMap::with_hasher(hasher) }
+    }
+
+    /// Creates an empty `IndexSet` with at least the specified capacity,
+    /// using `hasher` to hash the values.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn with_capacity_and_hasher(capacity: usize, hasher: S) -> IndexSet<T, S> {
+        IndexSet { map: IndexMap::with_capacity_and_hasher(capacity, hasher) }
+    }
+
+    /// Returns the number of elements the set can hold without reallocating.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn capacity(&self) -> usize {
+        self.map.capacity()
+    }
+
+    /// Returns a reference to the set's [`BuildHasher`].
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn hasher(&self) -> &S {
+        self.map.hasher()
+    }
+
+    /// Returns the number of elements in the set.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Returns `true` if the set contains no elements.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// An iterator visiting all elements in order.
+    /// The iterator element type is `&'a T`.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter { iter: self.map.keys() }
+    }
+
+    /// Clears the set, removing all values.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn clear(&mut self) {
+        self.map.clear()
+    }
+
+    /// Removes the values in the specified range of positions from the set,
+    /// and returns them in order as an iterator. Values after the range
+    /// shift down to close the gap.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the starting point is greater than the end point or if
+    /// the end point is greater than the length of the set.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn drain<R>(&mut self, range: R) -> Drain<'_, T>
+    where
+        R: RangeBounds<usize>,
+    {
+        Drain { iter: self.map.drain(range) }
+    }
+
+    /// Shortens the set, keeping the first `len` elements and dropping the
+    /// rest.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn truncate(&mut self, len: usize) {
+        self.map.truncate(len)
+    }
+
+    /// Retains only the elements specified by the predicate, preserving the
+    /// order of the retained elements.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.map.retain(|value, _| f(value))
+    }
+
+    /// Returns the value at position `index`, or `None` if `index` is out of
+    /// bounds.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn get_index(&self, index: usize) -> Option<&T> {
+        self.map.get_index(index).map(|(value, _)| value)
+    }
+
+    /// Returns the first value, or `None` if the set is empty.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn first(&self) -> Option<&T> {
+        self.map.first().map(|(value, _)| value)
+    }
+
+    /// Returns the last value, or `None` if the set is empty.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn last(&self) -> Option<&T> {
+        self.map.last().map(|(value, _)| value)
+    }
+
+    /// Removes and returns the last value, or `None` if the set is empty.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn pop(&mut self) -> Option<T> {
+        self.map.pop().map(|(value, ())| value)
+    }
+
+    /// Removes the value at position `index` and returns it, or `None` if
+    /// `index` is out of bounds. The last value takes its place.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn swap_remove_index(&mut self, index: usize) -> Option<T> {
+        self.map.swap_remove_index(index).map(|(value, ())| value)
+    }
+
+    /// Removes the value at position `index` and returns it, or `None` if
+    /// `index` is out of bounds. All following values shift down.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn shift_remove_index(&mut self, index: usize) -> Option<T> {
+        self.map.shift_remove_index(index).map(|(value, ())| value)
+    }
+
+    /// Swaps the positions of the values at `a` and `b`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` or `b` are out of bounds.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn swap_indices(&mut self, a: usize, b: usize) {
+        self.map.swap_indices(a, b)
+    }
+
+    /// Moves the value at position `from` to position `to`, shifting the
+    /// values in between by one to make room.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `from` or `to` are out of bounds.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn move_index(&mut self, from: usize, to: usize) {
+        self.map.move_index(from, to)
+    }
+
+    /// Reverses the order of the values in place.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn reverse(&mut self) {
+        self.map.reverse()
+    }
+
+    /// Sorts the set. This sort is stable.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn sort(&mut self)
+    where
+        T: Ord,
+    {
+        self.map.sort_keys()
+    }
+
+    /// Sorts the set with a comparator function. This sort is stable.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn sort_by<F>(&mut self, mut compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        self.map.sort_by(|a, _, b, _| compare(a, b))
+    }
+
+    /// Sorts the set, but might not preserve the order of equal values.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn sort_unstable(&mut self)
+    where
+        T: Ord,
+    {
+        self.map.sort_unstable_keys()
+    }
+
+    /// Sorts the set with a comparator function, but might not preserve the
+    /// order of equal values.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn sort_unstable_by<F>(&mut self, mut compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        self.map.sort_unstable_by(|a, _, b, _| compare(a, b))
+    }
+}
+
+impl<T, S> IndexSet<T, S>
+where
+    T: Eq + Hash,
+    S: BuildHasher,
+{
+    /// Reserves capacity for at least `additional` more elements to be
+    /// inserted in the set.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new allocation size overflows [`usize`].
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn reserve(&mut self, additional: usize) {
+        self.map.reserve(additional)
+    }
+
+    /// Tries to reserve capacity for at least `additional` more elements to
+    /// be inserted in the set.
+    ///
+    /// # Errors
+    ///
+    /// If the capacity overflows, or the allocator reports a failure, then
+    /// an error is returned.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.map.try_reserve(additional)
+    }
+
+    /// Shrinks the capacity of the set as much as possible.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn shrink_to_fit(&mut self) {
+        self.map.shrink_to_fit()
+    }
+
+    /// Adds a value to the set.
+    ///
+    /// Returns whether the value was newly inserted. A new value is appended
+    /// at the end; a value that is already present keeps its position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(index_map)]
+    /// use std::collections::IndexSet;
+    ///
+    /// let mut set = IndexSet::new();
+    /// assert_eq!(set.insert(2), true);
+    /// assert_eq!(set.insert(1), true);
+    /// assert_eq!(set.insert(2), false);
+    /// assert_eq!(set.get_index(0), Some(&2));
+    /// ```
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn insert(&mut self, value: T) -> bool {
+        self.map.insert(value, ()).is_none()
+    }
+
+    /// Adds a value to the set, and returns its position along with whether
+    /// it was newly inserted.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn insert_full(&mut self, value: T) -> (usize, bool) {
+        let (index, old) = self.map.insert_full(value, ());
+        (index, old.is_none())
+    }
+
+    /// Returns `true` if the set contains a value.
+    ///
+    /// The value may be any borrowed form of the set's value type, but
+    /// [`Hash`] and [`Eq`] on the borrowed form *must* match those for
+    /// the value type.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn contains<Q: ?Sized>(&self, value: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq,
+    {
+        self.map.contains_key(value)
+    }
+
+    /// Returns a reference to the value in the set, if any, that is equal to
+    /// the given value.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn get<Q: ?Sized>(&self, value: &Q) -> Option<&T>
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq,
+    {
+        self.map.get_key_value(value).map(|(value, _)| value)
+    }
+
+    /// Returns the position of the value in the set, if it is present.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn get_index_of<Q: ?Sized>(&self, value: &Q) -> Option<usize>
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq,
+    {
+        self.map.get_index_of(value)
+    }
+
+    /// Returns the position of the value in the set along with a reference
+    /// to the stored value, if it is present.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn get_full<Q: ?Sized>(&self, value: &Q) -> Option<(usize, &T)>
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq,
+    {
+        self.map.get_full(value).map(|(index, value, _)| (index, value))
+    }
+
+    /// Removes a value from the set, moving the last value into its place.
+    /// Returns whether the value was present in the set.
+    ///
+    /// This takes O(1) time, but disturbs the order of the set.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn swap_remove<Q: ?Sized>(&mut self, value: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq,
+    {
+        self.map.swap_remove(value).is_some()
+    }
+
+    /// Removes a value from the set, shifting all following values down.
+    /// Returns whether the value was present in the set.
+    ///
+    /// This preserves the order of the set, but takes O(n) time.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn shift_remove<Q: ?Sized>(&mut self, value: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq,
+    {
+        self.map.shift_remove(value).is_some()
+    }
+
+    /// Removes and returns the value in the set, if any, that is equal to the
+    /// given one, like [`swap_remove`](Self::swap_remove).
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn swap_take<Q: ?Sized>(&mut self, value: &Q) -> Option<T>
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq,
+    {
+        self.map.swap_remove_entry(value).map(|(value, ())| value)
+    }
+
+    /// Removes and returns the value in the set, if any, that is equal to the
+    /// given one, like [`shift_remove`](Self::shift_remove).
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn shift_take<Q: ?Sized>(&mut self, value: &Q) -> Option<T>
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq,
+    {
+        self.map.shift_remove_entry(value).map(|(value, ())| value)
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<T, S> Clone for IndexSet<T, S>
+where
+    T: Clone,
+    S: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self { map: self.map.clone() }
+    }
+}
+
+/// Sets are equal if they hold the same values, regardless of the order
+/// they are in.
+#[unstable(feature = "index_map", issue = "none")]
+impl<T, S> PartialEq for IndexSet<T, S>
+where
+    T: Eq + Hash,
+    S: BuildHasher,
+{
+    fn eq(&self, other: &IndexSet<T, S>) -> bool {
+        self.map == other.map
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<T, S> Eq for IndexSet<T, S>
+where
+    T: Eq + Hash,
+    S: BuildHasher,
+{
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<T, S> fmt::Debug for IndexSet<T, S>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<T, S> Default for IndexSet<T, S>
+where
+    S: Default,
+{
+    /// Creates an empty `IndexSet<T, S>` with the `Default` value for the hasher.
+    #[inline]
+    fn default() -> IndexSet<T, S> {
+        IndexSet { map: IndexMap::default() }
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<T, S> Index<usize> for IndexSet<T, S> {
+    type Output = T;
+
+    /// Returns a reference to the value at the supplied position.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    #[inline]
+    fn index(&self, index: usize) -> &T {
+        self.get_index(index).expect("IndexSet: index out of bounds")
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<T, const N: usize> From<[T; N]> for IndexSet<T, RandomState>
+where
+    T: Eq + Hash,
+{
+    /// Converts a `[T; N]` into an `IndexSet<T>`, keeping the order of the
+    /// first occurrence of each value.
+    fn from(arr: [T; N]) -> Self {
+        IndexSet::from_iter(arr)
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<T, S> FromIterator<T> for IndexSet<T, S>
+where
+    T: Eq + Hash,
+    S: BuildHasher + Default,
+{
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> IndexSet<T, S> {
+        IndexSet { map: iter.into_iter().map(|value| (value, ())).collect() }
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<T, S> Extend<T> for IndexSet<T, S>
+where
+    T: Eq + Hash,
+    S: BuildHasher,
+{
+    #[inline]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.map.extend(iter.into_iter().map(|value| (value, ())));
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<'a, T, S> Extend<&'a T> for IndexSet<T, S>
+where
+    T: 'a + Eq + Hash + Copy,
+    S: BuildHasher,
+{
+    #[inline]
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().cloned());
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<'a, T, S> IntoIterator for &'a IndexSet<T, S> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<T, S> IntoIterator for IndexSet<T, S> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    /// Creates a consuming iterator, that is, one that moves each value out
+    /// of the set in order. The set cannot be used after calling this.
+    #[inline]
+    fn into_iter(self) -> IntoIter<T> {
+        IntoIter { iter: self.map.into_keys() }
+    }
+}
+
+/// An iterator over the items of an `IndexSet`, in order.
+///
+/// This `struct` is created by the [`iter`] method on [`IndexSet`].
+/// See its documentation for more.
+///
+/// [`iter`]: IndexSet::iter
+#[unstable(feature = "index_map", issue = "none")]
+pub struct Iter<'a, T: 'a> {
+    iter: index_map::Keys<'a, T, ()>,
+}
+
+/// An owning iterator over the items of an `IndexSet`, in order.
+///
+/// This `struct` is created by the [`into_iter`] method on [`IndexSet`]
+/// (provided by the [`IntoIterator`] trait). See its documentation for more.
+///
+/// [`into_iter`]: IntoIterator::into_iter
+#[unstable(feature = "index_map", issue = "none")]
+pub struct IntoIter<T> {
+    iter: index_map::IntoKeys<T, ()>,
+}
+
+/// A draining iterator over a range of items of an `IndexSet`, in order.
+///
+/// This `struct` is created by the [`drain`] method on [`IndexSet`].
+/// See its documentation for more.
+///
+/// [`drain`]: IndexSet::drain
+#[unstable(feature = "index_map", issue = "none")]
+pub struct Drain<'a, T: 'a> {
+    iter: index_map::Drain<'a, T, ()>,
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<T> Clone for Iter<'_, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Iter { iter: self.iter.clone() }
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a T> {
+        self.iter.next()
+    }
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<T> DoubleEndedIterator for Iter<'_, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back()
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<T> ExactSizeIterator for Iter<'_, T> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<T> FusedIterator for Iter<'_, T> {}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<T: fmt::Debug> fmt::Debug for Iter<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        self.iter.next()
+    }
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<T> {
+        self.iter.next_back()
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<T> ExactSizeIterator for IntoIter<T> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<T> FusedIterator for IntoIter<T> {}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<T: fmt::Debug> fmt::Debug for IntoIter<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.iter, f)
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<T> Iterator for Drain<'_, T> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        self.iter.next().map(|(value, ())| value)
+    }
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<T> DoubleEndedIterator for Drain<'_, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<T> {
+        self.iter.next_back().map(|(value, ())| value)
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<T> ExactSizeIterator for Drain<'_, T> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<T> FusedIterator for Drain<'_, T> {}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<T: fmt::Debug> fmt::Debug for Drain<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter.keys()).finish()
+    }
+}
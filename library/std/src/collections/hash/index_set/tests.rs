@@ -0,0 +1,48 @@
+use super::IndexSet;
+
+#[test]
+fn test_insert_and_lookup() {
+    let mut s = IndexSet::new();
+    assert!(s.insert("b"));
+    assert!(s.insert("a"));
+    assert!(!s.insert("b"));
+    assert_eq!(s.insert_full("c"), (2, true));
+    assert_eq!(s.insert_full("a"), (1, false));
+    assert!(s.contains("a"));
+    assert_eq!(s.get_full("c"), Some((2, &"c")));
+    assert_eq!(s[0], "b");
+    assert_eq!(s.first(), Some(&"b"));
+    assert_eq!(s.last(), Some(&"c"));
+    assert_eq!(format!("{s:?}"), r#"{"b", "a", "c"}"#);
+}
+
+#[test]
+fn test_remove_and_take() {
+    let mut s: IndexSet<_> = (0..6).collect();
+    assert!(s.swap_remove(&0));
+    assert!(!s.swap_remove(&0));
+    assert_eq!(s.shift_take(&1), Some(1));
+    assert_eq!(s.swap_take(&1), None);
+    assert_eq!(s.iter().copied().collect::<Vec<_>>(), [5, 2, 3, 4]);
+    assert!(s.shift_remove(&5));
+    assert_eq!(s.get_index_of(&4), Some(2));
+    assert_eq!(s.pop(), Some(4));
+    assert_eq!(s.into_iter().collect::<Vec<_>>(), [2, 3]);
+}
+
+#[test]
+fn test_order_operations() {
+    let mut s: IndexSet<_> = [3, 1, 4, 1, 5, 9, 2, 6].into();
+    assert_eq!(s.len(), 7);
+    s.sort();
+    assert_eq!(s.iter().copied().collect::<Vec<_>>(), [1, 2, 3, 4, 5, 6, 9]);
+    s.sort_by(|a, b| b.cmp(a));
+    assert_eq!(s.get_index_of(&9), Some(0));
+    s.move_index(0, 6);
+    s.swap_indices(0, 1);
+    assert_eq!(s.iter().copied().collect::<Vec<_>>(), [5, 6, 4, 3, 2, 1, 9]);
+    assert_eq!(s.drain(1..3).collect::<Vec<_>>(), [6, 4]);
+    s.retain(|&x| x != 2);
+    assert_eq!(s.iter().copied().collect::<Vec<_>>(), [5, 3, 1, 9]);
+    assert_eq!(s.get_index_of(&9), Some(3));
+}
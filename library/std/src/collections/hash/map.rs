@@ -8,7 +8,7 @@
 use crate::collections::{TryReserveError, TryReserveErrorKind};
 use crate::error::Error;
 use crate::fmt::{self, Debug};
-use crate::hash::{BuildHasher, Hash, RandomState};
+use crate::hash::{BuildHasher, Hash, Hasher, RandomState};
 use crate::iter::FusedIterator;
 use crate::ops::Index;
 
@@ -1201,6 +1201,51 @@ pub fn insert(&mut self, k: K, v: V) -> Option<V> {
         self.base.insert(k, v)
     }
 
+    /// Inserts a key-value pair into the map, or returns an error if the map
+    /// needed to grow and the allocation failed.
+    ///
+    /// This is a fallible version of [`insert`]: the space for a new key is
+    /// reserved with [`try_reserve`] before looking up the key. On error, the
+    /// map is left unchanged and `k` and `v` are returned with the error.
+    ///
+    /// [`insert`]: HashMap::insert
+    /// [`try_reserve`]: HashMap::try_reserve
+    ///
+    /// # Errors
+    ///
+    /// If the capacity overflows, or the allocator reports a failure, then an error
+    /// is returned together with the key and the value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(fallible_collections)]
+    /// use std::collections::HashMap;
+    ///
+    /// let mut map = HashMap::new();
+    /// assert_eq!(map.try_reserve_and_insert(37, "a"), Ok(None));
+    /// assert_eq!(map.try_reserve_and_insert(37, "b"), Ok(Some("a")));
+    /// assert_eq!(map[&37], "b");
+    /// ```
+    #[unstable(feature = "fallible_collections", issue = "none")]
+    pub fn try_reserve_and_insert(
+        &mut self,
+        k: K,
+        v: V,
+    ) -> Result<Option<V>, (TryReserveError, K, V)> {
+        if let Err(e) = self.try_reserve(1) {
+            return Err((e, k, v));
+        }
+        // With the space reserved, inserting into a vacant entry does not allocate.
+        match self.base.rustc_entry(k) {
+            base::RustcEntry::Occupied(mut entry) => Ok(Some(entry.insert(v))),
+            base::RustcEntry::Vacant(entry) => {
+                entry.insert(v);
+                Ok(None)
+            }
+        }
+    }
+
     /// Tries to insert a key-value pair into the map, and returns
     /// a mutable reference to the value in the entry.
     ///
@@ -1288,6 +1333,152 @@ pub fn remove_entry<Q: ?Sized>(&mut self, k: &Q) -> Option<(K, V)>
     {
         self.base.remove_entry(k)
     }
+
+    /// Returns a reference to the value corresponding to a key that is
+    /// [`Equivalent`] to `k`.
+    ///
+    /// Unlike [`get`], this does not require the map's key type to implement
+    /// [`Borrow`] for the type of `k`, so a map can be queried with a type that
+    /// merely compares equal to its keys, such as a tuple of borrowed parts of
+    /// an owned key.
+    ///
+    /// [`get`]: HashMap::get
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(hash_equivalent)]
+    /// use std::collections::HashMap;
+    /// use std::collections::hash_map::Equivalent;
+    /// use std::hash::{Hash, Hasher};
+    ///
+    /// #[derive(PartialEq, Eq, Hash)]
+    /// struct Name {
+    ///     first: String,
+    ///     last: String,
+    /// }
+    ///
+    /// struct NameRef<'a>(&'a str, &'a str);
+    ///
+    /// // Hashes the same way as the derived `Hash` implementation of `Name`.
+    /// impl Hash for NameRef<'_> {
+    ///     fn hash<H: Hasher>(&self, state: &mut H) {
+    ///         self.0.hash(state);
+    ///         self.1.hash(state);
+    ///     }
+    /// }
+    ///
+    /// impl Equivalent<Name> for NameRef<'_> {
+    ///     fn equivalent(&self, key: &Name) -> bool {
+    ///         self.0 == key.first && self.1 == key.last
+    ///     }
+    /// }
+    ///
+    /// let mut ages = HashMap::new();
+    /// ages.insert(Name { first: "Ada".to_string(), last: "Lovelace".to_string() }, 36);
+    /// assert_eq!(ages.get_equivalent(&NameRef("Ada", "Lovelace")), Some(&36));
+    /// assert_eq!(ages.get_equivalent(&NameRef("Ada", "Byron")), None);
+    /// ```
+    #[inline]
+    #[unstable(feature = "hash_equivalent", issue = "none")]
+    pub fn get_equivalent<Q: ?Sized>(&self, k: &Q) -> Option<&V>
+    where
+        Q: Hash + Equivalent<K>,
+    {
+        self.get_key_value_equivalent(k).map(|(_, v)| v)
+    }
+
+    /// Returns the key-value pair corresponding to a key that is
+    /// [`Equivalent`] to `k`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(hash_equivalent)]
+    /// use std::collections::HashMap;
+    ///
+    /// let mut map = HashMap::new();
+    /// map.insert("a".to_string(), 1);
+    /// assert_eq!(map.get_key_value_equivalent("a"), Some((&"a".to_string(), &1)));
+    /// ```
+    #[inline]
+    #[unstable(feature = "hash_equivalent", issue = "none")]
+    pub fn get_key_value_equivalent<Q: ?Sized>(&self, k: &Q) -> Option<(&K, &V)>
+    where
+        Q: Hash + Equivalent<K>,
+    {
+        self.base.get_key_value(&EquivalentQuery(k))
+    }
+
+    /// Returns `true` if the map contains a key that is [`Equivalent`] to `k`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(hash_equivalent)]
+    /// use std::collections::HashMap;
+    ///
+    /// let mut map = HashMap::new();
+    /// map.insert("a".to_string(), 1);
+    /// assert!(map.contains_key_equivalent("a"));
+    /// assert!(!map.contains_key_equivalent("b"));
+    /// ```
+    #[inline]
+    #[unstable(feature = "hash_equivalent", issue = "none")]
+    pub fn contains_key_equivalent<Q: ?Sized>(&self, k: &Q) -> bool
+    where
+        Q: Hash + Equivalent<K>,
+    {
+        self.get_key_value_equivalent(k).is_some()
+    }
+
+    /// Returns a mutable reference to the value corresponding to a key that is
+    /// [`Equivalent`] to `k`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(hash_equivalent)]
+    /// use std::collections::HashMap;
+    ///
+    /// let mut map = HashMap::new();
+    /// map.insert("a".to_string(), 1);
+    /// if let Some(x) = map.get_mut_equivalent("a") {
+    ///     *x += 1;
+    /// }
+    /// assert_eq!(map["a"], 2);
+    /// ```
+    #[inline]
+    #[unstable(feature = "hash_equivalent", issue = "none")]
+    pub fn get_mut_equivalent<Q: ?Sized>(&mut self, k: &Q) -> Option<&mut V>
+    where
+        Q: Hash + Equivalent<K>,
+    {
+        self.base.get_mut(&EquivalentQuery(k))
+    }
+
+    /// Removes a key that is [`Equivalent`] to `k` from the map, returning the
+    /// stored key and value if the key was previously in the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(hash_equivalent)]
+    /// use std::collections::HashMap;
+    ///
+    /// let mut map = HashMap::new();
+    /// map.insert("a".to_string(), 1);
+    /// assert_eq!(map.remove_entry_equivalent("a"), Some(("a".to_string(), 1)));
+    /// assert!(map.is_empty());
+    /// ```
+    #[inline]
+    #[unstable(feature = "hash_equivalent", issue = "none")]
+    pub fn remove_entry_equivalent<Q: ?Sized>(&mut self, k: &Q) -> Option<(K, V)>
+    where
+        Q: Hash + Equivalent<K>,
+    {
+        self.base.remove_entry(&EquivalentQuery(k))
+    }
 }
 
 #[stable(feature = "rust1", since = "1.0.0")]
@@ -1835,6 +2026,50 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
     }
 }
 
+/// Key equivalence for looking up entries of a [`HashMap`] with a type other
+/// than its key type.
+///
+/// This generalizes the [`Borrow`]-based lookups: every type `Q` that a key
+/// type `K` borrows as implements `Equivalent<K>`, but a type can also be
+/// equivalent to a key type it cannot be borrowed from, such as a tuple of
+/// references to the fields of an owned key.
+///
+/// Implementations must be consistent with [`Hash`]: if `q.equivalent(k)`,
+/// then hashing `q` and `k` must produce the same hash. Breaking this rule
+/// does not cause undefined behavior, but lookups will then fail to find the
+/// key.
+#[unstable(feature = "hash_equivalent", issue = "none")]
+pub trait Equivalent<K: ?Sized> {
+    /// Checks whether `self` is equivalent to the given key.
+    fn equivalent(&self, key: &K) -> bool;
+}
+
+#[unstable(feature = "hash_equivalent", issue = "none")]
+impl<Q: ?Sized + Eq, K: ?Sized + Borrow<Q>> Equivalent<K> for Q {
+    #[inline]
+    fn equivalent(&self, key: &K) -> bool {
+        *self == *key.borrow()
+    }
+}
+
+/// Hands a query that is [`Equivalent`] to the keys of a map to the lookups of
+/// the underlying `hashbrown` map, which are written against its own trait.
+struct EquivalentQuery<'a, Q: ?Sized>(&'a Q);
+
+impl<Q: ?Sized + Hash> Hash for EquivalentQuery<'_, Q> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state)
+    }
+}
+
+impl<Q: ?Sized + Equivalent<K>, K: ?Sized> hashbrown::Equivalent<K> for EquivalentQuery<'_, Q> {
+    #[inline]
+    fn equivalent(&self, key: &K) -> bool {
+        self.0.equivalent(key)
+    }
+}
+
 /// The error returned by [`try_insert`](HashMap::try_insert) when the key already exists.
 ///
 /// Contains the occupied entry, and the value that was not inserted.
@@ -852,6 +852,19 @@ fn test_try_reserve() {
     }
 }
 
+#[test]
+fn test_try_reserve_and_insert() {
+    let mut map = HashMap::new();
+    for i in 0..100 {
+        assert_eq!(map.try_reserve_and_insert(i, i), Ok(None));
+    }
+    let capacity = map.capacity();
+    assert_eq!(map.try_reserve_and_insert(5, 50), Ok(Some(5)));
+    assert_eq!(map.capacity(), capacity);
+    assert_eq!(map.len(), 100);
+    assert_eq!(map[&5], 50);
+}
+
 mod test_extract_if {
     use super::*;
     use crate::panic::{AssertUnwindSafe, catch_unwind};
@@ -1054,3 +1067,57 @@ fn build_hasher(&self) -> Self::Hasher {
     y.insert((), ());
     assert_eq!(y.len(), 1);
 }
+
+#[test]
+fn test_get_equivalent() {
+    use super::Equivalent;
+    use crate::hash::{Hash, Hasher};
+
+    #[derive(PartialEq, Eq, Hash, Debug)]
+    struct Pair(String, String);
+
+    struct PairRef<'a>(&'a str, &'a str);
+
+    impl Hash for PairRef<'_> {
+        fn hash<H: Hasher>(&self, state: &mut H) {
+            self.0.hash(state);
+            self.1.hash(state);
+        }
+    }
+
+    impl Equivalent<Pair> for PairRef<'_> {
+        fn equivalent(&self, key: &Pair) -> bool {
+            self.0 == key.0 && self.1 == key.1
+        }
+    }
+
+    let mut map = HashMap::new();
+    for i in 0..100 {
+        map.insert(Pair(i.to_string(), (i * 2).to_string()), i);
+    }
+
+    for i in 0..100 {
+        let (a, b) = (i.to_string(), (i * 2).to_string());
+        let key = PairRef(&a, &b);
+        assert_eq!(map.get_equivalent(&key), Some(&i));
+        assert!(map.contains_key_equivalent(&key));
+        *map.get_mut_equivalent(&key).unwrap() += 1;
+        assert_eq!(
+            map.get_key_value_equivalent(&key),
+            Some((&Pair(a.clone(), b.clone()), &(i + 1)))
+        );
+        assert_eq!(map.get_equivalent(&PairRef(&b, &a)), if i == 0 { Some(&1) } else { None });
+    }
+
+    assert_eq!(
+        map.remove_entry_equivalent(&PairRef("7", "14")),
+        Some((Pair("7".into(), "14".into()), 8))
+    );
+    assert_eq!(map.remove_entry_equivalent(&PairRef("7", "14")), None);
+    assert_eq!(map.len(), 99);
+
+    // Any `Borrow` form is equivalent too.
+    let mut strings = HashMap::new();
+    strings.insert(String::from("a"), 1);
+    assert_eq!(strings.get_equivalent("a"), Some(&1));
+}
@@ -1,4 +1,8 @@
-//! Unordered containers, implemented as hash-tables
+//! Hash-table based containers: the unordered `HashMap` and `HashSet`, the
+//! insertion-ordered `IndexMap` and `IndexSet`, and the low-level `HashTable`.
 
+pub mod index_map;
+pub mod index_set;
 pub mod map;
 pub mod set;
+pub mod table;
@@ -917,6 +917,48 @@ pub fn insert(&mut self, value: T) -> bool {
         self.base.insert(value)
     }
 
+    /// Adds a value to the set, or returns an error if the set needed to
+    /// grow and the allocation failed.
+    ///
+    /// This is a fallible version of [`insert`]: the space for a new value
+    /// is reserved with [`try_reserve`] before looking up the value. On
+    /// error, the set is left unchanged and `value` is returned with the
+    /// error.
+    ///
+    /// [`insert`]: HashSet::insert
+    /// [`try_reserve`]: HashSet::try_reserve
+    ///
+    /// # Errors
+    ///
+    /// If the capacity overflows, or the allocator reports a failure, then an error
+    /// is returned together with the value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(fallible_collections)]
+    /// use std::collections::HashSet;
+    ///
+    /// let mut set = HashSet::new();
+    /// assert_eq!(set.try_reserve_and_insert(2), Ok(true));
+    /// assert_eq!(set.try_reserve_and_insert(2), Ok(false));
+    /// assert_eq!(set.len(), 1);
+    /// ```
+    #[unstable(feature = "fallible_collections", issue = "none")]
+    pub fn try_reserve_and_insert(&mut self, value: T) -> Result<bool, (TryReserveError, T)> {
+        if let Err(e) = self.try_reserve(1) {
+            return Err((e, value));
+        }
+        // With the space reserved, inserting into a vacant entry does not allocate.
+        match self.base.entry(value) {
+            base::Entry::Occupied(_) => Ok(false),
+            base::Entry::Vacant(entry) => {
+                entry.insert();
+                Ok(true)
+            }
+        }
+    }
+
     /// Adds a value to the set, replacing the existing value, if any, that is equal to the given
     /// one. Returns the replaced value.
     ///
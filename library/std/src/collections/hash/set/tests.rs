@@ -525,3 +525,15 @@ fn test_insert_does_not_overwrite_the_value() {
             must be the same as first value pointer we inserted"
     );
 }
+
+#[test]
+fn test_try_reserve_and_insert() {
+    let mut set = HashSet::new();
+    for i in 0..100 {
+        assert_eq!(set.try_reserve_and_insert(i), Ok(true));
+    }
+    let capacity = set.capacity();
+    assert_eq!(set.try_reserve_and_insert(5), Ok(false));
+    assert_eq!(set.capacity(), capacity);
+    assert_eq!(set.len(), 100);
+}
@@ -0,0 +1,239 @@
+#[cfg(test)]
+mod tests;
+
+use hashbrown::hash_table as base;
+
+use crate::fmt::{self, Debug};
+
+/// A hash table that stores values by a hash the caller computes.
+///
+/// Unlike [`HashMap`], a `HashTable` neither owns a hasher nor hashes its
+/// values itself. Every lookup and insertion is given the hash of the value
+/// it is looking for, together with a closure telling whether a stored value
+/// is the one being looked for. This makes it possible to hash a key once and
+/// reuse that hash, and to look values up by anything that can be compared
+/// with them, without going through [`Borrow`].
+///
+/// Operations that may need to grow the table also take a `hasher` closure,
+/// which must return the same hash for a stored value as the one it was
+/// inserted with: the table uses it to move values around when it resizes.
+/// Returning a different hash, or calling `eq` closures that disagree with
+/// the hashes, does not cause undefined behavior, but values may then not be
+/// found again.
+///
+/// [`HashMap`]: crate::collections::HashMap
+/// [`Borrow`]: crate::borrow::Borrow
+///
+/// # Examples
+///
+/// Interning strings, hashing each word only once and allocating only the
+/// ones that are new:
+///
+/// ```
+/// #![feature(hash_table)]
+/// use std::collections::HashTable;
+/// use std::hash::{BuildHasher, RandomState};
+///
+/// let state = RandomState::new();
+/// let mut table: HashTable<(Box<str>, usize)> = HashTable::new();
+/// let mut ids = Vec::new();
+/// for word in ["apple", "pear", "apple"] {
+///     let next_id = table.len();
+///     let (_, id) = table.find_or_insert_with(
+///         state.hash_one(word),
+///         |(s, _)| **s == *word,
+///         |(s, _)| state.hash_one(s),
+///         || (word.into(), next_id),
+///     );
+///     ids.push(*id);
+/// }
+/// assert_eq!(ids, [0, 1, 0]);
+/// assert_eq!(table.len(), 2);
+/// ```
+#[unstable(feature = "hash_table", issue = "none")]
+pub struct HashTable<T> {
+    base: base::HashTable<T>,
+}
+
+impl<T> HashTable<T> {
+    /// Creates an empty `HashTable`.
+    ///
+    /// The table is initially created with a capacity of 0, so it will not
+    /// allocate until it is first inserted into.
+    #[inline]
+    #[must_use]
+    #[unstable(feature = "hash_table", issue = "none")]
+    pub const fn new() -> HashTable<T> {
+        HashTable { base: base::HashTable::new() }
+    }
+
+    /// Creates an empty `HashTable` with at least the specified capacity.
+    ///
+    /// The table will be able to hold at least `capacity` values without
+    /// reallocating. If `capacity` is 0, the table will not allocate.
+    #[inline]
+    #[must_use]
+    #[unstable(feature = "hash_table", issue = "none")]
+    pub fn with_capacity(capacity: usize) -> HashTable<T> {
+        HashTable { base: base::HashTable::with_capacity(capacity) }
+    }
+
+    /// Returns the number of values the table can hold without reallocating.
+    #[inline]
+    #[unstable(feature = "hash_table", issue = "none")]
+    pub fn capacity(&self) -> usize {
+        self.base.capacity()
+    }
+
+    /// Returns the number of values in the table.
+    #[inline]
+    #[unstable(feature = "hash_table", issue = "none")]
+    pub fn len(&self) -> usize {
+        self.base.len()
+    }
+
+    /// Returns `true` if the table contains no values.
+    #[inline]
+    #[unstable(feature = "hash_table", issue = "none")]
+    pub fn is_empty(&self) -> bool {
+        self.base.is_empty()
+    }
+
+    /// Clears the table, removing all values. Keeps the allocated memory for
+    /// reuse.
+    #[inline]
+    #[unstable(feature = "hash_table", issue = "none")]
+    pub fn clear(&mut self) {
+        self.base.clear();
+    }
+
+    /// Reserves capacity for at least `additional` more values.
+    ///
+    /// `hasher` is called on the stored values if the table has to be grown.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new allocation size overflows [`usize`].
+    #[inline]
+    #[unstable(feature = "hash_table", issue = "none")]
+    pub fn reserve(&mut self, additional: usize, hasher: impl Fn(&T) -> u64) {
+        self.base.reserve(additional, hasher)
+    }
+
+    /// Returns a reference to a value with the given `hash` for which `eq`
+    /// returns `true`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(hash_table)]
+    /// use std::collections::HashTable;
+    /// use std::hash::{BuildHasher, RandomState};
+    ///
+    /// let state = RandomState::new();
+    /// let hasher = |v: &_| state.hash_one(v);
+    /// let mut table = HashTable::new();
+    /// table.insert_unique(hasher(&1), 1, hasher);
+    /// assert_eq!(table.find(hasher(&1), |&v| v == 1), Some(&1));
+    /// assert_eq!(table.find(hasher(&2), |&v| v == 2), None);
+    /// ```
+    #[inline]
+    #[unstable(feature = "hash_table", issue = "none")]
+    pub fn find(&self, hash: u64, eq: impl FnMut(&T) -> bool) -> Option<&T> {
+        self.base.find(hash, eq)
+    }
+
+    /// Returns a mutable reference to a value with the given `hash` for which
+    /// `eq` returns `true`.
+    ///
+    /// The value must not be modified in a way that changes its hash.
+    #[inline]
+    #[unstable(feature = "hash_table", issue = "none")]
+    pub fn find_mut(&mut self, hash: u64, eq: impl FnMut(&T) -> bool) -> Option<&mut T> {
+        self.base.find_mut(hash, eq)
+    }
+
+    /// Returns a mutable reference to a value with the given `hash` for which
+    /// `eq` returns `true`, inserting the value returned by `default` with
+    /// that hash if there is none.
+    ///
+    /// The table is only searched once: if the value is missing, it is
+    /// inserted in the slot found by the search.
+    #[inline]
+    #[unstable(feature = "hash_table", issue = "none")]
+    pub fn find_or_insert_with(
+        &mut self,
+        hash: u64,
+        eq: impl FnMut(&T) -> bool,
+        hasher: impl Fn(&T) -> u64,
+        default: impl FnOnce() -> T,
+    ) -> &mut T {
+        match self.base.entry(hash, eq, hasher) {
+            base::Entry::Occupied(entry) => entry.into_mut(),
+            base::Entry::Vacant(entry) => entry.insert(default()).into_mut(),
+        }
+    }
+
+    /// Inserts `value` with the given `hash`, without checking whether an
+    /// equal value is already in the table, and returns a mutable reference
+    /// to it.
+    ///
+    /// Inserting a value that is already present is not unsafe, but the
+    /// table will then hold both, and lookups may find either of them.
+    #[inline]
+    #[unstable(feature = "hash_table", issue = "none")]
+    pub fn insert_unique(&mut self, hash: u64, value: T, hasher: impl Fn(&T) -> u64) -> &mut T {
+        self.base.insert_unique(hash, value, hasher).into_mut()
+    }
+
+    /// Removes a value with the given `hash` for which `eq` returns `true`
+    /// from the table, and returns it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(hash_table)]
+    /// use std::collections::HashTable;
+    /// use std::hash::{BuildHasher, RandomState};
+    ///
+    /// let state = RandomState::new();
+    /// let hasher = |v: &_| state.hash_one(v);
+    /// let mut table = HashTable::new();
+    /// table.insert_unique(hasher(&1), 1, hasher);
+    /// assert_eq!(table.remove(hasher(&1), |&v| v == 1), Some(1));
+    /// assert_eq!(table.remove(hasher(&1), |&v| v == 1), None);
+    /// assert!(table.is_empty());
+    /// ```
+    #[inline]
+    #[unstable(feature = "hash_table", issue = "none")]
+    pub fn remove(&mut self, hash: u64, eq: impl FnMut(&T) -> bool) -> Option<T> {
+        match self.base.find_entry(hash, eq) {
+            Ok(entry) => Some(entry.remove().0),
+            Err(_) => None,
+        }
+    }
+}
+
+#[unstable(feature = "hash_table", issue = "none")]
+impl<T: Clone> Clone for HashTable<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        HashTable { base: self.base.clone() }
+    }
+}
+
+#[unstable(feature = "hash_table", issue = "none")]
+impl<T> Default for HashTable<T> {
+    /// Creates an empty `HashTable`.
+    #[inline]
+    fn default() -> HashTable<T> {
+        HashTable::new()
+    }
+}
+
+#[unstable(feature = "hash_table", issue = "none")]
+impl<T: Debug> Debug for HashTable<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.base.iter()).finish()
+    }
+}
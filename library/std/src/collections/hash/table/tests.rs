@@ -0,0 +1,72 @@
+use super::HashTable;
+use crate::hash::{BuildHasher, RandomState};
+
+#[test]
+fn test_find_insert_remove() {
+    let state = RandomState::new();
+    let hasher = |(k, _): &(u32, u32)| state.hash_one(k);
+    let mut table = HashTable::new();
+    for i in 0..100 {
+        table.insert_unique(state.hash_one(i), (i, i * 10), hasher);
+    }
+    assert_eq!(table.len(), 100);
+
+    for i in 0..100 {
+        let hash = state.hash_one(i);
+        assert_eq!(table.find(hash, |&(k, _)| k == i), Some(&(i, i * 10)));
+        table.find_mut(hash, |&(k, _)| k == i).unwrap().1 += 1;
+    }
+    assert_eq!(table.find(state.hash_one(100), |&(k, _)| k == 100), None);
+
+    for i in (0..100).step_by(2) {
+        assert_eq!(table.remove(state.hash_one(i), |&(k, _)| k == i), Some((i, i * 10 + 1)));
+    }
+    assert_eq!(table.remove(state.hash_one(0), |&(k, _)| k == 0), None);
+    assert_eq!(table.len(), 50);
+    for i in 0..100 {
+        let found = table.find(state.hash_one(i), |&(k, _)| k == i);
+        assert_eq!(found.is_some(), i % 2 == 1);
+    }
+
+    table.clear();
+    assert!(table.is_empty());
+}
+
+#[test]
+fn test_find_or_insert_with() {
+    let state = RandomState::new();
+    let hasher = |s: &String| state.hash_one(s);
+    let mut table = HashTable::with_capacity(4);
+    let mut created = 0;
+    for word in ["a", "b", "a", "c", "b", "a"] {
+        table.find_or_insert_with(
+            state.hash_one(word),
+            |s| s == word,
+            hasher,
+            || {
+                created += 1;
+                word.to_string()
+            },
+        );
+    }
+    assert_eq!(created, 3);
+    assert_eq!(table.len(), 3);
+    assert_eq!(
+        table.clone().find(state.hash_one("c"), |s| s == "c").map(String::as_str),
+        Some("c")
+    );
+}
+
+#[test]
+fn test_reserve() {
+    let state = RandomState::new();
+    let mut table = HashTable::<u32>::new();
+    assert_eq!(table.capacity(), 0);
+    table.reserve(100, |v| state.hash_one(v));
+    let capacity = table.capacity();
+    assert!(capacity >= 100);
+    for i in 0..100 {
+        table.insert_unique(state.hash_one(i), i, |_| unreachable!());
+    }
+    assert_eq!(table.capacity(), capacity);
+}
@@ -432,6 +432,15 @@
 #[stable(feature = "rust1", since = "1.0.0")]
 #[doc(inline)]
 pub use self::hash_set::HashSet;
+#[unstable(feature = "hash_table", issue = "none")]
+#[doc(inline)]
+pub use self::hash_table::HashTable;
+#[unstable(feature = "index_map", issue = "none")]
+#[doc(inline)]
+pub use self::index_map::IndexMap;
+#[unstable(feature = "index_map", issue = "none")]
+#[doc(inline)]
+pub use self::index_set::IndexSet;
 #[stable(feature = "rust1", since = "1.0.0")]
 // FIXME(#82080) The deprecation here is only theoretical, and does not actually produce a warning.
 #[deprecated(note = "moved to `std::ops::Bound`", since = "1.26.0")]
@@ -457,3 +466,24 @@ pub mod hash_set {
     #[stable(feature = "rust1", since = "1.0.0")]
     pub use super::hash::set::*;
 }
+
+#[unstable(feature = "hash_table", issue = "none")]
+pub mod hash_table {
+    //! A hash table that stores values by hashes computed by the caller.
+    #[unstable(feature = "hash_table", issue = "none")]
+    pub use super::hash::table::*;
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+pub mod index_map {
+    //! A hash map that keeps its entries in insertion order.
+    #[unstable(feature = "index_map", issue = "none")]
+    pub use super::hash::index_map::*;
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+pub mod index_set {
+    //! A hash set implemented as an `IndexMap` where the value is `()`.
+    #[unstable(feature = "index_map", issue = "none")]
+    pub use super::hash::index_set::*;
+}
@@ -0,0 +1,358 @@
+use crate::fmt;
+use crate::io::{self, BufRead, ErrorKind, Write};
+
+/// The default upper bound on the length of a single frame, 8 MiB.
+const DEFAULT_MAX_FRAME_LEN: usize = 8 * 1024 * 1024;
+
+/// The maximum number of bytes a varint length prefix of a `u64` can occupy.
+const MAX_VARINT_LEN: usize = 10;
+
+/// How the length of each frame is encoded by [`FramedReader`] and [`FramedWriter`].
+#[unstable(feature = "io_framing", issue = "none")]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LengthPrefix {
+    /// A 4-byte big-endian (network byte order) unsigned integer.
+    U32Be,
+    /// A 4-byte little-endian unsigned integer.
+    U32Le,
+    /// An unsigned LEB128 varint, as used by Protocol Buffers, of at most 10 bytes.
+    Varint,
+}
+
+impl LengthPrefix {
+    /// Encodes `len` into `buf`, returning the number of bytes used.
+    fn encode(self, len: u64, buf: &mut [u8; MAX_VARINT_LEN]) -> usize {
+        match self {
+            LengthPrefix::U32Be => {
+                buf[..4].copy_from_slice(&(len as u32).to_be_bytes());
+                4
+            }
+            LengthPrefix::U32Le => {
+                buf[..4].copy_from_slice(&(len as u32).to_le_bytes());
+                4
+            }
+            LengthPrefix::Varint => {
+                let mut len = len;
+                let mut i = 0;
+                while len >= 0x80 {
+                    buf[i] = (len as u8) | 0x80;
+                    len >>= 7;
+                    i += 1;
+                }
+                buf[i] = len as u8;
+                i + 1
+            }
+        }
+    }
+
+    /// The largest frame length this encoding can represent.
+    fn max_len(self) -> u64 {
+        match self {
+            LengthPrefix::U32Be | LengthPrefix::U32Le => u32::MAX as u64,
+            LengthPrefix::Varint => u64::MAX,
+        }
+    }
+}
+
+fn frame_too_long() -> io::Error {
+    io::const_error!(ErrorKind::InvalidData, "frame exceeds the maximum frame length")
+}
+
+/// Reads length-delimited frames from a buffered reader.
+///
+/// Each frame consists of a length prefix, encoded as described by [`LengthPrefix`],
+/// followed by that many bytes of payload. Frames are read into an internal buffer that
+/// is reused between calls, so reading a stream of frames does not allocate once the
+/// buffer has grown to the size of the largest frame.
+///
+/// To protect against peers announcing huge frames, every `FramedReader` has a maximum
+/// frame length. Frames announcing a longer payload are rejected before any memory is
+/// allocated for them.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(io_framing)]
+/// use std::io::{FramedReader, LengthPrefix};
+///
+/// let data: &[u8] = b"\x00\x00\x00\x05hello\x00\x00\x00\x05world";
+/// let mut reader = FramedReader::new(data, LengthPrefix::U32Be);
+///
+/// assert_eq!(reader.read_frame().unwrap(), Some(&b"hello"[..]));
+/// assert_eq!(reader.read_frame().unwrap(), Some(&b"world"[..]));
+/// assert_eq!(reader.read_frame().unwrap(), None);
+/// ```
+#[unstable(feature = "io_framing", issue = "none")]
+pub struct FramedReader<R> {
+    inner: R,
+    prefix: LengthPrefix,
+    max_frame_len: usize,
+    buf: Vec<u8>,
+}
+
+impl<R: BufRead> FramedReader<R> {
+    /// Creates a new `FramedReader` with a maximum frame length of 8 MiB.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(io_framing)]
+    /// use std::io::{BufReader, FramedReader, LengthPrefix};
+    /// use std::net::TcpStream;
+    ///
+    /// let stream = TcpStream::connect("127.0.0.1:34254").unwrap();
+    /// let reader = FramedReader::new(BufReader::new(stream), LengthPrefix::Varint);
+    /// ```
+    #[unstable(feature = "io_framing", issue = "none")]
+    pub fn new(inner: R, prefix: LengthPrefix) -> FramedReader<R> {
+        FramedReader::with_max_frame_len(DEFAULT_MAX_FRAME_LEN, inner, prefix)
+    }
+
+    /// Creates a new `FramedReader` which rejects frames longer than `max_frame_len` bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(io_framing)]
+    /// use std::io::{ErrorKind, FramedReader, LengthPrefix};
+    ///
+    /// let data: &[u8] = b"\x00\x00\x01\x00";
+    /// let mut reader = FramedReader::with_max_frame_len(16, data, LengthPrefix::U32Be);
+    ///
+    /// assert_eq!(reader.read_frame().unwrap_err().kind(), ErrorKind::InvalidData);
+    /// ```
+    #[unstable(feature = "io_framing", issue = "none")]
+    pub fn with_max_frame_len(
+        max_frame_len: usize,
+        inner: R,
+        prefix: LengthPrefix,
+    ) -> FramedReader<R> {
+        FramedReader { inner, prefix, max_frame_len, buf: Vec::new() }
+    }
+
+    /// Reads the next frame and returns its payload.
+    ///
+    /// Returns `Ok(None)` if the underlying reader is at EOF before the start of a frame.
+    /// The returned slice borrows the internal buffer and is overwritten by the next call.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of kind [`ErrorKind::InvalidData`] if the frame is longer than the
+    /// maximum frame length or the varint prefix is malformed, and an error of kind
+    /// [`ErrorKind::UnexpectedEof`] if EOF is reached in the middle of a frame. Errors from
+    /// the underlying reader are propagated.
+    ///
+    /// After an error the position of the underlying reader inside the stream is
+    /// unspecified, since parts of the frame may already have been consumed.
+    #[unstable(feature = "io_framing", issue = "none")]
+    pub fn read_frame(&mut self) -> io::Result<Option<&[u8]>> {
+        let Some(len) = self.read_len()? else { return Ok(None) };
+        if len > self.max_frame_len as u64 {
+            return Err(frame_too_long());
+        }
+
+        self.buf.clear();
+        self.buf.resize(len as usize, 0);
+        self.inner.read_exact(&mut self.buf)?;
+        Ok(Some(&self.buf))
+    }
+
+    fn read_len(&mut self) -> io::Result<Option<u64>> {
+        loop {
+            match self.inner.fill_buf() {
+                Ok([]) => return Ok(None),
+                Ok(_) => break,
+                Err(e) if e.is_interrupted() => continue,
+                Err(e) => return Err(e),
+            }
+        }
+
+        match self.prefix {
+            LengthPrefix::U32Be => {
+                let mut bytes = [0; 4];
+                self.inner.read_exact(&mut bytes)?;
+                Ok(Some(u32::from_be_bytes(bytes) as u64))
+            }
+            LengthPrefix::U32Le => {
+                let mut bytes = [0; 4];
+                self.inner.read_exact(&mut bytes)?;
+                Ok(Some(u32::from_le_bytes(bytes) as u64))
+            }
+            LengthPrefix::Varint => {
+                let mut len = 0u64;
+                for i in 0..MAX_VARINT_LEN {
+                    let mut byte = [0];
+                    self.inner.read_exact(&mut byte)?;
+                    let [byte] = byte;
+                    // The tenth byte only has room for the topmost bit of a `u64`.
+                    if i == MAX_VARINT_LEN - 1 && byte > 1 {
+                        break;
+                    }
+                    len |= u64::from(byte & 0x7f) << (7 * i);
+                    if byte & 0x80 == 0 {
+                        return Ok(Some(len));
+                    }
+                }
+                Err(io::const_error!(ErrorKind::InvalidData, "malformed varint length prefix"))
+            }
+        }
+    }
+}
+
+impl<R> FramedReader<R> {
+    /// Gets a reference to the underlying reader.
+    #[unstable(feature = "io_framing", issue = "none")]
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the underlying reader.
+    ///
+    /// Reading from the underlying reader directly desynchronizes the frame boundaries
+    /// unless it is done exactly between two frames.
+    #[unstable(feature = "io_framing", issue = "none")]
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Returns the maximum frame length accepted by this reader.
+    #[unstable(feature = "io_framing", issue = "none")]
+    pub fn max_frame_len(&self) -> usize {
+        self.max_frame_len
+    }
+
+    /// Unwraps this `FramedReader`, returning the underlying reader.
+    #[unstable(feature = "io_framing", issue = "none")]
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+#[unstable(feature = "io_framing", issue = "none")]
+impl<R: fmt::Debug> fmt::Debug for FramedReader<R> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("FramedReader")
+            .field("reader", &self.inner)
+            .field("prefix", &self.prefix)
+            .field("max_frame_len", &self.max_frame_len)
+            .finish()
+    }
+}
+
+/// Writes length-delimited frames to a writer.
+///
+/// This is the counterpart of [`FramedReader`]. Each call to [`write_frame`] writes the
+/// length prefix followed by the payload. No buffering is performed, so wrapping the
+/// writer in a [`BufWriter`] is recommended to avoid issuing separate writes for the
+/// prefix and the payload.
+///
+/// [`write_frame`]: FramedWriter::write_frame
+/// [`BufWriter`]: crate::io::BufWriter
+///
+/// # Examples
+///
+/// ```
+/// #![feature(io_framing)]
+/// use std::io::{FramedWriter, LengthPrefix};
+///
+/// let mut writer = FramedWriter::new(Vec::new(), LengthPrefix::Varint);
+/// writer.write_frame(b"hello").unwrap();
+///
+/// assert_eq!(writer.into_inner(), b"\x05hello");
+/// ```
+#[unstable(feature = "io_framing", issue = "none")]
+pub struct FramedWriter<W> {
+    inner: W,
+    prefix: LengthPrefix,
+    max_frame_len: usize,
+}
+
+impl<W: Write> FramedWriter<W> {
+    /// Creates a new `FramedWriter` with a maximum frame length of 8 MiB.
+    #[unstable(feature = "io_framing", issue = "none")]
+    pub fn new(inner: W, prefix: LengthPrefix) -> FramedWriter<W> {
+        FramedWriter::with_max_frame_len(DEFAULT_MAX_FRAME_LEN, inner, prefix)
+    }
+
+    /// Creates a new `FramedWriter` which refuses to write frames longer than
+    /// `max_frame_len` bytes.
+    #[unstable(feature = "io_framing", issue = "none")]
+    pub fn with_max_frame_len(
+        max_frame_len: usize,
+        inner: W,
+        prefix: LengthPrefix,
+    ) -> FramedWriter<W> {
+        FramedWriter { inner, prefix, max_frame_len }
+    }
+
+    /// Writes `frame` prefixed by its length.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of kind [`ErrorKind::InvalidInput`] without writing anything if
+    /// `frame` is longer than the maximum frame length or than the length prefix can
+    /// represent. Errors from the underlying writer are propagated; in that case a partial
+    /// frame may have been written.
+    #[unstable(feature = "io_framing", issue = "none")]
+    pub fn write_frame(&mut self, frame: &[u8]) -> io::Result<()> {
+        let len = frame.len() as u64;
+        if frame.len() > self.max_frame_len || len > self.prefix.max_len() {
+            return Err(io::const_error!(
+                ErrorKind::InvalidInput,
+                "frame exceeds the maximum frame length",
+            ));
+        }
+
+        let mut prefix = [0; MAX_VARINT_LEN];
+        let prefix_len = self.prefix.encode(len, &mut prefix);
+        self.inner.write_all(&prefix[..prefix_len])?;
+        self.inner.write_all(frame)
+    }
+
+    /// Flushes the underlying writer.
+    #[unstable(feature = "io_framing", issue = "none")]
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+impl<W> FramedWriter<W> {
+    /// Gets a reference to the underlying writer.
+    #[unstable(feature = "io_framing", issue = "none")]
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the underlying writer.
+    ///
+    /// Writing to the underlying writer directly corrupts the framing unless it is done
+    /// exactly between two frames.
+    #[unstable(feature = "io_framing", issue = "none")]
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Returns the maximum frame length accepted by this writer.
+    #[unstable(feature = "io_framing", issue = "none")]
+    pub fn max_frame_len(&self) -> usize {
+        self.max_frame_len
+    }
+
+    /// Unwraps this `FramedWriter`, returning the underlying writer.
+    #[unstable(feature = "io_framing", issue = "none")]
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+#[unstable(feature = "io_framing", issue = "none")]
+impl<W: fmt::Debug> fmt::Debug for FramedWriter<W> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("FramedWriter")
+            .field("writer", &self.inner)
+            .field("prefix", &self.prefix)
+            .field("max_frame_len", &self.max_frame_len)
+            .finish()
+    }
+}
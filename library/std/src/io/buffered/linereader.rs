@@ -0,0 +1,197 @@
+use core::slice::memchr;
+
+use crate::fmt;
+use crate::io::{self, BufRead, ErrorKind};
+
+/// The default upper bound on the length of a single line, 64 KiB.
+const DEFAULT_MAX_LINE_LEN: usize = 64 * 1024;
+
+/// Reads lines from a buffered reader into a reused buffer.
+///
+/// Unlike [`BufRead::lines`], which allocates a new [`String`] for every line, a
+/// `LineReader` hands out each line as a slice of an internal buffer that is reused for the
+/// next line. Lines are not required to be valid UTF-8.
+///
+/// Lines are terminated by a newline byte (`0x0A`, `'\n'`) or by EOF. The returned line
+/// does not include the newline, nor the carriage return (`0x0D`, `'\r'`) of a `"\r\n"`
+/// terminator.
+///
+/// Every `LineReader` has a maximum line length, which bounds the memory used when reading
+/// from an untrusted source. The terminator does not count towards the line length.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(io_framing)]
+/// use std::io::LineReader;
+///
+/// let data: &[u8] = b"GET / HTTP/1.1\r\nHost: example.com\r\n\r\n";
+/// let mut reader = LineReader::new(data);
+///
+/// assert_eq!(reader.next_line().unwrap(), Some(&b"GET / HTTP/1.1"[..]));
+/// assert_eq!(reader.next_line().unwrap(), Some(&b"Host: example.com"[..]));
+/// assert_eq!(reader.next_line().unwrap(), Some(&b""[..]));
+/// assert_eq!(reader.next_line().unwrap(), None);
+/// ```
+#[unstable(feature = "io_framing", issue = "none")]
+pub struct LineReader<R> {
+    inner: R,
+    max_line_len: usize,
+    buf: Vec<u8>,
+    // Whether `buf` holds the line returned by the last call, rather than the start of a
+    // line interrupted by an error.
+    line_returned: bool,
+    // Whether the rest of an overlong line is still to be skipped.
+    discarding: bool,
+}
+
+impl<R: BufRead> LineReader<R> {
+    /// Creates a new `LineReader` with a maximum line length of 64 KiB.
+    #[unstable(feature = "io_framing", issue = "none")]
+    pub fn new(inner: R) -> LineReader<R> {
+        LineReader::with_max_line_len(DEFAULT_MAX_LINE_LEN, inner)
+    }
+
+    /// Creates a new `LineReader` which rejects lines longer than `max_line_len` bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(io_framing)]
+    /// use std::io::{ErrorKind, LineReader};
+    ///
+    /// let data: &[u8] = b"a very long line\nshort\n";
+    /// let mut reader = LineReader::with_max_line_len(8, data);
+    ///
+    /// assert_eq!(reader.next_line().unwrap_err().kind(), ErrorKind::InvalidData);
+    /// ```
+    #[unstable(feature = "io_framing", issue = "none")]
+    pub fn with_max_line_len(max_line_len: usize, inner: R) -> LineReader<R> {
+        LineReader { inner, max_line_len, buf: Vec::new(), line_returned: false, discarding: false }
+    }
+
+    /// Reads the next line.
+    ///
+    /// Returns `Ok(None)` once the underlying reader is at EOF. The returned slice borrows
+    /// the internal buffer and is overwritten by the next call.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of kind [`ErrorKind::InvalidData`] if the line is longer than the
+    /// maximum line length. In that case the whole line is discarded: the error is returned
+    /// as soon as the limit is exceeded, and the next call skips the rest of the line up to
+    /// and including its newline before reading the following line.
+    ///
+    /// Errors from the underlying reader are propagated. The bytes of the line read so far
+    /// are kept, and the next call continues reading the same line.
+    #[unstable(feature = "io_framing", issue = "none")]
+    pub fn next_line(&mut self) -> io::Result<Option<&[u8]>> {
+        if self.line_returned {
+            self.buf.clear();
+            self.line_returned = false;
+        }
+        self.skip_discarded_line()?;
+
+        loop {
+            let available = match self.inner.fill_buf() {
+                Ok(available) => available,
+                Err(e) if e.is_interrupted() => continue,
+                Err(e) => return Err(e),
+            };
+            // The last line may be missing its terminator, but EOF right after a
+            // terminator does not start another line.
+            if available.is_empty() && self.buf.is_empty() {
+                return Ok(None);
+            }
+
+            let (chunk, used, done) = match memchr::memchr(b'\n', available) {
+                Some(i) => (&available[..i], i + 1, true),
+                None => (available, available.len(), available.is_empty()),
+            };
+
+            // Allow one extra byte for the carriage return of a `"\r\n"` terminator, which
+            // is checked for once the full line is known.
+            let len = self.buf.len() + chunk.len();
+            if len > self.max_line_len.saturating_add(1) {
+                self.inner.consume(used);
+                self.buf.clear();
+                self.discarding = !done;
+                return Err(line_too_long());
+            }
+
+            self.buf.extend_from_slice(chunk);
+            self.inner.consume(used);
+
+            if done {
+                self.line_returned = true;
+                let line = self.line();
+                if line.len() > self.max_line_len {
+                    return Err(line_too_long());
+                }
+                return Ok(Some(line));
+            }
+        }
+    }
+
+    /// Consumes the rest of an overlong line, through its newline or to EOF.
+    fn skip_discarded_line(&mut self) -> io::Result<()> {
+        while self.discarding {
+            let available = match self.inner.fill_buf() {
+                Ok(available) => available,
+                Err(e) if e.is_interrupted() => continue,
+                Err(e) => return Err(e),
+            };
+            let (used, done) = match memchr::memchr(b'\n', available) {
+                Some(i) => (i + 1, true),
+                None => (available.len(), available.is_empty()),
+            };
+            self.inner.consume(used);
+            self.discarding = !done;
+        }
+        Ok(())
+    }
+
+    fn line(&self) -> &[u8] {
+        self.buf.strip_suffix(b"\r").unwrap_or(&self.buf)
+    }
+}
+
+fn line_too_long() -> io::Error {
+    io::const_error!(ErrorKind::InvalidData, "line exceeds the maximum line length")
+}
+
+impl<R> LineReader<R> {
+    /// Gets a reference to the underlying reader.
+    #[unstable(feature = "io_framing", issue = "none")]
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the underlying reader.
+    #[unstable(feature = "io_framing", issue = "none")]
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Returns the maximum line length accepted by this reader.
+    #[unstable(feature = "io_framing", issue = "none")]
+    pub fn max_line_len(&self) -> usize {
+        self.max_line_len
+    }
+
+    /// Unwraps this `LineReader`, returning the underlying reader.
+    #[unstable(feature = "io_framing", issue = "none")]
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+#[unstable(feature = "io_framing", issue = "none")]
+impl<R: fmt::Debug> fmt::Debug for LineReader<R> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("LineReader")
+            .field("reader", &self.inner)
+            .field("max_line_len", &self.max_line_len)
+            .finish()
+    }
+}
@@ -0,0 +1,388 @@
+use crate::io::{
+    self, BorrowedBuf, BufRead, DEFAULT_BUF_SIZE, ErrorKind, Read, Seek, SeekFrom, SizeHint,
+};
+use crate::{cmp, fmt};
+
+/// A buffered reader whose lookahead buffer grows on demand.
+///
+/// [`BufReader`] has a fixed capacity, so [`BufReader::peek`] cannot look further ahead
+/// than that capacity. A `LookaheadReader` instead grows its buffer as needed, which allows
+/// code such as format detection to inspect an arbitrary number of upcoming bytes with
+/// [`peek_exact`] without consuming them.
+///
+/// It also supports [`mark`] and [`reset`]: while a mark is set, every byte read after it is
+/// retained so that the reader can be rewound to the mark, even when the underlying reader
+/// is not seekable. Its [`Seek`] implementation moves within the buffered data without
+/// discarding it, so rewinding after sniffing a file header does not read the header twice.
+///
+/// Since the buffer is never shrunk while bytes are retained, peeking far ahead or reading
+/// a lot of data while a mark is set uses a corresponding amount of memory.
+///
+/// [`BufReader`]: crate::io::BufReader
+/// [`BufReader::peek`]: crate::io::BufReader::peek
+/// [`peek_exact`]: LookaheadReader::peek_exact
+/// [`mark`]: LookaheadReader::mark
+/// [`reset`]: LookaheadReader::reset
+///
+/// # Examples
+///
+/// ```
+/// #![feature(io_lookahead)]
+/// use std::io::{LookaheadReader, Read};
+///
+/// let data: &[u8] = b"\x89PNG\r\n\x1a\n...";
+/// let mut reader = LookaheadReader::with_capacity(2, data);
+///
+/// // Looking further ahead than the capacity grows the buffer.
+/// assert_eq!(reader.peek_exact(8).unwrap(), b"\x89PNG\r\n\x1a\n");
+///
+/// // Nothing has been consumed.
+/// let mut contents = Vec::new();
+/// reader.read_to_end(&mut contents).unwrap();
+/// assert_eq!(contents, b"\x89PNG\r\n\x1a\n...");
+/// ```
+#[unstable(feature = "io_lookahead", issue = "none")]
+pub struct LookaheadReader<R: ?Sized> {
+    // Bytes read from `inner` that have not been discarded yet. The underlying reader is
+    // positioned right after the last of them.
+    buf: Vec<u8>,
+    // The index of the next byte to be read in `buf`, always <= `buf.len()`.
+    pos: usize,
+    // The index in `buf` that `reset` rewinds to.
+    mark: Option<usize>,
+    // The minimum number of bytes to request from `inner` when the buffer runs out.
+    capacity: usize,
+    inner: R,
+}
+
+impl<R: Read> LookaheadReader<R> {
+    /// Creates a new `LookaheadReader` with a default initial capacity. The default is
+    /// currently 8 KiB, but may change in the future.
+    #[unstable(feature = "io_lookahead", issue = "none")]
+    pub fn new(inner: R) -> LookaheadReader<R> {
+        LookaheadReader::with_capacity(DEFAULT_BUF_SIZE, inner)
+    }
+
+    /// Creates a new `LookaheadReader` with the specified initial capacity.
+    ///
+    /// The capacity is also the minimum amount of data requested from the underlying reader
+    /// whenever the buffer needs more data.
+    #[unstable(feature = "io_lookahead", issue = "none")]
+    pub fn with_capacity(capacity: usize, inner: R) -> LookaheadReader<R> {
+        LookaheadReader {
+            buf: Vec::with_capacity(capacity),
+            pos: 0,
+            mark: None,
+            capacity: cmp::max(capacity, 1),
+            inner,
+        }
+    }
+}
+
+impl<R: Read + ?Sized> LookaheadReader<R> {
+    /// Returns the next `n` bytes without consuming them, growing the buffer if necessary.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of kind [`ErrorKind::UnexpectedEof`] if the underlying reader
+    /// reaches EOF before `n` bytes are available. The bytes read so far stay buffered and
+    /// can still be read. Other errors from the underlying reader are propagated, except
+    /// for [`ErrorKind::Interrupted`] which is retried.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(io_lookahead)]
+    /// use std::io::{ErrorKind, LookaheadReader};
+    ///
+    /// let mut reader = LookaheadReader::new(&b"GIF89a"[..]);
+    /// assert_eq!(reader.peek_exact(3).unwrap(), b"GIF");
+    /// assert_eq!(reader.peek_exact(7).unwrap_err().kind(), ErrorKind::UnexpectedEof);
+    /// ```
+    #[unstable(feature = "io_lookahead", issue = "none")]
+    pub fn peek_exact(&mut self, n: usize) -> io::Result<&[u8]> {
+        while self.buf.len() - self.pos < n {
+            match self.read_more(n) {
+                Ok(0) => return Err(io::Error::READ_EXACT_EOF),
+                Ok(_) => {}
+                Err(e) if e.is_interrupted() => {}
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(&self.buf[self.pos..self.pos + n])
+    }
+
+    /// Returns up to `n` upcoming bytes without consuming them, growing the buffer if
+    /// necessary.
+    ///
+    /// The returned slice is shorter than `n` only if the underlying reader reached EOF.
+    #[unstable(feature = "io_lookahead", issue = "none")]
+    pub fn peek(&mut self, n: usize) -> io::Result<&[u8]> {
+        while self.buf.len() - self.pos < n {
+            match self.read_more(n) {
+                Ok(0) => break,
+                Ok(_) => {}
+                Err(e) if e.is_interrupted() => {}
+                Err(e) => return Err(e),
+            }
+        }
+        let end = cmp::min(self.pos + n, self.buf.len());
+        Ok(&self.buf[self.pos..end])
+    }
+
+    /// Reads more data into the buffer, making room for at least `want` unread bytes.
+    ///
+    /// Bytes before the read position are discarded first unless a mark retains them.
+    fn read_more(&mut self, want: usize) -> io::Result<usize> {
+        let keep_from = self.mark.map_or(self.pos, |mark| cmp::min(mark, self.pos));
+        if keep_from > 0 {
+            self.buf.drain(..keep_from);
+            self.pos -= keep_from;
+            self.mark = self.mark.map(|mark| mark - keep_from);
+        }
+
+        let unread = self.buf.len() - self.pos;
+        self.buf.reserve(cmp::max(self.capacity, want.saturating_sub(unread)));
+
+        let mut read_buf: BorrowedBuf<'_> = self.buf.spare_capacity_mut().into();
+        self.inner.read_buf(read_buf.unfilled())?;
+        let bytes_read = read_buf.len();
+        // SAFETY: `BorrowedBuf` guarantees that its filled bytes are initialized.
+        unsafe { self.buf.set_len(self.buf.len() + bytes_read) };
+        Ok(bytes_read)
+    }
+}
+
+impl<R: ?Sized> LookaheadReader<R> {
+    /// Sets a mark at the current position.
+    ///
+    /// Until the mark is removed with [`unmark`] or replaced by another call to `mark`, all
+    /// data read after it is retained in the buffer so that [`reset`] can rewind to it.
+    ///
+    /// [`unmark`]: LookaheadReader::unmark
+    /// [`reset`]: LookaheadReader::reset
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(io_lookahead)]
+    /// use std::io::{LookaheadReader, Read};
+    ///
+    /// let mut reader = LookaheadReader::with_capacity(4, &b"header body"[..]);
+    /// reader.mark();
+    ///
+    /// let mut header = [0; 7];
+    /// reader.read_exact(&mut header).unwrap();
+    /// assert_eq!(&header, b"header ");
+    ///
+    /// // Not what we expected, start over.
+    /// assert!(reader.reset());
+    /// let mut contents = String::new();
+    /// reader.read_to_string(&mut contents).unwrap();
+    /// assert_eq!(contents, "header body");
+    /// ```
+    #[unstable(feature = "io_lookahead", issue = "none")]
+    pub fn mark(&mut self) {
+        self.mark = Some(self.pos);
+    }
+
+    /// Rewinds the reader to the position of the mark set by [`mark`].
+    ///
+    /// The mark stays set, so the reader can be rewound to it several times. Returns `false`
+    /// without changing the position if no mark is set.
+    ///
+    /// [`mark`]: LookaheadReader::mark
+    #[unstable(feature = "io_lookahead", issue = "none")]
+    pub fn reset(&mut self) -> bool {
+        match self.mark {
+            Some(mark) => {
+                self.pos = mark;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Removes the mark, allowing the buffer to discard data before the current position.
+    #[unstable(feature = "io_lookahead", issue = "none")]
+    pub fn unmark(&mut self) {
+        self.mark = None;
+    }
+
+    /// Returns a reference to the buffered data that has not been consumed yet.
+    #[unstable(feature = "io_lookahead", issue = "none")]
+    pub fn buffer(&self) -> &[u8] {
+        &self.buf[self.pos..]
+    }
+
+    /// Gets a reference to the underlying reader.
+    ///
+    /// It is inadvisable to directly read from the underlying reader.
+    #[unstable(feature = "io_lookahead", issue = "none")]
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the underlying reader.
+    ///
+    /// It is inadvisable to directly read from the underlying reader.
+    #[unstable(feature = "io_lookahead", issue = "none")]
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Unwraps this `LookaheadReader`, returning the underlying reader.
+    ///
+    /// Note that any leftover data in the internal buffer is lost. Therefore,
+    /// a following read from the underlying reader may lead to data loss.
+    #[unstable(feature = "io_lookahead", issue = "none")]
+    pub fn into_inner(self) -> R
+    where
+        R: Sized,
+    {
+        self.inner
+    }
+
+    fn discard_buffer(&mut self) {
+        self.buf.clear();
+        self.pos = 0;
+        self.mark = None;
+    }
+}
+
+#[unstable(feature = "io_lookahead", issue = "none")]
+impl<R: ?Sized + Read> Read for LookaheadReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        // If we don't have any buffered data, aren't retaining data for a mark and we're
+        // doing a massive read (larger than our capacity), bypass our buffer entirely.
+        if self.pos == self.buf.len() && self.mark.is_none() && buf.len() >= self.capacity {
+            self.discard_buffer();
+            return self.inner.read(buf);
+        }
+        let mut rem = self.fill_buf()?;
+        let nread = rem.read(buf)?;
+        self.consume(nread);
+        Ok(nread)
+    }
+}
+
+#[unstable(feature = "io_lookahead", issue = "none")]
+impl<R: ?Sized + Read> BufRead for LookaheadReader<R> {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        if self.pos == self.buf.len() {
+            self.read_more(0)?;
+        }
+        Ok(self.buffer())
+    }
+
+    fn consume(&mut self, amt: usize) {
+        self.pos = cmp::min(self.pos + amt, self.buf.len());
+    }
+}
+
+#[unstable(feature = "io_lookahead", issue = "none")]
+impl<R: ?Sized + Seek> Seek for LookaheadReader<R> {
+    /// Seeks to an offset, in bytes, in the underlying reader.
+    ///
+    /// If the target position lies within the buffered data, including data retained
+    /// before the current position, only the position inside the buffer is updated and the
+    /// buffer and mark are preserved. Otherwise the buffer and the mark are discarded.
+    ///
+    /// Either way, the position of the underlying reader is queried with
+    /// [`Seek::stream_position`] to determine the target position.
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        let inner_pos = self.inner.stream_position()?;
+        let buf_start = inner_pos
+            .checked_sub(self.buf.len() as u64)
+            .expect("overflow when subtracting buffer size from inner stream position");
+
+        let target = match pos {
+            SeekFrom::Start(n) => Some(n),
+            SeekFrom::Current(n) => {
+                let current = buf_start + self.pos as u64;
+                match current.checked_add_signed(n) {
+                    Some(target) => Some(target),
+                    None => {
+                        return Err(io::const_error!(
+                            ErrorKind::InvalidInput,
+                            "invalid seek to a negative or overflowing position",
+                        ));
+                    }
+                }
+            }
+            SeekFrom::End(_) => None,
+        };
+
+        if let Some(target) = target {
+            if (buf_start..=inner_pos).contains(&target) {
+                self.pos = (target - buf_start) as usize;
+                return Ok(target);
+            }
+        }
+
+        let result = match target {
+            Some(target) => self.inner.seek(SeekFrom::Start(target))?,
+            None => self.inner.seek(pos)?,
+        };
+        self.discard_buffer();
+        Ok(result)
+    }
+
+    /// Returns the current seek position from the start of the stream.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the position of the inner reader is smaller
+    /// than the amount of buffered data, see [`BufReader::stream_position`].
+    ///
+    /// [`BufReader::stream_position`]: crate::io::BufReader::stream_position
+    fn stream_position(&mut self) -> io::Result<u64> {
+        let remainder = (self.buf.len() - self.pos) as u64;
+        self.inner.stream_position().map(|pos| {
+            pos.checked_sub(remainder).expect(
+                "overflow when subtracting remaining buffer size from inner stream position",
+            )
+        })
+    }
+
+    /// Seeks relative to the current position.
+    ///
+    /// If the new position lies within the buffered data, no call is made to the underlying
+    /// reader at all.
+    fn seek_relative(&mut self, offset: i64) -> io::Result<()> {
+        let target = (self.pos as u64).checked_add_signed(offset);
+        match target {
+            Some(target) if target <= self.buf.len() as u64 => {
+                self.pos = target as usize;
+                Ok(())
+            }
+            _ => self.seek(SeekFrom::Current(offset)).map(drop),
+        }
+    }
+}
+
+#[unstable(feature = "io_lookahead", issue = "none")]
+impl<R> fmt::Debug for LookaheadReader<R>
+where
+    R: ?Sized + fmt::Debug,
+{
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("LookaheadReader")
+            .field("reader", &&self.inner)
+            .field("buffer", &format_args!("{}/{}", self.buf.len() - self.pos, self.buf.capacity()))
+            .field("mark", &self.mark)
+            .finish()
+    }
+}
+
+impl<T: ?Sized> SizeHint for LookaheadReader<T> {
+    #[inline]
+    fn lower_bound(&self) -> usize {
+        SizeHint::lower_bound(self.get_ref()) + self.buffer().len()
+    }
+
+    #[inline]
+    fn upper_bound(&self) -> Option<usize> {
+        SizeHint::upper_bound(self.get_ref()).and_then(|up| self.buffer().len().checked_add(up))
+    }
+}
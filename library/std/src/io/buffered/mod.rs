@@ -2,15 +2,24 @@
 
 mod bufreader;
 mod bufwriter;
+mod framed;
+mod linereader;
 mod linewriter;
 mod linewritershim;
+mod lookahead;
 
 #[cfg(test)]
 mod tests;
 
 #[stable(feature = "bufwriter_into_parts", since = "1.56.0")]
 pub use bufwriter::WriterPanicked;
+#[unstable(feature = "io_framing", issue = "none")]
+pub use framed::{FramedReader, FramedWriter, LengthPrefix};
+#[unstable(feature = "io_framing", issue = "none")]
+pub use linereader::LineReader;
 use linewritershim::LineWriterShim;
+#[unstable(feature = "io_lookahead", issue = "none")]
+pub use lookahead::LookaheadReader;
 
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use self::{bufreader::BufReader, bufwriter::BufWriter, linewriter::LineWriter};
@@ -1085,3 +1085,197 @@ fn bufwriter_aliasing() {
     let w = BufWriter::new(Box::new(c));
     let _ = w.into_parts();
 }
+
+#[test]
+fn framed_round_trip() {
+    use crate::io::{FramedReader, FramedWriter, LengthPrefix};
+
+    for prefix in [LengthPrefix::U32Be, LengthPrefix::U32Le, LengthPrefix::Varint] {
+        let frames: [&[u8]; 4] = [b"", b"hello", &[0xAB; 300], b"world"];
+
+        let mut writer = FramedWriter::new(Vec::new(), prefix);
+        for frame in frames {
+            writer.write_frame(frame).unwrap();
+        }
+        let encoded = writer.into_inner();
+
+        // Read through a tiny buffer so that prefixes and payloads straddle refills.
+        let mut reader = FramedReader::new(BufReader::with_capacity(3, &encoded[..]), prefix);
+        for frame in frames {
+            assert_eq!(reader.read_frame().unwrap(), Some(frame));
+        }
+        assert_eq!(reader.read_frame().unwrap(), None);
+    }
+}
+
+#[test]
+fn framed_varint_encoding() {
+    use crate::io::{FramedWriter, LengthPrefix};
+
+    let mut writer = FramedWriter::new(Vec::new(), LengthPrefix::Varint);
+    writer.write_frame(&[0; 300]).unwrap();
+    assert_eq!(&writer.get_ref()[..2], [0xAC, 0x02]);
+    assert_eq!(writer.get_ref().len(), 302);
+}
+
+#[test]
+fn framed_max_frame_len() {
+    use crate::io::{FramedReader, FramedWriter, LengthPrefix};
+
+    let mut writer = FramedWriter::with_max_frame_len(4, Vec::new(), LengthPrefix::U32Be);
+    writer.write_frame(b"four").unwrap();
+    let err = writer.write_frame(b"five!").unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidInput);
+    assert_eq!(writer.get_ref(), b"\0\0\0\x04four");
+
+    // The announced length is rejected before the payload is read, so this must not
+    // try to allocate 4 GiB.
+    let data: &[u8] = b"\xff\xff\xff\xff";
+    let mut reader = FramedReader::with_max_frame_len(4, data, LengthPrefix::U32Be);
+    assert_eq!(reader.read_frame().unwrap_err().kind(), ErrorKind::InvalidData);
+}
+
+#[test]
+fn framed_truncated_input() {
+    use crate::io::{FramedReader, LengthPrefix};
+
+    let data: &[u8] = b"\0\0";
+    let mut reader = FramedReader::new(data, LengthPrefix::U32Be);
+    assert_eq!(reader.read_frame().unwrap_err().kind(), ErrorKind::UnexpectedEof);
+
+    let data: &[u8] = b"\x05abc";
+    let mut reader = FramedReader::new(data, LengthPrefix::Varint);
+    assert_eq!(reader.read_frame().unwrap_err().kind(), ErrorKind::UnexpectedEof);
+
+    let data: &[u8] = &[0xff; 11];
+    let mut reader = FramedReader::new(data, LengthPrefix::Varint);
+    assert_eq!(reader.read_frame().unwrap_err().kind(), ErrorKind::InvalidData);
+}
+
+#[test]
+fn line_reader_lines() {
+    use crate::io::LineReader;
+
+    let data: &[u8] = b"one\ntwo\r\n\nthree";
+    let mut reader = LineReader::new(BufReader::with_capacity(2, data));
+    assert_eq!(reader.next_line().unwrap(), Some(&b"one"[..]));
+    assert_eq!(reader.next_line().unwrap(), Some(&b"two"[..]));
+    assert_eq!(reader.next_line().unwrap(), Some(&b""[..]));
+    assert_eq!(reader.next_line().unwrap(), Some(&b"three"[..]));
+    assert_eq!(reader.next_line().unwrap(), None);
+}
+
+#[test]
+fn line_reader_max_line_len() {
+    use crate::io::LineReader;
+
+    let data: &[u8] = b"1234\r\n12345\n123456789\nok";
+    let mut reader = LineReader::with_max_line_len(4, BufReader::with_capacity(3, data));
+    assert_eq!(reader.next_line().unwrap(), Some(&b"1234"[..]));
+    assert_eq!(reader.next_line().unwrap_err().kind(), ErrorKind::InvalidData);
+    // The overlong line is rejected as soon as the limit is exceeded, and the rest of it is
+    // skipped by the next call.
+    assert_eq!(reader.next_line().unwrap_err().kind(), ErrorKind::InvalidData);
+    assert_eq!(reader.next_line().unwrap(), Some(&b"ok"[..]));
+    assert_eq!(reader.next_line().unwrap(), None);
+
+    let data: &[u8] = b"ok\n123456789";
+    let mut reader = LineReader::with_max_line_len(4, BufReader::with_capacity(3, data));
+    assert_eq!(reader.next_line().unwrap(), Some(&b"ok"[..]));
+    assert_eq!(reader.next_line().unwrap_err().kind(), ErrorKind::InvalidData);
+    assert_eq!(reader.next_line().unwrap(), None);
+}
+
+#[test]
+fn lookahead_reader_peek_exact_grows() {
+    use crate::io::LookaheadReader;
+
+    let inner = ShortReader { lengths: vec![0, 1, 2, 0, 3] };
+    let data: Vec<u8> = (0..100).collect();
+    let mut reader = LookaheadReader::with_capacity(4, &data[..]);
+    assert_eq!(reader.peek_exact(50).unwrap(), &data[..50]);
+    assert_eq!(reader.peek(200).unwrap(), &data[..]);
+    assert_eq!(reader.peek_exact(101).unwrap_err().kind(), ErrorKind::UnexpectedEof);
+
+    let mut out = Vec::new();
+    reader.read_to_end(&mut out).unwrap();
+    assert_eq!(out, data);
+
+    // A reader returning EOF in the middle of the stream makes `peek_exact` fail,
+    // but later calls keep reading.
+    let mut reader = LookaheadReader::new(inner);
+    assert_eq!(reader.peek_exact(1).unwrap_err().kind(), ErrorKind::UnexpectedEof);
+    assert_eq!(reader.peek_exact(3).unwrap().len(), 3);
+}
+
+#[test]
+fn lookahead_reader_mark_reset() {
+    use crate::io::LookaheadReader;
+
+    let mut reader = LookaheadReader::with_capacity(2, &b"abcdefghij"[..]);
+    assert!(!reader.reset());
+
+    let mut buf = [0; 3];
+    reader.read_exact(&mut buf).unwrap();
+    reader.mark();
+    let mut buf = [0; 5];
+    reader.read_exact(&mut buf).unwrap();
+    assert_eq!(&buf, b"defgh");
+
+    assert!(reader.reset());
+    assert!(reader.reset());
+    let mut rest = Vec::new();
+    reader.read_to_end(&mut rest).unwrap();
+    assert_eq!(rest, b"defghij");
+
+    reader.unmark();
+    assert!(!reader.reset());
+}
+
+#[test]
+fn lookahead_reader_seek_preserves_buffer() {
+    use crate::io::LookaheadReader;
+
+    struct CountingSeeks<R> {
+        inner: R,
+        seeks: usize,
+    }
+    impl<R: Read> Read for CountingSeeks<R> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            self.inner.read(buf)
+        }
+    }
+    impl<R: Seek> Seek for CountingSeeks<R> {
+        fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+            if pos != SeekFrom::Current(0) {
+                self.seeks += 1;
+            }
+            self.inner.seek(pos)
+        }
+    }
+
+    let data: Vec<u8> = (0..64).collect();
+    let inner = CountingSeeks { inner: io::Cursor::new(&data[..]), seeks: 0 };
+    let mut reader = LookaheadReader::with_capacity(4, inner);
+
+    assert_eq!(reader.peek_exact(16).unwrap(), &data[..16]);
+    reader.consume(10);
+    assert_eq!(reader.stream_position().unwrap(), 10);
+
+    // Everything within the buffered data is reachable without seeking the inner reader.
+    assert_eq!(reader.seek(SeekFrom::Start(12)).unwrap(), 12);
+    assert_eq!(reader.seek(SeekFrom::Current(-2)).unwrap(), 10);
+    reader.seek_relative(5).unwrap();
+    assert_eq!(reader.stream_position().unwrap(), 15);
+    assert_eq!(reader.get_ref().seeks, 0);
+    assert_eq!(reader.buffer()[0], 15);
+
+    // Seeking outside of it discards the buffer.
+    assert_eq!(reader.seek(SeekFrom::Start(40)).unwrap(), 40);
+    assert_eq!(reader.get_ref().seeks, 1);
+    assert_eq!(reader.buffer(), b"");
+    assert_eq!(reader.peek_exact(2).unwrap(), [40, 41]);
+    assert_eq!(reader.seek(SeekFrom::End(-1)).unwrap(), 63);
+    assert_eq!(reader.peek(10).unwrap(), [63]);
+    assert!(reader.seek(SeekFrom::Current(-100)).is_err());
+}
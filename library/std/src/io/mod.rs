@@ -301,8 +301,12 @@
 pub use core::io::{BorrowedBuf, BorrowedCursor};
 use core::slice::memchr;
 
+#[unstable(feature = "io_lookahead", issue = "none")]
+pub use self::buffered::LookaheadReader;
 #[stable(feature = "bufwriter_into_parts", since = "1.56.0")]
 pub use self::buffered::WriterPanicked;
+#[unstable(feature = "io_framing", issue = "none")]
+pub use self::buffered::{FramedReader, FramedWriter, LengthPrefix, LineReader};
 #[unstable(feature = "raw_os_error_ty", issue = "107792")]
 pub use self::error::RawOsError;
 #[doc(hidden)]
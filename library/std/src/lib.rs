@@ -335,6 +335,7 @@
 #![feature(clone_to_uninit)]
 #![feature(core_intrinsics)]
 #![feature(core_io_borrowed_buf)]
+#![feature(decimal)]
 #![feature(duration_constants)]
 #![feature(error_generic_member_access)]
 #![feature(error_iter)]
@@ -401,6 +402,7 @@
 // tidy-alphabetical-start
 #![feature(assert_matches)]
 #![feature(async_iterator)]
+#![feature(bigint)]
 #![feature(c_variadic)]
 #![feature(cfg_accessible)]
 #![feature(cfg_eval)]
@@ -631,6 +633,12 @@ pub mod simd {
 
     #[doc(inline)]
     pub use crate::std_float::StdFloat;
+
+    mod multiversion;
+
+    pub use self::multiversion::multiversion;
+    #[doc(hidden)]
+    pub use self::multiversion::multiversion_inner;
 }
 #[unstable(feature = "autodiff", issue = "124509")]
 /// This module provides support for automatic differentiation.
@@ -28,6 +28,8 @@
 pub use self::ip_addr::{IpAddr, Ipv4Addr, Ipv6Addr, Ipv6MulticastScope};
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use self::socket_addr::{SocketAddr, SocketAddrV4, SocketAddrV6, ToSocketAddrs};
+#[unstable(feature = "socket_builder", issue = "none")]
+pub use self::socket_builder::{SocketBuilder, TcpKeepalive};
 #[unstable(feature = "tcplistener_into_incoming", issue = "88373")]
 pub use self::tcp::IntoIncoming;
 #[stable(feature = "rust1", since = "1.0.0")]
@@ -38,6 +40,7 @@
 
 mod ip_addr;
 mod socket_addr;
+mod socket_builder;
 mod tcp;
 #[cfg(test)]
 pub(crate) mod test;
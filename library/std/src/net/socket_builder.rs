@@ -0,0 +1,337 @@
+#[cfg(all(
+    test,
+    not(any(
+        target_os = "emscripten",
+        all(target_os = "wasi", target_env = "p1"),
+        target_os = "xous",
+        target_os = "trusty",
+    ))
+))]
+mod tests;
+
+use crate::net::{SocketAddr, TcpListener, TcpStream, ToSocketAddrs, UdpSocket};
+use crate::sys::net as net_imp;
+use crate::sys_common::FromInner;
+use crate::time::Duration;
+use crate::{fmt, io};
+
+/// Options and flags which can be used to configure how a socket is created.
+///
+/// `std`'s socket types can only be configured after they have been created, connected
+/// or bound, which is too late for options such as `SO_REUSEPORT` or binding to a local
+/// address before connecting. A `SocketBuilder` collects such options and applies them
+/// while creating a [`TcpStream`], [`TcpListener`] or [`UdpSocket`].
+///
+/// Generally, a builder is created with [`new`], configured by chaining method calls
+/// and finished with [`connect`], [`listen`] or [`bind_udp`]. Options that are not set
+/// keep the platform's default, except where noted. The builder can be reused to create
+/// several sockets.
+///
+/// Options that the platform does not support make the socket creation fail with an
+/// error of kind [`Unsupported`].
+///
+/// [`new`]: SocketBuilder::new
+/// [`connect`]: SocketBuilder::connect
+/// [`listen`]: SocketBuilder::listen
+/// [`bind_udp`]: SocketBuilder::bind_udp
+/// [`Unsupported`]: io::ErrorKind::Unsupported
+///
+/// # Examples
+///
+/// Running several listeners on the same port to spread incoming connections across
+/// threads:
+///
+/// ```no_run
+/// #![feature(socket_builder)]
+/// use std::net::SocketBuilder;
+///
+/// let mut builder = SocketBuilder::new();
+/// builder.reuse_port(true);
+///
+/// let listeners = (0..4)
+///     .map(|_| builder.listen("0.0.0.0:8080"))
+///     .collect::<Result<Vec<_>, _>>()?;
+/// # Ok::<(), std::io::Error>(())
+/// ```
+///
+/// Connecting from a specific local address, with tuned keepalive probes:
+///
+/// ```no_run
+/// #![feature(socket_builder)]
+/// use std::net::{SocketBuilder, TcpKeepalive};
+/// use std::time::Duration;
+///
+/// let stream = SocketBuilder::new()
+///     .local_addr("192.168.0.2:0".parse().unwrap())
+///     .keepalive(TcpKeepalive::new().with_time(Duration::from_secs(30)))
+///     .connect("192.168.0.1:8080")?;
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[derive(Clone, Default, PartialEq, Eq)]
+#[unstable(feature = "socket_builder", issue = "none")]
+pub struct SocketBuilder {
+    pub(crate) reuse_address: Option<bool>,
+    pub(crate) reuse_port: Option<bool>,
+    pub(crate) recv_buffer_size: Option<usize>,
+    pub(crate) send_buffer_size: Option<usize>,
+    pub(crate) keepalive: Option<TcpKeepalive>,
+    pub(crate) fastopen: Option<bool>,
+    pub(crate) only_v6: Option<bool>,
+    pub(crate) local_addr: Option<SocketAddr>,
+    pub(crate) backlog: Option<u32>,
+}
+
+impl SocketBuilder {
+    /// Creates a blank set of options, under which sockets are created with the
+    /// platform's defaults.
+    #[unstable(feature = "socket_builder", issue = "none")]
+    #[must_use]
+    pub fn new() -> SocketBuilder {
+        SocketBuilder::default()
+    }
+
+    /// Sets the `SO_REUSEADDR` option.
+    ///
+    /// If this is not called, [`listen`] enables the option on platforms other than
+    /// Windows, like [`TcpListener::bind`] does. On Windows, `SO_REUSEADDR` allows
+    /// binding to an address that is actively in use by another socket.
+    ///
+    /// [`listen`]: SocketBuilder::listen
+    #[unstable(feature = "socket_builder", issue = "none")]
+    pub fn reuse_address(&mut self, reuse: bool) -> &mut Self {
+        self.reuse_address = Some(reuse);
+        self
+    }
+
+    /// Sets the `SO_REUSEPORT` option, which allows several sockets to bind to the same
+    /// address and port.
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// This option is currently supported on Linux, Android, the BSDs and Apple platforms.
+    #[unstable(feature = "socket_builder", issue = "none")]
+    pub fn reuse_port(&mut self, reuse: bool) -> &mut Self {
+        self.reuse_port = Some(reuse);
+        self
+    }
+
+    /// Sets the size of the socket's receive buffer (`SO_RCVBUF`).
+    ///
+    /// The operating system may round, double or clamp the value.
+    #[unstable(feature = "socket_builder", issue = "none")]
+    pub fn recv_buffer_size(&mut self, size: usize) -> &mut Self {
+        self.recv_buffer_size = Some(size);
+        self
+    }
+
+    /// Sets the size of the socket's send buffer (`SO_SNDBUF`).
+    ///
+    /// The operating system may round, double or clamp the value.
+    #[unstable(feature = "socket_builder", issue = "none")]
+    pub fn send_buffer_size(&mut self, size: usize) -> &mut Self {
+        self.send_buffer_size = Some(size);
+        self
+    }
+
+    /// Enables TCP keepalive (`SO_KEEPALIVE`) with the given parameters.
+    ///
+    /// Ignored when creating a [`UdpSocket`].
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// Tuning the parameters of [`TcpKeepalive`] is currently supported on Linux, Android,
+    /// FreeBSD, NetBSD, Fuchsia, Apple platforms and Windows.
+    #[unstable(feature = "socket_builder", issue = "none")]
+    pub fn keepalive(&mut self, keepalive: TcpKeepalive) -> &mut Self {
+        self.keepalive = Some(keepalive);
+        self
+    }
+
+    /// Enables TCP Fast Open ([RFC 7413]).
+    ///
+    /// For [`listen`] this sets `TCP_FASTOPEN`, allowing clients to send data in their
+    /// SYN packet. For [`connect`] this sets `TCP_FASTOPEN_CONNECT`, which makes the
+    /// connection send the first written data in its SYN packet if a Fast Open cookie for
+    /// the server is cached, deferring the handshake until then. Ignored when creating a
+    /// [`UdpSocket`].
+    ///
+    /// [RFC 7413]: https://www.rfc-editor.org/rfc/rfc7413
+    /// [`listen`]: SocketBuilder::listen
+    /// [`connect`]: SocketBuilder::connect
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// This option is currently only supported on Linux.
+    #[unstable(feature = "socket_builder", issue = "none")]
+    pub fn tcp_fastopen(&mut self, fastopen: bool) -> &mut Self {
+        self.fastopen = Some(fastopen);
+        self
+    }
+
+    /// Sets the `IPV6_V6ONLY` option, which restricts an IPv6 socket to IPv6
+    /// communication. If disabled, an IPv6 socket bound to `[::]` also accepts IPv4
+    /// traffic through IPv4-mapped addresses.
+    ///
+    /// Ignored for IPv4 sockets.
+    #[unstable(feature = "socket_builder", issue = "none")]
+    pub fn only_v6(&mut self, only_v6: bool) -> &mut Self {
+        self.only_v6 = Some(only_v6);
+        self
+    }
+
+    /// Binds the socket to `addr` before connecting.
+    ///
+    /// This selects the local address and, unless the port is 0, the local port used by
+    /// [`connect`]. Attempting to connect to an address of a different family than `addr`
+    /// fails with an [`InvalidInput`] error, so if the remote host resolves to several
+    /// addresses, only those of the same family can succeed. Ignored by [`listen`] and
+    /// [`bind_udp`], which bind to the address they are given.
+    ///
+    /// [`InvalidInput`]: io::ErrorKind::InvalidInput
+    /// [`connect`]: SocketBuilder::connect
+    /// [`listen`]: SocketBuilder::listen
+    /// [`bind_udp`]: SocketBuilder::bind_udp
+    #[unstable(feature = "socket_builder", issue = "none")]
+    pub fn local_addr(&mut self, addr: SocketAddr) -> &mut Self {
+        self.local_addr = Some(addr);
+        self
+    }
+
+    /// Sets the maximum number of pending connections for [`listen`].
+    ///
+    /// The default is chosen by the platform's `std` implementation and the operating
+    /// system may clamp the value.
+    ///
+    /// [`listen`]: SocketBuilder::listen
+    #[unstable(feature = "socket_builder", issue = "none")]
+    pub fn backlog(&mut self, backlog: u32) -> &mut Self {
+        self.backlog = Some(backlog);
+        self
+    }
+
+    /// Creates a TCP socket with these options and connects it to a remote host.
+    ///
+    /// Like [`TcpStream::connect`], if `addr` yields multiple addresses, each of them is
+    /// attempted until a connection is successful, and the error of the last attempt is
+    /// returned if none is.
+    #[unstable(feature = "socket_builder", issue = "none")]
+    pub fn connect<A: ToSocketAddrs>(&self, addr: A) -> io::Result<TcpStream> {
+        super::each_addr(addr, |addr| {
+            let addr = addr?;
+            if self.local_addr.is_some_and(|local| local.is_ipv4() != addr.is_ipv4()) {
+                return Err(io::const_error!(
+                    io::ErrorKind::InvalidInput,
+                    "local address and remote address have different address families",
+                ));
+            }
+            net_imp::TcpStream::connect_with(addr, self)
+        })
+        .map(TcpStream::from_inner)
+    }
+
+    /// Creates a TCP socket with these options, binds it to `addr` and starts listening
+    /// for connections.
+    ///
+    /// Like [`TcpListener::bind`], if `addr` yields multiple addresses, each of them is
+    /// attempted until one succeeds.
+    #[unstable(feature = "socket_builder", issue = "none")]
+    pub fn listen<A: ToSocketAddrs>(&self, addr: A) -> io::Result<TcpListener> {
+        super::each_addr(addr, |addr| net_imp::TcpListener::bind_with(addr?, self))
+            .map(TcpListener::from_inner)
+    }
+
+    /// Creates a UDP socket with these options and binds it to `addr`.
+    ///
+    /// Like [`UdpSocket::bind`], if `addr` yields multiple addresses, each of them is
+    /// attempted until one succeeds.
+    #[unstable(feature = "socket_builder", issue = "none")]
+    pub fn bind_udp<A: ToSocketAddrs>(&self, addr: A) -> io::Result<UdpSocket> {
+        super::each_addr(addr, |addr| net_imp::UdpSocket::bind_with(addr?, self))
+            .map(UdpSocket::from_inner)
+    }
+}
+
+#[unstable(feature = "socket_builder", issue = "none")]
+impl fmt::Debug for SocketBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut d = f.debug_struct("SocketBuilder");
+        macro_rules! fields {
+            ($($field:ident),*) => {$(
+                if let Some(value) = &self.$field {
+                    d.field(stringify!($field), value);
+                }
+            )*};
+        }
+        fields!(
+            reuse_address,
+            reuse_port,
+            recv_buffer_size,
+            send_buffer_size,
+            keepalive,
+            fastopen,
+            only_v6,
+            local_addr,
+            backlog
+        );
+        d.finish_non_exhaustive()
+    }
+}
+
+/// Parameters of TCP keepalive probes, used with [`SocketBuilder::keepalive`].
+///
+/// Parameters that are not set keep the operating system's default. Durations are rounded
+/// down to whole seconds, with a minimum of one second.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(socket_builder)]
+/// use std::net::TcpKeepalive;
+/// use std::time::Duration;
+///
+/// // Start probing after a minute of idleness, probe every 10 seconds and give up
+/// // after 5 unanswered probes.
+/// let keepalive = TcpKeepalive::new()
+///     .with_time(Duration::from_secs(60))
+///     .with_interval(Duration::from_secs(10))
+///     .with_retries(5);
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[unstable(feature = "socket_builder", issue = "none")]
+pub struct TcpKeepalive {
+    pub(crate) time: Option<Duration>,
+    pub(crate) interval: Option<Duration>,
+    pub(crate) retries: Option<u32>,
+}
+
+impl TcpKeepalive {
+    /// Creates keepalive parameters that keep all of the operating system's defaults.
+    #[unstable(feature = "socket_builder", issue = "none")]
+    #[must_use]
+    pub const fn new() -> TcpKeepalive {
+        TcpKeepalive { time: None, interval: None, retries: None }
+    }
+
+    /// Sets how long the connection must be idle before the first probe is sent
+    /// (`TCP_KEEPIDLE`, `TCP_KEEPALIVE` on Apple platforms and Windows).
+    #[unstable(feature = "socket_builder", issue = "none")]
+    #[must_use]
+    pub const fn with_time(self, time: Duration) -> TcpKeepalive {
+        TcpKeepalive { time: Some(time), ..self }
+    }
+
+    /// Sets the time between two probes (`TCP_KEEPINTVL`).
+    #[unstable(feature = "socket_builder", issue = "none")]
+    #[must_use]
+    pub const fn with_interval(self, interval: Duration) -> TcpKeepalive {
+        TcpKeepalive { interval: Some(interval), ..self }
+    }
+
+    /// Sets the number of unanswered probes after which the connection is dropped
+    /// (`TCP_KEEPCNT`).
+    #[unstable(feature = "socket_builder", issue = "none")]
+    #[must_use]
+    pub const fn with_retries(self, retries: u32) -> TcpKeepalive {
+        TcpKeepalive { retries: Some(retries), ..self }
+    }
+}
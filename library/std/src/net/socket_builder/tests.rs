@@ -0,0 +1,115 @@
+use crate::io::ErrorKind;
+use crate::io::prelude::*;
+use crate::net::test::{next_test_ip4, next_test_ip6};
+use crate::net::*;
+use crate::thread;
+use crate::time::Duration;
+
+fn each_ip(f: &mut dyn FnMut(SocketAddr)) {
+    f(next_test_ip4());
+    f(next_test_ip6());
+}
+
+macro_rules! t {
+    ($e:expr) => {
+        match $e {
+            Ok(t) => t,
+            Err(e) => panic!("received error for `{}`: {}", stringify!($e), e),
+        }
+    };
+}
+
+#[test]
+fn listen_connect() {
+    each_ip(&mut |addr| {
+        let listener = t!(SocketBuilder::new().listen(&addr));
+
+        let t = thread::spawn(move || {
+            let mut stream = t!(SocketBuilder::new().connect(&addr));
+            t!(stream.write_all(&[99]));
+        });
+
+        let mut stream = t!(listener.accept()).0;
+        let mut buf = [0];
+        t!(stream.read_exact(&mut buf));
+        assert_eq!(buf[0], 99);
+        t.join().unwrap();
+    })
+}
+
+#[test]
+fn connect_from_local_addr() {
+    let addr = next_test_ip4();
+    let local = next_test_ip4();
+    let listener = t!(TcpListener::bind(&addr));
+
+    let stream = t!(SocketBuilder::new().local_addr(local).connect(&addr));
+    assert_eq!(t!(stream.local_addr()), local);
+    let (_, peer) = t!(listener.accept());
+    assert_eq!(peer, local);
+}
+
+#[test]
+fn connect_local_addr_family_mismatch() {
+    let addr = next_test_ip4();
+    let _listener = t!(TcpListener::bind(&addr));
+
+    let err = SocketBuilder::new().local_addr(next_test_ip6()).connect(&addr).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidInput);
+}
+
+#[test]
+fn listen_only_v6() {
+    let addr = next_test_ip6();
+    let listener = t!(SocketBuilder::new().only_v6(true).backlog(16).listen(&addr));
+    assert!(t!(listener.only_v6()));
+}
+
+#[test]
+#[cfg(any(
+    target_os = "linux",
+    target_os = "android",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "fuchsia",
+    target_vendor = "apple",
+    windows,
+))]
+fn keepalive_and_buffer_sizes() {
+    each_ip(&mut |addr| {
+        let listener = t!(SocketBuilder::new().recv_buffer_size(64 * 1024).listen(&addr));
+        let keepalive = TcpKeepalive::new()
+            .with_time(Duration::from_secs(30))
+            .with_interval(Duration::from_millis(1500))
+            .with_retries(3);
+        let _stream = t!(SocketBuilder::new()
+            .keepalive(keepalive)
+            .send_buffer_size(64 * 1024)
+            .connect(&addr));
+        let _ = t!(listener.accept());
+
+        let socket = t!(SocketBuilder::new().recv_buffer_size(4096).bind_udp(&addr));
+        assert_eq!(t!(socket.local_addr()), addr);
+    })
+}
+
+#[test]
+#[cfg(any(target_os = "linux", target_os = "android", target_os = "freebsd"))]
+fn reuse_port() {
+    let addr = next_test_ip4();
+    let mut builder = SocketBuilder::new();
+    builder.reuse_port(true);
+
+    let _first = t!(builder.listen(&addr));
+    let _second = t!(builder.listen(&addr));
+    assert!(TcpListener::bind(&addr).is_err());
+}
+
+#[test]
+fn debug() {
+    let builder = SocketBuilder::new();
+    assert_eq!(format!("{builder:?}"), "SocketBuilder { .. }");
+
+    let builder = SocketBuilder::new().reuse_port(true).backlog(8).clone();
+    assert_eq!(format!("{builder:?}"), "SocketBuilder { reuse_port: true, backlog: 8, .. }");
+}
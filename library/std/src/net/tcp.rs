@@ -18,7 +18,11 @@
 use crate::net::{Shutdown, SocketAddr, ToSocketAddrs};
 use crate::sys::net as net_imp;
 use crate::sys_common::{AsInner, FromInner, IntoInner};
-use crate::time::Duration;
+use crate::time::{Duration, Instant};
+
+/// The delay between starting two connection attempts in
+/// [`TcpStream::connect_happy_eyeballs`], as recommended by RFC 8305.
+const CONNECTION_ATTEMPT_DELAY: Duration = Duration::from_millis(250);
 
 /// A TCP stream between a local and a remote socket.
 ///
@@ -117,6 +121,24 @@ pub struct IntoIncoming {
     listener: TcpListener,
 }
 
+/// Reorders `addrs` so that IPv6 and IPv4 addresses alternate, starting with the family
+/// of the first address and otherwise preserving the order, as described in section 4
+/// of RFC 8305.
+fn interleave_families(addrs: impl Iterator<Item = SocketAddr>) -> Vec<SocketAddr> {
+    let mut first_is_ipv6 = None;
+    let (preferred, other): (Vec<_>, Vec<_>) =
+        addrs.partition(|addr| *first_is_ipv6.get_or_insert(addr.is_ipv6()) == addr.is_ipv6());
+
+    let mut interleaved = Vec::with_capacity(preferred.len() + other.len());
+    let mut other = other.into_iter();
+    for addr in preferred {
+        interleaved.push(addr);
+        interleaved.extend(other.next());
+    }
+    interleaved.extend(other);
+    interleaved
+}
+
 impl TcpStream {
     /// Opens a TCP connection to a remote host.
     ///
@@ -180,6 +202,119 @@ pub fn connect_timeout(addr: &SocketAddr, timeout: Duration) -> io::Result<TcpSt
         net_imp::TcpStream::connect_timeout(addr, timeout).map(TcpStream)
     }
 
+    /// Opens a TCP connection to a remote host, giving up after `timeout`.
+    ///
+    /// Unlike [`connect_timeout`], this accepts anything which implements
+    /// [`ToSocketAddrs`]. Like [`connect`], the addresses are attempted one after
+    /// another until a connection is successful. `timeout` bounds the total time spent
+    /// on all attempts rather than the time spent on each of them, so that a host with
+    /// several unreachable addresses cannot stall the caller for a multiple of `timeout`.
+    /// It does not cover the time spent resolving `addr`.
+    ///
+    /// If none of the addresses result in a successful connection, the error returned
+    /// from the last connection attempt is returned. Addresses that are left once the
+    /// timeout has elapsed are not attempted; if that leaves no attempt at all, an error
+    /// of kind [`TimedOut`] is returned.
+    ///
+    /// It is an error to pass a zero `Duration` to this function.
+    ///
+    /// [`connect`]: TcpStream::connect
+    /// [`connect_timeout`]: TcpStream::connect_timeout
+    /// [`TimedOut`]: io::ErrorKind::TimedOut
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(tcp_connect_addrs)]
+    /// use std::net::TcpStream;
+    /// use std::time::Duration;
+    ///
+    /// let stream = TcpStream::connect_addrs_timeout("example.com:80", Duration::from_secs(5))
+    ///     .expect("Couldn't connect to the server...");
+    /// ```
+    #[unstable(feature = "tcp_connect_addrs", issue = "none")]
+    pub fn connect_addrs_timeout<A: ToSocketAddrs>(
+        addr: A,
+        timeout: Duration,
+    ) -> io::Result<TcpStream> {
+        if timeout.is_zero() {
+            return Err(io::Error::ZERO_TIMEOUT);
+        }
+        let addrs = addr.to_socket_addrs()?;
+        // If the deadline isn't representable it is too far in the future to matter.
+        let deadline = Instant::now().checked_add(timeout);
+        let mut last_err = None;
+        for addr in addrs {
+            let remaining = match deadline {
+                Some(deadline) => deadline.saturating_duration_since(Instant::now()),
+                None => timeout,
+            };
+            if remaining.is_zero() {
+                // Prefer the error of the last attempt, if there was one, over a timeout.
+                return Err(last_err.unwrap_or_else(|| {
+                    io::const_error!(io::ErrorKind::TimedOut, "connection timed out")
+                }));
+            }
+            match net_imp::TcpStream::connect_timeout(&addr, remaining) {
+                Ok(stream) => return Ok(TcpStream(stream)),
+                Err(e) => last_err = Some(e),
+            }
+        }
+        Err(last_err.unwrap_or_else(|| {
+            io::const_error!(io::ErrorKind::InvalidInput, "could not resolve to any addresses")
+        }))
+    }
+
+    /// Opens a TCP connection to a remote host, racing connection attempts to its
+    /// addresses as described by the "Happy Eyeballs" algorithm ([RFC 8305]).
+    ///
+    /// Hosts commonly resolve to both IPv6 and IPv4 addresses. If the route to one of the
+    /// address families is broken, [`connect`] waits for each attempt over that family to
+    /// time out before trying the next address, which can take minutes. This function
+    /// instead reorders the addresses so that the families alternate, starting with the
+    /// family of the first address returned by the resolver, and starts a new connection
+    /// attempt every 250 milliseconds (this delay may change in the future) while the
+    /// earlier attempts are still pending. A new attempt is also started as soon as an
+    /// earlier one fails. The first attempt to succeed is returned and all others are
+    /// abandoned.
+    ///
+    /// If `timeout` is `Some`, it bounds the total time spent connecting, not including
+    /// name resolution. It is an error to pass a zero `Duration`.
+    ///
+    /// If none of the addresses result in a successful connection, the error returned
+    /// from the last failed connection attempt is returned.
+    ///
+    /// [`connect`]: TcpStream::connect
+    /// [RFC 8305]: https://www.rfc-editor.org/rfc/rfc8305
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// Connection attempts are currently only raced on Unix platforms. Elsewhere the
+    /// addresses are attempted one after another in the order described above.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(tcp_connect_addrs)]
+    /// use std::net::TcpStream;
+    /// use std::time::Duration;
+    ///
+    /// let timeout = Some(Duration::from_secs(10));
+    /// let stream = TcpStream::connect_happy_eyeballs("example.com:80", timeout)
+    ///     .expect("Couldn't connect to the server...");
+    /// ```
+    #[unstable(feature = "tcp_connect_addrs", issue = "none")]
+    pub fn connect_happy_eyeballs<A: ToSocketAddrs>(
+        addr: A,
+        timeout: Option<Duration>,
+    ) -> io::Result<TcpStream> {
+        if timeout.is_some_and(|timeout| timeout.is_zero()) {
+            return Err(io::Error::ZERO_TIMEOUT);
+        }
+        let addrs = interleave_families(addr.to_socket_addrs()?);
+        net_imp::TcpStream::connect_racing(&addrs, CONNECTION_ATTEMPT_DELAY, timeout).map(TcpStream)
+    }
+
     /// Returns the socket address of the remote peer of this TCP connection.
     ///
     /// # Examples
@@ -937,3 +937,52 @@ fn connect_timeout_valid() {
     let addr = listener.local_addr().unwrap();
     TcpStream::connect_timeout(&addr, Duration::from_secs(2)).unwrap();
 }
+
+#[test]
+fn interleave_families() {
+    let v4 = |port| SocketAddr::from(([127, 0, 0, 1], port));
+    let v6 = |port| SocketAddr::from((Ipv6Addr::LOCALHOST, port));
+
+    let addrs = [v6(1), v6(2), v6(3), v4(4), v4(5)];
+    assert_eq!(super::interleave_families(addrs.into_iter()), [v6(1), v4(4), v6(2), v4(5), v6(3)]);
+
+    let addrs = [v4(1), v6(2), v6(3), v6(4), v4(5)];
+    assert_eq!(super::interleave_families(addrs.into_iter()), [v4(1), v6(2), v4(5), v6(3), v6(4)]);
+
+    assert_eq!(super::interleave_families([v4(1), v4(2)].into_iter()), [v4(1), v4(2)]);
+    assert_eq!(super::interleave_families([].into_iter()), []);
+}
+
+#[test]
+fn connect_addrs_timeout() {
+    let refused = next_test_ip4();
+    let listener = t!(TcpListener::bind("127.0.0.1:0"));
+    let addr = t!(listener.local_addr());
+
+    let addrs = [refused, addr];
+    t!(TcpStream::connect_addrs_timeout(&addrs[..], Duration::from_secs(5)));
+
+    let err = TcpStream::connect_addrs_timeout(&addrs[..], Duration::ZERO).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidInput);
+
+    // The error of the failed attempt is returned, not a timeout.
+    let err = TcpStream::connect_addrs_timeout(refused, Duration::from_secs(60)).unwrap_err();
+    assert_ne!(err.kind(), ErrorKind::TimedOut);
+}
+
+#[test]
+fn connect_happy_eyeballs() {
+    let listener = t!(TcpListener::bind("127.0.0.1:0"));
+    let addr = t!(listener.local_addr());
+
+    // The refused attempts fail immediately, so the working address is reached without
+    // waiting for the attempt delay of each of them.
+    let addrs = [next_test_ip6(), next_test_ip4(), next_test_ip6(), addr];
+    let start = Instant::now();
+    let stream = t!(TcpStream::connect_happy_eyeballs(&addrs[..], Some(Duration::from_secs(5))));
+    assert_eq!(t!(stream.peer_addr()), addr);
+    assert!(start.elapsed() < Duration::from_secs(5));
+
+    let err = TcpStream::connect_happy_eyeballs(&[][..] as &[SocketAddr], None).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidInput);
+}
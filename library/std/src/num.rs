@@ -20,9 +20,14 @@
     issue = "none"
 )]
 pub use core::num::ZeroablePrimitive;
+#[unstable(feature = "decimal", issue = "none")]
+pub use core::num::{Decimal, ParseDecimalError, RoundingMode};
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use core::num::{FpCategory, ParseFloatError, ParseIntError, TryFromIntError};
 #[stable(feature = "signed_nonzero", since = "1.34.0")]
 pub use core::num::{NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize};
 #[stable(feature = "nonzero", since = "1.28.0")]
 pub use core::num::{NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize};
+
+#[unstable(feature = "bigint", issue = "none")]
+pub use alloc_crate::num::{BigInt, BigUint, ParseBigIntError, TryFromBigIntError};
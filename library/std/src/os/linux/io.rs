@@ -0,0 +1,149 @@
+//! Linux-specific extensions to primitives in the [`std::io`] module.
+//!
+//! [`std::io`]: crate::io
+
+#![unstable(feature = "linux_splice", issue = "none")]
+
+use crate::io;
+use crate::os::fd::{AsFd, AsRawFd};
+use crate::sys::kernel_copy;
+
+#[cfg(test)]
+mod tests;
+
+/// The kernel mechanism [`splice`] used to move data between two file descriptors.
+///
+/// See [`splice`] for how the mechanism is selected.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SpliceMechanism {
+    /// `copy_file_range(2)`, which copies between regular files inside the kernel and may
+    /// create reflinks on copy-on-write filesystems.
+    CopyFileRange,
+    /// `sendfile(2)`, which requires a source that supports mmap-like operations,
+    /// such as most regular files and block devices.
+    Sendfile,
+    /// `splice(2)`, which requires at least one side of the transfer to be a pipe.
+    Splice,
+    /// A userspace `read(2)`/`write(2)` loop. Used when none of the kernel
+    /// mechanisms accept the file descriptors.
+    ReadWrite,
+}
+
+/// The result of a successful [`splice`] call.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Spliced {
+    bytes: u64,
+    mechanism: SpliceMechanism,
+}
+
+impl Spliced {
+    /// Returns the number of bytes moved from the reader to the writer.
+    #[must_use]
+    pub fn bytes(&self) -> u64 {
+        self.bytes
+    }
+
+    /// Returns the mechanism that moved the data.
+    #[must_use]
+    pub fn mechanism(&self) -> SpliceMechanism {
+        self.mechanism
+    }
+}
+
+/// Moves up to `len` bytes from `reader` to `writer` without passing them through
+/// userspace buffers when possible.
+///
+/// This is the explicit counterpart of the copy offloading that [`io::copy`] applies to
+/// std types. It works on any pair of file descriptors, which lets proxies and other
+/// programs built on raw descriptors benefit from the same syscalls.
+///
+/// The transfer ends when `len` bytes have been moved or when `reader` reaches EOF. Pass
+/// `u64::MAX` to copy until EOF. Both file descriptors are used at their current offsets,
+/// which are advanced by the number of bytes moved.
+///
+/// The mechanisms are tried in a fixed order:
+///
+/// 1. [`CopyFileRange`] if both descriptors refer to regular files,
+/// 2. [`Sendfile`] if the reader is a non-empty regular file or a block device,
+/// 3. [`Splice`] if either descriptor may be a pipe,
+/// 4. [`ReadWrite`] otherwise, or if the kernel rejected all of the above.
+///
+/// If a kernel mechanism gives up after having moved some data, the remainder is moved
+/// with a read-write loop and [`ReadWrite`] is reported.
+///
+/// Unlike [`io::copy`], this function does not guard against zero-copy semantics: when
+/// sending a file into a pipe or socket, modifications made to the file afterwards may
+/// become visible to the receiving end if the data has not been consumed yet.
+///
+/// [`CopyFileRange`]: SpliceMechanism::CopyFileRange
+/// [`Sendfile`]: SpliceMechanism::Sendfile
+/// [`Splice`]: SpliceMechanism::Splice
+/// [`ReadWrite`]: SpliceMechanism::ReadWrite
+///
+/// # Errors
+///
+/// Returns an error if any of the underlying syscalls fail with an error other than one
+/// indicating that the mechanism is unsupported for these descriptors. The number of bytes
+/// transferred before the error is not reported.
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(linux_splice)]
+/// use std::fs::File;
+/// use std::net::TcpStream;
+/// use std::os::linux::io::{splice, SpliceMechanism};
+///
+/// fn main() -> std::io::Result<()> {
+///     let file = File::open("index.html")?;
+///     let stream = TcpStream::connect("127.0.0.1:8080")?;
+///
+///     let spliced = splice(&file, &stream, u64::MAX)?;
+///     assert_eq!(spliced.mechanism(), SpliceMechanism::Sendfile);
+///     Ok(())
+/// }
+/// ```
+pub fn splice<R: AsFd, W: AsFd>(reader: &R, writer: &W, len: u64) -> io::Result<Spliced> {
+    let reader = reader.as_fd().as_raw_fd();
+    let writer = writer.as_fd().as_raw_fd();
+    let (bytes, mechanism) = kernel_copy::splice_fds(reader, writer, len)?;
+    Ok(Spliced { bytes, mechanism })
+}
+
+/// Duplicates up to `len` bytes from the pipe `reader` into the pipe `writer` without
+/// consuming them from `reader`.
+///
+/// This is a thin wrapper around `tee(2)`. Both file descriptors must refer to pipes, there
+/// is no fallback. A single call is made, so fewer than `len` bytes may be duplicated even
+/// if more are available; `Ok(0)` means no data was available in `reader` and the write
+/// end of that pipe has been closed.
+///
+/// Combined with [`splice`] this can be used to fan the same data out to several
+/// destinations without copying it through userspace.
+///
+/// # Errors
+///
+/// Returns an error if either descriptor is not a pipe ([`io::ErrorKind::InvalidInput`]),
+/// both refer to the same pipe, or the call would block on a non-blocking pipe.
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(linux_splice)]
+/// use std::io::{self, Write};
+/// use std::os::linux::io::tee;
+///
+/// fn main() -> io::Result<()> {
+///     let (reader, mut writer) = io::pipe()?;
+///     let (_copy_reader, copy_writer) = io::pipe()?;
+///     writer.write_all(b"hello")?;
+///
+///     // `reader` still contains "hello" afterwards.
+///     assert_eq!(tee(&reader, &copy_writer, 5)?, 5);
+///     Ok(())
+/// }
+/// ```
+pub fn tee<R: AsFd, W: AsFd>(reader: &R, writer: &W, len: usize) -> io::Result<usize> {
+    kernel_copy::tee_pipes(reader.as_fd().as_raw_fd(), writer.as_fd().as_raw_fd(), len)
+}
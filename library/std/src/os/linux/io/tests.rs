@@ -0,0 +1,91 @@
+use super::{SpliceMechanism, splice, tee};
+use crate::fs::OpenOptions;
+use crate::io::{self, Read, Seek, SeekFrom, Write};
+use crate::test_helpers::tmpdir;
+
+#[test]
+fn splice_file_to_file() -> io::Result<()> {
+    let tmp = tmpdir();
+    let mut source = OpenOptions::new()
+        .read(true)
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .open(tmp.join("splice.source"))?;
+    let mut sink = OpenOptions::new()
+        .read(true)
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .open(tmp.join("splice.sink"))?;
+
+    source.write_all(b"abcdefghijklmnop")?;
+    source.seek(SeekFrom::Start(2))?;
+
+    let spliced = splice(&source, &sink, 10)?;
+    assert_eq!(spliced.bytes(), 10);
+    // Some filesystems reject copy_file_range, in which case sendfile is used instead.
+    assert!(matches!(
+        spliced.mechanism(),
+        SpliceMechanism::CopyFileRange | SpliceMechanism::Sendfile | SpliceMechanism::ReadWrite
+    ));
+
+    let spliced = splice(&source, &sink, u64::MAX)?;
+    assert_eq!(spliced.bytes(), 4, "splice stops at EOF");
+
+    sink.seek(SeekFrom::Start(0))?;
+    let mut copied = Vec::new();
+    sink.read_to_end(&mut copied)?;
+    assert_eq!(copied, b"cdefghijklmnop");
+    Ok(())
+}
+
+#[test]
+fn splice_pipe_to_pipe() -> io::Result<()> {
+    let (source_reader, mut source_writer) = io::pipe()?;
+    let (mut sink_reader, sink_writer) = io::pipe()?;
+
+    source_writer.write_all(b"hello world")?;
+    drop(source_writer);
+
+    let spliced = splice(&source_reader, &sink_writer, u64::MAX)?;
+    assert_eq!(spliced.bytes(), 11);
+    assert_eq!(spliced.mechanism(), SpliceMechanism::Splice);
+    drop(sink_writer);
+
+    let mut copied = Vec::new();
+    sink_reader.read_to_end(&mut copied)?;
+    assert_eq!(copied, b"hello world");
+    Ok(())
+}
+
+#[test]
+fn tee_does_not_consume() -> io::Result<()> {
+    let (mut reader, mut writer) = io::pipe()?;
+    let (mut copy_reader, copy_writer) = io::pipe()?;
+
+    writer.write_all(b"hello")?;
+    drop(writer);
+
+    assert_eq!(tee(&reader, &copy_writer, 5)?, 5);
+    drop(copy_writer);
+
+    let mut original = Vec::new();
+    reader.read_to_end(&mut original)?;
+    let mut duplicate = Vec::new();
+    copy_reader.read_to_end(&mut duplicate)?;
+    assert_eq!(original, b"hello");
+    assert_eq!(duplicate, b"hello");
+    Ok(())
+}
+
+#[test]
+fn tee_rejects_non_pipes() -> io::Result<()> {
+    let tmp = tmpdir();
+    let file = OpenOptions::new().write(true).create(true).open(tmp.join("tee.sink"))?;
+    let (reader, _writer) = io::pipe()?;
+
+    let err = tee(&reader, &file, 5).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+    Ok(())
+}
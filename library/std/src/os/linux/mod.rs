@@ -4,6 +4,8 @@
 #![doc(cfg(target_os = "linux"))]
 
 pub mod fs;
+pub mod io;
 pub mod net;
 pub mod process;
 pub mod raw;
+pub mod time;
@@ -8,3 +8,5 @@
 pub use crate::os::net::linux_ext::socket::UnixSocketExt;
 #[unstable(feature = "tcp_quickack", issue = "96256")]
 pub use crate::os::net::linux_ext::tcp::TcpStreamExt;
+#[unstable(feature = "udp_batch", issue = "none")]
+pub use crate::os::net::linux_ext::udp::{Datagram, RecvMeta, UdpSocketExt};
@@ -0,0 +1,279 @@
+//! Linux-specific clocks, extending the [`std::time`] module.
+//!
+//! [`Instant`] measures `CLOCK_MONOTONIC`, which stops while the system is
+//! suspended and runs at a rate adjusted by NTP. [`ClockInstant`] measures any
+//! of the clocks in [`Clock`], with the same [`Duration`] arithmetic, and
+//! [`sleep_until`] waits for such a clock to reach a deadline.
+//!
+//! [`std::time`]: crate::time
+
+#![unstable(feature = "linux_clocks", issue = "none")]
+
+use crate::cmp::Ordering;
+use crate::ops::{Add, AddAssign, Sub, SubAssign};
+use crate::sys::cvt;
+use crate::sys::time::Timespec;
+use crate::sys_common::IntoInner;
+use crate::time::{Duration, Instant};
+use crate::{fmt, io, mem};
+
+#[cfg(test)]
+mod tests;
+
+/// A clock that [`ClockInstant`] can measure.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Clock {
+    /// `CLOCK_MONOTONIC`, the clock of [`Instant`]. It does not advance while
+    /// the system is suspended.
+    Monotonic,
+    /// `CLOCK_BOOTTIME`, which is like `Monotonic` but also counts the time
+    /// the system spends suspended. Timeouts measured with it expire across a
+    /// suspend.
+    Boottime,
+    /// `CLOCK_MONOTONIC_RAW`, which is like `Monotonic` but runs at the rate of
+    /// the hardware clock, without NTP frequency adjustments.
+    MonotonicRaw,
+    /// `CLOCK_PROCESS_CPUTIME_ID`, the CPU time consumed by all threads of the
+    /// process.
+    ProcessCpuTime,
+    /// `CLOCK_THREAD_CPUTIME_ID`, the CPU time consumed by the calling thread.
+    ///
+    /// Each thread has its own clock, so instants taken on different threads
+    /// should not be compared.
+    ThreadCpuTime,
+}
+
+impl Clock {
+    fn id(self) -> libc::clockid_t {
+        match self {
+            Clock::Monotonic => libc::CLOCK_MONOTONIC,
+            Clock::Boottime => libc::CLOCK_BOOTTIME,
+            Clock::MonotonicRaw => libc::CLOCK_MONOTONIC_RAW,
+            Clock::ProcessCpuTime => libc::CLOCK_PROCESS_CPUTIME_ID,
+            Clock::ThreadCpuTime => libc::CLOCK_THREAD_CPUTIME_ID,
+        }
+    }
+
+    /// Returns the resolution of the clock, as reported by `clock_getres`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(linux_clocks)]
+    /// use std::os::linux::time::Clock;
+    ///
+    /// assert!(!Clock::Boottime.resolution().is_zero());
+    /// ```
+    #[must_use]
+    pub fn resolution(self) -> Duration {
+        let mut res = mem::MaybeUninit::<libc::timespec>::uninit();
+        cvt(unsafe { libc::clock_getres(self.id(), res.as_mut_ptr()) }).unwrap();
+        let res = unsafe { res.assume_init() };
+        Duration::new(res.tv_sec as u64, res.tv_nsec as u32)
+    }
+}
+
+/// A measurement of one of the clocks in [`Clock`].
+///
+/// This is the counterpart of [`Instant`] for clocks other than
+/// `CLOCK_MONOTONIC`. Instants of different clocks measure unrelated
+/// quantities: they are never equal, comparing them returns `None`, and
+/// taking the duration between them panics.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(linux_clocks)]
+/// use std::os::linux::time::{Clock, ClockInstant};
+///
+/// let start = ClockInstant::now(Clock::ThreadCpuTime);
+/// let mut x = 0u64;
+/// for i in 0..1_000_000 {
+///     x = x.wrapping_add(std::hint::black_box(i));
+/// }
+/// println!("{x} took {:?} of CPU time", start.elapsed());
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ClockInstant {
+    clock: Clock,
+    t: Timespec,
+}
+
+impl ClockInstant {
+    /// Returns the current reading of the given clock.
+    #[must_use]
+    pub fn now(clock: Clock) -> ClockInstant {
+        ClockInstant { clock, t: Timespec::now(clock.id()) }
+    }
+
+    /// Returns the clock this instant was measured with.
+    #[must_use]
+    pub fn clock(&self) -> Clock {
+        self.clock
+    }
+
+    #[track_caller]
+    fn assert_same_clock(&self, other: &ClockInstant) {
+        assert!(
+            self.clock == other.clock,
+            "instants of clocks {:?} and {:?} cannot be subtracted",
+            self.clock,
+            other.clock,
+        );
+    }
+
+    /// Returns the amount of time elapsed from another instant to this one,
+    /// or zero if that instant is later than this one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the instants are of different clocks.
+    #[must_use]
+    #[track_caller]
+    pub fn duration_since(&self, earlier: ClockInstant) -> Duration {
+        self.saturating_duration_since(earlier)
+    }
+
+    /// Returns the amount of time elapsed from another instant to this one,
+    /// or `None` if that instant is later than this one or of a different
+    /// clock.
+    #[must_use]
+    pub fn checked_duration_since(&self, earlier: ClockInstant) -> Option<Duration> {
+        if self.clock != earlier.clock {
+            return None;
+        }
+        self.t.sub_timespec(&earlier.t).ok()
+    }
+
+    /// Returns the amount of time elapsed from another instant to this one,
+    /// or zero if that instant is later than this one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the instants are of different clocks.
+    #[must_use]
+    #[track_caller]
+    pub fn saturating_duration_since(&self, earlier: ClockInstant) -> Duration {
+        self.assert_same_clock(&earlier);
+        self.t.sub_timespec(&earlier.t).unwrap_or_default()
+    }
+
+    /// Returns the amount of time elapsed on the clock since this instant.
+    #[must_use]
+    pub fn elapsed(&self) -> Duration {
+        ClockInstant::now(self.clock).saturating_duration_since(*self)
+    }
+
+    /// Returns `Some(t)` where `t` is the time `self + duration` if `t` can be
+    /// represented, `None` otherwise.
+    pub fn checked_add(&self, duration: Duration) -> Option<ClockInstant> {
+        Some(ClockInstant { clock: self.clock, t: self.t.checked_add_duration(&duration)? })
+    }
+
+    /// Returns `Some(t)` where `t` is the time `self - duration` if `t` can be
+    /// represented, `None` otherwise.
+    pub fn checked_sub(&self, duration: Duration) -> Option<ClockInstant> {
+        Some(ClockInstant { clock: self.clock, t: self.t.checked_sub_duration(&duration)? })
+    }
+}
+
+impl From<Instant> for ClockInstant {
+    /// Converts an `Instant`, which is a reading of [`Clock::Monotonic`].
+    fn from(instant: Instant) -> ClockInstant {
+        ClockInstant { clock: Clock::Monotonic, t: instant.into_inner().into_timespec() }
+    }
+}
+
+impl PartialOrd for ClockInstant {
+    fn partial_cmp(&self, other: &ClockInstant) -> Option<Ordering> {
+        if self.clock == other.clock { Some(self.t.cmp(&other.t)) } else { None }
+    }
+}
+
+impl Add<Duration> for ClockInstant {
+    type Output = ClockInstant;
+
+    /// # Panics
+    ///
+    /// This function may panic if the resulting point in time cannot be represented by the
+    /// underlying data structure. See [`ClockInstant::checked_add`] for a version without panic.
+    fn add(self, other: Duration) -> ClockInstant {
+        self.checked_add(other).expect("overflow when adding duration to instant")
+    }
+}
+
+impl AddAssign<Duration> for ClockInstant {
+    fn add_assign(&mut self, other: Duration) {
+        *self = *self + other;
+    }
+}
+
+impl Sub<Duration> for ClockInstant {
+    type Output = ClockInstant;
+
+    fn sub(self, other: Duration) -> ClockInstant {
+        self.checked_sub(other).expect("overflow when subtracting duration from instant")
+    }
+}
+
+impl SubAssign<Duration> for ClockInstant {
+    fn sub_assign(&mut self, other: Duration) {
+        *self = *self - other;
+    }
+}
+
+impl Sub<ClockInstant> for ClockInstant {
+    type Output = Duration;
+
+    /// Returns the amount of time elapsed from another instant to this one,
+    /// or zero if that instant is later than this one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the instants are of different clocks.
+    fn sub(self, other: ClockInstant) -> Duration {
+        self.duration_since(other)
+    }
+}
+
+impl fmt::Debug for ClockInstant {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Clock readings are never negative.
+        let since_zero = self.t.sub_timespec(&Timespec::zero()).unwrap_or_default();
+        f.debug_struct("ClockInstant")
+            .field("clock", &self.clock)
+            .field("since_zero", &since_zero)
+            .finish()
+    }
+}
+
+/// Puts the current thread to sleep until the deadline's clock has reached it.
+///
+/// This calls `clock_nanosleep` with an absolute deadline, so the sleep ends
+/// when the clock reaches the deadline no matter how often it is interrupted
+/// by signals. With [`Clock::Boottime`], time spent suspended counts towards
+/// the deadline, unlike with [`std::thread::sleep_until`].
+///
+/// [`std::thread::sleep_until`]: crate::thread::sleep_until
+///
+/// # Errors
+///
+/// Returns an error if the kernel cannot sleep on the clock, which is the case
+/// for [`Clock::MonotonicRaw`] and [`Clock::ThreadCpuTime`].
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(linux_clocks)]
+/// use std::os::linux::time::{Clock, ClockInstant, sleep_until};
+/// use std::time::Duration;
+///
+/// // Wakes up an hour from now even if the machine is suspended in between.
+/// let deadline = ClockInstant::now(Clock::Boottime) + Duration::from_secs(3600);
+/// sleep_until(deadline)?;
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub fn sleep_until(deadline: ClockInstant) -> io::Result<()> {
+    deadline.t.sleep_until(deadline.clock.id())
+}
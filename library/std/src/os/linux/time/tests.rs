@@ -0,0 +1,94 @@
+use super::{Clock, ClockInstant, sleep_until};
+use crate::time::{Duration, Instant};
+
+const CLOCKS: [Clock; 5] = [
+    Clock::Monotonic,
+    Clock::Boottime,
+    Clock::MonotonicRaw,
+    Clock::ProcessCpuTime,
+    Clock::ThreadCpuTime,
+];
+
+#[test]
+fn clocks_are_monotonic() {
+    for clock in CLOCKS {
+        let a = ClockInstant::now(clock);
+        let b = ClockInstant::now(clock);
+        assert_eq!(a.clock(), clock);
+        assert!(b >= a, "{a:?} > {b:?}");
+        assert!(!clock.resolution().is_zero());
+    }
+}
+
+#[test]
+fn cpu_time_advances() {
+    for clock in [Clock::ProcessCpuTime, Clock::ThreadCpuTime] {
+        let start = ClockInstant::now(clock);
+        while start.elapsed() == Duration::ZERO {
+            crate::hint::black_box(0);
+        }
+        assert!(ClockInstant::now(clock) > start);
+    }
+}
+
+#[test]
+fn boottime_includes_monotonic() {
+    let monotonic = ClockInstant::now(Clock::Monotonic);
+    let boottime = ClockInstant::now(Clock::Boottime);
+    assert!(boottime.t >= monotonic.t);
+}
+
+#[test]
+fn from_instant() {
+    let instant = ClockInstant::from(Instant::now());
+    assert_eq!(instant.clock(), Clock::Monotonic);
+    assert!(instant <= ClockInstant::now(Clock::Monotonic));
+}
+
+#[test]
+fn arithmetic() {
+    let second = Duration::from_secs(1);
+    let a = ClockInstant::now(Clock::Boottime);
+    let mut b = a + second;
+    assert_eq!(b - a, second);
+    assert_eq!(a - b, Duration::ZERO);
+    assert_eq!(a.checked_duration_since(b), None);
+    assert_eq!(b.checked_sub(second), Some(a));
+    b -= second;
+    assert_eq!(a, b);
+    b += Duration::from_nanos(1);
+    assert_eq!(b.duration_since(a), Duration::from_nanos(1));
+    assert_eq!(a.checked_add(Duration::MAX), None);
+}
+
+#[test]
+fn different_clocks() {
+    let a = ClockInstant::now(Clock::Monotonic);
+    let b = ClockInstant::now(Clock::MonotonicRaw);
+    assert_ne!(a, b);
+    assert_eq!(a.partial_cmp(&b), None);
+    assert!(!(a < b) && !(a > b));
+    assert_eq!(a.checked_duration_since(b), None);
+}
+
+#[test]
+#[should_panic = "cannot be subtracted"]
+fn different_clocks_sub() {
+    let _ = ClockInstant::now(Clock::Monotonic) - ClockInstant::now(Clock::Boottime);
+}
+
+#[test]
+fn sleep_until_deadline() {
+    let delay = Duration::from_millis(10);
+    for clock in [Clock::Monotonic, Clock::Boottime] {
+        let deadline = ClockInstant::now(clock) + delay;
+        sleep_until(deadline).unwrap();
+        assert!(ClockInstant::now(clock) >= deadline);
+    }
+    // Deadlines in the past return immediately.
+    sleep_until(ClockInstant::now(Clock::Boottime) - delay).unwrap();
+
+    for clock in [Clock::MonotonicRaw, Clock::ThreadCpuTime] {
+        assert!(sleep_until(ClockInstant::now(clock) + delay).is_err());
+    }
+}
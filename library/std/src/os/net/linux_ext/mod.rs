@@ -11,5 +11,9 @@
 #[unstable(feature = "tcp_quickack", issue = "96256")]
 pub(crate) mod tcp;
 
+#[cfg(target_os = "linux")]
+#[unstable(feature = "udp_batch", issue = "none")]
+pub(crate) mod udp;
+
 #[cfg(test)]
 mod tests;
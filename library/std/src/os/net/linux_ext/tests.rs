@@ -50,3 +50,59 @@ macro_rules! t {
     stream.set_deferaccept(0).expect("set_deferaccept failed");
     assert_eq!(stream.deferaccept().unwrap(), 0);
 }
+
+#[test]
+#[cfg(target_os = "linux")]
+fn udp_send_recv_many() {
+    use crate::io::IoSliceMut;
+    use crate::net::test::{next_test_ip4, next_test_ip6};
+    use crate::net::{SocketAddr, UdpSocket};
+    use crate::os::net::linux_ext::udp::{Datagram, RecvMeta, UdpSocketExt};
+
+    macro_rules! t {
+        ($e:expr) => {
+            match $e {
+                Ok(t) => t,
+                Err(e) => panic!("received error for `{}`: {}", stringify!($e), e),
+            }
+        };
+    }
+
+    let check = |sender_addr: SocketAddr, receiver_addr: SocketAddr| {
+        let sender = t!(UdpSocket::bind(&sender_addr));
+        let receiver = t!(UdpSocket::bind(&receiver_addr));
+        t!(receiver.set_recv_pktinfo(true));
+        t!(receiver.set_recv_tos(true));
+
+        let datagrams = [
+            Datagram::new(b"hello").with_destination(receiver_addr).with_tos(0b10),
+            Datagram::new(b"world!")
+                .with_destination(receiver_addr)
+                .with_source(sender_addr.ip())
+                .with_tos(0b01),
+        ];
+        assert_eq!(t!(sender.send_many(&datagrams)), 2);
+
+        let mut storage = [[0; 64]; 4];
+        let mut bufs = storage.each_mut().map(|buf| IoSliceMut::new(buf));
+        let mut meta = [RecvMeta::new(); 4];
+        let mut received = 0;
+        while received < 2 {
+            received += t!(receiver.recv_many(&mut bufs[received..], &mut meta[received..]));
+        }
+        assert_eq!(received, 2);
+
+        assert_eq!(&bufs[0][..meta[0].len()], b"hello");
+        assert_eq!(&bufs[1][..meta[1].len()], b"world!");
+        for meta in &meta[..2] {
+            assert_eq!(meta.addr(), sender_addr);
+            assert_eq!(meta.destination(), Some(receiver_addr.ip()));
+            assert!(!meta.is_truncated());
+        }
+        assert_eq!(meta[0].ecn(), Some(0b10));
+        assert_eq!(meta[1].ecn(), Some(0b01));
+    };
+
+    check(next_test_ip4(), next_test_ip4());
+    check(next_test_ip6(), next_test_ip6());
+}
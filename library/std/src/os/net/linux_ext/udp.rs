@@ -0,0 +1,352 @@
+//! Linux-specific udp extensions to primitives in the [`std::net`] module.
+//!
+//! [`std::net`]: crate::net
+
+use crate::io::{self, IoSliceMut};
+use crate::net::{self, IpAddr, Ipv4Addr, SocketAddr, SocketAddrV4};
+use crate::sealed::Sealed;
+use crate::sys_common::AsInner;
+use crate::time::SystemTime;
+
+/// A datagram to be sent with [`UdpSocketExt::send_many`], along with the metadata to send
+/// it with.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(udp_batch)]
+/// use std::net::SocketAddr;
+/// use std::os::linux::net::Datagram;
+///
+/// let peer: SocketAddr = "192.0.2.1:4433".parse().unwrap();
+/// // Send with the ECT(0) ECN codepoint.
+/// let datagram = Datagram::new(b"hello").with_destination(peer).with_tos(0b10);
+/// ```
+#[derive(Clone, Copy, Debug)]
+#[unstable(feature = "udp_batch", issue = "none")]
+pub struct Datagram<'a> {
+    pub(crate) contents: &'a [u8],
+    pub(crate) destination: Option<SocketAddr>,
+    pub(crate) source: Option<IpAddr>,
+    pub(crate) tos: Option<u8>,
+    pub(crate) segment_size: Option<u16>,
+}
+
+impl<'a> Datagram<'a> {
+    /// Creates a datagram with the given contents, to be sent to the address the socket
+    /// is connected to.
+    #[unstable(feature = "udp_batch", issue = "none")]
+    #[must_use]
+    pub const fn new(contents: &'a [u8]) -> Datagram<'a> {
+        Datagram { contents, destination: None, source: None, tos: None, segment_size: None }
+    }
+
+    /// Sets the address to send the datagram to.
+    ///
+    /// This is required unless the socket is connected.
+    #[unstable(feature = "udp_batch", issue = "none")]
+    #[must_use]
+    pub const fn with_destination(self, addr: SocketAddr) -> Datagram<'a> {
+        Datagram { destination: Some(addr), ..self }
+    }
+
+    /// Sets the source address of the datagram (`IP_PKTINFO` or `IPV6_PKTINFO`).
+    ///
+    /// For a socket bound to an unspecified address, this selects which of the host's
+    /// addresses the datagram is sent from, typically the
+    /// [destination](RecvMeta::destination) of the datagram being replied to.
+    #[unstable(feature = "udp_batch", issue = "none")]
+    #[must_use]
+    pub const fn with_source(self, ip: IpAddr) -> Datagram<'a> {
+        Datagram { source: Some(ip), ..self }
+    }
+
+    /// Sets the type of service (`IP_TOS`) or traffic class (`IPV6_TCLASS`) byte of the
+    /// datagram.
+    ///
+    /// The two least significant bits are the ECN codepoint.
+    #[unstable(feature = "udp_batch", issue = "none")]
+    #[must_use]
+    pub const fn with_tos(self, tos: u8) -> Datagram<'a> {
+        Datagram { tos: Some(tos), ..self }
+    }
+
+    /// Sends the contents as a series of datagrams of `size` bytes each, the last one
+    /// possibly being shorter, using UDP generic segmentation offload (`UDP_SEGMENT`).
+    ///
+    /// Segmenting is done by the kernel or the network card, which is much cheaper than
+    /// sending the datagrams one by one. All segments share the metadata of this datagram.
+    /// Requires Linux 4.18 or later.
+    #[unstable(feature = "udp_batch", issue = "none")]
+    #[must_use]
+    pub const fn with_segment_size(self, size: u16) -> Datagram<'a> {
+        Datagram { segment_size: Some(size), ..self }
+    }
+
+    /// Returns the contents of the datagram.
+    #[unstable(feature = "udp_batch", issue = "none")]
+    #[must_use]
+    pub const fn contents(&self) -> &'a [u8] {
+        self.contents
+    }
+}
+
+/// Metadata of a datagram received with [`UdpSocketExt::recv_many`].
+///
+/// Which of the optional fields are available depends on the options enabled on the
+/// socket, see [`set_recv_pktinfo`], [`set_recv_tos`], [`set_recv_timestamps`] and
+/// [`set_gro`].
+///
+/// [`set_recv_pktinfo`]: UdpSocketExt::set_recv_pktinfo
+/// [`set_recv_tos`]: UdpSocketExt::set_recv_tos
+/// [`set_recv_timestamps`]: UdpSocketExt::set_recv_timestamps
+/// [`set_gro`]: UdpSocketExt::set_gro
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[unstable(feature = "udp_batch", issue = "none")]
+pub struct RecvMeta {
+    pub(crate) len: usize,
+    pub(crate) addr: SocketAddr,
+    pub(crate) truncated: bool,
+    pub(crate) destination: Option<IpAddr>,
+    pub(crate) interface: Option<u32>,
+    pub(crate) tos: Option<u8>,
+    pub(crate) timestamp: Option<SystemTime>,
+    pub(crate) segment_size: Option<usize>,
+}
+
+impl RecvMeta {
+    /// Creates empty metadata, to be filled in by [`UdpSocketExt::recv_many`].
+    #[unstable(feature = "udp_batch", issue = "none")]
+    #[must_use]
+    pub const fn new() -> RecvMeta {
+        RecvMeta {
+            len: 0,
+            addr: SocketAddr::V4(SocketAddrV4::new(Ipv4Addr::UNSPECIFIED, 0)),
+            truncated: false,
+            destination: None,
+            interface: None,
+            tos: None,
+            timestamp: None,
+            segment_size: None,
+        }
+    }
+
+    /// Returns the number of bytes received into the buffer.
+    #[unstable(feature = "udp_batch", issue = "none")]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns the address the datagram was sent from.
+    #[unstable(feature = "udp_batch", issue = "none")]
+    #[must_use]
+    pub fn addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    /// Returns `true` if the datagram did not fit in the buffer and its excess bytes were
+    /// discarded.
+    #[unstable(feature = "udp_batch", issue = "none")]
+    #[must_use]
+    pub fn is_truncated(&self) -> bool {
+        self.truncated
+    }
+
+    /// Returns the address the datagram was sent to, if `IP_PKTINFO` or `IPV6_PKTINFO`
+    /// was enabled with [`UdpSocketExt::set_recv_pktinfo`].
+    #[unstable(feature = "udp_batch", issue = "none")]
+    #[must_use]
+    pub fn destination(&self) -> Option<IpAddr> {
+        self.destination
+    }
+
+    /// Returns the index of the interface the datagram was received on, if `IP_PKTINFO`
+    /// or `IPV6_PKTINFO` was enabled with [`UdpSocketExt::set_recv_pktinfo`].
+    #[unstable(feature = "udp_batch", issue = "none")]
+    #[must_use]
+    pub fn interface_index(&self) -> Option<u32> {
+        self.interface
+    }
+
+    /// Returns the type of service or traffic class byte of the datagram, if enabled with
+    /// [`UdpSocketExt::set_recv_tos`].
+    #[unstable(feature = "udp_batch", issue = "none")]
+    #[must_use]
+    pub fn tos(&self) -> Option<u8> {
+        self.tos
+    }
+
+    /// Returns the ECN codepoint of the datagram, the two least significant bits of its
+    /// [type of service](RecvMeta::tos) byte.
+    #[unstable(feature = "udp_batch", issue = "none")]
+    #[must_use]
+    pub fn ecn(&self) -> Option<u8> {
+        self.tos.map(|tos| tos & 0b11)
+    }
+
+    /// Returns when the kernel received the datagram, if enabled with
+    /// [`UdpSocketExt::set_recv_timestamps`].
+    #[unstable(feature = "udp_batch", issue = "none")]
+    #[must_use]
+    pub fn timestamp(&self) -> Option<SystemTime> {
+        self.timestamp
+    }
+
+    /// Returns the size of the segments the buffer holds, if several datagrams of the same
+    /// sender were coalesced by [generic receive offload](UdpSocketExt::set_gro).
+    ///
+    /// The buffer then holds `len / segment_size` datagrams of `segment_size` bytes each,
+    /// followed by one shorter datagram if `len` is not a multiple of `segment_size`.
+    #[unstable(feature = "udp_batch", issue = "none")]
+    #[must_use]
+    pub fn segment_size(&self) -> Option<usize> {
+        self.segment_size
+    }
+}
+
+#[unstable(feature = "udp_batch", issue = "none")]
+impl Default for RecvMeta {
+    fn default() -> RecvMeta {
+        RecvMeta::new()
+    }
+}
+
+/// Os-specific extensions for [`UdpSocket`]
+///
+/// [`UdpSocket`]: net::UdpSocket
+#[unstable(feature = "udp_batch", issue = "none")]
+pub trait UdpSocketExt: Sealed {
+    /// Sends several datagrams with a single system call (`sendmmsg`).
+    ///
+    /// Returns the number of datagrams sent, which may be less than `datagrams.len()`.
+    /// An error is only returned if not even the first datagram could be sent.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(udp_batch)]
+    /// use std::net::UdpSocket;
+    /// use std::os::linux::net::{Datagram, UdpSocketExt};
+    ///
+    /// let socket = UdpSocket::bind("127.0.0.1:34254")?;
+    /// let peer = "127.0.0.1:4242".parse().unwrap();
+    /// let datagrams = [b"one", b"two", b"six"].map(|d| Datagram::new(d).with_destination(peer));
+    ///
+    /// let mut sent = 0;
+    /// while sent < datagrams.len() {
+    ///     sent += socket.send_many(&datagrams[sent..])?;
+    /// }
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    #[unstable(feature = "udp_batch", issue = "none")]
+    fn send_many(&self, datagrams: &[Datagram<'_>]) -> io::Result<usize>;
+
+    /// Receives several datagrams with a single system call (`recvmmsg`).
+    ///
+    /// Each datagram is received into one of `bufs`, and its metadata is stored in the
+    /// corresponding element of `meta`. This blocks until at least one datagram is
+    /// available, and then only receives the datagrams that are already queued. Returns
+    /// the number of datagrams received.
+    ///
+    /// If the metadata of a received datagram cannot be decoded, only the datagrams
+    /// before it are reported, and the rest of the batch is discarded. An error is
+    /// returned only if that leaves no datagram to report.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(udp_batch)]
+    /// use std::io::IoSliceMut;
+    /// use std::net::UdpSocket;
+    /// use std::os::linux::net::{RecvMeta, UdpSocketExt};
+    ///
+    /// let socket = UdpSocket::bind("127.0.0.1:34254")?;
+    /// socket.set_recv_pktinfo(true)?;
+    ///
+    /// let mut storage = [[0; 1500]; 8];
+    /// let mut bufs = storage.each_mut().map(|buf| IoSliceMut::new(buf));
+    /// let mut meta = [RecvMeta::new(); 8];
+    ///
+    /// let count = socket.recv_many(&mut bufs, &mut meta)?;
+    /// for (buf, meta) in bufs.iter().zip(&meta).take(count) {
+    ///     println!("{} bytes from {} to {:?}", meta.len(), meta.addr(), meta.destination());
+    ///     let _datagram = &buf[..meta.len()];
+    /// }
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    #[unstable(feature = "udp_batch", issue = "none")]
+    fn recv_many(&self, bufs: &mut [IoSliceMut<'_>], meta: &mut [RecvMeta]) -> io::Result<usize>;
+
+    /// Enables or disables UDP generic receive offload (`UDP_GRO`).
+    ///
+    /// When enabled, datagrams of the same size from the same sender may be coalesced
+    /// into a single buffer by [`recv_many`], see [`RecvMeta::segment_size`]. Buffers
+    /// should then be large enough for several datagrams, up to 64 KiB. Requires Linux 5.0
+    /// or later.
+    ///
+    /// [`recv_many`]: UdpSocketExt::recv_many
+    #[unstable(feature = "udp_batch", issue = "none")]
+    fn set_gro(&self, gro: bool) -> io::Result<()>;
+
+    /// Gets the value of the `UDP_GRO` option on this socket.
+    ///
+    /// For more information about this option, see [`UdpSocketExt::set_gro`].
+    #[unstable(feature = "udp_batch", issue = "none")]
+    fn gro(&self) -> io::Result<bool>;
+
+    /// Enables or disables reporting the destination address and interface of received
+    /// datagrams (`IP_PKTINFO` and `IPV6_RECVPKTINFO`).
+    ///
+    /// See [`RecvMeta::destination`] and [`RecvMeta::interface_index`].
+    #[unstable(feature = "udp_batch", issue = "none")]
+    fn set_recv_pktinfo(&self, enable: bool) -> io::Result<()>;
+
+    /// Enables or disables reporting the type of service byte of received datagrams,
+    /// which includes their ECN codepoint (`IP_RECVTOS` and `IPV6_RECVTCLASS`).
+    ///
+    /// See [`RecvMeta::tos`] and [`RecvMeta::ecn`].
+    #[unstable(feature = "udp_batch", issue = "none")]
+    fn set_recv_tos(&self, enable: bool) -> io::Result<()>;
+
+    /// Enables or disables reporting when datagrams were received by the kernel
+    /// (`SO_TIMESTAMPNS`).
+    ///
+    /// See [`RecvMeta::timestamp`].
+    #[unstable(feature = "udp_batch", issue = "none")]
+    fn set_recv_timestamps(&self, enable: bool) -> io::Result<()>;
+}
+
+#[unstable(feature = "udp_batch", issue = "none")]
+impl Sealed for net::UdpSocket {}
+
+#[unstable(feature = "udp_batch", issue = "none")]
+impl UdpSocketExt for net::UdpSocket {
+    fn send_many(&self, datagrams: &[Datagram<'_>]) -> io::Result<usize> {
+        self.as_inner().socket().send_many(datagrams)
+    }
+
+    fn recv_many(&self, bufs: &mut [IoSliceMut<'_>], meta: &mut [RecvMeta]) -> io::Result<usize> {
+        self.as_inner().socket().recv_many(bufs, meta)
+    }
+
+    fn set_gro(&self, gro: bool) -> io::Result<()> {
+        self.as_inner().socket().set_udp_gro(gro)
+    }
+
+    fn gro(&self) -> io::Result<bool> {
+        self.as_inner().socket().udp_gro()
+    }
+
+    fn set_recv_pktinfo(&self, enable: bool) -> io::Result<()> {
+        self.as_inner().socket().set_recv_pktinfo(enable)
+    }
+
+    fn set_recv_tos(&self, enable: bool) -> io::Result<()> {
+        self.as_inner().socket().set_recv_tos(enable)
+    }
+
+    fn set_recv_timestamps(&self, enable: bool) -> io::Result<()> {
+        self.as_inner().socket().set_recv_timestamps(enable)
+    }
+}
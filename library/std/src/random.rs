@@ -17,7 +17,7 @@
 /// The high quality of randomness provided by this source means it can be quite
 /// slow on some targets. If you need a large quantity of random numbers and
 /// security is not a concern,  consider using an alternative random number
-/// generator (potentially seeded from this one).
+/// generator such as [`Xoshiro256PlusPlus`] (potentially seeded from this one).
 ///
 /// # Underlying sources
 ///
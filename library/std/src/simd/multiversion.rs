@@ -0,0 +1,191 @@
+/// Defines a function that is compiled for several sets of target features
+/// and picks the best one for the running CPU.
+///
+/// [Portable SIMD](crate::simd) types are lowered to the instructions of the
+/// target features enabled for the whole crate, which usually leaves out the
+/// wider vector extensions of newer CPUs. `multiversion!` compiles the body of
+/// a function once for each listed set of [`target_feature`]s and once without
+/// any, and forwards every call to the first set that the CPU supports. The
+/// detection is done with the same cache as [`is_x86_feature_detected!`] and
+/// runs only on the first call; later calls are dispatched with a single
+/// atomic load.
+///
+/// [`target_feature`]: https://doc.rust-lang.org/reference/attributes/codegen.html#the-target_feature-attribute
+/// [`is_x86_feature_detected!`]: crate::arch::is_x86_feature_detected
+///
+/// The function is preceded by a `#[targets(...)]` attribute that lists the
+/// feature sets in order of preference, each tagged with its architecture:
+///
+/// ```text
+/// multiversion! {
+///     #[targets(x86("avx2", "fma"), x86("sse4.1"), aarch64("sve"))]
+///     fn name(arg: Type, ...) -> ReturnType { ... }
+/// }
+/// ```
+///
+/// `x86` covers both `x86` and `x86_64`. The sets listed for other
+/// architectures than the one being compiled for are ignored, so on those
+/// the function always runs the version without extra features. Other
+/// attributes of the function, including its documentation, go after
+/// `#[targets(...)]`.
+///
+/// The features are enabled with `#[target_feature]` in the calling crate,
+/// so features that are still unstable there, such as `avx512f`, require
+/// that crate to enable their feature gate, e.g.
+/// `#![feature(avx512_target_feature)]`.
+///
+/// The function may have attributes, a visibility and a return type, but no
+/// generic parameters, and its arguments must be plain identifiers rather
+/// than patterns. The body is compiled in a separate function for each set,
+/// so it cannot refer to `self` or to generic parameters of an enclosing
+/// item.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(portable_simd, simd_multiversion)]
+/// use std::simd::f32x8;
+/// use std::simd::num::SimdFloat;
+///
+/// std::simd::multiversion! {
+///     #[targets(x86("avx2", "fma"), x86("sse4.1"), aarch64("sve"))]
+///     /// Computes the dot product of two slices of equal length.
+///     pub fn dot(a: &[f32], b: &[f32]) -> f32 {
+///         assert_eq!(a.len(), b.len());
+///         let mut sum = f32x8::splat(0.0);
+///         for (a, b) in a.chunks_exact(8).zip(b.chunks_exact(8)) {
+///             sum += f32x8::from_slice(a) * f32x8::from_slice(b);
+///         }
+///         let tail = a.len() / 8 * 8;
+///         let tail: f32 = a[tail..].iter().zip(&b[tail..]).map(|(a, b)| a * b).sum();
+///         sum.reduce_sum() + tail
+///     }
+/// }
+///
+/// let a: Vec<f32> = (0..20).map(|x| x as f32).collect();
+/// assert_eq!(dot(&a, &a), 2470.0);
+/// ```
+#[unstable(feature = "simd_multiversion", issue = "none")]
+#[allow_internal_unstable(simd_multiversion_internals)]
+#[rustc_macro_transparency = "semitransparent"]
+pub macro multiversion(
+    #[targets($($arch:ident($($feature:tt),+ $(,)?)),+ $(,)?)]
+    $(#[$attr:meta])*
+    $vis:vis fn $name:ident($($arg:ident: $ty:ty),* $(,)?) $(-> $ret:ty)? $body:block
+) {
+    $(#[$attr])*
+    $vis fn $name($($arg: $ty),*) $(-> $ret)? {
+        // The index of the selected version, counting from 1, or 0 before the
+        // first call.
+        static __SELECTED: $crate::sync::atomic::AtomicUsize =
+            $crate::sync::atomic::AtomicUsize::new(0);
+        let selected = __SELECTED.load($crate::sync::atomic::Ordering::Relaxed);
+        $crate::simd::multiversion_inner!(
+            __SELECTED,
+            selected,
+            1,
+            [$($arch [$($feature),+])+],
+            ($($arg: $ty),*),
+            [$(-> $ret)?],
+            ($($arg),*),
+            $body
+        )
+    }
+}
+
+/// Emits the version of a `multiversion!` function for the first feature set
+/// in the list, followed by the remaining versions and finally the version
+/// without extra features.
+#[doc(hidden)]
+#[unstable(feature = "simd_multiversion_internals", issue = "none")]
+#[allow_internal_unstable(simd_multiversion_internals)]
+#[allow_internal_unsafe]
+#[rustc_macro_transparency = "semitransparent"]
+pub macro multiversion_inner {
+    (
+        $static:ident,
+        $selected:ident,
+        $index:expr,
+        [],
+        $params:tt,
+        [$($ret:tt)*],
+        $args:tt,
+        $body:block
+    ) => {{
+        if $selected == 0 {
+            $static.store($index, $crate::sync::atomic::Ordering::Relaxed);
+        }
+        $body
+    }},
+    (
+        $static:ident,
+        $selected:ident,
+        $index:expr,
+        [x86 [$($feature:tt),+] $($rest:tt)*],
+        $params:tt,
+        [$($ret:tt)*],
+        $args:tt,
+        $body:block
+    ) => {{
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        {
+            $(#[target_feature(enable = $feature)])+
+            fn __multiversion $params $($ret)* $body
+
+            if $selected == $index {
+                // SAFETY: the features were detected when this version was selected.
+                return unsafe { __multiversion $args };
+            }
+            if $selected == 0 $(&& $crate::arch::is_x86_feature_detected!($feature))+ {
+                $static.store($index, $crate::sync::atomic::Ordering::Relaxed);
+                // SAFETY: the CPU supports all features of this version.
+                return unsafe { __multiversion $args };
+            }
+        }
+        $crate::simd::multiversion_inner!(
+            $static, $selected, $index + 1, [$($rest)*], $params, [$($ret)*], $args, $body
+        )
+    }},
+    (
+        $static:ident,
+        $selected:ident,
+        $index:expr,
+        [aarch64 [$($feature:tt),+] $($rest:tt)*],
+        $params:tt,
+        [$($ret:tt)*],
+        $args:tt,
+        $body:block
+    ) => {{
+        #[cfg(target_arch = "aarch64")]
+        {
+            $(#[target_feature(enable = $feature)])+
+            fn __multiversion $params $($ret)* $body
+
+            if $selected == $index {
+                // SAFETY: the features were detected when this version was selected.
+                return unsafe { __multiversion $args };
+            }
+            if $selected == 0 $(&& $crate::arch::is_aarch64_feature_detected!($feature))+ {
+                $static.store($index, $crate::sync::atomic::Ordering::Relaxed);
+                // SAFETY: the CPU supports all features of this version.
+                return unsafe { __multiversion $args };
+            }
+        }
+        $crate::simd::multiversion_inner!(
+            $static, $selected, $index + 1, [$($rest)*], $params, [$($ret)*], $args, $body
+        )
+    }},
+    (
+        $static:ident,
+        $selected:ident,
+        $index:expr,
+        [$arch:ident $($rest:tt)*],
+        $($tail:tt)*
+    ) => {
+        $crate::compile_error!($crate::concat!(
+            "unsupported architecture `",
+            $crate::stringify!($arch),
+            "` in `multiversion!`, expected `x86` or `aarch64`",
+        ))
+    },
+}
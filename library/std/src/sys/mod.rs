@@ -22,6 +22,7 @@
 pub mod stdio;
 pub mod sync;
 pub mod thread_local;
+pub mod time_zone;
 
 // FIXME(117276): remove this, move feature implementations into individual
 //                submodules.
@@ -1,5 +1,5 @@
 use crate::io::{self, BorrowedCursor, IoSlice, IoSliceMut};
-use crate::net::{Ipv4Addr, Ipv6Addr, Shutdown, SocketAddr, ToSocketAddrs};
+use crate::net::{Ipv4Addr, Ipv6Addr, Shutdown, SocketAddr, SocketBuilder, ToSocketAddrs};
 use crate::sync::Arc;
 use crate::sys::abi::usercalls;
 use crate::sys::fd::FileDesc;
@@ -85,6 +85,16 @@ fn addr_to_sockaddr(addr: Option<&str>) -> io::Result<SocketAddr> {
         .map(|mut it| it.next().unwrap())
 }
 
+/// The usercall interface has no way to pass socket options, so only the defaults can be
+/// honored.
+fn check_default_options(opts: &SocketBuilder) -> io::Result<()> {
+    if *opts == SocketBuilder::new() {
+        Ok(())
+    } else {
+        Err(io::const_error!(io::ErrorKind::Unsupported, "socket options are not supported on SGX"))
+    }
+}
+
 impl TcpStream {
     pub fn connect(addr: io::Result<&SocketAddr>) -> io::Result<TcpStream> {
         let addr = io_err_to_addr(addr)?;
@@ -99,6 +109,32 @@ pub fn connect_timeout(addr: &SocketAddr, dur: Duration) -> io::Result<TcpStream
         Self::connect(Ok(addr)) // FIXME: ignoring timeout
     }
 
+    pub fn connect_racing(
+        addrs: &[SocketAddr],
+        _attempt_delay: Duration,
+        timeout: Option<Duration>,
+    ) -> io::Result<TcpStream> {
+        if timeout == Some(Duration::default()) {
+            return Err(io::Error::ZERO_TIMEOUT);
+        }
+        // FIXME: ignoring timeout, attempts can't be raced either
+        let mut last_err = None;
+        for addr in addrs {
+            match Self::connect(Ok(addr)) {
+                Ok(stream) => return Ok(stream),
+                Err(e) => last_err = Some(e),
+            }
+        }
+        Err(last_err.unwrap_or_else(|| {
+            io::const_error!(io::ErrorKind::InvalidInput, "could not resolve to any addresses")
+        }))
+    }
+
+    pub fn connect_with(addr: &SocketAddr, opts: &SocketBuilder) -> io::Result<TcpStream> {
+        check_default_options(opts)?;
+        Self::connect(Ok(addr))
+    }
+
     pub fn set_read_timeout(&self, dur: Option<Duration>) -> io::Result<()> {
         match dur {
             Some(dur) if dur == Duration::default() => {
@@ -253,6 +289,11 @@ pub fn bind(addr: io::Result<&SocketAddr>) -> io::Result<TcpListener> {
         Ok(TcpListener { inner: Socket::new(fd, local_addr) })
     }
 
+    pub fn bind_with(addr: &SocketAddr, opts: &SocketBuilder) -> io::Result<TcpListener> {
+        check_default_options(opts)?;
+        Self::bind(Ok(addr))
+    }
+
     pub fn socket_addr(&self) -> io::Result<SocketAddr> {
         addr_to_sockaddr(self.inner.local_addr.as_deref())
     }
@@ -320,6 +361,10 @@ pub fn bind(_: io::Result<&SocketAddr>) -> io::Result<UdpSocket> {
         unsupported()
     }
 
+    pub fn bind_with(_: &SocketAddr, _: &SocketBuilder) -> io::Result<UdpSocket> {
+        unsupported()
+    }
+
     pub fn peer_addr(&self) -> io::Result<SocketAddr> {
         self.0
     }
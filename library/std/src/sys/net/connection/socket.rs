@@ -3,10 +3,13 @@
 
 use crate::ffi::{c_int, c_void};
 use crate::io::{self, BorrowedCursor, ErrorKind, IoSlice, IoSliceMut};
-use crate::net::{Ipv4Addr, Ipv6Addr, Shutdown, SocketAddr, SocketAddrV4, SocketAddrV6};
+use crate::net::{
+    Ipv4Addr, Ipv6Addr, Shutdown, SocketAddr, SocketAddrV4, SocketAddrV6, SocketBuilder,
+    TcpKeepalive,
+};
 use crate::sys::common::small_c_string::run_with_cstr;
 use crate::sys_common::{AsInner, FromInner};
-use crate::time::Duration;
+use crate::time::{Duration, Instant};
 use crate::{cmp, fmt, mem, ptr};
 
 cfg_if::cfg_if! {
@@ -323,6 +326,172 @@ fn try_from((host, port): (&'a str, u16)) -> io::Result<LookupHost> {
     }
 }
 
+////////////////////////////////////////////////////////////////////////////////
+// SocketBuilder options
+////////////////////////////////////////////////////////////////////////////////
+
+/// Applies the options of `opts` which have to be set before `sock` is bound or
+/// connected to `addr`.
+fn apply_builder_options(
+    sock: &Socket,
+    addr: &SocketAddr,
+    ty: c_int,
+    opts: &SocketBuilder,
+) -> io::Result<()> {
+    if let Some(reuse) = opts.reuse_address {
+        setsockopt(sock, c::SOL_SOCKET, c::SO_REUSEADDR, reuse as c_int)?;
+    }
+    if let Some(reuse) = opts.reuse_port {
+        set_reuse_port(sock, reuse)?;
+    }
+    if let (Some(only_v6), SocketAddr::V6(_)) = (opts.only_v6, addr) {
+        setsockopt(sock, c::IPPROTO_IPV6, c::IPV6_V6ONLY, only_v6 as c_int)?;
+    }
+    if opts.recv_buffer_size.is_some() || opts.send_buffer_size.is_some() {
+        set_buffer_sizes(sock, opts.recv_buffer_size, opts.send_buffer_size)?;
+    }
+    if let Some(keepalive) = opts.keepalive {
+        if ty == c::SOCK_STREAM {
+            set_keepalive(sock, &keepalive)?;
+        }
+    }
+    Ok(())
+}
+
+cfg_if::cfg_if! {
+    if #[cfg(any(
+        target_os = "linux",
+        target_os = "android",
+        target_os = "dragonfly",
+        target_os = "freebsd",
+        target_os = "openbsd",
+        target_os = "netbsd",
+        target_vendor = "apple",
+    ))] {
+        fn set_reuse_port(sock: &Socket, reuse: bool) -> io::Result<()> {
+            setsockopt(sock, c::SOL_SOCKET, c::SO_REUSEPORT, reuse as c_int)
+        }
+    } else {
+        fn set_reuse_port(_: &Socket, _: bool) -> io::Result<()> {
+            Err(io::const_error!(
+                ErrorKind::Unsupported,
+                "SO_REUSEPORT is not supported on this platform",
+            ))
+        }
+    }
+}
+
+cfg_if::cfg_if! {
+    if #[cfg(any(unix, windows))] {
+        fn set_buffer_sizes(
+            sock: &Socket,
+            recv: Option<usize>,
+            send: Option<usize>,
+        ) -> io::Result<()> {
+            // Larger sizes are clamped by the operating system anyway.
+            let clamp = |size: usize| cmp::min(size, c_int::MAX as usize) as c_int;
+            if let Some(size) = recv {
+                setsockopt(sock, c::SOL_SOCKET, c::SO_RCVBUF, clamp(size))?;
+            }
+            if let Some(size) = send {
+                setsockopt(sock, c::SOL_SOCKET, c::SO_SNDBUF, clamp(size))?;
+            }
+            Ok(())
+        }
+
+        fn set_keepalive(sock: &Socket, keepalive: &TcpKeepalive) -> io::Result<()> {
+            setsockopt(sock, c::SOL_SOCKET, c::SO_KEEPALIVE, 1 as c_int)?;
+            if *keepalive != TcpKeepalive::new() {
+                set_keepalive_params(sock, keepalive)?;
+            }
+            Ok(())
+        }
+    } else {
+        fn set_buffer_sizes(_: &Socket, _: Option<usize>, _: Option<usize>) -> io::Result<()> {
+            Err(io::const_error!(
+                ErrorKind::Unsupported,
+                "setting socket buffer sizes is not supported on this platform",
+            ))
+        }
+
+        fn set_keepalive(_: &Socket, _: &TcpKeepalive) -> io::Result<()> {
+            Err(io::const_error!(
+                ErrorKind::Unsupported,
+                "TCP keepalive is not supported on this platform",
+            ))
+        }
+    }
+}
+
+cfg_if::cfg_if! {
+    if #[cfg(any(
+        target_os = "linux",
+        target_os = "android",
+        target_os = "freebsd",
+        target_os = "netbsd",
+        target_os = "fuchsia",
+        target_vendor = "apple",
+        windows,
+    ))] {
+        fn set_keepalive_params(sock: &Socket, keepalive: &TcpKeepalive) -> io::Result<()> {
+            #[cfg(any(target_vendor = "apple", windows))]
+            use c::TCP_KEEPALIVE as TCP_KEEPIDLE;
+            #[cfg(not(any(target_vendor = "apple", windows)))]
+            use c::TCP_KEEPIDLE;
+
+            // The options take whole seconds.
+            let keepalive_secs =
+                |dur: Duration| cmp::min(cmp::max(dur.as_secs(), 1), c_int::MAX as u64) as c_int;
+
+            if let Some(time) = keepalive.time {
+                setsockopt(sock, c::IPPROTO_TCP, TCP_KEEPIDLE, keepalive_secs(time))?;
+            }
+            if let Some(interval) = keepalive.interval {
+                setsockopt(sock, c::IPPROTO_TCP, c::TCP_KEEPINTVL, keepalive_secs(interval))?;
+            }
+            if let Some(retries) = keepalive.retries {
+                let retries = cmp::min(retries, c_int::MAX as u32) as c_int;
+                setsockopt(sock, c::IPPROTO_TCP, c::TCP_KEEPCNT, retries)?;
+            }
+            Ok(())
+        }
+    } else if #[cfg(any(unix, windows))] {
+        fn set_keepalive_params(_: &Socket, _: &TcpKeepalive) -> io::Result<()> {
+            Err(io::const_error!(
+                ErrorKind::Unsupported,
+                "tuning TCP keepalive is not supported on this platform",
+            ))
+        }
+    }
+}
+
+cfg_if::cfg_if! {
+    if #[cfg(target_os = "linux")] {
+        fn set_fastopen_connect(sock: &Socket, fastopen: bool) -> io::Result<()> {
+            setsockopt(sock, c::IPPROTO_TCP, c::TCP_FASTOPEN_CONNECT, fastopen as c_int)
+        }
+
+        fn set_fastopen_listen(sock: &Socket, backlog: c_int) -> io::Result<()> {
+            // The option value is the maximum number of pending Fast Open requests.
+            setsockopt(sock, c::IPPROTO_TCP, c::TCP_FASTOPEN, backlog)
+        }
+    } else {
+        fn set_fastopen_connect(_: &Socket, _: bool) -> io::Result<()> {
+            Err(io::const_error!(
+                ErrorKind::Unsupported,
+                "TCP Fast Open is not supported on this platform",
+            ))
+        }
+
+        fn set_fastopen_listen(_: &Socket, _: c_int) -> io::Result<()> {
+            Err(io::const_error!(
+                ErrorKind::Unsupported,
+                "TCP Fast Open is not supported on this platform",
+            ))
+        }
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // TCP streams
 ////////////////////////////////////////////////////////////////////////////////
@@ -350,6 +519,139 @@ pub fn connect_timeout(addr: &SocketAddr, timeout: Duration) -> io::Result<TcpSt
         Ok(TcpStream { inner: sock })
     }
 
+    pub fn connect_with(addr: &SocketAddr, opts: &SocketBuilder) -> io::Result<TcpStream> {
+        init();
+
+        let sock = Socket::new(addr, c::SOCK_STREAM)?;
+        apply_builder_options(&sock, addr, c::SOCK_STREAM, opts)?;
+        if let Some(fastopen) = opts.fastopen {
+            set_fastopen_connect(&sock, fastopen)?;
+        }
+        if let Some(local_addr) = &opts.local_addr {
+            let (local_addr, len) = socket_addr_to_c(local_addr);
+            cvt(unsafe { c::bind(sock.as_raw(), local_addr.as_ptr(), len as _) })?;
+        }
+        sock.connect(addr)?;
+        Ok(TcpStream { inner: sock })
+    }
+
+    /// Races connection attempts to `addrs`, starting a new attempt every `attempt_delay`
+    /// or as soon as the previous attempt failed, and returns the first one to succeed.
+    #[cfg(unix)]
+    pub fn connect_racing(
+        addrs: &[SocketAddr],
+        attempt_delay: Duration,
+        timeout: Option<Duration>,
+    ) -> io::Result<TcpStream> {
+        init();
+
+        let start = Instant::now();
+        let deadline = timeout.and_then(|timeout| start.checked_add(timeout));
+        let mut addrs = addrs.iter();
+        let mut pending: Vec<Socket> = Vec::new();
+        // When to start the next attempt, `None` once all addresses have been tried.
+        let mut next_attempt = Some(start);
+        let mut last_err = None;
+
+        loop {
+            let now = Instant::now();
+            if deadline.is_some_and(|deadline| now >= deadline) {
+                return Err(io::const_error!(ErrorKind::TimedOut, "connection timed out"));
+            }
+
+            if next_attempt.is_some_and(|next| now >= next) || pending.is_empty() {
+                let Some(addr) = addrs.next() else {
+                    if pending.is_empty() {
+                        return Err(last_err.unwrap_or_else(|| {
+                            io::const_error!(
+                                ErrorKind::InvalidInput,
+                                "could not resolve to any addresses",
+                            )
+                        }));
+                    }
+                    next_attempt = None;
+                    continue;
+                };
+
+                let attempt = Socket::new(addr, c::SOCK_STREAM)
+                    .and_then(|sock| Ok((sock.connect_nonblocking(addr)?, sock)));
+                match attempt {
+                    Ok((true, sock)) => {
+                        sock.set_nonblocking(false)?;
+                        return Ok(TcpStream { inner: sock });
+                    }
+                    Ok((false, sock)) => {
+                        pending.push(sock);
+                        next_attempt = Some(now + attempt_delay);
+                    }
+                    // Move on to the next address right away.
+                    Err(e) => {
+                        last_err = Some(e);
+                        next_attempt = Some(now);
+                    }
+                }
+                continue;
+            }
+
+            let wait_until = match (next_attempt, deadline) {
+                (Some(next), Some(deadline)) => Some(cmp::min(next, deadline)),
+                (next, deadline) => next.or(deadline),
+            };
+            let wait = match wait_until {
+                Some(until) => until.saturating_duration_since(now),
+                None => Duration::MAX,
+            };
+
+            if let Some((i, result)) = Socket::wait_connect_any(&pending, wait)? {
+                let sock = pending.swap_remove(i);
+                match result {
+                    Ok(()) => {
+                        sock.set_nonblocking(false)?;
+                        return Ok(TcpStream { inner: sock });
+                    }
+                    Err(e) => {
+                        last_err = Some(e);
+                        // Don't wait for the attempt delay if an attempt already failed.
+                        if next_attempt.is_some() {
+                            next_attempt = Some(now);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Attempts to connect to `addrs` one after another, as there is no way to wait for
+    /// several pending connections on this platform.
+    #[cfg(not(unix))]
+    pub fn connect_racing(
+        addrs: &[SocketAddr],
+        _attempt_delay: Duration,
+        timeout: Option<Duration>,
+    ) -> io::Result<TcpStream> {
+        let deadline = timeout.and_then(|timeout| Instant::now().checked_add(timeout));
+        let mut last_err = None;
+        for addr in addrs {
+            let result = match (timeout, deadline) {
+                (_, Some(deadline)) => match deadline.checked_duration_since(Instant::now()) {
+                    Some(remaining) if !remaining.is_zero() => {
+                        TcpStream::connect_timeout(addr, remaining)
+                    }
+                    _ => Err(io::const_error!(ErrorKind::TimedOut, "connection timed out")),
+                },
+                (Some(timeout), None) => TcpStream::connect_timeout(addr, timeout),
+                (None, None) => TcpStream::connect(Ok(addr)),
+            };
+            match result {
+                Ok(stream) => return Ok(stream),
+                Err(e) => last_err = Some(e),
+            }
+        }
+        Err(last_err.unwrap_or_else(|| {
+            io::const_error!(ErrorKind::InvalidInput, "could not resolve to any addresses")
+        }))
+    }
+
     #[inline]
     pub fn socket(&self) -> &Socket {
         &self.inner
@@ -503,8 +805,10 @@ pub struct TcpListener {
 
 impl TcpListener {
     pub fn bind(addr: io::Result<&SocketAddr>) -> io::Result<TcpListener> {
-        let addr = addr?;
+        TcpListener::bind_with(addr?, &SocketBuilder::new())
+    }
 
+    pub fn bind_with(addr: &SocketAddr, opts: &SocketBuilder) -> io::Result<TcpListener> {
         init();
 
         let sock = Socket::new(addr, c::SOCK_STREAM)?;
@@ -517,7 +821,10 @@ pub fn bind(addr: io::Result<&SocketAddr>) -> io::Result<TcpListener> {
         // which allows “socket hijacking”, so we explicitly don't set it here.
         // https://docs.microsoft.com/en-us/windows/win32/winsock/using-so-reuseaddr-and-so-exclusiveaddruse
         #[cfg(not(windows))]
-        setsockopt(&sock, c::SOL_SOCKET, c::SO_REUSEADDR, 1 as c_int)?;
+        if opts.reuse_address.is_none() {
+            setsockopt(&sock, c::SOL_SOCKET, c::SO_REUSEADDR, 1 as c_int)?;
+        }
+        apply_builder_options(&sock, addr, c::SOCK_STREAM, opts)?;
 
         // Bind our new socket
         let (addr, len) = socket_addr_to_c(addr);
@@ -528,16 +835,25 @@ pub fn bind(addr: io::Result<&SocketAddr>) -> io::Result<TcpListener> {
                 // The 3DS doesn't support a big connection backlog. Sometimes
                 // it allows up to about 37, but other times it doesn't even
                 // accept 32. There may be a global limitation causing this.
-                let backlog = 20;
+                let default_backlog = 20;
             } else if #[cfg(target_os = "haiku")] {
                 // Haiku does not support a queue length > 32
                 // https://github.com/haiku/haiku/blob/979a0bc487864675517fb2fab28f87dc8bf43041/headers/posix/sys/socket.h#L81
-                let backlog = 32;
+                let default_backlog = 32;
             } else {
                 // The default for all other platforms
-                let backlog = 128;
+                let default_backlog = 128;
             }
         }
+        let backlog = match opts.backlog {
+            Some(backlog) => cmp::min(backlog, c_int::MAX as u32) as c_int,
+            None => default_backlog,
+        };
+
+        if let Some(fastopen) = opts.fastopen {
+            // Allow as many pending Fast Open requests as regular connections.
+            set_fastopen_listen(&sock, if fastopen { backlog } else { 0 })?;
+        }
 
         // Start listening
         cvt(unsafe { c::listen(sock.as_raw(), backlog) })?;
@@ -628,11 +944,14 @@ pub struct UdpSocket {
 
 impl UdpSocket {
     pub fn bind(addr: io::Result<&SocketAddr>) -> io::Result<UdpSocket> {
-        let addr = addr?;
+        UdpSocket::bind_with(addr?, &SocketBuilder::new())
+    }
 
+    pub fn bind_with(addr: &SocketAddr, opts: &SocketBuilder) -> io::Result<UdpSocket> {
         init();
 
         let sock = Socket::new(addr, c::SOCK_DGRAM)?;
+        apply_builder_options(&sock, addr, c::SOCK_DGRAM, opts)?;
         let (addr, len) = socket_addr_to_c(addr);
         cvt(unsafe { c::bind(sock.as_raw(), addr.as_ptr(), len as _) })?;
         Ok(UdpSocket { inner: sock })
@@ -2,12 +2,20 @@
 
 use crate::ffi::CStr;
 use crate::io::{self, BorrowedBuf, BorrowedCursor, IoSlice, IoSliceMut};
+#[cfg(target_os = "linux")]
+use crate::net::{IpAddr, Ipv4Addr, Ipv6Addr};
 use crate::net::{Shutdown, SocketAddr};
+#[cfg(target_os = "linux")]
+use crate::os::linux::net::{Datagram, RecvMeta};
 use crate::os::unix::io::{AsFd, AsRawFd, BorrowedFd, FromRawFd, IntoRawFd, RawFd};
+#[cfg(target_os = "linux")]
+use crate::ptr;
 use crate::sys::fd::FileDesc;
 use crate::sys::net::{getsockopt, setsockopt};
 use crate::sys::pal::IsMinusOne;
 use crate::sys_common::{AsInner, FromInner, IntoInner};
+#[cfg(target_os = "linux")]
+use crate::time::SystemTime;
 use crate::time::{Duration, Instant};
 use crate::{cmp, mem};
 
@@ -21,6 +29,8 @@
 
 pub(super) use libc as netc;
 
+#[cfg(target_os = "linux")]
+use super::SocketAddrCRepr;
 use super::{socket_addr_from_c, socket_addr_to_c};
 pub use crate::sys::{cvt, cvt_r};
 
@@ -244,6 +254,73 @@ pub fn connect_timeout(&self, addr: &SocketAddr, timeout: Duration) -> io::Resul
         }
     }
 
+    /// Starts connecting to `addr` and puts the socket in nonblocking mode.
+    ///
+    /// Returns `true` if the connection was established immediately and `false` if it is
+    /// still in progress, in which case `wait_connect_any` reports when it completes.
+    pub fn connect_nonblocking(&self, addr: &SocketAddr) -> io::Result<bool> {
+        self.set_nonblocking(true)?;
+        let r = unsafe {
+            let (addr, len) = socket_addr_to_c(addr);
+            cvt(libc::connect(self.as_raw_fd(), addr.as_ptr(), len))
+        };
+
+        match r {
+            Ok(_) => Ok(true),
+            Err(ref e) if e.raw_os_error() == Some(libc::EINPROGRESS) => Ok(false),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Waits up to `timeout` for one of the connections started with `connect_nonblocking`
+    /// on `socks` to complete.
+    ///
+    /// Returns the index of the first completed socket together with the outcome of its
+    /// connection attempt, or `None` if no connection completed in time. Returning `None`
+    /// early is allowed, callers are expected to check the time and call again.
+    pub fn wait_connect_any(
+        socks: &[Socket],
+        timeout: Duration,
+    ) -> io::Result<Option<(usize, io::Result<()>)>> {
+        let mut pollfds: Vec<libc::pollfd> = socks
+            .iter()
+            .map(|sock| libc::pollfd { fd: sock.as_raw_fd(), events: libc::POLLOUT, revents: 0 })
+            .collect();
+
+        let mut timeout = timeout
+            .as_secs()
+            .saturating_mul(1_000)
+            .saturating_add(timeout.subsec_nanos() as u64 / 1_000_000);
+        if timeout == 0 {
+            timeout = 1;
+        }
+        let timeout = cmp::min(timeout, c_int::MAX as u64) as c_int;
+
+        match unsafe { libc::poll(pollfds.as_mut_ptr(), pollfds.len() as _, timeout) } {
+            -1 => {
+                let err = io::Error::last_os_error();
+                return if err.is_interrupted() { Ok(None) } else { Err(err) };
+            }
+            0 => return Ok(None),
+            _ => {}
+        }
+
+        let Some(i) = pollfds.iter().position(|pollfd| pollfd.revents != 0) else {
+            return Ok(None);
+        };
+        // See `connect_timeout` for why both POLLHUP and POLLERR are checked, and why
+        // VxWorks needs to consult SO_ERROR even on success.
+        let failed = pollfds[i].revents & (libc::POLLHUP | libc::POLLERR) != 0;
+        let result = match socks[i].take_error()? {
+            Some(e) => Err(e),
+            None if failed => {
+                Err(io::const_error!(io::ErrorKind::Uncategorized, "no error set after POLLHUP",))
+            }
+            None => Ok(()),
+        };
+        Ok(Some((i, result)))
+    }
+
     pub fn accept(&self, storage: *mut sockaddr, len: *mut socklen_t) -> io::Result<Socket> {
         // Unfortunately the only known way right now to accept a socket and
         // atomically set the CLOEXEC flag is to use the `accept4` syscall on
@@ -593,6 +670,278 @@ pub fn as_raw(&self) -> RawFd {
     }
 }
 
+/// The maximum number of datagrams sent or received by a single `sendmmsg` or `recvmmsg`
+/// call, which bounds the stack space used for their headers.
+#[cfg(target_os = "linux")]
+const MAX_BATCH: usize = 32;
+
+/// Room for the control messages of a single datagram: its packet info, TOS byte and
+/// timestamp or GRO/GSO segment size.
+#[cfg(target_os = "linux")]
+const CMSG_BUF_LEN: usize = 128;
+
+// From `linux/udp.h`.
+#[cfg(target_os = "linux")]
+const UDP_SEGMENT: c_int = 103;
+#[cfg(target_os = "linux")]
+const UDP_GRO: c_int = 104;
+
+/// A control message buffer, aligned for `cmsghdr`.
+#[cfg(target_os = "linux")]
+#[derive(Clone, Copy)]
+#[repr(C, align(8))]
+struct CmsgBuf([u8; CMSG_BUF_LEN]);
+
+#[cfg(target_os = "linux")]
+impl Socket {
+    pub fn send_many(&self, datagrams: &[Datagram<'_>]) -> io::Result<usize> {
+        let datagrams = &datagrams[..cmp::min(datagrams.len(), MAX_BATCH)];
+        if datagrams.is_empty() {
+            return Ok(0);
+        }
+
+        let mut names = [const { mem::MaybeUninit::<SocketAddrCRepr>::uninit() }; MAX_BATCH];
+        let mut iovs = [const { mem::MaybeUninit::<libc::iovec>::uninit() }; MAX_BATCH];
+        let mut cmsgs = [CmsgBuf([0; CMSG_BUF_LEN]); MAX_BATCH];
+        let mut msgs: [libc::mmsghdr; MAX_BATCH] = unsafe { mem::zeroed() };
+        // Only looked up if a control message depends on it.
+        let mut socket_is_ipv6 = None;
+
+        for (i, datagram) in datagrams.iter().enumerate() {
+            let msg = &mut msgs[i].msg_hdr;
+            let iov = iovs[i].write(libc::iovec {
+                iov_base: datagram.contents.as_ptr() as *mut c_void,
+                iov_len: datagram.contents.len(),
+            });
+            msg.msg_iov = iov;
+            msg.msg_iovlen = 1;
+
+            if let Some(destination) = &datagram.destination {
+                let (name, len) = socket_addr_to_c(destination);
+                msg.msg_name = (names[i].write(name) as *mut SocketAddrCRepr).cast();
+                msg.msg_namelen = len;
+            }
+
+            msg.msg_control = cmsgs[i].0.as_mut_ptr().cast();
+            if datagram.source.is_some() || datagram.tos.is_some() {
+                let is_ipv6 = match socket_is_ipv6 {
+                    Some(is_ipv6) => is_ipv6,
+                    None => *socket_is_ipv6.insert(self.domain()? == libc::AF_INET6),
+                };
+                match (datagram.source, is_ipv6) {
+                    (Some(IpAddr::V4(ip)), false) => {
+                        let info = libc::in_pktinfo {
+                            ipi_ifindex: 0,
+                            ipi_spec_dst: libc::in_addr { s_addr: u32::from_ne_bytes(ip.octets()) },
+                            ipi_addr: libc::in_addr { s_addr: 0 },
+                        };
+                        unsafe { push_cmsg(msg, libc::IPPROTO_IP, libc::IP_PKTINFO, info) };
+                    }
+                    (Some(IpAddr::V6(_)), false) => {
+                        return Err(io::const_error!(
+                            io::ErrorKind::InvalidInput,
+                            "cannot send from an IPv6 address on an IPv4 socket",
+                        ));
+                    }
+                    (Some(ip), true) => {
+                        let ip = match ip {
+                            IpAddr::V4(ip) => ip.to_ipv6_mapped(),
+                            IpAddr::V6(ip) => ip,
+                        };
+                        let info = libc::in6_pktinfo {
+                            ipi6_addr: libc::in6_addr { s6_addr: ip.octets() },
+                            ipi6_ifindex: 0,
+                        };
+                        unsafe { push_cmsg(msg, libc::IPPROTO_IPV6, libc::IPV6_PKTINFO, info) };
+                    }
+                    (None, _) => {}
+                }
+                if let Some(tos) = datagram.tos {
+                    let (level, ty) = if is_ipv6 {
+                        (libc::IPPROTO_IPV6, libc::IPV6_TCLASS)
+                    } else {
+                        (libc::IPPROTO_IP, libc::IP_TOS)
+                    };
+                    unsafe { push_cmsg(msg, level, ty, tos as c_int) };
+                }
+            }
+            if let Some(size) = datagram.segment_size {
+                unsafe { push_cmsg(msg, libc::SOL_UDP, UDP_SEGMENT, size) };
+            }
+            if msg.msg_controllen == 0 {
+                msg.msg_control = ptr::null_mut();
+            }
+        }
+
+        let n = cvt(unsafe {
+            libc::sendmmsg(self.as_raw_fd(), msgs.as_mut_ptr(), datagrams.len() as _, 0)
+        })?;
+        Ok(n as usize)
+    }
+
+    pub fn recv_many(
+        &self,
+        bufs: &mut [IoSliceMut<'_>],
+        meta: &mut [RecvMeta],
+    ) -> io::Result<usize> {
+        let len = cmp::min(cmp::min(bufs.len(), meta.len()), MAX_BATCH);
+        if len == 0 {
+            return Ok(0);
+        }
+
+        let mut names = [const { mem::MaybeUninit::<libc::sockaddr_storage>::uninit() }; MAX_BATCH];
+        let mut cmsgs = [CmsgBuf([0; CMSG_BUF_LEN]); MAX_BATCH];
+        let mut msgs: [libc::mmsghdr; MAX_BATCH] = unsafe { mem::zeroed() };
+
+        for (i, buf) in bufs[..len].iter_mut().enumerate() {
+            let msg = &mut msgs[i].msg_hdr;
+            msg.msg_name = names[i].as_mut_ptr().cast();
+            msg.msg_namelen = size_of::<libc::sockaddr_storage>() as libc::socklen_t;
+            // `IoSliceMut` is guaranteed to be ABI compatible with `iovec`.
+            msg.msg_iov = (buf as *mut IoSliceMut<'_>).cast();
+            msg.msg_iovlen = 1;
+            msg.msg_control = cmsgs[i].0.as_mut_ptr().cast();
+            msg.msg_controllen = CMSG_BUF_LEN as _;
+        }
+
+        // Block for the first datagram only, then return whatever else is queued.
+        let n = cvt(unsafe {
+            libc::recvmmsg(
+                self.as_raw_fd(),
+                msgs.as_mut_ptr(),
+                len as _,
+                libc::MSG_WAITFORONE as _,
+                ptr::null_mut(),
+            )
+        })? as usize;
+
+        // The datagrams are already dequeued, so report the ones before a datagram that
+        // cannot be decoded rather than losing them to the error.
+        for (i, (msg, meta)) in msgs[..n].iter().zip(meta.iter_mut()).enumerate() {
+            match unsafe { recv_meta(msg) } {
+                Ok(m) => *meta = m,
+                Err(e) if i == 0 => return Err(e),
+                Err(_) => return Ok(i),
+            }
+        }
+        Ok(n)
+    }
+
+    pub fn set_udp_gro(&self, gro: bool) -> io::Result<()> {
+        setsockopt(self, libc::SOL_UDP, UDP_GRO, gro as c_int)
+    }
+
+    pub fn udp_gro(&self) -> io::Result<bool> {
+        let raw: c_int = getsockopt(self, libc::SOL_UDP, UDP_GRO)?;
+        Ok(raw != 0)
+    }
+
+    pub fn set_recv_pktinfo(&self, enable: bool) -> io::Result<()> {
+        self.set_recv_option(libc::IP_PKTINFO, libc::IPV6_RECVPKTINFO, enable)
+    }
+
+    pub fn set_recv_tos(&self, enable: bool) -> io::Result<()> {
+        self.set_recv_option(libc::IP_RECVTOS, libc::IPV6_RECVTCLASS, enable)
+    }
+
+    pub fn set_recv_timestamps(&self, enable: bool) -> io::Result<()> {
+        setsockopt(self, libc::SOL_SOCKET, libc::SO_TIMESTAMPNS, enable as c_int)
+    }
+
+    /// Sets the option matching the socket's address family. IPv6 sockets which are not
+    /// restricted to IPv6 also receive IPv4 datagrams, so both options are set on them.
+    fn set_recv_option(&self, v4: c_int, v6: c_int, enable: bool) -> io::Result<()> {
+        if self.domain()? == libc::AF_INET6 {
+            setsockopt(self, libc::IPPROTO_IPV6, v6, enable as c_int)?;
+            let only_v6: c_int = getsockopt(self, libc::IPPROTO_IPV6, libc::IPV6_V6ONLY)?;
+            if only_v6 != 0 {
+                return Ok(());
+            }
+        }
+        setsockopt(self, libc::IPPROTO_IP, v4, enable as c_int)
+    }
+
+    fn domain(&self) -> io::Result<c_int> {
+        getsockopt(self, libc::SOL_SOCKET, libc::SO_DOMAIN)
+    }
+}
+
+/// Appends a control message to those of `msg`.
+///
+/// # Safety
+///
+/// `msg.msg_control` must point to a `CmsgBuf` with room for the message.
+#[cfg(target_os = "linux")]
+unsafe fn push_cmsg<T>(msg: &mut libc::msghdr, level: c_int, ty: c_int, value: T) {
+    let len = msg.msg_controllen as usize;
+    let space = unsafe { libc::CMSG_SPACE(size_of::<T>() as _) } as usize;
+    debug_assert!(len + space <= CMSG_BUF_LEN);
+    unsafe {
+        // Every message takes a multiple of the alignment of `cmsghdr`, so the new one is
+        // suitably aligned.
+        let cmsg = msg.msg_control.cast::<u8>().add(len).cast::<libc::cmsghdr>();
+        (*cmsg).cmsg_level = level;
+        (*cmsg).cmsg_type = ty;
+        (*cmsg).cmsg_len = libc::CMSG_LEN(size_of::<T>() as _) as _;
+        ptr::write_unaligned(libc::CMSG_DATA(cmsg).cast::<T>(), value);
+    }
+    msg.msg_controllen = (len + space) as _;
+}
+
+/// Decodes the address and control messages of a datagram received by `recvmmsg`.
+///
+/// # Safety
+///
+/// `msg` must have been filled in by a successful `recvmmsg` call.
+#[cfg(target_os = "linux")]
+unsafe fn recv_meta(msg: &libc::mmsghdr) -> io::Result<RecvMeta> {
+    let hdr = &msg.msg_hdr;
+    let addr = unsafe { socket_addr_from_c(hdr.msg_name.cast(), hdr.msg_namelen as usize)? };
+    let mut meta = RecvMeta {
+        len: msg.msg_len as usize,
+        addr,
+        truncated: hdr.msg_flags & libc::MSG_TRUNC != 0,
+        ..RecvMeta::new()
+    };
+
+    let mut cmsg = unsafe { libc::CMSG_FIRSTHDR(hdr) };
+    while !cmsg.is_null() {
+        unsafe {
+            let data = libc::CMSG_DATA(cmsg);
+            match ((*cmsg).cmsg_level, (*cmsg).cmsg_type) {
+                (libc::IPPROTO_IP, libc::IP_PKTINFO) => {
+                    let info: libc::in_pktinfo = ptr::read_unaligned(data.cast());
+                    let ip = Ipv4Addr::from(info.ipi_addr.s_addr.to_ne_bytes());
+                    meta.destination = Some(IpAddr::V4(ip));
+                    meta.interface = Some(info.ipi_ifindex as u32);
+                }
+                (libc::IPPROTO_IPV6, libc::IPV6_PKTINFO) => {
+                    let info: libc::in6_pktinfo = ptr::read_unaligned(data.cast());
+                    meta.destination = Some(IpAddr::V6(Ipv6Addr::from(info.ipi6_addr.s6_addr)));
+                    meta.interface = Some(info.ipi6_ifindex as u32);
+                }
+                // The kernel reports the IPv4 TOS as a single byte.
+                (libc::IPPROTO_IP, libc::IP_TOS) => meta.tos = Some(*data),
+                (libc::IPPROTO_IPV6, libc::IPV6_TCLASS) => {
+                    meta.tos = Some(ptr::read_unaligned(data.cast::<c_int>()) as u8);
+                }
+                (libc::SOL_SOCKET, libc::SCM_TIMESTAMPNS) => {
+                    let ts: libc::timespec = ptr::read_unaligned(data.cast());
+                    meta.timestamp = u64::try_from(ts.tv_sec).ok().and_then(|secs| {
+                        SystemTime::UNIX_EPOCH.checked_add(Duration::new(secs, ts.tv_nsec as u32))
+                    });
+                }
+                (libc::SOL_UDP, UDP_GRO) => {
+                    meta.segment_size = Some(ptr::read_unaligned(data.cast::<c_int>()) as usize);
+                }
+                _ => {}
+            }
+            cmsg = libc::CMSG_NXTHDR(hdr, cmsg);
+        }
+    }
+    Ok(meta)
+}
+
 impl AsInner<FileDesc> for Socket {
     #[inline]
     fn as_inner(&self) -> &FileDesc {
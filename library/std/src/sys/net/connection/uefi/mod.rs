@@ -1,6 +1,6 @@
 use crate::fmt;
 use crate::io::{self, BorrowedCursor, IoSlice, IoSliceMut};
-use crate::net::{Ipv4Addr, Ipv6Addr, Shutdown, SocketAddr};
+use crate::net::{Ipv4Addr, Ipv6Addr, Shutdown, SocketAddr, SocketBuilder};
 use crate::sys::unsupported;
 use crate::time::Duration;
 
@@ -15,6 +15,18 @@ pub fn connect_timeout(_: &SocketAddr, _: Duration) -> io::Result<TcpStream> {
         unsupported()
     }
 
+    pub fn connect_racing(
+        _: &[SocketAddr],
+        _: Duration,
+        _: Option<Duration>,
+    ) -> io::Result<TcpStream> {
+        unsupported()
+    }
+
+    pub fn connect_with(_: &SocketAddr, _: &SocketBuilder) -> io::Result<TcpStream> {
+        unsupported()
+    }
+
     pub fn set_read_timeout(&self, _: Option<Duration>) -> io::Result<()> {
         self.0
     }
@@ -125,6 +137,10 @@ pub fn bind(_: io::Result<&SocketAddr>) -> io::Result<TcpListener> {
         unsupported()
     }
 
+    pub fn bind_with(_: &SocketAddr, _: &SocketBuilder) -> io::Result<TcpListener> {
+        unsupported()
+    }
+
     pub fn socket_addr(&self) -> io::Result<SocketAddr> {
         self.0
     }
@@ -175,6 +191,10 @@ pub fn bind(_: io::Result<&SocketAddr>) -> io::Result<UdpSocket> {
         unsupported()
     }
 
+    pub fn bind_with(_: &SocketAddr, _: &SocketBuilder) -> io::Result<UdpSocket> {
+        unsupported()
+    }
+
     pub fn peer_addr(&self) -> io::Result<SocketAddr> {
         self.0
     }
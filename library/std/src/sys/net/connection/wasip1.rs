@@ -2,7 +2,7 @@
 
 use crate::fmt;
 use crate::io::{self, BorrowedCursor, IoSlice, IoSliceMut};
-use crate::net::{Ipv4Addr, Ipv6Addr, Shutdown, SocketAddr};
+use crate::net::{Ipv4Addr, Ipv6Addr, Shutdown, SocketAddr, SocketBuilder};
 use crate::os::wasi::io::{AsFd, AsRawFd, BorrowedFd, FromRawFd, IntoRawFd, RawFd};
 use crate::sys::fd::WasiFd;
 use crate::sys::{err2io, unsupported};
@@ -68,6 +68,18 @@ pub fn connect_timeout(_: &SocketAddr, _: Duration) -> io::Result<TcpStream> {
         unsupported()
     }
 
+    pub fn connect_racing(
+        _: &[SocketAddr],
+        _: Duration,
+        _: Option<Duration>,
+    ) -> io::Result<TcpStream> {
+        unsupported()
+    }
+
+    pub fn connect_with(_: &SocketAddr, _: &SocketBuilder) -> io::Result<TcpStream> {
+        unsupported()
+    }
+
     pub fn set_read_timeout(&self, _: Option<Duration>) -> io::Result<()> {
         unsupported()
     }
@@ -216,6 +228,10 @@ pub fn bind(_: io::Result<&SocketAddr>) -> io::Result<TcpListener> {
         unsupported()
     }
 
+    pub fn bind_with(_: &SocketAddr, _: &SocketBuilder) -> io::Result<TcpListener> {
+        unsupported()
+    }
+
     pub fn socket_addr(&self) -> io::Result<SocketAddr> {
         unsupported()
     }
@@ -320,6 +336,10 @@ pub fn bind(_: io::Result<&SocketAddr>) -> io::Result<UdpSocket> {
         unsupported()
     }
 
+    pub fn bind_with(_: &SocketAddr, _: &SocketBuilder) -> io::Result<UdpSocket> {
+        unsupported()
+    }
+
     pub fn peer_addr(&self) -> io::Result<SocketAddr> {
         unsupported()
     }
@@ -2,7 +2,7 @@
 use core::sync::atomic::{AtomicBool, AtomicU16, AtomicUsize, Ordering};
 
 use super::*;
-use crate::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+use crate::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketBuilder};
 use crate::os::xous::services;
 use crate::sync::Arc;
 use crate::{fmt, io};
@@ -37,6 +37,13 @@ pub fn bind(socketaddr: io::Result<&SocketAddr>) -> io::Result<TcpListener> {
         });
     }
 
+    pub fn bind_with(addr: &SocketAddr, opts: &SocketBuilder) -> io::Result<TcpListener> {
+        if *opts != SocketBuilder::new() {
+            unimpl!();
+        }
+        Self::bind(Ok(addr))
+    }
+
     /// This returns the raw fd of a Listener, so that it can also be used by the
     /// accept routine to replenish the Listener object after its handle has been converted into
     /// a TcpStream object.
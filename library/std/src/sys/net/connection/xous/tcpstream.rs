@@ -3,10 +3,12 @@
 use super::*;
 use crate::fmt;
 use crate::io::{self, BorrowedCursor, IoSlice, IoSliceMut};
-use crate::net::{IpAddr, Ipv4Addr, Shutdown, SocketAddr, SocketAddrV4, SocketAddrV6};
+use crate::net::{
+    IpAddr, Ipv4Addr, Shutdown, SocketAddr, SocketAddrV4, SocketAddrV6, SocketBuilder,
+};
 use crate::os::xous::services;
 use crate::sync::Arc;
-use crate::time::Duration;
+use crate::time::{Duration, Instant};
 
 macro_rules! unimpl {
     () => {
@@ -135,6 +137,44 @@ pub fn connect_timeout(addr: &SocketAddr, duration: Duration) -> io::Result<TcpS
         })
     }
 
+    pub fn connect_racing(
+        addrs: &[SocketAddr],
+        _attempt_delay: Duration,
+        timeout: Option<Duration>,
+    ) -> io::Result<TcpStream> {
+        // The net server blocks until an attempt completes, so attempts are made one after
+        // another, each with the time that is left of the timeout.
+        let deadline = timeout.and_then(|timeout| Instant::now().checked_add(timeout));
+        let mut last_err = None;
+        for addr in addrs {
+            let result = match (timeout, deadline) {
+                (_, Some(deadline)) => match deadline.checked_duration_since(Instant::now()) {
+                    // The net server takes whole milliseconds, and zero means no timeout.
+                    Some(remaining) if !remaining.is_zero() => {
+                        Self::connect_timeout(addr, remaining.max(Duration::from_millis(1)))
+                    }
+                    _ => Err(io::const_error!(io::ErrorKind::TimedOut, "connection timed out")),
+                },
+                (Some(timeout), None) => Self::connect_timeout(addr, timeout),
+                (None, None) => Self::connect(Ok(addr)),
+            };
+            match result {
+                Ok(stream) => return Ok(stream),
+                Err(e) => last_err = Some(e),
+            }
+        }
+        Err(last_err.unwrap_or_else(|| {
+            io::const_error!(io::ErrorKind::InvalidInput, "could not resolve to any addresses")
+        }))
+    }
+
+    pub fn connect_with(addr: &SocketAddr, opts: &SocketBuilder) -> io::Result<TcpStream> {
+        if *opts != SocketBuilder::new() {
+            unimpl!();
+        }
+        Self::connect(Ok(addr))
+    }
+
     pub fn set_read_timeout(&self, timeout: Option<Duration>) -> io::Result<()> {
         if let Some(to) = timeout {
             if to.is_zero() {
@@ -3,7 +3,7 @@
 
 use super::*;
 use crate::cell::Cell;
-use crate::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+use crate::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketBuilder};
 use crate::os::xous::services;
 use crate::sync::Arc;
 use crate::time::Duration;
@@ -101,6 +101,13 @@ pub fn bind(socketaddr: io::Result<&SocketAddr>) -> io::Result<UdpSocket> {
         Err(io::const_error!(io::ErrorKind::InvalidInput, "invalid response"))
     }
 
+    pub fn bind_with(addr: &SocketAddr, opts: &SocketBuilder) -> io::Result<UdpSocket> {
+        if *opts != SocketBuilder::new() {
+            unimpl!();
+        }
+        Self::bind(Ok(addr))
+    }
+
     pub fn peer_addr(&self) -> io::Result<SocketAddr> {
         match self.remote.get() {
             Some(dest) => Ok(dest),
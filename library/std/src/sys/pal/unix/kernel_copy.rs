@@ -57,6 +57,8 @@
 };
 use crate::mem::ManuallyDrop;
 use crate::net::TcpStream;
+#[cfg(target_os = "linux")]
+use crate::os::linux::io::SpliceMechanism;
 use crate::os::unix::fs::FileTypeExt;
 use crate::os::unix::io::{AsRawFd, FromRawFd, RawFd};
 use crate::os::unix::net::UnixStream;
@@ -78,6 +80,69 @@ pub(crate) fn copy_spec<R: Read + ?Sized, W: Write + ?Sized>(
     SpecCopy::copy(copier)
 }
 
+/// Moves up to `len` bytes from `reader` to `writer` using the cheapest kernel mechanism
+/// that works for this pair of file descriptors, falling back to a read-write loop.
+///
+/// Unlike `copy_spec` this is only reached through an explicit request by the caller,
+/// so zero-copy transfers are attempted even where `safe_kernel_copy` would reject them.
+#[cfg(target_os = "linux")]
+pub(crate) fn splice_fds(reader: RawFd, writer: RawFd, len: u64) -> Result<(u64, SpliceMechanism)> {
+    let input_meta = fd_to_meta(&reader);
+    let output_meta = fd_to_meta(&writer);
+
+    let mut written = 0u64;
+
+    let mut attempt = |mode: Option<SpliceMode>, written: &mut u64| -> Result<Option<u64>> {
+        let result = match mode {
+            None => copy_regular_files(reader, writer, len - *written),
+            Some(mode) => sendfile_splice(mode, reader, writer, len - *written),
+        };
+        match result {
+            CopyResult::Ended(bytes) => Ok(Some(*written + bytes)),
+            CopyResult::Error(e, _) => Err(e),
+            CopyResult::Fallback(bytes) => {
+                *written += bytes;
+                Ok(None)
+            }
+        }
+    };
+
+    if input_meta.copy_file_range_candidate(FdHandle::Input)
+        && output_meta.copy_file_range_candidate(FdHandle::Output)
+    {
+        if let Some(bytes) = attempt(None, &mut written)? {
+            return Ok((bytes, SpliceMechanism::CopyFileRange));
+        }
+    }
+
+    // Only start with zero-copy mechanisms if no bytes have been moved yet, otherwise the
+    // reported mechanism would not describe the whole transfer.
+    if written == 0 && input_meta.potential_sendfile_source() {
+        if let Some(bytes) = attempt(Some(SpliceMode::Sendfile), &mut written)? {
+            return Ok((bytes, SpliceMechanism::Sendfile));
+        }
+    }
+
+    if written == 0 && (input_meta.maybe_fifo() || output_meta.maybe_fifo()) {
+        if let Some(bytes) = attempt(Some(SpliceMode::Splice), &mut written)? {
+            return Ok((bytes, SpliceMechanism::Splice));
+        }
+    }
+
+    let reader = ManuallyDrop::new(unsafe { File::from_raw_fd(reader) });
+    let writer = ManuallyDrop::new(unsafe { File::from_raw_fd(writer) });
+    let bytes = generic_copy(&mut (&*reader).take(len - written), &mut &*writer)?;
+    Ok((written + bytes, SpliceMechanism::ReadWrite))
+}
+
+/// Duplicates up to `len` bytes from the pipe `reader` into the pipe `writer` without
+/// consuming them, using a single `tee(2)` call.
+#[cfg(target_os = "linux")]
+pub(crate) fn tee_pipes(reader: RawFd, writer: RawFd, len: usize) -> Result<usize> {
+    let ret = cvt(unsafe { libc::tee(reader, writer, len, 0) })?;
+    Ok(ret as usize)
+}
+
 /// This type represents either the inferred `FileType` of a `RawFd` based on the source
 /// type from which it was extracted or the actual metadata
 ///
@@ -6,7 +6,7 @@
 #[cfg(any(target_os = "solaris", target_os = "illumos", target_os = "nto",))]
 use crate::sys::weak::weak;
 use crate::sys::{os, stack_overflow};
-use crate::time::Duration;
+use crate::time::{Duration, Instant};
 use crate::{cmp, io, ptr};
 #[cfg(not(any(target_os = "l4re", target_os = "vxworks", target_os = "espidf")))]
 pub const DEFAULT_MIN_STACK_SIZE: usize = 2 * 1024 * 1024;
@@ -319,6 +319,32 @@ pub fn sleep(dur: Duration) {
         }
     }
 
+    // Sleeping until an absolute deadline on the clock of `Instant` does not
+    // oversleep by the time taken to compute the remaining duration, and
+    // needs no recomputation when the sleep is interrupted by a signal.
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    pub fn sleep_until(deadline: Instant) {
+        use crate::sys_common::IntoInner;
+
+        let timespec = deadline.into_inner().into_timespec();
+        if timespec.sleep_until(libc::CLOCK_MONOTONIC).is_err() {
+            // The deadline does not fit in a `timespec` of the C library, so
+            // it is far enough away that a relative sleep is just as good.
+            if let Some(delay) = deadline.checked_duration_since(Instant::now()) {
+                Self::sleep(delay);
+            }
+        }
+    }
+
+    #[cfg(not(any(target_os = "linux", target_os = "android")))]
+    pub fn sleep_until(deadline: Instant) {
+        let now = Instant::now();
+
+        if let Some(delay) = deadline.checked_duration_since(now) {
+            Self::sleep(delay);
+        }
+    }
+
     pub fn join(self) {
         let id = self.into_id();
         let ret = unsafe { libc::pthread_join(id, ptr::null_mut()) };
@@ -213,6 +213,24 @@ pub fn to_timespec(&self) -> Option<libc::timespec> {
         })
     }
 
+    /// Sleeps until `clock` reaches this time, resuming the sleep when it is
+    /// interrupted by a signal.
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    pub(crate) fn sleep_until(&self, clock: libc::clockid_t) -> io::Result<()> {
+        let Some(ts) = self.to_timespec() else {
+            return Err(io::const_error!(io::ErrorKind::InvalidInput, "deadline out of range"));
+        };
+        loop {
+            let flags = libc::TIMER_ABSTIME;
+            // clock_nanosleep returns the error instead of setting errno.
+            match unsafe { libc::clock_nanosleep(clock, flags, &ts, crate::ptr::null_mut()) } {
+                0 => return Ok(()),
+                libc::EINTR => {}
+                err => return Err(io::Error::from_raw_os_error(err)),
+            }
+        }
+    }
+
     // On QNX Neutrino, the maximum timespec for e.g. pthread_cond_timedwait
     // is 2^64 nanoseconds
     #[cfg(target_os = "nto")]
@@ -291,6 +309,11 @@ pub fn now() -> Instant {
         Instant { t: Timespec::now(clock_id) }
     }
 
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    pub(crate) fn into_timespec(self) -> Timespec {
+        self.t
+    }
+
     pub fn checked_sub_instant(&self, other: &Instant) -> Option<Duration> {
         self.t.sub_timespec(&other.t).ok()
     }
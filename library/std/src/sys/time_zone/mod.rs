@@ -0,0 +1,11 @@
+cfg_if::cfg_if! {
+    // Android keeps its zone data in a combined file rather than in TZif
+    // files under `/usr/share/zoneinfo`.
+    if #[cfg(all(unix, not(target_os = "android")))] {
+        mod tzif;
+        pub use tzif::local;
+    } else {
+        mod unsupported;
+        pub use unsupported::local;
+    }
+}
@@ -0,0 +1,54 @@
+//! Local time zone lookup on systems that ship the time zone database as
+//! TZif files, following the conventions of the C library.
+
+use crate::ffi::OsStr;
+use crate::os::unix::ffi::OsStrExt;
+use crate::path::Path;
+use crate::time::TimeZone;
+use crate::{env, fs, io, str};
+
+const LOCALTIME: &str = "/etc/localtime";
+const ZONEINFO: &str = "/usr/share/zoneinfo";
+
+pub fn local() -> io::Result<TimeZone> {
+    match env::var_os("TZ") {
+        Some(tz) => from_tz_var(tz.as_bytes()),
+        None => match fs::read(LOCALTIME) {
+            Ok(data) => TimeZone::from_tzif(&data),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(TimeZone::UTC),
+            Err(e) => Err(e),
+        },
+    }
+}
+
+/// Resolves the value of `TZ`: a file if it starts with `:` or names one,
+/// and otherwise a POSIX `TZ` string.
+fn from_tz_var(tz: &[u8]) -> io::Result<TimeZone> {
+    if tz.is_empty() {
+        return Ok(TimeZone::UTC);
+    }
+    let (tz, file_only) = match tz.strip_prefix(b":") {
+        Some(name) => (name, true),
+        None => (tz, false),
+    };
+    let path = if tz.starts_with(b"/") {
+        Path::new(OsStr::from_bytes(tz)).to_path_buf()
+    } else {
+        // Like the C library, refuse names that could escape the database.
+        if tz.split(|&b| b == b'/').any(|c| c == b"..") {
+            return Err(io::const_error!(io::ErrorKind::InvalidInput, "invalid time zone name"));
+        }
+        let dir = env::var_os("TZDIR").unwrap_or_else(|| ZONEINFO.into());
+        Path::new(&dir).join(OsStr::from_bytes(tz))
+    };
+    match fs::read(&path) {
+        Ok(data) => TimeZone::from_tzif(&data),
+        Err(e) if !file_only && e.kind() == io::ErrorKind::NotFound => {
+            let tz = str::from_utf8(tz).map_err(|_| {
+                io::const_error!(io::ErrorKind::InvalidInput, "invalid POSIX TZ string")
+            })?;
+            TimeZone::from_posix_tz(tz)
+        }
+        Err(e) => Err(e),
+    }
+}
@@ -0,0 +1,6 @@
+use crate::io;
+use crate::time::TimeZone;
+
+pub fn local() -> io::Result<TimeZone> {
+    Err(io::Error::UNSUPPORTED_PLATFORM)
+}
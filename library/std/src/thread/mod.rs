@@ -897,8 +897,12 @@ pub fn sleep(dur: Duration) {
 ///
 /// # Platform-specific behavior
 ///
-/// This function uses [`sleep`] internally, see its platform-specific behavior.
+/// On Linux and Android this function calls `clock_nanosleep` with an
+/// absolute deadline on the clock of [`Instant`], so a sleep interrupted by a
+/// signal resumes without drifting. Other platforms use [`sleep`] internally,
+/// see its platform-specific behavior.
 ///
+/// Note that this behavior may change in the future.
 ///
 /// # Examples
 ///
@@ -960,11 +964,7 @@ pub fn sleep(dur: Duration) {
 /// ```
 #[unstable(feature = "thread_sleep_until", issue = "113752")]
 pub fn sleep_until(deadline: Instant) {
-    let now = Instant::now();
-
-    if let Some(delay) = deadline.checked_duration_since(now) {
-        sleep(delay);
-    }
+    imp::Thread::sleep_until(deadline)
 }
 
 /// Used to ensure that `park` and `park_timeout` do not unwind, as that can
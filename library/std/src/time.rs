@@ -36,12 +36,22 @@
 #[stable(feature = "duration_checked_float", since = "1.66.0")]
 pub use core::time::TryFromFloatSecsError;
 
+#[unstable(feature = "civil_time", issue = "none")]
+pub use self::civil::{
+    ComponentRangeError, Date, DateTime, OffsetDateTime, ParseDateTimeError, Time, UtcOffset,
+    Weekday,
+};
+#[unstable(feature = "civil_time", issue = "none")]
+pub use self::zone::TimeZone;
 use crate::error::Error;
 use crate::fmt;
 use crate::ops::{Add, AddAssign, Sub, SubAssign};
 use crate::sys::time;
 use crate::sys_common::{FromInner, IntoInner};
 
+mod civil;
+mod zone;
+
 /// A measurement of a monotonically nondecreasing clock.
 /// Opaque and useful only with [`Duration`].
 ///
@@ -709,6 +719,12 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
     }
 }
 
+impl IntoInner<time::Instant> for Instant {
+    fn into_inner(self) -> time::Instant {
+        self.0
+    }
+}
+
 impl FromInner<time::SystemTime> for SystemTime {
     fn from_inner(time: time::SystemTime) -> SystemTime {
         SystemTime(time)
@@ -0,0 +1,925 @@
+//! Civil (calendar) dates and times in the proleptic Gregorian calendar.
+
+use crate::error::Error;
+use crate::hash::{Hash, Hasher};
+use crate::str::FromStr;
+use crate::time::{Duration, SystemTime, TimeZone, UNIX_EPOCH};
+use crate::{cmp, fmt, io};
+
+const SECS_PER_DAY: i64 = 86_400;
+const NANOS_PER_SEC: u32 = 1_000_000_000;
+
+/// Days from 0000-03-01 to 1970-01-01.
+const UNIX_EPOCH_DAY_OFFSET: i64 = 719_468;
+/// Days in a 400 year cycle of the Gregorian calendar.
+const DAYS_PER_ERA: i64 = 146_097;
+
+/// Returns the number of days between 1970-01-01 and the given date.
+///
+/// This is the `days_from_civil` algorithm from Howard Hinnant's
+/// "chrono-Compatible Low-Level Date Algorithms", counting years from March
+/// so that the leap day is the last day of the year.
+pub(super) const fn days_from_civil(year: i64, month: u8, day: u8) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month_from_march = (month as i64 + 9) % 12;
+    let day_of_year = (153 * month_from_march + 2) / 5 + day as i64 - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * DAYS_PER_ERA + day_of_era - UNIX_EPOCH_DAY_OFFSET
+}
+
+/// The inverse of [`days_from_civil`].
+pub(super) const fn civil_from_days(days: i64) -> (i64, u8, u8) {
+    let days = days + UNIX_EPOCH_DAY_OFFSET;
+    let era = days.div_euclid(DAYS_PER_ERA);
+    let day_of_era = days - era * DAYS_PER_ERA;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_from_march = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * month_from_march + 2) / 5 + 1) as u8;
+    let month = if month_from_march < 10 { month_from_march + 3 } else { month_from_march - 9 };
+    let year = year_of_era + era * 400 + (month <= 2) as i64;
+    (year, month as u8, day)
+}
+
+pub(super) const fn is_leap_year(year: i64) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+pub(super) const fn days_in_month(year: i64, month: u8) -> u8 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Splits a `SystemTime` into whole seconds since the Unix epoch, rounded
+/// towards negative infinity, and the remaining nanoseconds.
+pub(super) fn unix_parts(time: SystemTime) -> (i64, u32) {
+    match time.duration_since(UNIX_EPOCH) {
+        Ok(d) => (i64::try_from(d.as_secs()).unwrap_or(i64::MAX), d.subsec_nanos()),
+        Err(e) => {
+            let d = e.duration();
+            let secs = i64::try_from(d.as_secs()).unwrap_or(i64::MAX);
+            match d.subsec_nanos() {
+                0 => (-secs, 0),
+                nanos => (-secs - 1, NANOS_PER_SEC - nanos),
+            }
+        }
+    }
+}
+
+/// A day of the week.
+#[unstable(feature = "civil_time", issue = "none")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Weekday {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+impl Weekday {
+    const ALL: [Weekday; 7] = [
+        Weekday::Monday,
+        Weekday::Tuesday,
+        Weekday::Wednesday,
+        Weekday::Thursday,
+        Weekday::Friday,
+        Weekday::Saturday,
+        Weekday::Sunday,
+    ];
+
+    /// Returns the day of the week as a number, counting Monday as 1 and
+    /// Sunday as 7, as in ISO 8601.
+    #[unstable(feature = "civil_time", issue = "none")]
+    #[must_use]
+    pub const fn number_from_monday(self) -> u8 {
+        self as u8 + 1
+    }
+
+    /// Returns the following day of the week.
+    #[unstable(feature = "civil_time", issue = "none")]
+    #[must_use]
+    pub const fn succ(self) -> Weekday {
+        Self::ALL[(self as usize + 1) % 7]
+    }
+
+    /// Returns the preceding day of the week.
+    #[unstable(feature = "civil_time", issue = "none")]
+    #[must_use]
+    pub const fn pred(self) -> Weekday {
+        Self::ALL[(self as usize + 6) % 7]
+    }
+}
+
+/// A calendar date in the proleptic Gregorian calendar, without a time zone.
+///
+/// Years from -9999 to 9999 are supported. Year 0 is the year before 1 AD,
+/// as in ISO 8601.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(civil_time)]
+/// use std::time::{Date, Weekday};
+///
+/// let date = Date::new(2024, 2, 29).unwrap();
+/// assert_eq!(date.weekday(), Weekday::Thursday);
+/// assert_eq!(date.ordinal(), 60);
+/// assert_eq!(date.to_string(), "2024-02-29");
+/// assert!(Date::new(2023, 2, 29).is_err());
+/// ```
+#[unstable(feature = "civil_time", issue = "none")]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Date {
+    // Field order matters for the derived comparisons.
+    year: i32,
+    month: u8,
+    day: u8,
+}
+
+impl Date {
+    /// The earliest supported date, -9999-01-01.
+    #[unstable(feature = "civil_time", issue = "none")]
+    pub const MIN: Date = Date { year: -9999, month: 1, day: 1 };
+
+    /// The latest supported date, 9999-12-31.
+    #[unstable(feature = "civil_time", issue = "none")]
+    pub const MAX: Date = Date { year: 9999, month: 12, day: 31 };
+
+    /// Creates a date from a year, a month from 1 to 12 and a day of the month
+    /// starting at 1.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the year is outside of the supported range or the
+    /// month or day do not exist in the calendar.
+    #[unstable(feature = "civil_time", issue = "none")]
+    pub const fn new(year: i32, month: u8, day: u8) -> Result<Date, ComponentRangeError> {
+        if year < Self::MIN.year || year > Self::MAX.year {
+            return Err(ComponentRangeError { component: "year" });
+        }
+        if month < 1 || month > 12 {
+            return Err(ComponentRangeError { component: "month" });
+        }
+        if day < 1 || day > days_in_month(year as i64, month) {
+            return Err(ComponentRangeError { component: "day" });
+        }
+        Ok(Date { year, month, day })
+    }
+
+    /// Creates a date from the number of days since 1970-01-01.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the resulting date is outside of the supported range.
+    #[unstable(feature = "civil_time", issue = "none")]
+    pub const fn from_unix_days(days: i64) -> Result<Date, ComponentRangeError> {
+        if days < Self::MIN.unix_days() || days > Self::MAX.unix_days() {
+            return Err(ComponentRangeError { component: "year" });
+        }
+        let (year, month, day) = civil_from_days(days);
+        Ok(Date { year: year as i32, month, day })
+    }
+
+    /// Returns the number of days since 1970-01-01, negative for earlier dates.
+    #[unstable(feature = "civil_time", issue = "none")]
+    #[must_use]
+    pub const fn unix_days(self) -> i64 {
+        days_from_civil(self.year as i64, self.month, self.day)
+    }
+
+    /// Returns the year.
+    #[unstable(feature = "civil_time", issue = "none")]
+    #[must_use]
+    pub const fn year(self) -> i32 {
+        self.year
+    }
+
+    /// Returns the month, from 1 to 12.
+    #[unstable(feature = "civil_time", issue = "none")]
+    #[must_use]
+    pub const fn month(self) -> u8 {
+        self.month
+    }
+
+    /// Returns the day of the month, starting at 1.
+    #[unstable(feature = "civil_time", issue = "none")]
+    #[must_use]
+    pub const fn day(self) -> u8 {
+        self.day
+    }
+
+    /// Returns the day of the year, from 1 to 366.
+    #[unstable(feature = "civil_time", issue = "none")]
+    #[must_use]
+    pub const fn ordinal(self) -> u16 {
+        (self.unix_days() - days_from_civil(self.year as i64, 1, 1) + 1) as u16
+    }
+
+    /// Returns the day of the week.
+    #[unstable(feature = "civil_time", issue = "none")]
+    #[must_use]
+    pub const fn weekday(self) -> Weekday {
+        // 1970-01-01 was a Thursday.
+        Weekday::ALL[(self.unix_days() + 3).rem_euclid(7) as usize]
+    }
+
+    /// Returns `true` if the date falls in a leap year.
+    #[unstable(feature = "civil_time", issue = "none")]
+    #[must_use]
+    pub const fn is_leap_year(self) -> bool {
+        is_leap_year(self.year as i64)
+    }
+
+    /// Returns the number of days in the month of this date.
+    #[unstable(feature = "civil_time", issue = "none")]
+    #[must_use]
+    pub const fn days_in_month(self) -> u8 {
+        days_in_month(self.year as i64, self.month)
+    }
+
+    /// Adds a number of days, which may be negative, returning `None` if the
+    /// result is outside of the supported range.
+    #[unstable(feature = "civil_time", issue = "none")]
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub const fn checked_add_days(self, days: i64) -> Option<Date> {
+        match self.unix_days().checked_add(days) {
+            Some(days) => match Date::from_unix_days(days) {
+                Ok(date) => Some(date),
+                Err(_) => None,
+            },
+            None => None,
+        }
+    }
+}
+
+/// A time of day, without a date or time zone.
+///
+/// Leap seconds are not represented: the second is always below 60. When
+/// parsing, a leap second such as `23:59:60` is read as `23:59:59.999999999`,
+/// the last representable moment before the following second.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(civil_time)]
+/// use std::time::Time;
+///
+/// let time = Time::new(13, 5, 9, 250_000_000).unwrap();
+/// assert_eq!(time.to_string(), "13:05:09.250");
+/// assert_eq!(format!("{time:.0}"), "13:05:09");
+/// assert_eq!("13:05:09.25".parse(), Ok(time));
+/// ```
+#[unstable(feature = "civil_time", issue = "none")]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Time {
+    // Field order matters for the derived comparisons.
+    hour: u8,
+    minute: u8,
+    second: u8,
+    nanosecond: u32,
+}
+
+impl Time {
+    /// The start of the day, 00:00:00.
+    #[unstable(feature = "civil_time", issue = "none")]
+    pub const MIDNIGHT: Time = Time { hour: 0, minute: 0, second: 0, nanosecond: 0 };
+
+    /// Creates a time of day from its components.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the hour is above 23, the minute or second is
+    /// above 59, or the nanosecond is above 999,999,999.
+    #[unstable(feature = "civil_time", issue = "none")]
+    pub const fn new(
+        hour: u8,
+        minute: u8,
+        second: u8,
+        nanosecond: u32,
+    ) -> Result<Time, ComponentRangeError> {
+        if hour > 23 {
+            return Err(ComponentRangeError { component: "hour" });
+        }
+        if minute > 59 {
+            return Err(ComponentRangeError { component: "minute" });
+        }
+        if second > 59 {
+            return Err(ComponentRangeError { component: "second" });
+        }
+        if nanosecond >= NANOS_PER_SEC {
+            return Err(ComponentRangeError { component: "nanosecond" });
+        }
+        Ok(Time { hour, minute, second, nanosecond })
+    }
+
+    const fn from_seconds_of_day(secs: u32, nanosecond: u32) -> Time {
+        Time {
+            hour: (secs / 3600) as u8,
+            minute: (secs / 60 % 60) as u8,
+            second: (secs % 60) as u8,
+            nanosecond,
+        }
+    }
+
+    const fn seconds_of_day(self) -> u32 {
+        self.hour as u32 * 3600 + self.minute as u32 * 60 + self.second as u32
+    }
+
+    /// Returns the hour, from 0 to 23.
+    #[unstable(feature = "civil_time", issue = "none")]
+    #[must_use]
+    pub const fn hour(self) -> u8 {
+        self.hour
+    }
+
+    /// Returns the minute, from 0 to 59.
+    #[unstable(feature = "civil_time", issue = "none")]
+    #[must_use]
+    pub const fn minute(self) -> u8 {
+        self.minute
+    }
+
+    /// Returns the second, from 0 to 59.
+    #[unstable(feature = "civil_time", issue = "none")]
+    #[must_use]
+    pub const fn second(self) -> u8 {
+        self.second
+    }
+
+    /// Returns the fraction of the second in nanoseconds.
+    #[unstable(feature = "civil_time", issue = "none")]
+    #[must_use]
+    pub const fn nanosecond(self) -> u32 {
+        self.nanosecond
+    }
+}
+
+/// A calendar date and time of day, without a time zone.
+#[unstable(feature = "civil_time", issue = "none")]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DateTime {
+    date: Date,
+    time: Time,
+}
+
+impl DateTime {
+    /// Combines a date and a time of day.
+    #[unstable(feature = "civil_time", issue = "none")]
+    #[must_use]
+    pub const fn new(date: Date, time: Time) -> DateTime {
+        DateTime { date, time }
+    }
+
+    /// Returns the date.
+    #[unstable(feature = "civil_time", issue = "none")]
+    #[must_use]
+    pub const fn date(self) -> Date {
+        self.date
+    }
+
+    /// Returns the time of day.
+    #[unstable(feature = "civil_time", issue = "none")]
+    #[must_use]
+    pub const fn time(self) -> Time {
+        self.time
+    }
+
+    /// Interprets this date and time as local time at the given offset from UTC.
+    #[unstable(feature = "civil_time", issue = "none")]
+    #[must_use]
+    pub const fn with_offset(self, offset: UtcOffset) -> OffsetDateTime {
+        OffsetDateTime { datetime: self, offset }
+    }
+
+    /// Seconds since 1970-01-01 00:00:00, ignoring the fraction.
+    const fn unix_seconds(self) -> i64 {
+        self.date.unix_days() * SECS_PER_DAY + self.time.seconds_of_day() as i64
+    }
+
+    fn from_unix_seconds(secs: i64, nanosecond: u32) -> Result<DateTime, ComponentRangeError> {
+        let date = Date::from_unix_days(secs.div_euclid(SECS_PER_DAY))?;
+        let time = Time::from_seconds_of_day(secs.rem_euclid(SECS_PER_DAY) as u32, nanosecond);
+        Ok(DateTime { date, time })
+    }
+}
+
+/// A fixed offset from UTC, east of Greenwich being positive.
+///
+/// Offsets are less than 24 hours in either direction.
+#[unstable(feature = "civil_time", issue = "none")]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct UtcOffset {
+    seconds: i32,
+}
+
+impl UtcOffset {
+    /// The offset of UTC itself.
+    #[unstable(feature = "civil_time", issue = "none")]
+    pub const UTC: UtcOffset = UtcOffset { seconds: 0 };
+
+    /// Creates an offset from a number of seconds east of UTC.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the offset is 24 hours or more in either direction.
+    #[unstable(feature = "civil_time", issue = "none")]
+    pub const fn from_seconds(seconds: i32) -> Result<UtcOffset, ComponentRangeError> {
+        if seconds <= -(SECS_PER_DAY as i32) || seconds >= SECS_PER_DAY as i32 {
+            return Err(ComponentRangeError { component: "offset" });
+        }
+        Ok(UtcOffset { seconds })
+    }
+
+    /// Returns the offset in seconds east of UTC.
+    #[unstable(feature = "civil_time", issue = "none")]
+    #[must_use]
+    pub const fn as_seconds(self) -> i32 {
+        self.seconds
+    }
+
+    /// Returns `true` if this is the offset of UTC.
+    #[unstable(feature = "civil_time", issue = "none")]
+    #[must_use]
+    pub const fn is_utc(self) -> bool {
+        self.seconds == 0
+    }
+}
+
+/// A date and time of day at a fixed offset from UTC, identifying an instant.
+///
+/// This is the value of an [RFC 3339] timestamp, which is what its [`Display`]
+/// and [`FromStr`] implementations produce and accept. Comparison, equality and
+/// hashing consider only the instant, so two values at different offsets can be
+/// equal.
+///
+/// [RFC 3339]: https://www.rfc-editor.org/rfc/rfc3339
+/// [`Display`]: fmt::Display
+///
+/// # Examples
+///
+/// ```
+/// #![feature(civil_time)]
+/// use std::time::{Duration, OffsetDateTime, UNIX_EPOCH, UtcOffset};
+///
+/// let t = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+/// let utc = OffsetDateTime::from_system_time(t, UtcOffset::UTC).unwrap();
+/// assert_eq!(utc.to_string(), "2023-11-14T22:13:20Z");
+///
+/// let tokyo = utc.to_offset(UtcOffset::from_seconds(9 * 3600).unwrap()).unwrap();
+/// assert_eq!(tokyo.to_string(), "2023-11-15T07:13:20+09:00");
+/// assert_eq!(tokyo, utc);
+///
+/// let parsed: OffsetDateTime = "2023-11-15T07:13:20+09:00".parse().unwrap();
+/// assert_eq!(parsed.to_system_time(), Some(t));
+/// ```
+#[unstable(feature = "civil_time", issue = "none")]
+#[derive(Clone, Copy)]
+pub struct OffsetDateTime {
+    datetime: DateTime,
+    offset: UtcOffset,
+}
+
+impl OffsetDateTime {
+    /// Combines a local date and time with its offset from UTC.
+    #[unstable(feature = "civil_time", issue = "none")]
+    #[must_use]
+    pub const fn new(datetime: DateTime, offset: UtcOffset) -> OffsetDateTime {
+        OffsetDateTime { datetime, offset }
+    }
+
+    /// Returns the current time in UTC.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the system clock is set outside of the years supported by
+    /// [`Date`].
+    #[unstable(feature = "civil_time", issue = "none")]
+    #[must_use]
+    pub fn now_utc() -> OffsetDateTime {
+        OffsetDateTime::from_system_time(SystemTime::now(), UtcOffset::UTC)
+            .expect("system clock out of range")
+    }
+
+    /// Returns the current time in the local time zone, as given by
+    /// [`TimeZone::local`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the local time zone can not be determined.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the system clock is set outside of the years supported by
+    /// [`Date`].
+    #[unstable(feature = "civil_time", issue = "none")]
+    pub fn now_local() -> io::Result<OffsetDateTime> {
+        let zone = TimeZone::local()?;
+        Ok(zone.to_local(SystemTime::now()).expect("system clock out of range"))
+    }
+
+    /// Converts a `SystemTime` to the date and time at the given offset.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the local date is outside of the supported range.
+    #[unstable(feature = "civil_time", issue = "none")]
+    pub fn from_system_time(
+        time: SystemTime,
+        offset: UtcOffset,
+    ) -> Result<OffsetDateTime, ComponentRangeError> {
+        let (secs, nanos) = unix_parts(time);
+        let local = secs.checked_add(offset.seconds as i64);
+        let local = local.ok_or(ComponentRangeError { component: "year" })?;
+        Ok(OffsetDateTime { datetime: DateTime::from_unix_seconds(local, nanos)?, offset })
+    }
+
+    /// Converts this instant to a `SystemTime`, returning `None` if the
+    /// platform's `SystemTime` cannot represent it.
+    #[unstable(feature = "civil_time", issue = "none")]
+    #[must_use]
+    pub fn to_system_time(&self) -> Option<SystemTime> {
+        let secs = self.unix_timestamp();
+        let nanos = Duration::from_nanos(self.datetime.time.nanosecond as u64);
+        if secs >= 0 {
+            UNIX_EPOCH.checked_add(Duration::from_secs(secs as u64))?.checked_add(nanos)
+        } else {
+            UNIX_EPOCH.checked_sub(Duration::from_secs(secs.unsigned_abs()))?.checked_add(nanos)
+        }
+    }
+
+    /// Returns the same instant at a different offset from UTC.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the local date at the new offset is outside of the
+    /// supported range.
+    #[unstable(feature = "civil_time", issue = "none")]
+    pub fn to_offset(&self, offset: UtcOffset) -> Result<OffsetDateTime, ComponentRangeError> {
+        let local = self.unix_timestamp() + offset.seconds as i64;
+        let datetime = DateTime::from_unix_seconds(local, self.datetime.time.nanosecond)?;
+        Ok(OffsetDateTime { datetime, offset })
+    }
+
+    /// Returns the number of whole seconds since 1970-01-01 00:00:00 UTC,
+    /// ignoring the fraction of the second.
+    #[unstable(feature = "civil_time", issue = "none")]
+    #[must_use]
+    pub const fn unix_timestamp(&self) -> i64 {
+        self.datetime.unix_seconds() - self.offset.seconds as i64
+    }
+
+    /// Returns the local date and time.
+    #[unstable(feature = "civil_time", issue = "none")]
+    #[must_use]
+    pub const fn datetime(&self) -> DateTime {
+        self.datetime
+    }
+
+    /// Returns the local date.
+    #[unstable(feature = "civil_time", issue = "none")]
+    #[must_use]
+    pub const fn date(&self) -> Date {
+        self.datetime.date
+    }
+
+    /// Returns the local time of day.
+    #[unstable(feature = "civil_time", issue = "none")]
+    #[must_use]
+    pub const fn time(&self) -> Time {
+        self.datetime.time
+    }
+
+    /// Returns the offset from UTC.
+    #[unstable(feature = "civil_time", issue = "none")]
+    #[must_use]
+    pub const fn offset(&self) -> UtcOffset {
+        self.offset
+    }
+
+    fn instant(&self) -> (i64, u32) {
+        (self.unix_timestamp(), self.datetime.time.nanosecond)
+    }
+}
+
+#[unstable(feature = "civil_time", issue = "none")]
+impl PartialEq for OffsetDateTime {
+    fn eq(&self, other: &Self) -> bool {
+        self.instant() == other.instant()
+    }
+}
+
+#[unstable(feature = "civil_time", issue = "none")]
+impl Eq for OffsetDateTime {}
+
+#[unstable(feature = "civil_time", issue = "none")]
+impl PartialOrd for OffsetDateTime {
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[unstable(feature = "civil_time", issue = "none")]
+impl Ord for OffsetDateTime {
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        self.instant().cmp(&other.instant())
+    }
+}
+
+#[unstable(feature = "civil_time", issue = "none")]
+impl Hash for OffsetDateTime {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.instant().hash(state);
+    }
+}
+
+/// An error returned when a date or time component is out of range.
+#[unstable(feature = "civil_time", issue = "none")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ComponentRangeError {
+    component: &'static str,
+}
+
+#[unstable(feature = "civil_time", issue = "none")]
+impl fmt::Display for ComponentRangeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} out of range", self.component)
+    }
+}
+
+#[unstable(feature = "civil_time", issue = "none")]
+impl Error for ComponentRangeError {}
+
+/// An error returned when parsing a date or time fails.
+///
+/// This error is used as the error type for the [`FromStr`] implementations
+/// of [`Date`], [`Time`], [`DateTime`], [`UtcOffset`] and [`OffsetDateTime`].
+#[unstable(feature = "civil_time", issue = "none")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParseDateTimeError {
+    kind: ParseErrorKind,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum ParseErrorKind {
+    Syntax,
+    Range(ComponentRangeError),
+    Trailing,
+}
+
+impl From<ComponentRangeError> for ParseDateTimeError {
+    fn from(e: ComponentRangeError) -> Self {
+        ParseDateTimeError { kind: ParseErrorKind::Range(e) }
+    }
+}
+
+#[unstable(feature = "civil_time", issue = "none")]
+impl fmt::Display for ParseDateTimeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            ParseErrorKind::Syntax => f.write_str("invalid date and time syntax"),
+            ParseErrorKind::Range(e) => e.fmt(f),
+            ParseErrorKind::Trailing => f.write_str("unexpected characters after date and time"),
+        }
+    }
+}
+
+#[unstable(feature = "civil_time", issue = "none")]
+impl Error for ParseDateTimeError {}
+
+const SYNTAX: ParseDateTimeError = ParseDateTimeError { kind: ParseErrorKind::Syntax };
+
+/// A cursor over the ISO 8601 extended format used by RFC 3339.
+struct Parser<'a> {
+    input: &'a [u8],
+}
+
+impl<'a> Parser<'a> {
+    fn eat(&mut self, accept: impl Fn(u8) -> bool) -> Option<u8> {
+        let (&b, rest) = self.input.split_first()?;
+        if !accept(b) {
+            return None;
+        }
+        self.input = rest;
+        Some(b)
+    }
+
+    fn expect(&mut self, byte: u8) -> Result<(), ParseDateTimeError> {
+        self.eat(|b| b == byte).map(drop).ok_or(SYNTAX)
+    }
+
+    /// Parses exactly `n` decimal digits.
+    fn digits(&mut self, n: usize) -> Result<u32, ParseDateTimeError> {
+        let mut value = 0;
+        for _ in 0..n {
+            let d = self.eat(|b| b.is_ascii_digit()).ok_or(SYNTAX)?;
+            value = value * 10 + (d - b'0') as u32;
+        }
+        Ok(value)
+    }
+
+    fn finish<T>(self, value: T) -> Result<T, ParseDateTimeError> {
+        match self.input {
+            [] => Ok(value),
+            _ => Err(ParseDateTimeError { kind: ParseErrorKind::Trailing }),
+        }
+    }
+
+    /// `[+-]YYYY-MM-DD`
+    fn date(&mut self) -> Result<Date, ParseDateTimeError> {
+        let negative = self.eat(|b| b == b'-' || b == b'+') == Some(b'-');
+        let year = self.digits(4)? as i32;
+        self.expect(b'-')?;
+        let month = self.digits(2)? as u8;
+        self.expect(b'-')?;
+        let day = self.digits(2)? as u8;
+        Ok(Date::new(if negative { -year } else { year }, month, day)?)
+    }
+
+    /// `HH:MM:SS[.fraction]`, where digits beyond nanoseconds are truncated.
+    ///
+    /// A leap second, `SS` = 60, is clamped to 59.999999999.
+    fn time(&mut self) -> Result<Time, ParseDateTimeError> {
+        let hour = self.digits(2)? as u8;
+        self.expect(b':')?;
+        let minute = self.digits(2)? as u8;
+        self.expect(b':')?;
+        let second = self.digits(2)? as u8;
+        let mut nanosecond = 0;
+        if self.eat(|b| b == b'.').is_some() {
+            let mut scale = NANOS_PER_SEC;
+            let mut any = false;
+            while let Some(d) = self.eat(|b| b.is_ascii_digit()) {
+                scale /= 10;
+                nanosecond += (d - b'0') as u32 * scale;
+                any = true;
+            }
+            if !any {
+                return Err(SYNTAX);
+            }
+        }
+        if second == 60 {
+            return Ok(Time::new(hour, minute, 59, NANOS_PER_SEC - 1)?);
+        }
+        Ok(Time::new(hour, minute, second, nanosecond)?)
+    }
+
+    /// `DATE('T'|'t'|' ')TIME`
+    fn datetime(&mut self) -> Result<DateTime, ParseDateTimeError> {
+        let date = self.date()?;
+        self.eat(|b| matches!(b, b'T' | b't' | b' ')).ok_or(SYNTAX)?;
+        Ok(DateTime::new(date, self.time()?))
+    }
+
+    /// `'Z'|'z'|('+'|'-')HH:MM[:SS]`
+    fn offset(&mut self) -> Result<UtcOffset, ParseDateTimeError> {
+        if self.eat(|b| b == b'Z' || b == b'z').is_some() {
+            return Ok(UtcOffset::UTC);
+        }
+        let negative = self.eat(|b| b == b'+' || b == b'-').ok_or(SYNTAX)? == b'-';
+        let hours = self.digits(2)?;
+        self.expect(b':')?;
+        let minutes = self.digits(2)?;
+        let seconds = if self.eat(|b| b == b':').is_some() { self.digits(2)? } else { 0 };
+        if minutes > 59 || seconds > 59 {
+            return Err(ComponentRangeError { component: "offset" }.into());
+        }
+        let total = (hours * 3600 + minutes * 60 + seconds) as i32;
+        Ok(UtcOffset::from_seconds(if negative { -total } else { total })?)
+    }
+}
+
+/// Formats the fraction of a second: as many digits as the precision asks
+/// for, up to nine, or otherwise the shortest of 0, 3, 6 or 9 digits that
+/// represents it exactly.
+fn fmt_fraction(f: &mut fmt::Formatter<'_>, nanosecond: u32) -> fmt::Result {
+    let digits = match f.precision() {
+        Some(p) => p.min(9),
+        None if nanosecond == 0 => 0,
+        None if nanosecond.is_multiple_of(1_000_000) => 3,
+        None if nanosecond.is_multiple_of(1_000) => 6,
+        None => 9,
+    };
+    if digits == 0 {
+        return Ok(());
+    }
+    let value = nanosecond / 10u32.pow(9 - digits as u32);
+    write!(f, ".{value:0digits$}")
+}
+
+#[unstable(feature = "civil_time", issue = "none")]
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.year < 0 {
+            f.write_str("-")?;
+        }
+        write!(f, "{:04}-{:02}-{:02}", self.year.unsigned_abs(), self.month, self.day)
+    }
+}
+
+/// Formats a time as `HH:MM:SS` followed by the fraction of the second.
+///
+/// The precision, if given, sets the number of fractional digits.
+#[unstable(feature = "civil_time", issue = "none")]
+impl fmt::Display for Time {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02}:{:02}:{:02}", self.hour, self.minute, self.second)?;
+        fmt_fraction(f, self.nanosecond)
+    }
+}
+
+#[unstable(feature = "civil_time", issue = "none")]
+impl fmt::Display for DateTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}T", self.date)?;
+        fmt::Display::fmt(&self.time, f)
+    }
+}
+
+/// Formats an offset as `+HH:MM`, adding `:SS` if it is not a whole number
+/// of minutes.
+#[unstable(feature = "civil_time", issue = "none")]
+impl fmt::Display for UtcOffset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sign = if self.seconds < 0 { '-' } else { '+' };
+        let abs = self.seconds.unsigned_abs();
+        write!(f, "{sign}{:02}:{:02}", abs / 3600, abs / 60 % 60)?;
+        if !abs.is_multiple_of(60) {
+            write!(f, ":{:02}", abs % 60)?;
+        }
+        Ok(())
+    }
+}
+
+/// Formats as an RFC 3339 timestamp, writing `Z` for UTC.
+///
+/// The precision, if given, sets the number of fractional digits.
+#[unstable(feature = "civil_time", issue = "none")]
+impl fmt::Display for OffsetDateTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.datetime, f)?;
+        if self.offset.is_utc() { f.write_str("Z") } else { write!(f, "{}", self.offset) }
+    }
+}
+
+macro_rules! debug_as_display {
+    ($($t:ty)*) => {$(
+        #[unstable(feature = "civil_time", issue = "none")]
+        impl fmt::Debug for $t {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Display::fmt(self, f)
+            }
+        }
+    )*};
+}
+
+debug_as_display! { Date Time DateTime UtcOffset OffsetDateTime }
+
+macro_rules! from_str {
+    ($($t:ty => $method:ident,)*) => {$(
+        #[unstable(feature = "civil_time", issue = "none")]
+        impl FromStr for $t {
+            type Err = ParseDateTimeError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let mut parser = Parser { input: s.as_bytes() };
+                let value = parser.$method()?;
+                parser.finish(value)
+            }
+        }
+    )*};
+}
+
+from_str! {
+    Date => date,
+    Time => time,
+    DateTime => datetime,
+    UtcOffset => offset,
+}
+
+/// Parses an RFC 3339 timestamp such as `2024-05-01T12:30:00.5+02:00`.
+///
+/// Lowercase `t` and `z` and a space instead of `T` are accepted, as RFC 3339
+/// allows. Fractional seconds are truncated to nanoseconds. A leap second,
+/// such as in `2016-12-31T23:59:60Z`, is read as 59.999999999 seconds, since
+/// [`Time`] cannot represent it.
+#[unstable(feature = "civil_time", issue = "none")]
+impl FromStr for OffsetDateTime {
+    type Err = ParseDateTimeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser { input: s.as_bytes() };
+        let datetime = parser.datetime()?;
+        let offset = parser.offset()?;
+        parser.finish(OffsetDateTime { datetime, offset })
+    }
+}
@@ -0,0 +1,509 @@
+//! Time zones described by TZif files and POSIX `TZ` strings.
+
+use super::civil::{civil_from_days, days_from_civil, days_in_month, is_leap_year, unix_parts};
+use crate::time::{ComponentRangeError, OffsetDateTime, SystemTime, UtcOffset};
+use crate::{io, str, sys};
+
+const INVALID_TZIF: io::Error = io::const_error!(io::ErrorKind::InvalidData, "invalid TZif data");
+const INVALID_TZ_STRING: io::Error =
+    io::const_error!(io::ErrorKind::InvalidInput, "invalid POSIX TZ string");
+
+/// Rules are evaluated for times within about 35 million years of the epoch,
+/// which keeps the calendar arithmetic far away from overflow.
+const MAX_RULE_SECS: i64 = 1 << 50;
+
+/// A time zone: the offsets from UTC observed in a region over time.
+///
+/// Zones are loaded from [TZif] data, the compiled form of the IANA time
+/// zone database found under `/usr/share/zoneinfo` on most Unix systems, or
+/// from a POSIX `TZ` string such as `CET-1CEST,M3.5.0,M10.5.0/3`. Leap second
+/// records in TZif data are ignored, so zones from the `right/` hierarchy
+/// are off by the accumulated leap seconds.
+///
+/// [TZif]: https://www.rfc-editor.org/rfc/rfc8536
+///
+/// # Examples
+///
+/// ```
+/// #![feature(civil_time)]
+/// use std::time::{Duration, TimeZone, UNIX_EPOCH};
+///
+/// let zone = TimeZone::from_posix_tz("CET-1CEST,M3.5.0,M10.5.0/3").unwrap();
+/// let summer = UNIX_EPOCH + Duration::from_secs(1_720_000_000);
+/// assert_eq!(zone.abbreviation_at(summer), "CEST");
+/// assert_eq!(zone.to_local(summer).unwrap().to_string(), "2024-07-03T11:46:40+02:00");
+/// ```
+#[unstable(feature = "civil_time", issue = "none")]
+#[derive(Clone, Debug)]
+pub struct TimeZone {
+    /// Transition times in seconds since the epoch, strictly increasing.
+    transitions: Vec<i64>,
+    /// The index into `types` that applies from each transition on.
+    transition_types: Vec<u8>,
+    /// Local time types; the first one applies before the first transition.
+    types: Vec<LocalTimeType>,
+    /// The rule for times after the last transition.
+    rule: Option<PosixTz>,
+}
+
+#[derive(Clone, Debug)]
+struct LocalTimeType {
+    offset: UtcOffset,
+    is_dst: bool,
+    abbreviation: Box<str>,
+}
+
+/// The rule of a POSIX `TZ` string: a standard time and optionally a
+/// daylight saving time with the dates it starts and ends on.
+#[derive(Clone, Debug)]
+struct PosixTz {
+    std: LocalTimeType,
+    dst: Option<(LocalTimeType, Transition, Transition)>,
+}
+
+/// A yearly transition, at a time of day in seconds, which may be negative
+/// or exceed a day, local to the time type in effect before it.
+#[derive(Clone, Copy, Debug)]
+struct Transition {
+    date: RuleDate,
+    time: i32,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum RuleDate {
+    /// `Jn`: day 1 to 365, never counting February 29.
+    Julian1(u16),
+    /// `n`: day 0 to 365, counting February 29.
+    Julian0(u16),
+    /// `Mm.w.d`: weekday `d` (0 is Sunday) of week `w` of month `m`, where
+    /// week 5 means the last such weekday.
+    MonthWeekDay { month: u8, week: u8, weekday: u8 },
+}
+
+impl RuleDate {
+    /// Returns the date in the given year as days since the epoch.
+    fn unix_days(self, year: i64) -> i64 {
+        let jan1 = days_from_civil(year, 1, 1);
+        match self {
+            RuleDate::Julian1(n) => {
+                let leap_day = (is_leap_year(year) && n >= 60) as i64;
+                jan1 + n as i64 - 1 + leap_day
+            }
+            RuleDate::Julian0(n) => jan1 + n as i64,
+            RuleDate::MonthWeekDay { month, week, weekday } => {
+                let first = days_from_civil(year, month, 1);
+                // 1970-01-01 was a Thursday.
+                let first_weekday = (first + 4).rem_euclid(7);
+                let mut day = first + (weekday as i64 - first_weekday).rem_euclid(7);
+                day += (week as i64 - 1) * 7;
+                while day >= first + days_in_month(year, month) as i64 {
+                    day -= 7;
+                }
+                day
+            }
+        }
+    }
+}
+
+impl Transition {
+    /// Returns the transition in the given year in seconds since the epoch.
+    fn unix_seconds(self, year: i64, before: UtcOffset) -> i64 {
+        self.date.unix_days(year) * 86_400 + self.time as i64 - before.as_seconds() as i64
+    }
+}
+
+impl PosixTz {
+    fn find(&self, secs: i64) -> &LocalTimeType {
+        let Some((dst, start, end)) = &self.dst else { return &self.std };
+        let secs = secs.clamp(-MAX_RULE_SECS, MAX_RULE_SECS);
+        let local_days = (secs + self.std.offset.as_seconds() as i64).div_euclid(86_400);
+        let year = civil_from_days(local_days).0;
+        let start = start.unix_seconds(year, self.std.offset);
+        let end = end.unix_seconds(year, dst.offset);
+        let in_dst = if start <= end {
+            start <= secs && secs < end
+        } else {
+            // Southern hemisphere: daylight saving time spans the new year.
+            !(end <= secs && secs < start)
+        };
+        if in_dst { dst } else { &self.std }
+    }
+}
+
+impl TimeZone {
+    /// Coordinated Universal Time.
+    #[unstable(feature = "civil_time", issue = "none")]
+    pub const UTC: TimeZone = TimeZone {
+        transitions: Vec::new(),
+        transition_types: Vec::new(),
+        types: Vec::new(),
+        rule: None,
+    };
+
+    /// Returns the local time zone of the system.
+    ///
+    /// On Unix, this follows the C library: the `TZ` environment variable, if
+    /// set, names a zone under `/usr/share/zoneinfo` (or `TZDIR`), an absolute
+    /// path to a TZif file, or a POSIX `TZ` string. Otherwise `/etc/localtime`
+    /// is used, and UTC if it does not exist.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the zone data cannot be read or is invalid, or if
+    /// the platform has no notion of a local time zone that is supported.
+    #[unstable(feature = "civil_time", issue = "none")]
+    pub fn local() -> io::Result<TimeZone> {
+        sys::time_zone::local()
+    }
+
+    /// Parses a zone from TZif data, such as the contents of
+    /// `/etc/localtime`.
+    ///
+    /// All versions of the format are supported. For version 2 and later the
+    /// 64-bit data and the footer rule for times after the last transition
+    /// are used.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of kind [`InvalidData`](io::ErrorKind::InvalidData)
+    /// if the data is malformed.
+    #[unstable(feature = "civil_time", issue = "none")]
+    pub fn from_tzif(data: &[u8]) -> io::Result<TimeZone> {
+        parse_tzif(data).ok_or(INVALID_TZIF)
+    }
+
+    /// Parses a zone from a POSIX `TZ` string, such as `EST5EDT` or
+    /// `AEST-10AEDT,M10.1.0,M4.1.0/3`.
+    ///
+    /// Offsets in the string are west of Greenwich, so `EST5` is UTC-05:00.
+    /// The time zone database extensions of [RFC 8536] are accepted: rule
+    /// times may be negative or as large as 167 hours.
+    ///
+    /// [RFC 8536]: https://www.rfc-editor.org/rfc/rfc8536#section-3.3.1
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of kind [`InvalidInput`](io::ErrorKind::InvalidInput)
+    /// if the string is malformed.
+    #[unstable(feature = "civil_time", issue = "none")]
+    pub fn from_posix_tz(tz: &str) -> io::Result<TimeZone> {
+        let rule = parse_posix_tz(tz.as_bytes()).ok_or(INVALID_TZ_STRING)?;
+        Ok(TimeZone { rule: Some(rule), ..TimeZone::UTC })
+    }
+
+    fn find(&self, time: SystemTime) -> Option<&LocalTimeType> {
+        let (secs, _) = unix_parts(time);
+        let after_transitions = self.transitions.last().is_none_or(|&last| secs >= last);
+        if let (Some(rule), true) = (&self.rule, after_transitions) {
+            return Some(rule.find(secs));
+        }
+        let i = self.transitions.partition_point(|&t| t <= secs);
+        match i.checked_sub(1) {
+            Some(i) => self.types.get(self.transition_types[i] as usize),
+            None => self.types.first(),
+        }
+    }
+
+    /// Returns the offset from UTC in effect at the given time.
+    #[unstable(feature = "civil_time", issue = "none")]
+    #[must_use]
+    pub fn offset_at(&self, time: SystemTime) -> UtcOffset {
+        self.find(time).map_or(UtcOffset::UTC, |t| t.offset)
+    }
+
+    /// Returns the abbreviation of the local time in effect at the given
+    /// time, such as `CET` or `CEST`.
+    #[unstable(feature = "civil_time", issue = "none")]
+    #[must_use]
+    pub fn abbreviation_at(&self, time: SystemTime) -> &str {
+        self.find(time).map_or("UTC", |t| &t.abbreviation)
+    }
+
+    /// Returns `true` if daylight saving time is in effect at the given time.
+    #[unstable(feature = "civil_time", issue = "none")]
+    #[must_use]
+    pub fn is_dst_at(&self, time: SystemTime) -> bool {
+        self.find(time).is_some_and(|t| t.is_dst)
+    }
+
+    /// Converts a `SystemTime` to the local date and time in this zone.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the local date is outside of the range supported
+    /// by [`Date`](crate::time::Date).
+    #[unstable(feature = "civil_time", issue = "none")]
+    pub fn to_local(&self, time: SystemTime) -> Result<OffsetDateTime, ComponentRangeError> {
+        OffsetDateTime::from_system_time(time, self.offset_at(time))
+    }
+}
+
+/// A cursor over a byte slice that returns `None` once the input is exhausted
+/// or malformed.
+struct Reader<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, n: usize) -> Option<&'a [u8]> {
+        let (head, tail) = self.data.split_at_checked(n)?;
+        self.data = tail;
+        Some(head)
+    }
+
+    fn take_array<const N: usize>(&mut self) -> Option<[u8; N]> {
+        self.take(N)?.try_into().ok()
+    }
+
+    fn u32(&mut self) -> Option<u32> {
+        self.take_array().map(u32::from_be_bytes)
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.data.first().copied()
+    }
+
+    fn eat(&mut self, byte: u8) -> bool {
+        let found = self.peek() == Some(byte);
+        if found {
+            self.data = &self.data[1..];
+        }
+        found
+    }
+
+    /// Takes the longest prefix of bytes matching the predicate.
+    fn take_while(&mut self, f: impl Fn(u8) -> bool) -> &'a [u8] {
+        let len = self.data.iter().position(|&b| !f(b)).unwrap_or(self.data.len());
+        self.take(len).unwrap()
+    }
+
+    /// Parses a decimal number no greater than `max`.
+    fn number(&mut self, max: u32) -> Option<u32> {
+        let digits = self.take_while(|b| b.is_ascii_digit());
+        if digits.is_empty() || digits.len() > 3 {
+            return None;
+        }
+        let n = digits.iter().fold(0, |n, &d| n * 10 + (d - b'0') as u32);
+        (n <= max).then_some(n)
+    }
+}
+
+/// The counts from a TZif header, in the order they appear.
+struct Header {
+    version: u8,
+    isutcnt: usize,
+    isstdcnt: usize,
+    leapcnt: usize,
+    timecnt: usize,
+    typecnt: usize,
+    charcnt: usize,
+}
+
+impl Header {
+    fn parse(r: &mut Reader<'_>) -> Option<Header> {
+        if r.take(4)? != b"TZif" {
+            return None;
+        }
+        let version = r.take(1)?[0];
+        r.take(15)?;
+        let mut count = || r.u32().and_then(|n| usize::try_from(n).ok());
+        Some(Header {
+            version,
+            isutcnt: count()?,
+            isstdcnt: count()?,
+            leapcnt: count()?,
+            timecnt: count()?,
+            typecnt: count()?,
+            charcnt: count()?,
+        })
+    }
+
+    /// Returns the size of the data block that follows the header, with
+    /// transition times of `time_size` bytes.
+    fn data_len(&self, time_size: usize) -> Option<usize> {
+        [
+            self.timecnt.checked_mul(time_size + 1)?,
+            self.typecnt.checked_mul(6)?,
+            self.charcnt,
+            self.leapcnt.checked_mul(time_size + 4)?,
+            self.isstdcnt,
+            self.isutcnt,
+        ]
+        .into_iter()
+        .try_fold(0usize, |sum, n| sum.checked_add(n))
+    }
+}
+
+fn parse_tzif(data: &[u8]) -> Option<TimeZone> {
+    let mut r = Reader { data };
+    let mut header = Header::parse(&mut r)?;
+    let time_size = if header.version == 0 {
+        4
+    } else {
+        // Skip the 32-bit data and use the 64-bit data that follows it.
+        r.take(header.data_len(4)?)?;
+        header = Header::parse(&mut r)?;
+        8
+    };
+    if header.typecnt == 0 || header.charcnt == 0 {
+        return None;
+    }
+    let mut footer = r;
+    let mut r = Reader { data: footer.take(header.data_len(time_size)?)? };
+
+    let transitions = (0..header.timecnt)
+        .map(|_| {
+            let bytes = r.take(time_size)?;
+            Some(match *bytes {
+                [a, b, c, d] => i32::from_be_bytes([a, b, c, d]) as i64,
+                _ => i64::from_be_bytes(bytes.try_into().ok()?),
+            })
+        })
+        .collect::<Option<Vec<i64>>>()?;
+    if !transitions.is_sorted_by(|a, b| a < b) {
+        return None;
+    }
+    let transition_types = r.take(header.timecnt)?.to_vec();
+    if transition_types.iter().any(|&i| i as usize >= header.typecnt) {
+        return None;
+    }
+
+    let mut raw_types = Vec::with_capacity(header.typecnt);
+    for _ in 0..header.typecnt {
+        let offset = i32::from_be_bytes(r.take_array()?);
+        let [is_dst, abbreviation_index] = r.take_array()?;
+        raw_types.push((offset, is_dst, abbreviation_index as usize));
+    }
+    let chars = r.take(header.charcnt)?;
+    let types = raw_types
+        .into_iter()
+        .map(|(offset, is_dst, index)| {
+            let abbreviation = chars.get(index..)?;
+            let len = abbreviation.iter().position(|&b| b == 0)?;
+            Some(LocalTimeType {
+                offset: UtcOffset::from_seconds(offset).ok()?,
+                is_dst: match is_dst {
+                    0 => false,
+                    1 => true,
+                    _ => return None,
+                },
+                abbreviation: str::from_utf8(&abbreviation[..len]).ok()?.into(),
+            })
+        })
+        .collect::<Option<Vec<_>>>()?;
+    // Leap second records and the standard/wall and UT/local indicators
+    // remain; none of them affect local time.
+
+    let mut rule = None;
+    if header.version != 0 {
+        if !footer.eat(b'\n') {
+            return None;
+        }
+        let tz = footer.take_while(|b| b != b'\n');
+        if !footer.eat(b'\n') {
+            return None;
+        }
+        if !tz.is_empty() {
+            rule = Some(parse_posix_tz(tz)?);
+        }
+    }
+
+    Some(TimeZone { transitions, transition_types, types, rule })
+}
+
+fn parse_posix_tz(tz: &[u8]) -> Option<PosixTz> {
+    let mut r = Reader { data: tz };
+    let std_name = parse_abbreviation(&mut r)?;
+    let std_offset = parse_offset(&mut r)?;
+    let std = LocalTimeType { offset: std_offset, is_dst: false, abbreviation: std_name };
+    if r.data.is_empty() {
+        return Some(PosixTz { std, dst: None });
+    }
+
+    let dst_name = parse_abbreviation(&mut r)?;
+    let dst_offset = match r.peek() {
+        None | Some(b',') => UtcOffset::from_seconds(std_offset.as_seconds() + 3600).ok()?,
+        Some(_) => parse_offset(&mut r)?,
+    };
+    let dst = LocalTimeType { offset: dst_offset, is_dst: true, abbreviation: dst_name };
+    let (start, end) = if r.data.is_empty() {
+        // POSIX leaves the default rule to the implementation; use the
+        // current United States rule, as the time zone database does.
+        let start = RuleDate::MonthWeekDay { month: 3, week: 2, weekday: 0 };
+        let end = RuleDate::MonthWeekDay { month: 11, week: 1, weekday: 0 };
+        (Transition { date: start, time: 7200 }, Transition { date: end, time: 7200 })
+    } else {
+        if !r.eat(b',') {
+            return None;
+        }
+        let start = parse_transition(&mut r)?;
+        if !r.eat(b',') {
+            return None;
+        }
+        (start, parse_transition(&mut r)?)
+    };
+    r.data.is_empty().then_some(PosixTz { std, dst: Some((dst, start, end)) })
+}
+
+/// Parses an abbreviation: three or more letters, or three or more
+/// alphanumerics, `+` or `-` in angle brackets.
+fn parse_abbreviation(r: &mut Reader<'_>) -> Option<Box<str>> {
+    let name = if r.eat(b'<') {
+        let name = r.take_while(|b| b.is_ascii_alphanumeric() || b == b'+' || b == b'-');
+        if !r.eat(b'>') {
+            return None;
+        }
+        name
+    } else {
+        r.take_while(|b| b.is_ascii_alphabetic())
+    };
+    if name.len() < 3 {
+        return None;
+    }
+    // The accepted bytes are all ASCII.
+    Some(str::from_utf8(name).ok()?.into())
+}
+
+/// Parses `[+-]hh[:mm[:ss]]` with hours up to `max_hours`, returning seconds.
+fn parse_hms(r: &mut Reader<'_>, max_hours: u32) -> Option<i32> {
+    let negative = r.eat(b'-');
+    if !negative {
+        r.eat(b'+');
+    }
+    let mut secs = r.number(max_hours)? * 3600;
+    if r.eat(b':') {
+        secs += r.number(59)? * 60;
+        if r.eat(b':') {
+            secs += r.number(59)?;
+        }
+    }
+    let secs = secs as i32;
+    Some(if negative { -secs } else { secs })
+}
+
+/// Parses an offset, which POSIX gives as positive west of Greenwich.
+fn parse_offset(r: &mut Reader<'_>) -> Option<UtcOffset> {
+    UtcOffset::from_seconds(-parse_hms(r, 24)?).ok()
+}
+
+/// Parses `date[/time]`, the time defaulting to 02:00:00.
+fn parse_transition(r: &mut Reader<'_>) -> Option<Transition> {
+    let date = if r.eat(b'J') {
+        RuleDate::Julian1(r.number(365).filter(|&n| n >= 1)? as u16)
+    } else if r.eat(b'M') {
+        let month = r.number(12).filter(|&n| n >= 1)? as u8;
+        if !r.eat(b'.') {
+            return None;
+        }
+        let week = r.number(5).filter(|&n| n >= 1)? as u8;
+        if !r.eat(b'.') {
+            return None;
+        }
+        let weekday = r.number(6)? as u8;
+        RuleDate::MonthWeekDay { month, week, weekday }
+    } else {
+        RuleDate::Julian0(r.number(365)? as u16)
+    };
+    let time = if r.eat(b'/') { parse_hms(r, 167)? } else { 7200 };
+    Some(Transition { date, time })
+}
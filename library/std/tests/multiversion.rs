@@ -0,0 +1,86 @@
+#![feature(portable_simd, simd_multiversion)]
+// The unsafe code needed to call the versions is internal to the macro.
+#![forbid(unsafe_code)]
+
+use std::num::ParseIntError;
+use std::simd::num::SimdUint;
+use std::simd::{multiversion, u32x16};
+use std::thread;
+
+multiversion! {
+    #[targets(x86("avx2"), x86("sse4.1"), aarch64("sve"))]
+    /// Adds up the elements of `xs`, wrapping on overflow.
+    fn sum(xs: &[u32]) -> u32 {
+        let mut acc = u32x16::splat(0);
+        let mut chunks = xs.chunks_exact(16);
+        for chunk in &mut chunks {
+            acc += u32x16::from_slice(chunk);
+        }
+        chunks.remainder().iter().fold(acc.reduce_sum(), |a, &b| a.wrapping_add(b))
+    }
+}
+
+multiversion! {
+    #[targets(x86("avx2", "fma"))]
+    fn fill(out: &mut [u32], value: u32) {
+        out.fill(value);
+    }
+}
+
+multiversion! {
+    #[targets(aarch64("sve"))]
+    pub fn parse_doubled(s: &str) -> Result<u32, ParseIntError> {
+        let n: u32 = s.parse()?;
+        if n == 0 {
+            return Ok(1);
+        }
+        Ok(n * 2)
+    }
+}
+
+multiversion! {
+    #[targets(x86("sse4.1"), x86("sse2"),)]
+    fn fib(n: u64) -> u64 {
+        if n < 2 { n } else { fib(n - 1) + fib(n - 2) }
+    }
+}
+
+multiversion! {
+    #[targets(x86("avx2"))]
+    fn concat(a: String, b: String,) -> String {
+        a + &b
+    }
+}
+
+#[test]
+fn multiversion_simd() {
+    let xs: Vec<u32> = (0..1000).collect();
+    assert_eq!(sum(&xs), 499500);
+    assert_eq!(sum(&xs[..7]), 21);
+    assert_eq!(sum(&[]), 0);
+}
+
+#[test]
+fn multiversion_signatures() {
+    let mut out = [0; 5];
+    fill(&mut out, 7);
+    assert_eq!(out, [7; 5]);
+
+    assert_eq!(parse_doubled("21"), Ok(42));
+    assert_eq!(parse_doubled("0"), Ok(1));
+    assert!(parse_doubled("x").is_err());
+
+    assert_eq!(fib(20), 6765);
+
+    assert_eq!(concat("multi".to_string(), "version".to_string()), "multiversion");
+}
+
+#[test]
+fn multiversion_threads() {
+    let xs: Vec<u32> = (0..100).collect();
+    thread::scope(|s| {
+        for _ in 0..8 {
+            s.spawn(|| assert_eq!(sum(&xs), 4950));
+        }
+    });
+}
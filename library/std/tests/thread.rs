@@ -1,7 +1,9 @@
+#![feature(thread_sleep_until)]
+
 use std::cell::{Cell, RefCell};
 use std::sync::{Arc, Mutex};
 use std::thread;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 #[test]
 #[cfg_attr(any(target_os = "emscripten", target_os = "wasi"), ignore)] // no threads
@@ -17,6 +19,34 @@ fn sleep_very_long() {
     assert_eq!(*finished.lock().unwrap(), false);
 }
 
+#[test]
+fn sleep_until() {
+    let now = Instant::now();
+    let period = Duration::from_millis(100);
+    let deadline = now + period;
+    thread::sleep_until(deadline);
+
+    assert!(Instant::now() >= deadline);
+    // A deadline in the past returns immediately.
+    thread::sleep_until(now);
+}
+
+#[test]
+#[cfg_attr(any(target_os = "emscripten", target_os = "wasi"), ignore)] // no threads
+#[cfg_attr(miri, ignore)] // Miri does not like the thread leak
+fn sleep_until_very_late() {
+    let finished = Arc::new(Mutex::new(false));
+    let t_finished = finished.clone();
+    thread::spawn(move || {
+        let far =
+            (1..64).rev().find_map(|n| Instant::now().checked_add(Duration::from_secs(1 << n)));
+        thread::sleep_until(far.unwrap());
+        *t_finished.lock().unwrap() = true;
+    });
+    thread::sleep(Duration::from_millis(100));
+    assert_eq!(*finished.lock().unwrap(), false);
+}
+
 #[test]
 fn thread_local_containing_const_statements() {
     // This exercises the `const $init:block` cases of the thread_local macro.
@@ -1,7 +1,11 @@
+#![feature(civil_time)]
 #![feature(duration_constants)]
 
 use std::fmt::Debug;
-use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use std::time::{
+    Date, DateTime, Duration, Instant, OffsetDateTime, SystemTime, Time, TimeZone, UNIX_EPOCH,
+    UtcOffset, Weekday,
+};
 
 macro_rules! assert_almost_eq {
     ($a:expr, $b:expr) => {{
@@ -227,3 +231,287 @@ fn check<T: Eq + Copy + Debug>(start: Option<T>, op: impl Fn(&T, Duration) -> Op
     check(instant.checked_add(Duration::from_secs(100)), Instant::checked_sub);
     check(instant.checked_add(Duration::from_secs(i64::MAX as _)), Instant::checked_sub);
 }
+
+#[test]
+fn civil_date_unix_days() {
+    let epoch = Date::new(1970, 1, 1).unwrap();
+    assert_eq!(epoch.unix_days(), 0);
+    assert_eq!(epoch.weekday(), Weekday::Thursday);
+    assert_eq!(Date::from_unix_days(-1).unwrap(), Date::new(1969, 12, 31).unwrap());
+    assert_eq!(Date::from_unix_days(Date::MIN.unix_days()), Ok(Date::MIN));
+    assert_eq!(Date::from_unix_days(Date::MAX.unix_days()), Ok(Date::MAX));
+    assert!(Date::from_unix_days(Date::MIN.unix_days() - 1).is_err());
+    assert!(Date::from_unix_days(Date::MAX.unix_days() + 1).is_err());
+
+    // Walk a whole 400 year cycle, which covers every leap year rule.
+    let mut prev = Date::new(1999, 12, 31).unwrap();
+    for days in prev.unix_days() + 1..=Date::new(2400, 1, 1).unwrap().unix_days() {
+        let date = Date::from_unix_days(days).unwrap();
+        assert_eq!(date.unix_days(), days);
+        assert_eq!(date.weekday(), prev.weekday().succ());
+        if date.day() == 1 {
+            assert_eq!(prev.day(), prev.days_in_month());
+            assert_eq!(date.ordinal() == 1, date.month() == 1);
+        } else {
+            assert_eq!(
+                (date.year(), date.month(), date.day()),
+                (prev.year(), prev.month(), prev.day() + 1)
+            );
+            assert_eq!(date.ordinal(), prev.ordinal() + 1);
+        }
+        prev = date;
+    }
+}
+
+#[test]
+fn civil_date_components() {
+    assert!(Date::new(2000, 2, 29).unwrap().is_leap_year());
+    assert!(Date::new(1900, 2, 29).is_err());
+    assert!(Date::new(2023, 4, 31).is_err());
+    assert!(Date::new(2023, 0, 1).is_err());
+    assert!(Date::new(2023, 13, 1).is_err());
+    assert!(Date::new(10000, 1, 1).is_err());
+    assert_eq!(Date::new(2024, 12, 31).unwrap().ordinal(), 366);
+    assert_eq!(Date::new(2024, 3, 1).unwrap().checked_add_days(-1), Date::new(2024, 2, 29).ok());
+    assert_eq!(Date::MAX.checked_add_days(1), None);
+    assert_eq!(Weekday::Monday.pred(), Weekday::Sunday);
+    assert_eq!(Weekday::Sunday.number_from_monday(), 7);
+    assert_eq!(Date::new(-1, 3, 1).unwrap().to_string(), "-0001-03-01");
+    assert_eq!(Date::new(-1, 3, 1).unwrap().weekday(), Weekday::Monday);
+    assert_eq!(Date::new(1, 1, 1).unwrap().weekday(), Weekday::Monday);
+
+    assert!(Time::new(24, 0, 0, 0).is_err());
+    assert!(Time::new(23, 60, 0, 0).is_err());
+    assert!(Time::new(23, 59, 60, 0).is_err());
+    assert!(Time::new(23, 59, 59, 1_000_000_000).is_err());
+    assert!(UtcOffset::from_seconds(86_400).is_err());
+    assert_eq!(UtcOffset::from_seconds(-86_399).unwrap().to_string(), "-23:59:59");
+}
+
+#[test]
+fn civil_format() {
+    let date = Date::new(2024, 5, 1).unwrap();
+    let t = |nanos| DateTime::new(date, Time::new(8, 3, 9, nanos).unwrap());
+    assert_eq!(t(0).to_string(), "2024-05-01T08:03:09");
+    assert_eq!(t(500_000_000).to_string(), "2024-05-01T08:03:09.500");
+    assert_eq!(t(500_000).to_string(), "2024-05-01T08:03:09.000500");
+    assert_eq!(t(5).to_string(), "2024-05-01T08:03:09.000000005");
+    assert_eq!(format!("{:.2}", t(987_654_321)), "2024-05-01T08:03:09.98");
+    assert_eq!(format!("{:.0}", t(987_654_321)), "2024-05-01T08:03:09");
+    assert_eq!(format!("{:.12}", t(1)), "2024-05-01T08:03:09.000000001");
+
+    let offset = UtcOffset::from_seconds(-(5 * 3600 + 30 * 60)).unwrap();
+    assert_eq!(t(0).with_offset(offset).to_string(), "2024-05-01T08:03:09-05:30");
+    assert_eq!(t(0).with_offset(UtcOffset::UTC).to_string(), "2024-05-01T08:03:09Z");
+    assert_eq!(format!("{:?}", t(0).with_offset(UtcOffset::UTC)), "2024-05-01T08:03:09Z");
+}
+
+#[test]
+fn civil_parse() {
+    let parse = |s: &str| s.parse::<OffsetDateTime>();
+    for s in [
+        "2024-05-01T08:03:09Z",
+        "2024-05-01T08:03:09.5+01:00",
+        "2024-05-01T08:03:09.123456789-12:45",
+        "0000-01-01T00:00:00Z",
+        "-0044-03-15T12:00:00+00:30:15",
+    ] {
+        let dt = parse(s).unwrap();
+        assert_eq!(parse(&dt.to_string()).unwrap().to_string(), dt.to_string());
+    }
+    assert_eq!(parse("2024-05-01t08:03:09z"), parse("2024-05-01T08:03:09Z"));
+    assert_eq!(parse("2024-05-01 08:03:09+00:00"), parse("2024-05-01T08:03:09Z"));
+    assert_eq!(parse("2024-05-01T10:03:09+02:00"), parse("2024-05-01T08:03:09Z"));
+    assert_eq!(parse("2024-05-01T08:03:09.1234567891Z").unwrap().time().nanosecond(), 123_456_789);
+    assert_eq!("2024-05-01".parse(), Ok(Date::new(2024, 5, 1).unwrap()));
+    assert_eq!("23:59:59.999".parse(), Ok(Time::new(23, 59, 59, 999_000_000).unwrap()));
+    assert_eq!("-08:00".parse(), Ok(UtcOffset::from_seconds(-8 * 3600).unwrap()));
+
+    // Leap seconds are clamped to the end of the preceding second.
+    let leap = parse("2016-12-31T23:59:60Z").unwrap();
+    assert_eq!(leap.time(), Time::new(23, 59, 59, 999_999_999).unwrap());
+    assert!(leap < parse("2017-01-01T00:00:00Z").unwrap());
+    assert_eq!(parse("2016-12-31T23:59:60.5Z"), Ok(leap));
+    assert_eq!("08:59:60".parse(), Ok(Time::new(8, 59, 59, 999_999_999).unwrap()));
+
+    for s in [
+        "",
+        "2024-05-01",
+        "2024-05-01T08:03:09",
+        "2024-5-01T08:03:09Z",
+        "2024-05-01T08:03Z",
+        "2024-05-01T08:03:09.Z",
+        "2024-05-01T08:03:09+0100",
+        "2024-05-01T08:03:09Z ",
+        "2024-02-30T08:03:09Z",
+        "2024-05-01T24:00:00Z",
+        "2024-05-01T23:59:61Z",
+        "2024-05-01T08:03:09+24:00",
+        "2024-05-01T08:03:09+01:60",
+    ] {
+        assert!(parse(s).is_err(), "{s:?} should not parse");
+    }
+    assert_eq!(parse("2024-02-30T08:03:09Z").unwrap_err().to_string(), "day out of range");
+    assert_eq!(
+        parse("2024-05-01T08:03:09Zx").unwrap_err().to_string(),
+        "unexpected characters after date and time"
+    );
+}
+
+#[test]
+fn civil_system_time() {
+    let utc = |t| OffsetDateTime::from_system_time(t, UtcOffset::UTC).unwrap();
+    assert_eq!(utc(UNIX_EPOCH).to_string(), "1970-01-01T00:00:00Z");
+    let before = UNIX_EPOCH - Duration::from_millis(1500);
+    assert_eq!(utc(before).to_string(), "1969-12-31T23:59:58.500Z");
+    assert_eq!(utc(before).unix_timestamp(), -2);
+    assert_eq!(utc(before).to_system_time(), Some(before));
+
+    let t = UNIX_EPOCH + Duration::new(951_782_400, 7);
+    let dt = utc(t);
+    assert_eq!(dt.to_string(), "2000-02-29T00:00:00.000000007Z");
+    assert_eq!(dt.to_system_time(), Some(t));
+    let west = dt.to_offset(UtcOffset::from_seconds(-3600).unwrap()).unwrap();
+    assert_eq!(west.to_string(), "2000-02-28T23:00:00.000000007-01:00");
+    assert_eq!(west, dt);
+    assert!(west.datetime() < dt.datetime());
+    assert!(utc(t + Duration::from_nanos(1)) > west);
+
+    let now = SystemTime::now();
+    assert_eq!(utc(now).to_system_time(), Some(now));
+}
+
+/// Builds TZif data with the given version, transitions and
+/// `(offset, is_dst, abbreviation)` local time types.
+fn tzif(
+    version: u8,
+    transitions: &[(i64, u8)],
+    types: &[(i32, bool, &str)],
+    footer: &str,
+) -> Vec<u8> {
+    let mut chars = Vec::new();
+    let mut type_bytes = Vec::new();
+    for &(offset, is_dst, abbreviation) in types {
+        type_bytes.extend(offset.to_be_bytes());
+        type_bytes.extend([is_dst as u8, chars.len() as u8]);
+        chars.extend(abbreviation.bytes().chain([0]));
+    }
+    let block = |out: &mut Vec<u8>, wide: bool| {
+        out.extend(b"TZif");
+        out.push(version);
+        out.extend([0; 15]);
+        for n in [0, 0, 0, transitions.len(), types.len(), chars.len()] {
+            out.extend((n as u32).to_be_bytes());
+        }
+        for &(t, _) in transitions {
+            if wide { out.extend(t.to_be_bytes()) } else { out.extend((t as i32).to_be_bytes()) }
+        }
+        out.extend(transitions.iter().map(|&(_, i)| i));
+        out.extend(&type_bytes);
+        out.extend(&chars);
+    };
+    let mut out = Vec::new();
+    block(&mut out, false);
+    if version != 0 {
+        block(&mut out, true);
+        out.push(b'\n');
+        out.extend(footer.bytes());
+        out.push(b'\n');
+    }
+    out
+}
+
+#[test]
+fn time_zone_tzif() {
+    let at = |secs: i64| {
+        if secs >= 0 {
+            UNIX_EPOCH + Duration::from_secs(secs as u64)
+        } else {
+            UNIX_EPOCH - Duration::from_secs(secs.unsigned_abs())
+        }
+    };
+    let types = [(-17762, false, "LMT"), (-18000, false, "EST"), (-14400, true, "EDT")];
+    // 1918-03-31 07:00 UTC, then the spring and fall of 2007.
+    let transitions = [(-1633280400, 1), (1173596400, 2), (1194156000, 1)];
+    for version in [0, b'2', b'3'] {
+        let footer = "EST5EDT,M3.2.0,M11.1.0";
+        let zone = TimeZone::from_tzif(&tzif(version, &transitions, &types, footer)).unwrap();
+        assert_eq!(zone.offset_at(at(-1633280401)).as_seconds(), -17762);
+        assert_eq!(zone.abbreviation_at(at(-1633280400)), "EST");
+        assert_eq!(zone.abbreviation_at(at(1173596399)), "EST");
+        assert_eq!(zone.abbreviation_at(at(1173596400)), "EDT");
+        assert!(zone.is_dst_at(at(1180000000)));
+        assert_eq!(zone.abbreviation_at(at(1194156000)), "EST");
+        // After the last transition, only version 2 and later have a rule.
+        let summer = at(1720000000);
+        assert_eq!(zone.is_dst_at(summer), version != 0);
+        if version != 0 {
+            assert_eq!(zone.to_local(summer).unwrap().to_string(), "2024-07-03T05:46:40-04:00");
+        }
+    }
+
+    let zone = TimeZone::from_tzif(&tzif(b'2', &[], &[(3600, false, "CET")], "")).unwrap();
+    assert_eq!(zone.offset_at(SystemTime::now()).as_seconds(), 3600);
+
+    let valid = tzif(b'2', &transitions, &types, "EST5EDT,M3.2.0,M11.1.0");
+    for len in 0..valid.len() {
+        let err = TimeZone::from_tzif(&valid[..len]).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+    }
+    for invalid in [
+        tzif(b'2', &[(10, 1), (5, 0)], &types, ""),
+        tzif(b'2', &[(10, 3)], &types, ""),
+        tzif(b'2', &[], &[], ""),
+        tzif(b'2', &[], &[(86400, false, "BAD")], ""),
+        tzif(b'2', &[], &types, "EST5EDT,M3.2.0"),
+    ] {
+        assert!(TimeZone::from_tzif(&invalid).is_err());
+    }
+}
+
+#[test]
+fn time_zone_posix() {
+    let local = |zone: &TimeZone, s: &str| {
+        let t = s.parse::<OffsetDateTime>().unwrap().to_system_time().unwrap();
+        zone.to_local(t).unwrap().to_string()
+    };
+
+    let zone = TimeZone::from_posix_tz("EST5EDT").unwrap();
+    assert_eq!(local(&zone, "2024-03-10T06:59:59Z"), "2024-03-10T01:59:59-05:00");
+    assert_eq!(local(&zone, "2024-03-10T07:00:00Z"), "2024-03-10T03:00:00-04:00");
+    assert_eq!(local(&zone, "2024-11-03T05:59:59Z"), "2024-11-03T01:59:59-04:00");
+    assert_eq!(local(&zone, "2024-11-03T06:00:00Z"), "2024-11-03T01:00:00-05:00");
+
+    // Southern hemisphere, with daylight saving time over the new year.
+    let zone = TimeZone::from_posix_tz("AEST-10AEDT,M10.1.0,M4.1.0/3").unwrap();
+    assert_eq!(local(&zone, "2024-01-15T00:00:00Z"), "2024-01-15T11:00:00+11:00");
+    assert_eq!(local(&zone, "2024-04-06T15:59:59Z"), "2024-04-07T02:59:59+11:00");
+    assert_eq!(local(&zone, "2024-04-06T16:00:00Z"), "2024-04-07T02:00:00+10:00");
+    assert_eq!(local(&zone, "2024-10-05T15:59:59Z"), "2024-10-06T01:59:59+10:00");
+    assert_eq!(local(&zone, "2024-10-05T16:00:00Z"), "2024-10-06T03:00:00+11:00");
+    assert_eq!(local(&zone, "2024-12-31T13:00:00Z"), "2025-01-01T00:00:00+11:00");
+
+    let zone = TimeZone::from_posix_tz("<+0330>-3:30").unwrap();
+    assert_eq!(zone.abbreviation_at(UNIX_EPOCH), "+0330");
+    assert_eq!(local(&zone, "2024-01-01T00:00:00Z"), "2024-01-01T03:30:00+03:30");
+
+    // Julian days, with and without February 29: daylight saving time is
+    // off from the 60th day, counting from zero, to March 1.
+    let zone = TimeZone::from_posix_tz("XST0XDT-1,J60/0,59/0").unwrap();
+    assert_eq!(local(&zone, "2024-02-28T22:59:59Z"), "2024-02-28T23:59:59+01:00");
+    assert_eq!(local(&zone, "2024-02-29T12:00:00Z"), "2024-02-29T12:00:00Z");
+    assert_eq!(local(&zone, "2024-03-01T00:00:00Z"), "2024-03-01T01:00:00+01:00");
+    assert_eq!(local(&zone, "2023-02-28T22:59:59Z"), "2023-02-28T23:59:59+01:00");
+    assert_eq!(local(&zone, "2023-02-28T23:00:00Z"), "2023-02-28T23:00:00Z");
+    assert_eq!(local(&zone, "2023-03-01T00:00:00Z"), "2023-03-01T01:00:00+01:00");
+
+    // Permanent daylight saving time, as the time zone database writes it.
+    let zone = TimeZone::from_posix_tz("EST5EDT,0/0,J365/25").unwrap();
+    assert!(zone.is_dst_at(SystemTime::now()));
+
+    for tz in ["", "ES5", "EST", "EST5EDT,M3.2.0", "EST5EDT,M13.1.0,M11.1.0", "EST25", "<AB>1"] {
+        let err = TimeZone::from_posix_tz(tz).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput, "{tz:?}");
+    }
+    assert_eq!(TimeZone::UTC.abbreviation_at(SystemTime::now()), "UTC");
+}
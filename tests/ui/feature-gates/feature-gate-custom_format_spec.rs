@@ -0,0 +1,6 @@
+// Custom format specs are only parsed with `#![feature(custom_format_spec)]`.
+
+fn main() {
+    format!("{:iso8601}", "3");
+    //~^ ERROR: unknown format trait `iso8601`
+}
@@ -0,0 +1,39 @@
+//@ build-fail
+// Checks that a custom format spec rejected by the type's parser is a compile error.
+
+#![feature(custom_format_spec)]
+
+use std::fmt::{self, CustomFormat, CustomSpec, ParseCustomSpec};
+
+struct Temperature(f64);
+
+enum Scale {
+    Celsius,
+    Fahrenheit,
+}
+
+impl CustomFormat for Temperature {
+    type Spec = Scale;
+
+    fn fmt_custom(&self, scale: &Scale, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match scale {
+            Scale::Celsius => write!(f, "{}°C", self.0),
+            Scale::Fahrenheit => write!(f, "{}°F", self.0 * 9.0 / 5.0 + 32.0),
+        }
+    }
+}
+
+impl<S: CustomSpec> ParseCustomSpec<S> for Temperature {
+    const PARSED: Scale = match S::SPEC.as_bytes() {
+        b"celsius" => Scale::Celsius,
+        b"fahrenheit" => Scale::Fahrenheit,
+        _ => panic!("unknown temperature scale"),
+        //~^ ERROR evaluation of `<Temperature as ParseCustomSpec<main::Spec>>::PARSED` failed
+    };
+}
+
+fn main() {
+    let t = Temperature(100.0);
+    println!("{t:celsius}");
+    println!("{t:kelvin}");
+}
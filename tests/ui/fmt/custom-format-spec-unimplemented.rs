@@ -0,0 +1,10 @@
+// Checks the error for a custom format spec on a type that doesn't parse format specs.
+
+#![feature(custom_format_spec)]
+
+struct Point;
+
+fn main() {
+    format!("{:polar}", Point);
+    //~^ ERROR `Point` doesn't support custom format specs
+}
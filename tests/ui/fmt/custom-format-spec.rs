@@ -0,0 +1,52 @@
+//@ run-pass
+// Checks that a type can parse its own format spec at compile time.
+
+#![feature(custom_format_spec)]
+
+use std::fmt::{self, CustomFormat, CustomSpec, ParseCustomSpec};
+
+struct Temperature(f64);
+
+#[derive(Debug, PartialEq)]
+enum Scale {
+    Celsius,
+    Fahrenheit,
+}
+
+impl CustomFormat for Temperature {
+    type Spec = Scale;
+
+    fn fmt_custom(&self, scale: &Scale, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match scale {
+            Scale::Celsius => format!("{}°C", self.0),
+            Scale::Fahrenheit => format!("{}°F", self.0 * 9.0 / 5.0 + 32.0),
+        };
+        f.pad(&s)
+    }
+}
+
+impl<S: CustomSpec> ParseCustomSpec<S> for Temperature {
+    const PARSED: Scale = match S::SPEC.as_bytes() {
+        b"celsius" | b"c" => Scale::Celsius,
+        b"fahrenheit" | b"f" => Scale::Fahrenheit,
+        _ => panic!("unknown temperature scale"),
+    };
+}
+
+fn main() {
+    let t = Temperature(100.0);
+    assert_eq!(format!("{t:celsius}"), "100°C");
+    assert_eq!(format!("{t:fahrenheit}"), "212°F");
+    assert_eq!(format!("{:c} {0:f} {0:c}", t), "100°C 212°F 100°C");
+    assert_eq!(format!("[{t:>8c}]"), "[   100°C]");
+    assert_eq!(format!("[{:<w$f}]", t, w = 7), "[212°F  ]");
+
+    // The built-in specs keep their meaning.
+    assert_eq!(format!("{:x} {:X?} {:?}", 255, [10u8], Scale::Celsius), "ff [A] Celsius");
+
+    // Custom specs in nested format_args are kept apart.
+    assert_eq!(
+        format!("{t:c} {}", format_args!("{t:f} {}", format_args!("{t:c}"))),
+        "100°C 212°F 100°C",
+    );
+}